@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+
+const LEN: usize = 1_000_000;
+
+fn f32_range(c: &mut Criterion) {
+    let mut group = c.benchmark_group("f32_range");
+
+    group.bench_function("single", |b| {
+        b.iter_batched_ref(
+            || (RngComponent::with_seed(5), vec![0.0f32; LEN]),
+            |(rng, out)| {
+                for value in out.iter_mut() {
+                    *value = rng.lerp_f32(-1.0, 1.0);
+                }
+                black_box(out);
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.bench_function("bulk", |b| {
+        b.iter_batched_ref(
+            || (RngComponent::with_seed(5), vec![0.0f32; LEN]),
+            |(rng, out)| {
+                rng.fill_f32_range(out, -1.0..1.0);
+                black_box(out);
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.finish();
+}
+
+fn u32_range(c: &mut Criterion) {
+    let mut group = c.benchmark_group("u32_range");
+
+    group.bench_function("single", |b| {
+        b.iter_batched_ref(
+            || (RngComponent::with_seed(5), vec![0u32; LEN]),
+            |(rng, out)| {
+                for value in out.iter_mut() {
+                    *value = rng.u32(10..1000);
+                }
+                black_box(out);
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.bench_function("bulk", |b| {
+        b.iter_batched_ref(
+            || (RngComponent::with_seed(5), vec![0u32; LEN]),
+            |(rng, out)| {
+                rng.fill_u32_range(out, 10..1000);
+                black_box(out);
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.finish();
+}
+
+fn vec2(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vec2");
+
+    group.bench_function("single", |b| {
+        b.iter_batched_ref(
+            || (RngComponent::with_seed(5), vec![Vec2::ZERO; LEN]),
+            |(rng, out)| {
+                for value in out.iter_mut() {
+                    *value = Vec2::new(rng.lerp_f32(0.0, 1920.0), rng.lerp_f32(0.0, 1080.0));
+                }
+                black_box(out);
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.bench_function("bulk", |b| {
+        b.iter_batched_ref(
+            || (RngComponent::with_seed(5), vec![Vec2::ZERO; LEN]),
+            |(rng, out)| {
+                rng.fill_vec2(out, 0.0..1920.0, 0.0..1080.0);
+                black_box(out);
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, f32_range, u32_range, vec2);
+criterion_main!(benches);
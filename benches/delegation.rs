@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const DRAWS: usize = 100_000;
+
+#[derive(Resource, Default)]
+struct Draws(Vec<u32>);
+
+fn u32_draws(c: &mut Criterion) {
+    let mut group = c.benchmark_group("u32_draws");
+
+    group.bench_function("rng", |b| {
+        let rng = Rng::with_seed(5);
+
+        b.iter(|| {
+            for _ in 0..DRAWS {
+                black_box(rng.u32(..));
+            }
+        });
+    });
+
+    group.bench_function("component", |b| {
+        let mut rng = RngComponent::with_seed(5);
+
+        b.iter(|| {
+            for _ in 0..DRAWS {
+                black_box(rng.u32(..));
+            }
+        });
+    });
+
+    group.bench_function("component_with_source", |b| {
+        let mut rng = RngComponent::with_seed(5);
+
+        b.iter(|| {
+            rng.with_source(|source| {
+                for _ in 0..DRAWS {
+                    black_box(source.u32(..));
+                }
+            });
+        });
+    });
+
+    group.bench_function("global", |b| {
+        let mut rng = GlobalRng::with_seed(5);
+
+        b.iter(|| {
+            for _ in 0..DRAWS {
+                black_box(rng.u32(..));
+            }
+        });
+    });
+
+    group.bench_function("global_with_source", |b| {
+        let mut rng = GlobalRng::with_seed(5);
+
+        b.iter(|| {
+            rng.with_source(|source| {
+                for _ in 0..DRAWS {
+                    black_box(source.u32(..));
+                }
+            });
+        });
+    });
+
+    group.finish();
+}
+
+fn app_draws(c: &mut Criterion) {
+    let mut group = c.benchmark_group("app_draws");
+
+    group.bench_function("delegated", |b| {
+        let mut app = App::new();
+
+        app.add_plugins(RngPlugin::new().with_rng_seed(5))
+            .init_resource::<Draws>()
+            .add_systems(
+                Update,
+                |mut global: ResMut<GlobalRng>, mut draws: ResMut<Draws>| {
+                    draws.0.clear();
+                    draws.0.extend((0..DRAWS).map(|_| global.u32(..)));
+                },
+            );
+
+        b.iter(|| app.update());
+    });
+
+    group.bench_function("with_source", |b| {
+        let mut app = App::new();
+
+        app.add_plugins(RngPlugin::new().with_rng_seed(5))
+            .init_resource::<Draws>()
+            .add_systems(
+                Update,
+                |mut global: ResMut<GlobalRng>, mut draws: ResMut<Draws>| {
+                    draws.0.clear();
+                    global.with_source(|source| {
+                        draws.0.extend((0..DRAWS).map(|_| source.u32(..)));
+                    });
+                },
+            );
+
+        b.iter(|| app.update());
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, u32_draws, app_draws);
+criterion_main!(benches);
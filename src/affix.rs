@@ -0,0 +1,154 @@
+use crate::*;
+
+/// An entry in an [`AffixRoller`] pool.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct AffixEntry<Id> {
+    /// The identifier of the affix, returned when it is rolled.
+    pub id: Id,
+    /// The relative weight of the affix. Affixes with a weight of `0.0` or less are never rolled.
+    pub weight: f64,
+    /// The mutually exclusive group of the affix. At most one affix is rolled per group.
+    pub group: u32,
+}
+
+/// The outcome of [`AffixRoller::roll`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RolledAffixes<Id> {
+    /// The rolled affixes, in the order they were rolled.
+    pub affixes: Vec<Id>,
+    /// Whether as many affixes as requested could be rolled. If `false`, the pool ran out
+    /// of groups to roll from, and `affixes` contains as many as could be rolled.
+    pub complete: bool,
+}
+
+/// A weighted roller for picking a set of affixes from a pool, such as for item generation,
+/// where each affix belongs to a mutually exclusive group. Once an affix is rolled, its whole
+/// group is removed from the pool and the weights of the remaining affixes renormalised, so that
+/// no duplicates or two affixes from the same group are ever rolled.
+///
+/// Unlike [`DelegatedRng::weighted_sample`], weights are relative to each other and are not
+/// limited to `0.0..=1.0`. Rolls are deterministic for a given RNG state and pool order.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Affix {
+///     Sharp,
+///     Keen,
+///     Fiery,
+///     Frozen,
+///     Sturdy,
+/// }
+///
+/// const DAMAGE: u32 = 0;
+/// const ELEMENT: u32 = 1;
+/// const DEFENCE: u32 = 2;
+///
+/// let roller = AffixRoller::new()
+///     .with_affix(Affix::Sharp, 10.0, DAMAGE)
+///     .with_affix(Affix::Keen, 5.0, DAMAGE)
+///     .with_affix(Affix::Fiery, 3.0, ELEMENT)
+///     .with_affix(Affix::Frozen, 3.0, ELEMENT)
+///     .with_affix(Affix::Sturdy, 8.0, DEFENCE);
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let rolled = roller.roll(2, &mut rng);
+///
+/// assert!(rolled.complete);
+/// assert_eq!(rolled.affixes.len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(transparent))]
+pub struct AffixRoller<Id> {
+    entries: Vec<AffixEntry<Id>>,
+}
+
+impl<Id> AffixRoller<Id> {
+    /// Creates a new, empty [`AffixRoller`].
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Builder function to add an affix to the pool.
+    #[inline]
+    #[must_use]
+    pub fn with_affix(mut self, id: Id, weight: f64, group: u32) -> Self {
+        self.add_affix(id, weight, group);
+        self
+    }
+
+    /// Adds an affix to the pool.
+    #[inline]
+    pub fn add_affix(&mut self, id: Id, weight: f64, group: u32) {
+        self.entries.push(AffixEntry { id, weight, group });
+    }
+
+    /// Returns the affixes in the pool.
+    #[inline]
+    #[must_use]
+    pub fn entries(&self) -> &[AffixEntry<Id>] {
+        &self.entries
+    }
+
+    /// Rolls up to `count` affixes from the pool, with at most one affix per group.
+    pub fn roll(&self, count: usize, rng: &mut impl DelegatedRng) -> RolledAffixes<Id>
+    where
+        Id: Clone,
+    {
+        let mut available: Vec<&AffixEntry<Id>> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.weight > 0.0 && entry.weight.is_finite())
+            .collect();
+
+        let mut affixes = Vec::with_capacity(count);
+
+        while affixes.len() < count && !available.is_empty() {
+            let total: f64 = available.iter().map(|entry| entry.weight).sum();
+            let mut target = rng.get_mut().f64() * total;
+
+            // Falls back to the last entry should rounding leave the target past the total.
+            let picked = available
+                .iter()
+                .position(|entry| {
+                    target -= entry.weight;
+                    target < 0.0
+                })
+                .unwrap_or(available.len() - 1);
+
+            let group = available[picked].group;
+
+            affixes.push(available[picked].id.clone());
+            available.retain(|entry| entry.group != group);
+        }
+
+        RolledAffixes {
+            complete: affixes.len() == count,
+            affixes,
+        }
+    }
+}
+
+impl<Id> Default for AffixRoller<Id> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Id> FromIterator<AffixEntry<Id>> for AffixRoller<Id> {
+    fn from_iter<I: IntoIterator<Item = AffixEntry<Id>>>(iter: I) -> Self {
+        Self {
+            entries: iter.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,376 @@
+use crate::*;
+use bevy_ecs::{component::ComponentId, world::DeferredWorld};
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    collections::VecDeque,
+    fmt,
+    io::{self, Write},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+/// Default amount of entries an [`RngAuditLog`] will keep before discarding the oldest ones.
+pub const DEFAULT_AUDIT_CAPACITY: usize = 4096;
+
+thread_local! {
+    static AUDIT_LABEL: RefCell<Option<Cow<'static, str>>> = const { RefCell::new(None) };
+}
+
+/// The outcome of an audited probability call.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "audit")))]
+pub enum AuditOutcome {
+    /// Outcome of a pass/fail check, such as [`DelegatedRng::chance`].
+    Bool(bool),
+    /// Index of the selected element of a weighted sample, or `None` if nothing
+    /// could be selected.
+    Index(Option<usize>),
+}
+
+impl fmt::Display for AuditOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(outcome) => write!(f, "{outcome}"),
+            Self::Index(Some(index)) => write!(f, "{index}"),
+            Self::Index(None) => f.write_str("none"),
+        }
+    }
+}
+
+/// A single recorded probability call.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "audit")))]
+pub struct AuditEntry {
+    /// The label that was active on the calling thread, usually a system name.
+    /// See [`audit_label`].
+    pub label: Option<Cow<'static, str>>,
+    /// The name of the delegated method that was called.
+    pub method: &'static str,
+    /// The input probability. For weighted samples, this is the normalised
+    /// probability of the selected element.
+    pub input: f64,
+    /// The result of the call.
+    pub outcome: AuditOutcome,
+}
+
+#[derive(Debug)]
+struct AuditBuffer {
+    entries: VecDeque<AuditEntry>,
+    capacity: usize,
+    dropped: u64,
+    recording: bool,
+}
+
+impl AuditBuffer {
+    fn push(&mut self, entry: AuditEntry) {
+        if !self.recording {
+            return;
+        }
+
+        if self.capacity == 0 {
+            self.dropped += 1;
+            return;
+        }
+
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+            self.dropped += 1;
+        }
+
+        self.entries.push_back(entry);
+    }
+}
+
+/// A bounded log of every probability-bearing call made through [`DelegatedRng`]
+/// (`chance`, `ratio`, `weighted_sample` and `weighted_sample_mut`), for validating the
+/// perceived fairness of a play session.
+///
+/// The log is a cheap handle to shared storage, so it can be cloned out of the
+/// `App` and inspected/exported from anywhere. Each RNG wrapper records into the log it
+/// holds a handle to, so every `App` keeps its own log, even when several run in the same
+/// process. [`RngPlugin`] inserts one automatically, and hands it to the [`GlobalRng`] and
+/// [`GlobalChaChaRng`] resources as well as to every [`RngComponent`] and
+/// [`ChaChaRngComponent`] added to the world while the log is present. Wrappers created
+/// outside of a world, or in a world without a log, record nothing.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn export_audit(log: Res<RngAuditLog>) {
+///     let mut csv = Vec::new();
+///
+///     log.write_csv(&mut csv).unwrap();
+/// }
+/// ```
+#[derive(Debug, Clone, Resource)]
+#[cfg_attr(docsrs, doc(cfg(feature = "audit")))]
+pub struct RngAuditLog {
+    inner: Arc<Mutex<AuditBuffer>>,
+}
+
+impl RngAuditLog {
+    /// Create a new [`RngAuditLog`] that keeps at most `capacity` entries,
+    /// discarding the oldest ones once full.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(AuditBuffer {
+                entries: VecDeque::with_capacity(capacity.min(DEFAULT_AUDIT_CAPACITY)),
+                capacity,
+                dropped: 0,
+                recording: true,
+            })),
+        }
+    }
+
+    /// Sets whether the log records calls, such as for only auditing parts of a session.
+    /// Calls made while not recording are discarded without counting as dropped.
+    pub fn set_recording(&self, recording: bool) {
+        self.lock().recording = recording;
+    }
+
+    /// Returns whether the log records calls, which it does by default.
+    #[must_use]
+    pub fn is_recording(&self) -> bool {
+        self.lock().recording
+    }
+
+    /// Returns a copy of all the currently retained entries, oldest first.
+    #[must_use]
+    pub fn entries(&self) -> Vec<AuditEntry> {
+        self.lock().entries.iter().cloned().collect()
+    }
+
+    /// The amount of currently retained entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Returns `true` if no entries are retained.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().entries.is_empty()
+    }
+
+    /// The maximum amount of entries that are retained.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.lock().capacity
+    }
+
+    /// The amount of entries that were discarded due to the log being full.
+    #[must_use]
+    pub fn dropped(&self) -> u64 {
+        self.lock().dropped
+    }
+
+    /// Discards all retained entries and resets the dropped count.
+    pub fn clear(&self) {
+        let mut buffer = self.lock();
+        buffer.entries.clear();
+        buffer.dropped = 0;
+    }
+
+    /// Exports all retained entries as CSV, with a `label,method,input,outcome`
+    /// header row. Entries recorded without a label have an empty label column.
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "label,method,input,outcome")?;
+
+        for entry in self.lock().entries.iter() {
+            writeln!(
+                writer,
+                "{},{},{},{}",
+                entry.label.as_deref().map(escape_csv).unwrap_or_default(),
+                entry.method,
+                entry.input,
+                entry.outcome
+            )?;
+        }
+
+        writer.flush()
+    }
+
+    #[cold]
+    #[inline(never)]
+    pub(crate) fn record(&self, method: &'static str, input: f64, outcome: AuditOutcome) {
+        let label = AUDIT_LABEL.with(|label| label.borrow().clone());
+
+        self.lock().push(AuditEntry {
+            label,
+            method,
+            input,
+            outcome,
+        });
+    }
+
+    /// Records a weighted sample of the element at `index`, given the `weights` of all
+    /// elements, along with its normalised probability.
+    #[cold]
+    #[inline(never)]
+    pub(crate) fn record_weighted(
+        &self,
+        method: &'static str,
+        weights: &[f64],
+        index: Option<usize>,
+    ) {
+        let total: f64 = weights.iter().sum();
+        let input = index.map_or(0.0, |index| weights[index] / total);
+
+        self.record(method, input, AuditOutcome::Index(index));
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, AuditBuffer> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for RngAuditLog {
+    /// Creates a [`RngAuditLog`] with [`DEFAULT_AUDIT_CAPACITY`].
+    fn default() -> Self {
+        Self::new(DEFAULT_AUDIT_CAPACITY)
+    }
+}
+
+/// Guard returned by [`audit_label`]. Restores the previous label once dropped.
+#[derive(Debug)]
+#[must_use = "the label is removed as soon as the guard is dropped"]
+#[cfg_attr(docsrs, doc(cfg(feature = "audit")))]
+pub struct AuditLabelGuard {
+    previous: Option<Cow<'static, str>>,
+}
+
+impl Drop for AuditLabelGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        AUDIT_LABEL.with(|label| *label.borrow_mut() = previous);
+    }
+}
+
+/// Labels all audited calls made on the current thread until the returned guard is dropped.
+/// Bevy does not expose the running system to the RNG, so systems that want their calls
+/// attributed should set a label at the start of the system.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn loot_drop(mut q_chest: Query<&mut RngComponent>) {
+///     let _label = audit_label("loot_drop");
+///
+///     for mut rng in q_chest.iter_mut() {
+///         let _dropped = rng.chance(0.25);
+///     }
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "audit")))]
+pub fn audit_label(label: impl Into<Cow<'static, str>>) -> AuditLabelGuard {
+    let previous = AUDIT_LABEL.with(|current| current.borrow_mut().replace(label.into()));
+
+    AuditLabelGuard { previous }
+}
+
+/// The [`RngAuditLog`] an RNG wrapper records into, if it was handed one.
+#[derive(Clone, Default)]
+pub(crate) struct AuditSink(Option<RngAuditLog>);
+
+impl AuditSink {
+    pub(crate) const DETACHED: Self = Self(None);
+
+    #[inline]
+    pub(crate) fn log(&self) -> Option<&RngAuditLog> {
+        self.0.as_ref()
+    }
+
+    #[inline]
+    fn attach(&mut self, log: RngAuditLog) {
+        self.0 = Some(log);
+    }
+}
+
+impl fmt::Debug for AuditSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AuditSink").field(&self.0.is_some()).finish()
+    }
+}
+
+/// RNG wrappers that can be handed an [`RngAuditLog`] to record into.
+pub(crate) trait AuditedRng {
+    fn audit_sink_mut(&mut self) -> &mut AuditSink;
+}
+
+/// Collects the weights of a list prior to it being sampled, evaluating `weight_sampler`
+/// once per element, so that the probability of the selected element can be recorded.
+#[cold]
+#[inline(never)]
+pub(crate) fn weights<T>(list: &[T], weight_sampler: impl Fn((&T, usize)) -> f64) -> Vec<f64> {
+    list.iter()
+        .enumerate()
+        .map(|(index, item)| weight_sampler((item, index)))
+        .collect()
+}
+
+/// Samples an index from precomputed `weights`, drawing exactly what
+/// [`TurboRand::weighted_sample`] draws for a list with the same weights.
+#[cold]
+#[inline(never)]
+pub(crate) fn weighted_index(source: &impl TurboRand, weights: &[f64]) -> Option<usize> {
+    let indices: Vec<usize> = (0..weights.len()).collect();
+
+    source
+        .weighted_sample(&indices, |(_, index)| weights[index])
+        .copied()
+}
+
+/// Hands the world's [`RngAuditLog`], if any, to an RNG component as it is added.
+pub(crate) fn attach_on_add<C: Component + AuditedRng>(
+    mut world: DeferredWorld<'_>,
+    entity: Entity,
+    _: ComponentId,
+) {
+    let Some(log) = world.get_resource::<RngAuditLog>().cloned() else {
+        return;
+    };
+
+    if let Some(mut rng) = world.get_mut::<C>(entity) {
+        rng.bypass_change_detection().audit_sink_mut().attach(log);
+    }
+}
+
+/// Hands the [`RngAuditLog`] of `world`, if any, to the global RNG resource `R`.
+pub(crate) fn attach_in_world<R: Resource + AuditedRng>(world: &mut World) {
+    let Some(log) = world.get_resource::<RngAuditLog>().cloned() else {
+        return;
+    };
+
+    if let Some(mut global) = world.get_resource_mut::<R>() {
+        global
+            .bypass_change_detection()
+            .audit_sink_mut()
+            .attach(log);
+    }
+}
+
+/// Hands the world's [`RngAuditLog`] to the global RNG resource `R`.
+pub(crate) fn attach_to_resource<R: Resource + AuditedRng>(
+    log: Res<'_, RngAuditLog>,
+    global: Option<ResMut<'_, R>>,
+) {
+    if let Some(mut global) = global {
+        global
+            .bypass_change_detection()
+            .audit_sink_mut()
+            .attach(log.clone());
+    }
+}
+
+fn escape_csv(label: &str) -> Cow<'_, str> {
+    if label.contains([',', '"', '\n']) {
+        Cow::Owned(format!("\"{}\"", label.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(label)
+    }
+}
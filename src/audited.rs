@@ -0,0 +1,208 @@
+use crate::*;
+use std::{collections::VecDeque, marker::PhantomData};
+use tracing::debug;
+
+/// The default amount of past ticks an [`AuditedRng`] keeps the call counts of.
+pub const DEFAULT_TICK_HISTORY: usize = 64;
+
+/// A thin wrapper around any [`DelegatedRng`] that forwards every call to it as is, while
+/// counting the calls made through it during each fixed tick. Meant for catching systems that
+/// silently desync a deterministic replay, such as by being moved between [`Update`] and
+/// [`FixedUpdate`] and so running a different amount of times per tick.
+///
+/// Every method of [`DelegatedRng`] counts as one call, as does every use of
+/// [`DelegatedRng::get_mut`]. With the [`AuditedRngPlugin`] added for the wrapped type, the
+/// count is recorded into the wrapper's history and reset at the end of every fixed tick, in
+/// [`FixedLast`]. Usable both as a [`Component`] and as a [`Resource`].
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// let mut rng = AuditedRng::new(RngComponent::with_seed(5));
+///
+/// let value = rng.u32(..);
+///
+/// rng.assert_draw_count(1);
+/// assert_eq!(value, RngComponent::with_seed(5).u32(..));
+///
+/// rng.end_tick();
+///
+/// rng.assert_draw_count(0);
+/// assert_eq!(rng.tick_history().collect::<Vec<_>>(), [1]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Component, Resource)]
+pub struct AuditedRng<T: DelegatedRng + Send + Sync + 'static> {
+    rng: T,
+    draws: u64,
+    history: VecDeque<u64>,
+    capacity: usize,
+}
+
+impl<T: DelegatedRng + Send + Sync + 'static> AuditedRng<T> {
+    /// Wraps the given RNG, keeping the counts of the last [`DEFAULT_TICK_HISTORY`] ticks.
+    #[inline]
+    #[must_use]
+    pub fn new(rng: T) -> Self {
+        Self::with_history(rng, DEFAULT_TICK_HISTORY)
+    }
+
+    /// Wraps the given RNG, keeping the counts of the given amount of past ticks.
+    #[inline]
+    #[must_use]
+    pub fn with_history(rng: T, capacity: usize) -> Self {
+        Self {
+            rng,
+            draws: 0,
+            history: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Returns the amount of calls made through the wrapper during the current tick.
+    #[inline]
+    #[must_use]
+    pub const fn draw_count(&self) -> u64 {
+        self.draws
+    }
+
+    /// Asserts that exactly `expected` calls were made through the wrapper during the
+    /// current tick.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the amount of calls made differs from `expected`.
+    #[inline]
+    #[track_caller]
+    pub fn assert_draw_count(&self, expected: u64) {
+        assert_eq!(
+            self.draws, expected,
+            "expected {expected} RNG calls this tick, but {} were made",
+            self.draws
+        );
+    }
+
+    /// Returns the amount of calls made during each of the recorded past ticks, from the
+    /// oldest to the most recent.
+    #[inline]
+    pub fn tick_history(&self) -> impl Iterator<Item = u64> + '_ {
+        self.history.iter().copied()
+    }
+
+    /// Records the amount of calls made during the current tick into the history, and starts
+    /// counting a new tick. Called at the end of every fixed tick by the
+    /// [`AuditedRngPlugin`].
+    pub fn end_tick(&mut self) {
+        if self.capacity > 0 {
+            if self.history.len() == self.capacity {
+                self.history.pop_front();
+            }
+
+            self.history.push_back(self.draws);
+        }
+
+        self.draws = 0;
+    }
+
+    /// Returns a reference to the wrapped RNG, without counting a call.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &T {
+        &self.rng
+    }
+
+    /// Consumes the [`AuditedRng`], returning the wrapped RNG with its state intact.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.rng
+    }
+}
+
+impl<T: DelegatedRng + Send + Sync + 'static> DelegatedRng for AuditedRng<T> {
+    type Source = T::Source;
+
+    #[inline]
+    fn get_mut(&mut self) -> &mut Self::Source {
+        self.draws += 1;
+
+        self.rng.get_mut()
+    }
+}
+
+impl<T: DelegatedRng + Default + Send + Sync + 'static> Default for AuditedRng<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+/// A [`Plugin`] ending the tick of every [`AuditedRng`] wrapping a `T`, both as components
+/// and as a resource, at the end of every fixed tick in [`FixedLast`]. In debug builds, the
+/// calls made during each tick are also logged at the `debug` level.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// App::new()
+///     .add_plugins(AuditedRngPlugin::<GlobalRng>::new())
+///     .insert_resource(AuditedRng::new(GlobalRng::with_seed(5)));
+/// ```
+#[derive(Debug)]
+pub struct AuditedRngPlugin<T> {
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> AuditedRngPlugin<T> {
+    /// Creates a new [`AuditedRngPlugin`].
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for AuditedRngPlugin<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: DelegatedRng + Send + Sync + 'static> Plugin for AuditedRngPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedLast, end_audited_ticks::<T>);
+    }
+}
+
+fn end_audited_ticks<T: DelegatedRng + Send + Sync + 'static>(
+    mut q_audited: Query<'_, '_, (Entity, &mut AuditedRng<T>)>,
+    resource: Option<ResMut<'_, AuditedRng<T>>>,
+) {
+    if let Some(mut audited) = resource {
+        #[cfg(debug_assertions)]
+        debug!(
+            "{} calls this tick: {}",
+            std::any::type_name::<T>(),
+            audited.draws
+        );
+
+        audited.end_tick();
+    }
+
+    for (_entity, mut audited) in q_audited.iter_mut() {
+        #[cfg(debug_assertions)]
+        debug!(
+            "{} calls this tick on {_entity}: {}",
+            std::any::type_name::<T>(),
+            audited.draws
+        );
+
+        audited.end_tick();
+    }
+}
@@ -0,0 +1,117 @@
+use crate::*;
+
+/// An authoring [`Component`] for giving an entity a plain seed in a scene, such as one
+/// written by a level editor, instead of the serialized internal state of an
+/// [`RngComponent`]. When [`RngPlugin`] sees an entity with an [`RngSeed`] but no
+/// [`RngComponent`], it inserts an [`RngComponent::with_seed`] with the seed, and removes
+/// the [`RngSeed`] unless [`RngPlugin::keep_seed_components`] is set. An entity that
+/// already has an [`RngComponent`] keeps it, and its [`RngSeed`] is left as is.
+///
+/// The seed is resolved in [`PreUpdate`], before the [`RngSystems::ForkOnSpawn`] set.
+///
+/// # Example
+/// A scene entity authored with a seed, in RON:
+/// ```ron
+/// {
+///     "bevy_turborand::authoring::RngSeed": (12345),
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Component, Reflect)]
+#[cfg(feature = "wyrand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, Default, PartialEq, Hash, Component, Serialize, Deserialize)
+)]
+#[cfg_attr(
+    not(feature = "serialize"),
+    reflect(Debug, Default, PartialEq, Hash, Component)
+)]
+pub struct RngSeed(pub u64);
+
+/// An authoring [`Component`] for giving an entity a plain seed in a scene, resolved into a
+/// [`ChaChaRngComponent`] the same way an [`RngSeed`] is resolved into an [`RngComponent`].
+/// The seed is serialized as a list of 40 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component, Reflect)]
+#[cfg(feature = "chacha")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, Default, PartialEq, Hash, Component, Serialize, Deserialize)
+)]
+#[cfg_attr(
+    not(feature = "serialize"),
+    reflect(Debug, Default, PartialEq, Hash, Component)
+)]
+pub struct ChaChaSeed(#[cfg_attr(feature = "serialize", serde(with = "seed_bytes"))] pub [u8; 40]);
+
+#[cfg(feature = "chacha")]
+impl Default for ChaChaSeed {
+    #[inline]
+    fn default() -> Self {
+        Self([0; 40])
+    }
+}
+
+/// Resolves the [`RngSeed`]s of entities without an [`RngComponent`].
+#[cfg(feature = "wyrand")]
+pub(crate) fn resolve_rng_seeds(
+    keep: bool,
+    mut commands: Commands<'_, '_>,
+    q_seeds: Query<'_, '_, (Entity, &RngSeed), Without<RngComponent>>,
+) {
+    for (entity, seed) in q_seeds.iter() {
+        let mut entity = commands.entity(entity);
+
+        entity.insert(RngComponent::with_seed(seed.0));
+
+        if !keep {
+            entity.remove::<RngSeed>();
+        }
+    }
+}
+
+/// Resolves the [`ChaChaSeed`]s of entities without a [`ChaChaRngComponent`].
+#[cfg(feature = "chacha")]
+pub(crate) fn resolve_chacha_seeds(
+    keep: bool,
+    mut commands: Commands<'_, '_>,
+    q_seeds: Query<'_, '_, (Entity, &ChaChaSeed), Without<ChaChaRngComponent>>,
+) {
+    for (entity, seed) in q_seeds.iter() {
+        let mut entity = commands.entity(entity);
+
+        entity.insert(ChaChaRngComponent::with_seed(seed.0));
+
+        if !keep {
+            entity.remove::<ChaChaSeed>();
+        }
+    }
+}
+
+/// Serializes a 40 byte seed as a list of bytes, as serde only supports arrays of up to 32
+/// elements.
+#[cfg(all(feature = "chacha", feature = "serialize"))]
+mod seed_bytes {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        seed: &[u8; 40],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(seed)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[u8; 40], D::Error> {
+        let bytes = Vec::<u8>::deserialize(deserializer)?;
+        let len = bytes.len();
+
+        bytes
+            .try_into()
+            .map_err(|_| D::Error::invalid_length(len, &"a seed of 40 bytes"))
+    }
+}
@@ -0,0 +1,247 @@
+use crate::*;
+use bevy_app::MainScheduleOrder;
+use bevy_diagnostic::DiagnosticPath;
+use bevy_ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use tracing::error;
+
+/// What happens when an RNG wrapper exceeds its [`RngBudget`] within a frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "diagnostics")))]
+pub enum RngBudgetAction {
+    /// Logs an error and sends an [`RngBudgetExceeded`] event.
+    #[default]
+    Log,
+    /// Panics in debug builds, and otherwise behaves like [`RngBudgetAction::Log`].
+    PanicInDebug,
+}
+
+/// An opt-in limit on how many calls can be made through each RNG wrapper per frame, for
+/// catching systems that accidentally draw far more than intended, such as from within an
+/// inner loop.
+///
+/// Calls are counted the same way as `call_count()` on the wrappers, with each limit keyed by
+/// the `CALLS_DIAGNOSTIC` path of its wrapper. The counts are checked after every schedule of
+/// the main loop, so that the first check to find a wrapper over its limit can name the
+/// schedule in which it happened. Each wrapper is reported at most once per frame, via an
+/// [`RngBudgetExceeded`] event and the configured [`RngBudgetAction`], with the frame counts
+/// starting over right before [`First`].
+///
+/// The checks are registered by [`RngPlugin`], and only do anything while this resource
+/// is present.
+///
+/// The counts are shared by the whole process, not kept per [`World`], so with several
+/// [`App`]s in one process, such as a server and a client, or tests running in parallel,
+/// the draws of one app count towards the budget of every other, and resetting the counts
+/// in one app resets them for all.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn hot_loop(mut global: ResMut<GlobalRng>) {
+///     for _ in 0..100 {
+///         global.u32(..);
+///     }
+/// }
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins(RngPlugin::new().with_rng_seed(1))
+///     .insert_resource(RngBudget::new().with_limit(GlobalRng::CALLS_DIAGNOSTIC, 10))
+///     .add_systems(Update, hot_loop);
+///
+/// app.update();
+///
+/// let events = app.world().resource::<Events<RngBudgetExceeded>>();
+///
+/// assert_eq!(events.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default, Resource)]
+#[cfg_attr(docsrs, doc(cfg(feature = "diagnostics")))]
+pub struct RngBudget {
+    limits: Vec<BudgetLimit>,
+    action: RngBudgetAction,
+}
+
+#[derive(Debug, Clone)]
+struct BudgetLimit {
+    path: DiagnosticPath,
+    max_calls: u64,
+    frame_start: u64,
+    reported: bool,
+}
+
+impl RngBudget {
+    /// Creates a new [`RngBudget`] without any limits, logging any violations.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            limits: Vec::new(),
+            action: RngBudgetAction::Log,
+        }
+    }
+
+    /// Builder function to limit the calls made per frame through the RNG wrapper with the
+    /// given `CALLS_DIAGNOSTIC` path, such as [`GlobalRng::CALLS_DIAGNOSTIC`]. Replaces any
+    /// previous limit for the same wrapper.
+    #[inline]
+    #[must_use]
+    pub fn with_limit(mut self, path: DiagnosticPath, max_calls: u64) -> Self {
+        self.set_limit(path, max_calls);
+        self
+    }
+
+    /// Builder function to set what happens when a wrapper exceeds its limit.
+    #[inline]
+    #[must_use]
+    pub const fn with_action(mut self, action: RngBudgetAction) -> Self {
+        self.action = action;
+        self
+    }
+
+    /// Limits the calls made per frame through the RNG wrapper with the given
+    /// `CALLS_DIAGNOSTIC` path. Replaces any previous limit for the same wrapper.
+    pub fn set_limit(&mut self, path: DiagnosticPath, max_calls: u64) {
+        let frame_start = crate::diagnostics::current_count(&path).unwrap_or(0);
+
+        match self.limits.iter_mut().find(|limit| limit.path == path) {
+            Some(limit) => limit.max_calls = max_calls,
+            None => self.limits.push(BudgetLimit {
+                path,
+                max_calls,
+                frame_start,
+                reported: false,
+            }),
+        }
+    }
+
+    /// Returns the per frame limit for the RNG wrapper with the given `CALLS_DIAGNOSTIC` path,
+    /// if it has one.
+    #[inline]
+    #[must_use]
+    pub fn limit(&self, path: &DiagnosticPath) -> Option<u64> {
+        self.limits
+            .iter()
+            .find(|limit| limit.path == *path)
+            .map(|limit| limit.max_calls)
+    }
+
+    /// Returns what happens when a wrapper exceeds its limit.
+    #[inline]
+    #[must_use]
+    pub const fn action(&self) -> RngBudgetAction {
+        self.action
+    }
+}
+
+/// Sent when an RNG wrapper exceeds its [`RngBudget`] within a frame.
+#[derive(Debug, Clone, Event)]
+#[cfg_attr(docsrs, doc(cfg(feature = "diagnostics")))]
+pub struct RngBudgetExceeded {
+    /// The `CALLS_DIAGNOSTIC` path of the wrapper over its limit.
+    pub wrapper: DiagnosticPath,
+    /// The schedule during which the limit was exceeded.
+    pub schedule: InternedScheduleLabel,
+    /// The calls made through the wrapper so far this frame.
+    pub calls: u64,
+    /// The limit of the wrapper.
+    pub max_calls: u64,
+}
+
+/// A schedule run by the main loop right before [`First`], starting a new frame of counts.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, ScheduleLabel)]
+struct BudgetFrameStart;
+
+/// A schedule run by the main loop right after the given schedule, checking the budget.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, ScheduleLabel)]
+struct BudgetCheck(InternedScheduleLabel);
+
+/// Adds a budget check after every schedule of the main loop.
+pub(crate) fn register_budget_checks(app: &mut App) {
+    app.add_event::<RngBudgetExceeded>();
+
+    let Some(labels) = app
+        .world()
+        .get_resource::<MainScheduleOrder>()
+        .map(|order| order.labels.clone())
+    else {
+        return;
+    };
+
+    app.add_systems(BudgetFrameStart, start_budget_frame);
+    app.world_mut()
+        .resource_mut::<MainScheduleOrder>()
+        .insert_before(First, BudgetFrameStart);
+
+    for label in labels {
+        app.add_systems(
+            BudgetCheck(label),
+            move |budget: Option<ResMut<'_, RngBudget>>,
+                  events: EventWriter<'_, RngBudgetExceeded>| {
+                check_budget(label, budget, events);
+            },
+        );
+        app.world_mut()
+            .resource_mut::<MainScheduleOrder>()
+            .insert_after(label, BudgetCheck(label));
+    }
+}
+
+fn start_budget_frame(budget: Option<ResMut<'_, RngBudget>>) {
+    let Some(mut budget) = budget else {
+        return;
+    };
+
+    for limit in &mut budget.limits {
+        limit.frame_start = crate::diagnostics::current_count(&limit.path).unwrap_or(0);
+        limit.reported = false;
+    }
+}
+
+fn check_budget(
+    schedule: InternedScheduleLabel,
+    budget: Option<ResMut<'_, RngBudget>>,
+    mut events: EventWriter<'_, RngBudgetExceeded>,
+) {
+    let Some(mut budget) = budget else {
+        return;
+    };
+
+    let action = budget.action;
+
+    for limit in budget.limits.iter_mut().filter(|limit| !limit.reported) {
+        let Some(current) = crate::diagnostics::current_count(&limit.path) else {
+            continue;
+        };
+
+        // Counts reset mid-frame are treated as having started over.
+        let calls = current.checked_sub(limit.frame_start).unwrap_or(current);
+
+        if calls <= limit.max_calls {
+            continue;
+        }
+
+        limit.reported = true;
+
+        if action == RngBudgetAction::PanicInDebug && cfg!(debug_assertions) {
+            panic!(
+                "{} made {calls} calls during {schedule:?}, over its budget of {} per frame",
+                limit.path, limit.max_calls
+            );
+        }
+
+        error!(
+            "{} made {calls} calls during {schedule:?}, over its budget of {} per frame",
+            limit.path, limit.max_calls
+        );
+
+        events.send(RngBudgetExceeded {
+            wrapper: limit.path.clone(),
+            schedule,
+            calls,
+            max_calls: limit.max_calls,
+        });
+    }
+}
@@ -0,0 +1,153 @@
+use crate::*;
+
+/// A buffered stream of random bytes, borrowed from a [`DelegatedRng`] with
+/// [`DelegatedRng::byte_stream`], for consuming bytes a few at a time without a
+/// [`TurboCore::fill_bytes`] call for each, such as when generating procedural textures.
+/// Bytes are generated in chunks of a fixed size, each with a single
+/// [`TurboCore::fill_bytes`] call, and only once the buffered bytes run out.
+///
+/// The stream always yields bytes in the order they were generated, whether through
+/// [`RandomByteStream::next_u8`], [`RandomByteStream::take_bytes`], [`Iterator`] or
+/// [`std::io::Read`]. For a chunk size that is a multiple of 8 bytes, the stream yields the
+/// same bytes as one big [`TurboCore::fill_bytes`] call would, as `Rng` generates bytes 8 at
+/// a time, discarding any left over from a call. `ChaChaRng` buffers its leftover bytes
+/// instead, so it yields the same bytes for any chunk size.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// let mut rng = RngComponent::with_seed(5);
+/// let mut stream = rng.byte_stream(256);
+///
+/// let pixels: Vec<[u8; 3]> = (0..64)
+///     .map(|_| [stream.next_u8(), stream.next_u8(), stream.next_u8()])
+///     .collect();
+///
+/// let noise = stream.take_bytes(16);
+///
+/// assert_eq!(noise.len(), 16);
+/// ```
+#[derive(Debug)]
+pub struct RandomByteStream<'a, S: TurboCore> {
+    rng: &'a mut S,
+    chunk: usize,
+    buffer: Vec<u8>,
+    position: usize,
+}
+
+impl<'a, S: TurboCore> RandomByteStream<'a, S> {
+    /// Creates a new [`RandomByteStream`] drawing from `rng` in chunks of `chunk` bytes. No
+    /// bytes are generated until they are first asked for.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk` is zero.
+    #[inline]
+    #[must_use]
+    pub fn new(rng: &'a mut S, chunk: usize) -> Self {
+        assert!(chunk > 0, "byte stream chunk size must not be zero");
+
+        Self {
+            rng,
+            chunk,
+            buffer: Vec::new(),
+            position: 0,
+        }
+    }
+
+    /// Returns the next random byte, generating a new chunk if the buffer has run out.
+    #[inline]
+    pub fn next_u8(&mut self) -> u8 {
+        if self.position == self.buffer.len() {
+            self.refill();
+        }
+
+        let byte = self.buffer[self.position];
+        self.position += 1;
+        byte
+    }
+
+    /// Returns the next `n` random bytes, generating as many new chunks as needed to serve
+    /// them. The bytes still buffered are served first, so no bytes are skipped. Not named
+    /// `take`, as [`Iterator::take`] and [`std::io::Read::take`] would shadow it.
+    pub fn take_bytes(&mut self, n: usize) -> &[u8] {
+        if self.remaining() < n {
+            self.buffer.drain(..self.position);
+            self.position = 0;
+
+            while self.buffer.len() < n {
+                let start = self.buffer.len();
+
+                self.buffer.resize(start + self.chunk, 0);
+                self.rng.fill_bytes(&mut self.buffer[start..]);
+            }
+        }
+
+        let start = self.position;
+        self.position += n;
+
+        &self.buffer[start..self.position]
+    }
+
+    /// Discards any buffered bytes and generates a new chunk in their place.
+    pub fn refill(&mut self) {
+        self.buffer.clear();
+        self.buffer.resize(self.chunk, 0);
+        self.rng.fill_bytes(&mut self.buffer);
+        self.position = 0;
+    }
+
+    /// Returns how many generated bytes are still buffered.
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.buffer.len() - self.position
+    }
+
+    /// Returns the amount of bytes generated per chunk.
+    #[inline]
+    #[must_use]
+    pub fn chunk(&self) -> usize {
+        self.chunk
+    }
+}
+
+impl<S: TurboCore> Iterator for RandomByteStream<'_, S> {
+    type Item = u8;
+
+    /// Returns the next random byte, the same as [`RandomByteStream::next_u8`]. The stream
+    /// never ends.
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_u8())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<S: TurboCore> std::io::Read for RandomByteStream<'_, S> {
+    /// Fills all of `buf` with random bytes, never failing nor reading short.
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut filled = 0;
+
+        while filled < buf.len() {
+            if self.remaining() == 0 {
+                self.refill();
+            }
+
+            let amount = self.remaining().min(buf.len() - filled);
+            let start = self.position;
+
+            buf[filled..filled + amount].copy_from_slice(&self.buffer[start..start + amount]);
+
+            self.position += amount;
+            filled += amount;
+        }
+
+        Ok(filled)
+    }
+}
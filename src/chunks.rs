@@ -0,0 +1,188 @@
+use crate::{hash::mix, *};
+use std::collections::VecDeque;
+
+/// The key of a cached chunk, keeping 2D and 3D coordinates apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChunkKey {
+    Flat(IVec2),
+    Volume(IVec3),
+}
+
+/// A [`Resource`] providing each chunk of a streamed world with its own RNG stream, derived
+/// purely from the world seed and the chunk's coordinates. Chunks can thus be generated in
+/// any order, as they are loaded, with each chunk always getting the same stream.
+///
+/// Coordinates are hashed with a `wymix` based mixing function, in which every bit of the
+/// coordinates affects every bit of the derived seed. Adjacent chunks therefore get
+/// uncorrelated streams, with on average half of the bits of their seeds differing.
+///
+/// Chunks that are generated over several frames can have their progress kept in an
+/// optional LRU cache, with [`ChunkRngProvider::store`] and [`ChunkRngProvider::resume`].
+/// The cache is searched linearly, so it is meant to stay small.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// let provider = ChunkRngProvider::new(12345);
+///
+/// let rng = provider.rng_for(IVec2::new(3, -7));
+/// let height = rng.f32();
+///
+/// // The same chunk always gets the same stream, no matter what was generated before.
+/// assert_eq!(provider.rng_for(IVec2::new(3, -7)).f32(), height);
+/// ```
+#[derive(Debug, Clone, Resource)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub struct ChunkRngProvider {
+    seed: u64,
+    capacity: usize,
+    cache: VecDeque<(ChunkKey, RngComponent)>,
+}
+
+impl ChunkRngProvider {
+    /// Creates a new [`ChunkRngProvider`] for the given world seed, without a cache.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            capacity: 0,
+            cache: VecDeque::new(),
+        }
+    }
+
+    /// Builder function to enable the cache, keeping the stored state of up to `capacity`
+    /// chunks, with the least recently stored ones evicted first.
+    #[inline]
+    #[must_use]
+    pub fn with_cache(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self.cache.truncate(capacity);
+        self
+    }
+
+    /// Returns the world seed of the provider.
+    #[inline]
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the seed of the stream for the chunk at the given 2D coordinates.
+    #[inline]
+    #[must_use]
+    pub const fn seed_for(&self, coord: IVec2) -> u64 {
+        mix(self.seed, pack(coord.x, coord.y))
+    }
+
+    /// Returns the seed of the stream for the chunk at the given 3D coordinates. The
+    /// streams of 3D chunks are unrelated to those of 2D chunks, even at a `z` of `0`.
+    #[inline]
+    #[must_use]
+    pub const fn seed_for_3d(&self, coord: IVec3) -> u64 {
+        mix(
+            self.seed_for(IVec2::new(coord.x, coord.y)),
+            coord.z as u32 as u64 | 1 << 32,
+        )
+    }
+
+    /// Returns a new [`Rng`] at the start of the stream for the chunk at the given 2D
+    /// coordinates, ignoring any state stored in the cache.
+    #[inline]
+    #[must_use]
+    pub fn rng_for(&self, coord: IVec2) -> Rng {
+        Rng::with_seed(self.seed_for(coord))
+    }
+
+    /// Returns a new [`Rng`] at the start of the stream for the chunk at the given 3D
+    /// coordinates, ignoring any state stored in the cache.
+    #[inline]
+    #[must_use]
+    pub fn rng_for_3d(&self, coord: IVec3) -> Rng {
+        Rng::with_seed(self.seed_for_3d(coord))
+    }
+
+    /// Stores the progress of the chunk at the given 2D coordinates, to be picked up
+    /// later with [`ChunkRngProvider::resume`]. Does nothing if the cache is disabled.
+    #[inline]
+    pub fn store(&mut self, coord: IVec2, rng: Rng) {
+        self.store_key(ChunkKey::Flat(coord), rng);
+    }
+
+    /// Stores the progress of the chunk at the given 3D coordinates, to be picked up
+    /// later with [`ChunkRngProvider::resume_3d`]. Does nothing if the cache is disabled.
+    #[inline]
+    pub fn store_3d(&mut self, coord: IVec3, rng: Rng) {
+        self.store_key(ChunkKey::Volume(coord), rng);
+    }
+
+    /// Takes the stored progress of the chunk at the given 2D coordinates out of the cache,
+    /// or returns the start of its stream if there is none.
+    #[inline]
+    #[must_use]
+    pub fn resume(&mut self, coord: IVec2) -> Rng {
+        self.take_key(ChunkKey::Flat(coord))
+            .unwrap_or_else(|| self.rng_for(coord))
+    }
+
+    /// Takes the stored progress of the chunk at the given 3D coordinates out of the cache,
+    /// or returns the start of its stream if there is none.
+    #[inline]
+    #[must_use]
+    pub fn resume_3d(&mut self, coord: IVec3) -> Rng {
+        self.take_key(ChunkKey::Volume(coord))
+            .unwrap_or_else(|| self.rng_for_3d(coord))
+    }
+
+    /// Returns whether progress is stored for the chunk at the given 2D coordinates.
+    #[inline]
+    #[must_use]
+    pub fn is_stored(&self, coord: IVec2) -> bool {
+        self.position(ChunkKey::Flat(coord)).is_some()
+    }
+
+    /// Returns whether progress is stored for the chunk at the given 3D coordinates.
+    #[inline]
+    #[must_use]
+    pub fn is_stored_3d(&self, coord: IVec3) -> bool {
+        self.position(ChunkKey::Volume(coord)).is_some()
+    }
+
+    /// Clears all stored progress from the cache.
+    #[inline]
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+
+    fn position(&self, key: ChunkKey) -> Option<usize> {
+        self.cache.iter().position(|(stored, _)| *stored == key)
+    }
+
+    fn store_key(&mut self, key: ChunkKey, rng: Rng) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if let Some(index) = self.position(key) {
+            self.cache.remove(index);
+        } else if self.cache.len() == self.capacity {
+            self.cache.pop_front();
+        }
+
+        self.cache.push_back((key, RngComponent::from(rng)));
+    }
+
+    fn take_key(&mut self, key: ChunkKey) -> Option<Rng> {
+        let index = self.position(key)?;
+
+        self.cache.remove(index).map(|(_, rng)| rng.into_inner())
+    }
+}
+
+/// Packs both coordinates into a single key, without any two coordinates sharing a key.
+#[inline(always)]
+const fn pack(x: i32, y: i32) -> u64 {
+    (x as u32 as u64) | (y as u32 as u64) << 32
+}
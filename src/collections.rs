@@ -0,0 +1,189 @@
+use crate::*;
+use std::fmt;
+
+/// A deck of items drawn without replacement, such as a deck of cards or a bag of tiles,
+/// which keeps its place across frames. Each cycle shuffles the indices of the items once,
+/// after which every draw takes the next index in constant time, until all items have been
+/// drawn and the deck needs a [`RandomDeck::reshuffle`].
+///
+/// With [`RandomDeck::with_auto_reshuffle`] set, drawing from an empty deck reshuffles it
+/// first, so the deck never runs out. The remaining order is part of the deck's state, so a
+/// deck that is saved and loaded mid-cycle keeps drawing the same items in the same order.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let mut tiles = RandomDeck::new(vec!['A', 'B', 'C'], &mut rng);
+///
+/// let mut drawn: Vec<char> = std::iter::from_fn(|| tiles.draw(&mut rng).copied()).collect();
+///
+/// drawn.sort_unstable();
+///
+/// assert_eq!(drawn, ['A', 'B', 'C']);
+/// assert_eq!(tiles.draw(&mut rng), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(
+        try_from = "DeckState<T>",
+        bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>")
+    )
+)]
+#[reflect(Component)]
+pub struct RandomDeck<T: Send + Sync + 'static> {
+    items: Vec<T>,
+    /// The indices of the items left in the current cycle, with the next one to be drawn last.
+    remaining: Vec<usize>,
+    auto_reshuffle: bool,
+}
+
+/// The error returned when loading a [`RandomDeck`] whose remaining order doesn't match its
+/// items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RandomDeckError {
+    /// The first invalid index in the remaining order.
+    pub index: usize,
+}
+
+impl fmt::Display for RandomDeckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the remaining order of the deck has an out of range or repeated index of {}",
+            self.index
+        )
+    }
+}
+
+impl std::error::Error for RandomDeckError {}
+
+impl<T: Send + Sync + 'static> RandomDeck<T> {
+    /// Creates a new [`RandomDeck`] from the given items, shuffled and ready to draw from.
+    #[inline]
+    #[must_use]
+    pub fn new(items: Vec<T>, rng: &mut impl DelegatedRng) -> Self {
+        let mut deck = Self {
+            items,
+            remaining: Vec::new(),
+            auto_reshuffle: false,
+        };
+
+        deck.reshuffle(rng);
+
+        deck
+    }
+
+    /// Builder function to set whether drawing from an empty deck reshuffles it first,
+    /// starting a new cycle.
+    #[inline]
+    #[must_use]
+    pub fn with_auto_reshuffle(mut self, auto_reshuffle: bool) -> Self {
+        self.auto_reshuffle = auto_reshuffle;
+        self
+    }
+
+    /// Draws the next item of the current cycle, or returns `None` if every item has been
+    /// drawn. With auto reshuffling set, an empty deck is reshuffled instead, so that only a
+    /// deck without any items returns `None`.
+    pub fn draw(&mut self, rng: &mut impl DelegatedRng) -> Option<&T> {
+        if self.remaining.is_empty() && self.auto_reshuffle {
+            self.reshuffle(rng);
+        }
+
+        self.remaining.pop().map(|index| &self.items[index])
+    }
+
+    /// Returns the item the next draw returns, without drawing it. Returns `None` if every
+    /// item of the current cycle has been drawn, even with auto reshuffling set, as the
+    /// next cycle isn't shuffled yet.
+    #[inline]
+    #[must_use]
+    pub fn peek(&self) -> Option<&T> {
+        self.remaining.last().map(|&index| &self.items[index])
+    }
+
+    /// Puts every item back into the deck and shuffles it, starting a new cycle.
+    pub fn reshuffle(&mut self, rng: &mut impl DelegatedRng) {
+        self.remaining.clear();
+        self.remaining.extend(0..self.items.len());
+        rng.shuffle(&mut self.remaining);
+    }
+
+    /// Returns the amount of items left to draw in the current cycle.
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.remaining.len()
+    }
+
+    /// Returns the items left to draw in the current cycle, in the order they will be drawn.
+    #[inline]
+    pub fn remaining_items(&self) -> impl Iterator<Item = &T> + '_ {
+        self.remaining.iter().rev().map(|&index| &self.items[index])
+    }
+
+    /// Returns every item of the deck, drawn or not, in the order they were given.
+    #[inline]
+    #[must_use]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns the amount of items in the deck, drawn or not.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the deck has no items at all.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns whether drawing from an empty deck reshuffles it first.
+    #[inline]
+    #[must_use]
+    pub const fn auto_reshuffle(&self) -> bool {
+        self.auto_reshuffle
+    }
+}
+
+/// The serialized form of a [`RandomDeck`], validated before being turned into one.
+#[cfg(feature = "serialize")]
+#[derive(Deserialize)]
+#[serde(rename = "RandomDeck")]
+struct DeckState<T> {
+    items: Vec<T>,
+    remaining: Vec<usize>,
+    auto_reshuffle: bool,
+}
+
+#[cfg(feature = "serialize")]
+impl<T: Send + Sync + 'static> TryFrom<DeckState<T>> for RandomDeck<T> {
+    type Error = RandomDeckError;
+
+    fn try_from(state: DeckState<T>) -> Result<Self, Self::Error> {
+        let mut seen = vec![false; state.items.len()];
+
+        for &index in &state.remaining {
+            match seen.get_mut(index) {
+                Some(seen) if !*seen => *seen = true,
+                _ => return Err(RandomDeckError { index }),
+            }
+        }
+
+        Ok(Self {
+            items: state.items,
+            remaining: state.remaining,
+            auto_reshuffle: state.auto_reshuffle,
+        })
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{state::from_state_integers, *};
+use serde::{
+    de::{
+        DeserializeOwned, DeserializeSeed, Error as _, IgnoredAny, MapAccess, SeqAccess, Visitor,
+    },
+    Deserializer, Serializer,
+};
+use std::fmt;
+
+/// The version byte prefixed to the compact representation.
+pub const COMPACT_VERSION: u8 = 1;
+
+/// The representations accepted when deserializing. The compact one is tried first.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Representation {
+    Compact(Vec<u8>),
+    Default(StateIntegers),
+}
+
+/// The integers making up the state of an RNG source in its default representation, in the
+/// order they appear. The structure around them is not kept, as formats such as RON do not
+/// describe newtypes well enough to deserialize the source directly once buffered.
+struct StateIntegers(Vec<u64>);
+
+impl<'de> Deserialize<'de> for StateIntegers {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut integers = Vec::new();
+
+        deserializer.deserialize_any(IntegerCollector(&mut integers))?;
+
+        Ok(Self(integers))
+    }
+}
+
+/// Collects every integer visited into the given list, descending into all nested values.
+struct IntegerCollector<'a>(&'a mut Vec<u64>);
+
+impl<'de> DeserializeSeed<'de> for IntegerCollector<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for IntegerCollector<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("the state of an RNG source")
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<(), E> {
+        self.0.push(v);
+        Ok(())
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while seq.next_element_seed(IntegerCollector(self.0))?.is_some() {}
+        Ok(())
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        // Field names are skipped, relying on fields being in the order they were serialized.
+        while map.next_key::<IgnoredAny>()?.is_some() {
+            map.next_value_seed(IntegerCollector(self.0))?;
+        }
+        Ok(())
+    }
+}
+
+/// Serializes an RNG wrapper in the compact representation, as a sequence of bytes made up
+/// of [`COMPACT_VERSION`] followed by [`StateBytes::state_bytes`].
+///
+/// # Errors
+///
+/// Returns any error raised by the serializer.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: DelegatedRng + Clone,
+    T::Source: StateBytes,
+    S: Serializer,
+{
+    let state = value.clone().state_bytes();
+
+    serializer.collect_seq(std::iter::once(COMPACT_VERSION).chain(state))
+}
+
+/// Deserializes an RNG wrapper from either the compact representation or the default,
+/// derived representation, so that data saved before switching to the compact one can
+/// still be loaded. Accepting both requires a self-describing format, such as RON or JSON.
+///
+/// # Errors
+///
+/// Returns an error if the data is in neither representation, if the version byte is not
+/// [`COMPACT_VERSION`], or if the state is not valid for the RNG source.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: DelegatedRng + From<T::Source>,
+    T::Source: StateBytes + Serialize + DeserializeOwned,
+    D: Deserializer<'de>,
+{
+    let source = match Representation::deserialize(deserializer)? {
+        Representation::Default(StateIntegers(integers)) => from_state_integers(&integers),
+        Representation::Compact(bytes) => match bytes.split_first() {
+            Some((&COMPACT_VERSION, state)) => T::Source::from_state_bytes(state),
+            Some((version, _)) => {
+                return Err(D::Error::custom(format_args!(
+                    "unsupported compact RNG state version {version}"
+                )))
+            }
+            None => return Err(D::Error::custom("compact RNG state is empty")),
+        },
+    };
+
+    source.map(T::from).map_err(D::Error::custom)
+}
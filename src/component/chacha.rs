@@ -1,4 +1,11 @@
+#[cfg(feature = "audit")]
+use crate::audit::{AuditSink, AuditedRng};
+#[cfg(any(debug_assertions, feature = "audit"))]
+use crate::provenance::{AuditProvenance, Provenance};
+use crate::sync::SyncRng;
 use crate::*;
+use std::ops::RangeBounds;
+use tracing::warn;
 
 /// A [`ChaChaRng`] component that wraps a random number generator,
 /// specifically the [`ChaChaRng`] struct, which provides a cryptographically
@@ -10,6 +17,8 @@ use crate::*;
 /// a mut reference to a [`DelegatedRng`], such as [`ResMut`], or a
 /// [`Component`], or from a [`TurboCore`] source directly. You can't create or seed
 /// [`ChaChaRngComponent`] from sources that are not backed by a [`SecureCore`] source.
+/// An existing [`ChaChaRng`] can also be wrapped as is with [`From<ChaChaRng>`],
+/// keeping its state intact.
 ///
 /// # Examples
 ///
@@ -55,41 +64,221 @@ use crate::*;
 ///    }
 /// }
 /// ```
-#[derive(Debug, Clone, Component, PartialEq, Reflect)]
+#[derive(Debug, Clone, Component, Reflect)]
+#[cfg_attr(
+    feature = "audit",
+    component(on_add = crate::audit::attach_on_add::<ChaChaRngComponent>)
+)]
 #[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
-#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serialize",
-    reflect(opaque, Debug, PartialEq, Default, Serialize, Deserialize)
+    reflect(opaque, Debug, PartialEq, Default, Component, Serialize, Deserialize)
 )]
-#[cfg_attr(not(feature = "serialize"), reflect(opaque, Debug, PartialEq, Default))]
-pub struct ChaChaRngComponent(ChaChaRng);
-
-unsafe impl Sync for ChaChaRngComponent {}
+#[cfg_attr(
+    not(feature = "serialize"),
+    reflect(opaque, Debug, PartialEq, Default, Component)
+)]
+pub struct ChaChaRngComponent(
+    SyncRng<ChaChaRng>,
+    #[cfg(any(debug_assertions, feature = "audit"))] Provenance,
+    #[cfg(feature = "audit")] AuditSink,
+);
 
 impl ChaChaRngComponent {
     /// Create a new [`ChaChaRngComponent`] with a randomised seed.
     #[inline]
     #[must_use]
     pub fn new() -> Self {
-        Self(ChaChaRng::new())
+        Self::from_entropy(ChaChaRng::new())
     }
 
     /// Create a new [`ChaChaRngComponent`] with a given seed.
     #[inline]
     #[must_use]
     pub fn with_seed(seed: [u8; 40]) -> Self {
-        Self(ChaChaRng::with_seed(seed))
+        Self::seeded(ChaChaRng::with_seed(seed))
+    }
+
+    /// Create a new [`ChaChaRngComponent`] seeded from a parent RNG accessed through change
+    /// detection, such as a [`Mut`] or a [`ResMut`], without flagging the parent as changed.
+    /// Yields the same component as [`ChaChaRngComponent::from`] would.
+    #[inline]
+    #[must_use]
+    pub fn from_parent_untracked<T: DelegatedRng>(
+        parent: &mut impl DetectChangesMut<Inner = T>,
+    ) -> Self
+    where
+        T::Source: SecureCore,
+    {
+        Self::from(parent.bypass_change_detection())
+    }
+
+    /// Create a new [`ChaChaRngComponent`] seeded from the [`GlobalChaChaRng`] resource of `world`, the same
+    /// as [`ChaChaRngComponent::from`] would from the resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MissingGlobalRng`] if the [`GlobalChaChaRng`] resource is missing from the world.
+    #[inline]
+    pub fn try_from_world(world: &mut World) -> Result<Self, MissingGlobalRng> {
+        world
+            .get_resource_mut::<GlobalChaChaRng>()
+            .map(|mut global| Self::from(&mut global))
+            .ok_or(MissingGlobalRng {
+                resource: "GlobalChaChaRng",
+            })
+    }
+
+    /// Create a new [`ChaChaRngComponent`] seeded from the given [`GlobalChaChaRng`], the same as
+    /// [`ChaChaRngComponent::from`] would, or with a randomised seed if there is none, such as for
+    /// systems taking an `Option<ResMut<GlobalChaChaRng>>`. Falling back to a randomised seed logs a
+    /// warning, as the component is then **not** deterministic.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// fn spawn_critter(mut commands: Commands, mut global: Option<ResMut<GlobalChaChaRng>>) {
+    ///     commands.spawn(ChaChaRngComponent::from_optional_global(global.as_deref_mut()));
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_optional_global(global: Option<&mut GlobalChaChaRng>) -> Self {
+        if let Some(global) = global {
+            return Self::from(global);
+        }
+
+        warn!("No GlobalChaChaRng to seed a ChaChaRngComponent from, so seeding it from entropy instead");
+
+        Self::new()
+    }
+
+    /// Forks a new [`ChaChaRngComponent`] from this one with [`ForkableCore::fork`], the same
+    /// as wrapping the source from [`DelegatedRng::fork`] would, such as for handing a child
+    /// entity its own secure RNG.
+    #[inline]
+    #[must_use]
+    pub fn fork_component(&mut self) -> Self {
+        Self::seeded(self.fork())
+    }
+
+    /// Forks `n` new [`ChaChaRngComponent`]s from this one in a single call, yielding the same
+    /// components as `n` sequential calls to [`ChaChaRngComponent::fork_component`].
+    #[inline]
+    #[must_use]
+    pub fn fork_components(&mut self, n: usize) -> Vec<Self> {
+        self.fork_many(n).into_iter().map(Self::seeded).collect()
+    }
+
+    /// Spawns an entity for each of the given bundles, each alongside its own
+    /// [`ChaChaRngComponent`] forked from `rng`. All sources are forked up front with
+    /// [`DelegatedRng::fork_many`], and then spawned in a single [`Commands::spawn_batch`] call.
+    pub fn spawn_batch_with_rng<R, B>(
+        commands: &mut Commands<'_, '_>,
+        rng: &mut R,
+        bundles: impl IntoIterator<Item = B>,
+    ) where
+        R: DelegatedRng<Source = ChaChaRng>,
+        B: Bundle,
+    {
+        let bundles: Vec<B> = bundles.into_iter().collect();
+        let sources = rng.fork_many(bundles.len());
+
+        let batch: Vec<(B, Self)> = bundles
+            .into_iter()
+            .zip(sources.into_iter().map(Self::seeded))
+            .collect();
+
+        commands.spawn_batch(batch);
+    }
+
+    #[inline]
+    const fn seeded(rng: ChaChaRng) -> Self {
+        Self(
+            SyncRng::new(rng),
+            #[cfg(any(debug_assertions, feature = "audit"))]
+            Provenance::Seeded,
+            #[cfg(feature = "audit")]
+            AuditSink::DETACHED,
+        )
+    }
+
+    #[inline]
+    const fn from_entropy(rng: ChaChaRng) -> Self {
+        Self(
+            SyncRng::new(rng),
+            #[cfg(any(debug_assertions, feature = "audit"))]
+            Provenance::Entropy,
+            #[cfg(feature = "audit")]
+            AuditSink::DETACHED,
+        )
+    }
+
+    /// Replaces the wrapped source with a freshly seeded or forked one, which counts as
+    /// seeded, while keeping the audit log the component records into, if any.
+    #[inline]
+    pub(crate) fn set_source(&mut self, source: ChaChaRng) {
+        *self.0.get_mut() = source;
+
+        #[cfg(any(debug_assertions, feature = "audit"))]
+        {
+            self.1 = Provenance::Seeded;
+        }
+    }
+
+    /// Reseeds the [`ChaChaRngComponent`] with a new seed, the same as [`DelegatedRng::reseed`]
+    /// does, without needing to import [`DelegatedRng`].
+    #[inline]
+    pub fn reseed(&mut self, seed: [u8; 40]) {
+        <Self as DelegatedRng>::reseed(self, seed);
+    }
+
+    /// Consumes the [`ChaChaRngComponent`], returning the wrapped [`ChaChaRng`] with its state intact.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> ChaChaRng {
+        self.0.into_inner()
     }
 }
 
+impl ChaChaRngComponent {
+    delegated_methods!(delegate_inherent_methods);
+    inherent_methods!(delegate_inherent_methods);
+}
+
 impl DelegatedRng for ChaChaRngComponent {
     type Source = ChaChaRng;
 
     #[inline]
+    #[allow(unused_attributes)]
     #[must_use]
     fn get_mut(&mut self) -> &mut Self::Source {
-        &mut self.0
+        #[cfg(feature = "diagnostics")]
+        Self::count_call();
+
+        self.0.get_mut()
+    }
+
+    #[cfg(feature = "audit")]
+    #[inline]
+    fn audit_log(&self) -> Option<&RngAuditLog> {
+        self.2.log()
+    }
+}
+
+impl AsMut<ChaChaRng> for ChaChaRngComponent {
+    fn as_mut(&mut self) -> &mut ChaChaRng {
+        self.get_mut()
+    }
+}
+
+#[cfg(feature = "audit")]
+impl AuditedRng for ChaChaRngComponent {
+    #[inline]
+    fn audit_sink_mut(&mut self) -> &mut AuditSink {
+        &mut self.2
     }
 }
 
@@ -105,9 +294,10 @@ impl Default for ChaChaRngComponent {
 
 impl<T: TurboCore + GenCore + SecureCore> From<&T> for ChaChaRngComponent {
     #[inline]
+    #[allow(unused_attributes)]
     #[must_use]
     fn from(rng: &T) -> Self {
-        Self(ChaChaRng::with_seed(rng.gen()))
+        Self::seeded(ChaChaRng::with_seed(rng.gen()))
     }
 }
 
@@ -116,9 +306,10 @@ where
     T::Source: SecureCore,
 {
     #[inline]
+    #[allow(unused_attributes)]
     #[must_use]
     fn from(rng: &mut T) -> Self {
-        Self(ChaChaRng::with_seed(rng.get_mut().gen()))
+        Self::seeded(ChaChaRng::with_seed(rng.get_mut().gen()))
     }
 }
 
@@ -127,9 +318,10 @@ where
     T::Source: SecureCore,
 {
     #[inline]
+    #[allow(unused_attributes)]
     #[must_use]
     fn from(rng: &mut Mut<'_, T>) -> Self {
-        Self(ChaChaRng::with_seed(rng.get_mut().gen()))
+        Self::seeded(ChaChaRng::with_seed(rng.get_mut().gen()))
     }
 }
 
@@ -139,8 +331,61 @@ where
     T::Source: SecureCore,
 {
     #[inline]
+    #[allow(unused_attributes)]
     #[must_use]
     fn from(rng: &mut ResMut<'_, T>) -> Self {
-        Self(ChaChaRng::with_seed(rng.get_mut().gen()))
+        Self::seeded(ChaChaRng::with_seed(rng.get_mut().gen()))
+    }
+}
+
+impl From<ChaChaRng> for ChaChaRngComponent {
+    /// Wraps an existing [`ChaChaRng`] as is, taking ownership of it without reseeding or forking.
+    #[inline]
+    fn from(rng: ChaChaRng) -> Self {
+        Self::seeded(rng)
+    }
+}
+
+impl PartialEq for ChaChaRngComponent {
+    /// Compares the states of the wrapped [`ChaChaRng`]s only, regardless of how either was seeded.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl Serialize for ChaChaRngComponent {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct("ChaChaRngComponent", &self.0)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for ChaChaRngComponent {
+    /// Deserializes the state of the wrapped [`ChaChaRng`], which counts as seeded, as it was
+    /// restored rather than created from entropy. Human-readable formats also accept the
+    /// payloads of older releases, which nest the state in fewer newtypes, such as
+    /// `((state:(24691)))` or `(state:24691)` in RON.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            return crate::lenient::deserialize_lenient(deserializer).map(Self::seeded);
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename = "ChaChaRngComponent")]
+        struct Repr(ChaChaRng);
+
+        Repr::deserialize(deserializer).map(|Repr(rng)| Self::seeded(rng))
+    }
+}
+
+#[cfg(any(debug_assertions, feature = "audit"))]
+impl AuditProvenance for ChaChaRngComponent {
+    const NAME: &'static str = "ChaChaRngComponent";
+
+    #[inline]
+    fn provenance(&self) -> Provenance {
+        self.1
     }
 }
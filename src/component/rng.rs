@@ -1,4 +1,11 @@
+#[cfg(feature = "audit")]
+use crate::audit::{AuditSink, AuditedRng};
+#[cfg(any(debug_assertions, feature = "audit"))]
+use crate::provenance::{AuditProvenance, Provenance};
+use crate::sync::SyncRng;
 use crate::*;
+use std::ops::RangeBounds;
+use tracing::warn;
 
 /// A [`Rng`] component that wraps a random number generator,
 /// specifically the [`Rng`] struct, which provides a fast, but
@@ -8,10 +15,15 @@ use crate::*;
 ///
 /// You can creates a new [`RngComponent`] directly from anything that yields
 /// a mut reference to a [`DelegatedRng`], such as [`ResMut`] or a
-/// [`Component`], or from a [`TurboCore`] source directly.
+/// [`Component`], or from a [`TurboCore`] source directly. These `From` conversions
+/// **fork** the source: they draw a seed from it, advancing it, and the new component
+/// yields a different stream. An existing [`Rng`] can instead be wrapped as is with
+/// [`From<Rng>`], keeping its state intact, and the exact state of a [`GlobalRng`] can be
+/// copied with [`RngComponent::from_global_state`], or exchanged with
+/// [`RngComponent::swap_state`].
 ///
 /// # Examples
-/// 
+///
 /// Randomised Component:
 /// ```
 /// use bevy::prelude::*;
@@ -71,41 +83,344 @@ use crate::*;
 ///    }
 /// }
 /// ```
-#[derive(Debug, Clone, Component, PartialEq, Reflect)]
+///
+/// Drawing values requires mutable access, so it does not compile through a shared
+/// reference, such as from a read-only query running in parallel with other systems:
+///
+/// ```compile_fail
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn roll(q_rng: Query<&RngComponent>) {
+///     for rng in q_rng.iter() {
+///         let value = rng.u32(..);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+#[cfg_attr(
+    feature = "audit",
+    component(on_add = crate::audit::attach_on_add::<RngComponent>)
+)]
 #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
-#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serialize",
-    reflect(opaque, Debug, PartialEq, Default, Serialize, Deserialize)
+    reflect(opaque, Debug, PartialEq, Default, Component, Serialize, Deserialize)
 )]
-#[cfg_attr(not(feature = "serialize"), reflect(opaque, Debug, PartialEq, Default))]
-pub struct RngComponent(Rng);
-
-unsafe impl Sync for RngComponent {}
+#[cfg_attr(
+    not(feature = "serialize"),
+    reflect(opaque, Debug, PartialEq, Default, Component)
+)]
+pub struct RngComponent(
+    SyncRng<Rng>,
+    #[cfg(any(debug_assertions, feature = "audit"))] Provenance,
+    #[cfg(feature = "audit")] AuditSink,
+);
 
 impl RngComponent {
     /// Create a new [`RngComponent`] with a randomised seed.
     #[inline]
     #[must_use]
     pub fn new() -> Self {
-        Self(Rng::new())
+        Self::from_entropy(Rng::new())
     }
 
     /// Create a new [`RngComponent`] instance with a given seed.
     #[inline]
     #[must_use]
     pub fn with_seed(seed: u64) -> Self {
-        Self(Rng::with_seed(seed))
+        Self::seeded(Rng::with_seed(seed))
+    }
+
+    /// Create a new [`RngComponent`] instance with the seed derived by a [`SeedMixer`].
+    #[inline]
+    #[must_use]
+    pub fn with_mixed_seed(mixer: SeedMixer) -> Self {
+        Self::with_seed(mixer.finish())
+    }
+
+    /// Create a new [`RngComponent`] seeded from a parent RNG accessed through change
+    /// detection, such as a [`Mut`] or a [`ResMut`], without flagging the parent as changed.
+    /// Yields the same component as [`RngComponent::from`] would, for when seeding children
+    /// shouldn't count as a change of the parent, such as for replication.
+    #[inline]
+    #[must_use]
+    pub fn from_parent_untracked<T: DelegatedRng>(
+        parent: &mut impl DetectChangesMut<Inner = T>,
+    ) -> Self {
+        Self::from(parent.bypass_change_detection())
+    }
+
+    /// Create a new [`RngComponent`] seeded from the [`GlobalRng`] resource of `world`, the same
+    /// as [`RngComponent::from`] would from the resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MissingGlobalRng`] if the [`GlobalRng`] resource is missing from the world.
+    #[inline]
+    pub fn try_from_world(world: &mut World) -> Result<Self, MissingGlobalRng> {
+        world
+            .get_resource_mut::<GlobalRng>()
+            .map(|mut global| Self::from(&mut global))
+            .ok_or(MissingGlobalRng {
+                resource: "GlobalRng",
+            })
+    }
+
+    /// Create a new [`RngComponent`] seeded from the given [`GlobalRng`], the same as
+    /// [`RngComponent::from`] would, or with a randomised seed if there is none, such as for
+    /// systems taking an `Option<ResMut<GlobalRng>>`. Falling back to a randomised seed logs a
+    /// warning, as the component is then **not** deterministic.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// fn spawn_critter(mut commands: Commands, mut global: Option<ResMut<GlobalRng>>) {
+    ///     commands.spawn(RngComponent::from_optional_global(global.as_deref_mut()));
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_optional_global(global: Option<&mut GlobalRng>) -> Self {
+        if let Some(global) = global {
+            return Self::from(global);
+        }
+
+        warn!("No GlobalRng to seed a RngComponent from, so seeding it from entropy instead");
+
+        Self::new()
+    }
+
+    /// Spawns an entity for each of the given bundles, each alongside its own [`RngComponent`]
+    /// forked from `rng`. All sources are forked up front with [`DelegatedRng::fork_many`],
+    /// and then spawned in a single [`Commands::spawn_batch`] call.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy(u32);
+    ///
+    /// fn spawn_horde(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+    ///     RngComponent::spawn_batch_with_rng(&mut commands, &mut *global, (0..10_000).map(Enemy));
+    /// }
+    /// ```
+    pub fn spawn_batch_with_rng<R, B>(
+        commands: &mut Commands<'_, '_>,
+        rng: &mut R,
+        bundles: impl IntoIterator<Item = B>,
+    ) where
+        R: DelegatedRng<Source = Rng>,
+        B: Bundle,
+    {
+        let bundles: Vec<B> = bundles.into_iter().collect();
+        let sources = rng.fork_many(bundles.len());
+
+        let batch: Vec<(B, Self)> = bundles
+            .into_iter()
+            .zip(sources.into_iter().map(Self::seeded))
+            .collect();
+
+        commands.spawn_batch(batch);
+    }
+
+    /// Returns the exact state of the wrapped [`Rng`] as the little-endian seed bytes of a
+    /// `WyRand` source from the `wyrand` crate, as used by `bevy_rand`. Seeding one with
+    /// these bytes, such as with `EntropyComponent::<WyRand>::from_seed`, or a
+    /// [`WyRandCompat`], continues the exact stream of the [`RngComponent`], as both
+    /// implement the same algorithm. Doesn't advance the [`RngComponent`].
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    /// use rand_core::{RngCore, SeedableRng};
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let mut transferred = WyRandCompat::from_seed(rng.to_wyrand_entropy_bytes());
+    ///
+    /// assert_eq!(transferred.next_u64(), rng.u64(..));
+    /// ```
+    #[cfg(feature = "bevy_rand-compat")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_rand-compat")))]
+    #[inline]
+    #[must_use]
+    pub fn to_wyrand_entropy_bytes(&self) -> [u8; 8] {
+        let mut bytes = [0; 8];
+
+        bytes.copy_from_slice(&self.0.state_bytes());
+
+        bytes
+    }
+
+    /// Creates an [`RngComponent`] continuing the exact stream of a `WyRand` source from the
+    /// `wyrand` crate, as used by `bevy_rand`, from the little-endian bytes of its state.
+    /// The seed a `WyRand` source was created with is its state at that point, so the seed
+    /// recorded by `bevy_rand` for an entity can be converted as is. The inverse of
+    /// [`RngComponent::to_wyrand_entropy_bytes`].
+    ///
+    /// These bytes are a state, not a seed, so this differs from
+    /// `RngComponent::with_seed(u64::from_le_bytes(bytes))`, which derives its state from
+    /// the seed.
+    #[cfg(feature = "bevy_rand-compat")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_rand-compat")))]
+    #[inline]
+    #[must_use]
+    pub fn from_entropy_bytes(bytes: [u8; 8]) -> Self {
+        Self::seeded(<WyRandCompat as rand_core::SeedableRng>::from_seed(bytes).into_inner())
+    }
+
+    /// Restores an [`RngComponent`] from the state bytes of an [`Rng`] as stored by an older
+    /// release, carrying the state over into the current algorithm. Use
+    /// [`LegacyRngComponent::from_legacy_state`] instead to keep reproducing the exact stream
+    /// of the older release.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RngStateError::InvalidLength`] if `bytes` is not a valid [`Rng`] state.
+    #[cfg(feature = "compat")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "compat")))]
+    #[inline]
+    pub fn from_legacy_state(bytes: &[u8]) -> Result<Self, RngStateError> {
+        let legacy = LegacyRng::from_state_bytes(bytes)?;
+
+        Rng::from_state_bytes(&legacy.state_bytes()).map(Self::seeded)
+    }
+
+    #[inline]
+    const fn seeded(rng: Rng) -> Self {
+        Self(
+            SyncRng::new(rng),
+            #[cfg(any(debug_assertions, feature = "audit"))]
+            Provenance::Seeded,
+            #[cfg(feature = "audit")]
+            AuditSink::DETACHED,
+        )
+    }
+
+    #[inline]
+    const fn from_entropy(rng: Rng) -> Self {
+        Self(
+            SyncRng::new(rng),
+            #[cfg(any(debug_assertions, feature = "audit"))]
+            Provenance::Entropy,
+            #[cfg(feature = "audit")]
+            AuditSink::DETACHED,
+        )
+    }
+
+    /// Replaces the wrapped source with a freshly seeded or forked one, which counts as
+    /// seeded, while keeping the audit log the component records into, if any.
+    #[inline]
+    pub(crate) fn set_source(&mut self, source: Rng) {
+        *self.0.get_mut() = source;
+
+        #[cfg(any(debug_assertions, feature = "audit"))]
+        {
+            self.1 = Provenance::Seeded;
+        }
+    }
+
+    /// Create a new [`RngComponent`] with an exact copy of the state of `global`, so that
+    /// both yield the same outputs from then on. Unlike [`RngComponent::from`], which forks
+    /// a new stream by drawing a seed from the [`GlobalRng`], this neither advances `global`
+    /// nor starts a different stream.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut global = GlobalRng::with_seed(5);
+    /// let mut copied = RngComponent::from_global_state(&global);
+    ///
+    /// assert_eq!(copied.u64(..), global.u64(..));
+    ///
+    /// // Forking draws a seed instead, advancing the global and starting a new stream.
+    /// let mut forked = RngComponent::from(&mut global);
+    ///
+    /// assert_ne!(forked.u64(..), copied.u64(..));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_global_state(global: &GlobalRng) -> Self {
+        Self::seeded(global.clone().into_inner())
+    }
+
+    /// Exchanges the states of the [`RngComponent`] and `other`, such as a [`GlobalRng`], so
+    /// that each continues the stream of the other, without advancing either.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut player = RngComponent::with_seed(5);
+    /// let mut global = GlobalRng::with_seed(6);
+    ///
+    /// player.swap_state(&mut global);
+    ///
+    /// assert_eq!(player.u64(..), RngComponent::with_seed(6).u64(..));
+    /// assert_eq!(global.u64(..), RngComponent::with_seed(5).u64(..));
+    /// ```
+    #[inline]
+    pub fn swap_state(&mut self, other: &mut impl DelegatedRng<Source = Rng>) {
+        std::mem::swap(self.get_mut(), other.get_mut());
+    }
+
+    /// Reseeds the [`RngComponent`] with a new seed, the same as [`DelegatedRng::reseed`]
+    /// does, without needing to import [`DelegatedRng`].
+    #[inline]
+    pub fn reseed(&mut self, seed: u64) {
+        <Self as DelegatedRng>::reseed(self, seed);
+    }
+
+    /// Consumes the [`RngComponent`], returning the wrapped [`Rng`] with its state intact.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Rng {
+        self.0.into_inner()
     }
 }
 
+impl RngComponent {
+    delegated_methods!(delegate_inherent_methods);
+    inherent_methods!(delegate_inherent_methods);
+}
+
 impl DelegatedRng for RngComponent {
     type Source = Rng;
 
     #[inline]
+    #[allow(unused_attributes)]
     #[must_use]
     fn get_mut(&mut self) -> &mut Self::Source {
-        &mut self.0
+        #[cfg(feature = "diagnostics")]
+        Self::count_call();
+
+        self.0.get_mut()
+    }
+
+    #[cfg(feature = "audit")]
+    #[inline]
+    fn audit_log(&self) -> Option<&RngAuditLog> {
+        self.2.log()
+    }
+}
+
+impl AsMut<Rng> for RngComponent {
+    fn as_mut(&mut self) -> &mut Rng {
+        self.get_mut()
+    }
+}
+
+#[cfg(feature = "audit")]
+impl AuditedRng for RngComponent {
+    #[inline]
+    fn audit_sink_mut(&mut self) -> &mut AuditSink {
+        &mut self.2
     }
 }
 
@@ -120,33 +435,93 @@ impl Default for RngComponent {
 }
 
 impl<T: TurboCore + GenCore> From<&T> for RngComponent {
+    /// Forks a new [`RngComponent`] from `rng`, seeding it with a value drawn from `rng`.
+    /// Doesn't copy the state of `rng`, see [`RngComponent::from_global_state`] for that.
     #[inline]
+    #[allow(unused_attributes)]
     #[must_use]
     fn from(rng: &T) -> Self {
-        Self(Rng::with_seed(rng.gen_u64()))
+        Self::seeded(Rng::with_seed(rng.gen_u64()))
     }
 }
 
 impl<T: DelegatedRng> From<&mut T> for RngComponent {
+    /// Forks a new [`RngComponent`] from `rng`, seeding it with a value drawn from `rng`.
+    /// Doesn't copy the state of `rng`, see [`RngComponent::from_global_state`] for that.
     #[inline]
+    #[allow(unused_attributes)]
     #[must_use]
     fn from(rng: &mut T) -> Self {
-        Self(Rng::with_seed(rng.get_mut().gen_u64()))
+        Self::seeded(Rng::with_seed(rng.get_mut().gen_u64()))
     }
 }
 
 impl<T: DelegatedRng> From<&mut Mut<'_, T>> for RngComponent {
     #[inline]
+    #[allow(unused_attributes)]
     #[must_use]
     fn from(rng: &mut Mut<'_, T>) -> Self {
-        Self(Rng::with_seed(rng.get_mut().gen_u64()))
+        Self::seeded(Rng::with_seed(rng.get_mut().gen_u64()))
     }
 }
 
 impl<T: DelegatedRng + Resource + Send + Sync + 'static> From<&mut ResMut<'_, T>> for RngComponent {
     #[inline]
+    #[allow(unused_attributes)]
     #[must_use]
     fn from(rng: &mut ResMut<'_, T>) -> Self {
-        Self(Rng::with_seed(rng.get_mut().gen_u64()))
+        Self::seeded(Rng::with_seed(rng.get_mut().gen_u64()))
+    }
+}
+
+impl From<Rng> for RngComponent {
+    /// Wraps an existing [`Rng`] as is, taking ownership of it without reseeding or forking.
+    #[inline]
+    fn from(rng: Rng) -> Self {
+        Self::seeded(rng)
+    }
+}
+
+impl PartialEq for RngComponent {
+    /// Compares the states of the wrapped [`Rng`]s only, regardless of how either was seeded.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl Serialize for RngComponent {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct("RngComponent", &self.0)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for RngComponent {
+    /// Deserializes the state of the wrapped [`Rng`], which counts as seeded, as it was
+    /// restored rather than created from entropy. Human-readable formats also accept the
+    /// payloads of older releases, which nest the state in fewer newtypes, such as
+    /// `((state:(24691)))` or `(state:24691)` in RON.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            return crate::lenient::deserialize_lenient(deserializer).map(Self::seeded);
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename = "RngComponent")]
+        struct Repr(Rng);
+
+        Repr::deserialize(deserializer).map(|Repr(rng)| Self::seeded(rng))
+    }
+}
+
+#[cfg(any(debug_assertions, feature = "audit"))]
+impl AuditProvenance for RngComponent {
+    const NAME: &'static str = "RngComponent";
+
+    #[inline]
+    fn provenance(&self) -> Provenance {
+        self.1
     }
 }
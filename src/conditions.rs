@@ -0,0 +1,99 @@
+use crate::*;
+
+/// Run condition that is `true` with a probability of `rate` each time it is evaluated,
+/// drawing from a [`ForkedRng`] of the [`GlobalRng`].
+///
+/// Bevy run conditions only ever get read-only access to the [`World`], so the condition
+/// does not draw from the [`GlobalRng`] itself, but from its own RNG forked from it when the
+/// condition is initialised. Evaluating it thus never contends with other users of the
+/// [`GlobalRng`], and it stays deterministic as long as the [`GlobalRng`] is seeded and the
+/// schedule is built in the same order. Each use of the condition gets its own fork, so two
+/// systems using `chance_global(0.5)` will not run or skip in lockstep.
+///
+/// # Panics
+///
+/// Panics on initialisation if the [`GlobalRng`] resource is not present, and on evaluation
+/// if `rate` is not within `0.0..=1.0`.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn spawn_ambush() {
+///     // 10% chance of an ambush each frame
+/// }
+///
+/// App::new()
+///     .add_plugins(RngPlugin::new().with_rng_seed(12345))
+///     .add_systems(Update, spawn_ambush.run_if(chance_global(0.1)));
+/// ```
+#[cfg(feature = "wyrand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub fn chance_global(rate: f64) -> impl FnMut(Local<'_, ForkedRng<GlobalRng>>) -> bool + Clone {
+    chance_from::<GlobalRng>(rate)
+}
+
+/// Run condition that is `true` with a probability of exactly `numerator / denominator` each
+/// time it is evaluated, drawing from a [`ForkedRng`] of the [`GlobalRng`]. Forking behaves
+/// the same as with [`chance_global`].
+///
+/// # Panics
+///
+/// Panics if `denominator` is `0`, and on initialisation if the [`GlobalRng`] resource is
+/// not present.
+#[cfg(feature = "wyrand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub fn ratio_global(
+    numerator: u32,
+    denominator: u32,
+) -> impl FnMut(Local<'_, ForkedRng<GlobalRng>>) -> bool + Clone {
+    ratio_from::<GlobalRng>(numerator, denominator)
+}
+
+/// Run condition that is `true` with a probability of `rate` each time it is evaluated,
+/// drawing from a [`ForkedRng`] of the RNG resource `R`. Forking behaves the same as with
+/// [`chance_global`].
+///
+/// # Panics
+///
+/// Panics on initialisation if the `R` resource is not present, and on evaluation if `rate`
+/// is not within `0.0..=1.0`.
+pub fn chance_from<R>(rate: f64) -> impl FnMut(Local<'_, ForkedRng<R>>) -> bool + Clone
+where
+    R: DelegatedRng + Resource,
+    R::Source: Send,
+{
+    move |mut rng: Local<'_, ForkedRng<R>>| rng.chance(rate)
+}
+
+/// Run condition that is `true` with a probability of exactly `numerator / denominator` each
+/// time it is evaluated, drawing from a [`ForkedRng`] of the RNG resource `R`. Forking behaves
+/// the same as with [`chance_global`].
+///
+/// # Panics
+///
+/// Panics if `denominator` is `0`, and on initialisation if the `R` resource is not present.
+pub fn ratio_from<R>(
+    numerator: u32,
+    denominator: u32,
+) -> impl FnMut(Local<'_, ForkedRng<R>>) -> bool + Clone
+where
+    R: DelegatedRng + Resource,
+    R::Source: Send,
+{
+    assert!(denominator != 0, "ratio denominator must not be zero");
+
+    move |mut rng: Local<'_, ForkedRng<R>>| rng.ratio(numerator, denominator)
+}
+
+/// Run condition that is `true` whenever the [`RandomInterval`] resource tagged with `Tag`
+/// elapsed during its last tick, such as one inserted by
+/// [`RngPlugin::with_interval_resource`]. Is `false` if the resource is not present.
+#[cfg(feature = "bevy_time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy_time")))]
+pub fn interval_elapsed<Tag: Send + Sync + 'static>(
+    interval: Option<Res<'_, RandomInterval<Tag>>>,
+) -> bool {
+    interval.is_some_and(|interval| interval.just_elapsed())
+}
@@ -1,16 +1,163 @@
+/// Invokes `$callback!` with the list of all [`TurboRand`] methods that [`DelegatedRng`]
+/// delegates as is, each with its return type and, if it takes one, its input along with
+/// an example value. Adding a method to this list is all that is needed for it to be
+/// delegated, as all wrapper types get their methods from [`DelegatedRng`]. Exported, though
+/// hidden, so that the parity tests are driven by the same list.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! delegated_methods {
+    ($callback:ident) => {
+        $callback! {
+            u128(range: impl RangeBounds<u128> = ..) -> u128;
+            u64(range: impl RangeBounds<u64> = ..) -> u64;
+            u32(range: impl RangeBounds<u32> = ..) -> u32;
+            u16(range: impl RangeBounds<u16> = ..) -> u16;
+            u8(range: impl RangeBounds<u8> = ..) -> u8;
+            i128(range: impl RangeBounds<i128> = ..) -> i128;
+            i64(range: impl RangeBounds<i64> = ..) -> i64;
+            i32(range: impl RangeBounds<i32> = ..) -> i32;
+            i16(range: impl RangeBounds<i16> = ..) -> i16;
+            i8(range: impl RangeBounds<i8> = ..) -> i8;
+            usize(range: impl RangeBounds<usize> = ..) -> usize;
+            isize(range: impl RangeBounds<isize> = ..) -> isize;
+            index(bound: impl RangeBounds<usize> = ..10) -> usize;
+            char(range: impl RangeBounds<char> = 'a'..='z') -> char;
+            digit(radix: u8 = 10) -> char;
+            alphabetic() -> char;
+            alphanumeric() -> char;
+            lowercase() -> char;
+            uppercase() -> char;
+            bool() -> bool;
+            f64() -> f64;
+            f32() -> f32;
+            f64_normalized() -> f64;
+            f32_normalized() -> f32;
+        }
+    };
+}
+
+/// Generates the [`DelegatedRng`] trait methods for a list given by [`delegated_methods`].
 macro_rules! delegate_rng_trait {
-    ($method:tt, $type:ty, $doc:tt) => {
-        #[doc = $doc]
-        #[inline]
-        fn $method(&mut self) -> $type {
-            self.get_mut().$method()
+    ($($method:ident($($input:ident: $type:ty = $example:expr)?) -> $output:ty;)*) => {
+        $(
+            #[doc = concat!(
+                "Delegated [`TurboRand::",
+                stringify!($method),
+                "`] method from [`TurboRand`]."
+            )]
+            #[inline]
+            #[cfg_attr(debug_assertions, track_caller)]
+            fn $method(&mut self $(, $input: $type)?) -> $output {
+                $(check_delegated_input!($method, $input, $input);)?
+                self.get_mut().$method($($input)?)
+            }
+        )*
+    };
+}
+
+/// Invokes `$callback!` with the list of the [`DelegatedRng`] methods beyond those of
+/// [`delegated_methods`] that the wrapper types also provide as inherent methods, each with
+/// its generics in brackets, its inputs, its return type if any, and its bounds, if it has
+/// any, in brackets after `where`. Adding a method to this list is all that is needed for all
+/// wrapper types to get an inherent version of it.
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+macro_rules! inherent_methods {
+    ($callback:ident) => {
+        $callback! {
+            chance[](rate: f64) -> bool;
+            pick[T: Clone](options: &[T]) -> Option<T>;
+            sample['a, T](list: &'a [T]) -> Option<&'a T>;
+            sample_mut['a, T](list: &'a mut [T]) -> Option<&'a mut T>;
+            sample_multiple['a, T](list: &'a [T], amount: usize) -> Vec<&'a T>;
+            sample_multiple_mut['a, T](list: &'a mut [T], amount: usize) -> Vec<&'a mut T>;
+            weighted_sample['a, T, F](list: &'a [T], weight_sampler: F) -> Option<&'a T>
+                where [F: Fn((&T, usize)) -> f64];
+            weighted_sample_mut['a, T, F](list: &'a mut [T], weight_sampler: F) -> Option<&'a mut T>
+                where [F: Fn((&T, usize)) -> f64];
+            shuffle[T](slice: &mut [T]);
+            partial_shuffle['a, T](slice: &'a mut [T], amount: usize) -> (&'a mut [T], &'a mut [T]);
+            shuffle_prefix['a, T](slice: &'a mut [T], amount: usize) -> (&'a mut [T], &'a mut [T]);
+            weighted_shuffle[T, F: Fn(&T) -> f64](slice: &mut [T], weight: F);
+            #[must_use]
+            fork[]() -> <Self as DelegatedRng>::Source;
         }
     };
-    ($method:tt, $output:ty, $input:ty, $doc:tt) => {
-        #[doc = $doc]
-        #[inline]
-        fn $method(&mut self, input: $input) -> $output {
-            self.get_mut().$method(input)
+}
+
+/// Generates inherent versions of the methods in a list given by [`delegated_methods`] or
+/// by [`inherent_methods`] for the wrapper types, so that they are usable and discoverable
+/// without importing [`DelegatedRng`]. Each forwards to the [`DelegatedRng`] method of the
+/// same name, so both always yield the same outputs.
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+macro_rules! delegate_inherent_methods {
+    ($($method:ident($($input:ident: $type:ty = $example:expr)?) -> $output:ty;)*) => {
+        $(
+            #[doc = concat!(
+                "Inherent version of [`DelegatedRng::",
+                stringify!($method),
+                "`], usable without importing [`DelegatedRng`]."
+            )]
+            #[inline]
+            #[cfg_attr(debug_assertions, track_caller)]
+            pub fn $method(&mut self $(, $input: $type)?) -> $output {
+                <Self as DelegatedRng>::$method(self $(, $input)?)
+            }
+        )*
+    };
+    ($(
+        $(#[$attr:meta])*
+        $method:ident[$($generics:tt)*]($($input:ident: $type:ty),*) $(-> $output:ty)?
+            $(where [$($bounds:tt)*])?;
+    )*) => {
+        $(
+            #[doc = concat!(
+                "Inherent version of [`DelegatedRng::",
+                stringify!($method),
+                "`], usable without importing [`DelegatedRng`]."
+            )]
+            #[inline]
+            $(#[$attr])*
+            pub fn $method<$($generics)*>(&mut self $(, $input: $type)*) $(-> $output)?
+            $(where $($bounds)*)?
+            {
+                <Self as DelegatedRng>::$method(self $(, $input)*)
+            }
+        )*
+    };
+}
+
+/// Checks the range given to a delegated method in debug builds, so that an empty range
+/// panics with a message naming the wrapper and the method, instead of one from deep inside
+/// [`turborand`]. Inputs that aren't ranges aren't checked.
+macro_rules! check_delegated_input {
+    ($method:ident, $input:ident, range) => {
+        #[cfg(debug_assertions)]
+        crate::range::debug_check_range::<Self, _>(stringify!($method), &$input);
+    };
+    ($method:ident, $input:ident, bound) => {
+        #[cfg(debug_assertions)]
+        crate::range::debug_check_range::<Self, _>(stringify!($method), &$input);
+    };
+    ($method:ident, $input:ident, $other:ident) => {};
+}
+
+/// Generates a function calling every method from a list given by [`delegated_methods`]
+/// with its example input, both through [`DelegatedRng`] and directly on its source, so
+/// that any mismatch between the two fails to compile.
+macro_rules! assert_delegated_parity {
+    ($($method:ident($($input:ident: $type:ty = $example:expr)?) -> $output:ty;)*) => {
+        #[allow(dead_code)]
+        fn assert_delegated_parity<R: DelegatedRng>(rng: &mut R) {
+            $(
+                let _: $output = rng.$method($($example)?);
+                let _: $output = rng.get_mut().$method($($example)?);
+            )*
         }
     };
 }
+
+const _: () = {
+    use crate::*;
+
+    delegated_methods!(assert_delegated_parity);
+};
@@ -0,0 +1,219 @@
+use crate::*;
+use bevy_app::MainScheduleOrder;
+use bevy_ecs::{
+    component::ComponentId,
+    schedule::{InternedScheduleLabel, NodeId, ScheduleLabel},
+};
+use std::collections::{HashMap, HashSet};
+use tracing::warn;
+
+/// Settings for the determinism checks added by [`RngPlugin::deterministic`], inserted by
+/// the plugin along with the seed the app was made deterministic with. The checks only run
+/// while this resource is present.
+///
+/// While [`DeterminismSettings::warn_on_ambiguous_rng`] is set, a warning is logged for every
+/// pair of systems accessing the same global RNG without being ordered against each other,
+/// as the order of their draws may then change between runs. Pairs allowed to be ambiguous,
+/// such as with [`IntoSystemConfigs::ambiguous_with`], are never reported, and each pair is
+/// only reported once. Systems in the [`Last`] schedule aren't checked.
+///
+/// Components seeded from entropy are reported by the [`RngAuditConfig`] check, which
+/// [`RngPlugin::deterministic`] enables as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Resource, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq, Resource)]
+pub struct DeterminismSettings {
+    /// The seed the app was made deterministic with.
+    pub seed: u64,
+    /// Whether to warn about systems accessing the same global RNG without being ordered
+    /// against each other.
+    pub warn_on_ambiguous_rng: bool,
+}
+
+impl DeterminismSettings {
+    /// Creates new [`DeterminismSettings`] for the given seed, with all checks enabled.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            warn_on_ambiguous_rng: true,
+        }
+    }
+}
+
+/// Returns the ids of the global RNG resources that are registered in the `world`.
+fn global_rng_ids(world: &World) -> impl Iterator<Item = ComponentId> + '_ {
+    let components = world.components();
+
+    [
+        #[cfg(feature = "wyrand")]
+        components.resource_id::<GlobalRng>(),
+        #[cfg(feature = "wyrand")]
+        components.resource_id::<StrictGlobalRng>(),
+        #[cfg(feature = "chacha")]
+        components.resource_id::<GlobalChaChaRng>(),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// Warns about pairs of unordered systems accessing the same global RNG, in every schedule
+/// that isn't running. Each pair is only looked at once, so that frames after the schedules
+/// are built only walk their conflicts without allocating.
+pub(crate) fn warn_ambiguous_rng_access(
+    world: &World,
+    mut checked: Local<'_, HashSet<(InternedScheduleLabel, NodeId, NodeId)>>,
+) {
+    let Some(settings) = world.get_resource::<DeterminismSettings>() else {
+        return;
+    };
+
+    if !settings.warn_on_ambiguous_rng {
+        return;
+    }
+
+    let components = world.components();
+
+    for (_, schedule) in world.resource::<Schedules>().iter() {
+        let label = schedule.label();
+
+        for (a, b, conflicts) in schedule.graph().conflicting_systems() {
+            if checked.contains(&(label, *a, *b)) {
+                continue;
+            }
+
+            checked.insert((label, *a, *b));
+
+            let resources: Vec<&str> = conflicts
+                .iter()
+                .filter(|conflict| global_rng_ids(world).any(|id| id == **conflict))
+                .filter_map(|&conflict| components.get_name(conflict))
+                .collect();
+
+            if resources.is_empty() {
+                continue;
+            }
+
+            // Systems are moved out of the graph once a schedule is initialized, so their
+            // names are looked up in the initialized schedule instead.
+            let name_of = |id: &NodeId| {
+                schedule
+                    .systems()
+                    .ok()?
+                    .find(|(system, _)| system == id)
+                    .map(|(_, system)| system.name())
+            };
+            let (Some(a), Some(b)) = (name_of(a), name_of(b)) else {
+                continue;
+            };
+
+            let resources = resources.join(", ");
+
+            warn!(
+                "{a} and {b} in {label:?} both access {resources} without being ordered against each other, so the order of their draws may change between runs"
+            );
+        }
+    }
+}
+
+/// A schedule run by the main loop once, right before [`PreStartup`], checking the order of
+/// the [`RngSystems::SeedSpawning`] set. Building the schedules the set is in initializes the
+/// state of their systems, which may fork a global RNG, so it happens before any system of
+/// the app gets to draw from one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, ScheduleLabel)]
+struct SeedSpawningCheck;
+
+/// Adds the check of the [`RngSystems::SeedSpawning`] set before [`PreStartup`].
+pub(crate) fn register_seed_spawning_check(app: &mut App, strict: bool) {
+    if !app.world().contains_resource::<MainScheduleOrder>() {
+        return;
+    }
+
+    app.add_systems(SeedSpawningCheck, move |world: &mut World| {
+        check_seed_spawning_order(strict, world);
+    });
+    app.world_mut()
+        .resource_mut::<MainScheduleOrder>()
+        .insert_startup_before(PreStartup, SeedSpawningCheck);
+}
+
+/// Reports pairs of systems in the [`RngSystems::SeedSpawning`] set that access the same
+/// global RNG, or the whole [`World`], without being ordered against each other. Runs once,
+/// before [`PreStartup`], building every schedule the set is configured in so that the check
+/// happens before any of them seed. Panics on the first such pair if `strict` is set, and
+/// warns about each pair otherwise.
+fn check_seed_spawning_order(strict: bool, world: &mut World) {
+    let labels: Vec<_> = world
+        .resource::<Schedules>()
+        .iter()
+        .filter(|(_, schedule)| seed_spawning_set(schedule).is_some())
+        .map(|(_, schedule)| schedule.label())
+        .collect();
+
+    let globals: Vec<_> = global_rng_ids(world).collect();
+
+    for label in labels {
+        world.schedule_scope(label, |world, schedule| {
+            // A schedule that fails to build reports its own error once it runs.
+            if schedule.initialize(world).is_ok() {
+                check_schedule(strict, schedule, &globals);
+            }
+        });
+    }
+}
+
+fn seed_spawning_set(schedule: &Schedule) -> Option<NodeId> {
+    schedule.graph().system_sets().find_map(|(id, set, _)| {
+        set.as_dyn_eq()
+            .dyn_eq(RngSystems::SeedSpawning.as_dyn_eq())
+            .then_some(id)
+    })
+}
+
+fn check_schedule(strict: bool, schedule: &Schedule, globals: &[ComponentId]) {
+    let label = schedule.label();
+
+    let (Some(set), Ok(systems)) = (seed_spawning_set(schedule), schedule.systems()) else {
+        return;
+    };
+
+    let graph = schedule.graph();
+
+    // Systems can be in the set through nested sets, so the whole subtree counts.
+    let mut members = HashSet::new();
+    let mut pending = vec![set];
+
+    while let Some(node) = pending.pop() {
+        for child in graph.hierarchy().graph().neighbors(node) {
+            if members.insert(child) {
+                pending.push(child);
+            }
+        }
+    }
+
+    let names: HashMap<NodeId, String> = systems
+        .filter(|(id, _)| members.contains(id))
+        .map(|(id, system)| (id, system.name().to_string()))
+        .collect();
+
+    for (a, b, conflicts) in graph.conflicting_systems() {
+        let (Some(a), Some(b)) = (names.get(a), names.get(b)) else {
+            continue;
+        };
+
+        if !conflicts.is_empty() && !conflicts.iter().any(|conflict| globals.contains(conflict)) {
+            continue;
+        }
+
+        let message = format!(
+            "{a} and {b} in {label:?} are both in RngSystems::SeedSpawning without being ordered against each other, so the order in which they seed may change between runs. Order them with `chain`, `before` or `after`"
+        );
+
+        if strict {
+            panic!("{message}");
+        }
+
+        warn!("{message}");
+    }
+}
@@ -0,0 +1,154 @@
+use crate::*;
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Implements a process-wide call counter for an RNG wrapper, along with its
+/// [`DiagnosticPath`].
+macro_rules! impl_call_counter {
+    ($wrapper:ty, $counter:ident, $path:literal) => {
+        static $counter: AtomicU64 = AtomicU64::new(0);
+
+        impl $wrapper {
+            #[doc = concat!(
+                "The [`DiagnosticPath`] for the number of calls made through all [`",
+                stringify!($wrapper),
+                "`] instances each frame, across the whole process."
+            )]
+            pub const CALLS_DIAGNOSTIC: DiagnosticPath = DiagnosticPath::const_new($path);
+
+            #[doc = concat!(
+                "Returns the number of calls made through all [`",
+                stringify!($wrapper),
+                "`] instances since the last reset. Every [`DelegatedRng`] method counts as ",
+                "at least one call, as does every other access to the source via ",
+                "[`DelegatedRng::get_mut`]. The count is shared by the whole process."
+            )]
+            #[cfg_attr(docsrs, doc(cfg(feature = "diagnostics")))]
+            #[inline]
+            #[must_use]
+            pub fn call_count() -> u64 {
+                $counter.load(Ordering::Relaxed)
+            }
+
+            #[doc = concat!(
+                "Resets the number of calls made through all [`",
+                stringify!($wrapper),
+                "`] instances back to zero, for the whole process."
+            )]
+            #[cfg_attr(docsrs, doc(cfg(feature = "diagnostics")))]
+            #[inline]
+            pub fn reset_call_count() {
+                $counter.store(0, Ordering::Relaxed);
+            }
+
+            #[inline(always)]
+            pub(crate) fn count_call() {
+                $counter.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "wyrand")]
+impl_call_counter!(
+    GlobalRng,
+    GLOBAL_RNG_CALLS,
+    "bevy_turborand/global_rng_calls"
+);
+#[cfg(feature = "wyrand")]
+impl_call_counter!(
+    RngComponent,
+    RNG_COMPONENT_CALLS,
+    "bevy_turborand/rng_component_calls"
+);
+#[cfg(feature = "chacha")]
+impl_call_counter!(
+    GlobalChaChaRng,
+    GLOBAL_CHACHA_RNG_CALLS,
+    "bevy_turborand/global_chacha_rng_calls"
+);
+#[cfg(feature = "chacha")]
+impl_call_counter!(
+    ChaChaRngComponent,
+    CHACHA_RNG_COMPONENT_CALLS,
+    "bevy_turborand/chacha_rng_component_calls"
+);
+
+/// Returns the counters along with their diagnostics, for all enabled wrappers.
+fn counters() -> impl Iterator<Item = (&'static AtomicU64, DiagnosticPath)> {
+    [
+        #[cfg(feature = "wyrand")]
+        (&GLOBAL_RNG_CALLS, GlobalRng::CALLS_DIAGNOSTIC),
+        #[cfg(feature = "wyrand")]
+        (&RNG_COMPONENT_CALLS, RngComponent::CALLS_DIAGNOSTIC),
+        #[cfg(feature = "chacha")]
+        (&GLOBAL_CHACHA_RNG_CALLS, GlobalChaChaRng::CALLS_DIAGNOSTIC),
+        #[cfg(feature = "chacha")]
+        (
+            &CHACHA_RNG_COMPONENT_CALLS,
+            ChaChaRngComponent::CALLS_DIAGNOSTIC,
+        ),
+    ]
+    .into_iter()
+}
+
+/// Returns the current count of the wrapper with the given diagnostic, if it is enabled.
+pub(crate) fn current_count(path: &DiagnosticPath) -> Option<u64> {
+    counters()
+        .find(|(_, counter_path)| counter_path == path)
+        .map(|(counter, _)| counter.load(Ordering::Relaxed))
+}
+
+/// A system that resets the call counts of all RNG wrappers back to zero. The counts are
+/// shared by the whole process, so this resets them for every [`App`] in it, along with the
+/// frame counts of any [`RngBudget`].
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn roll(mut global: ResMut<GlobalRng>) {
+///     global.u32(..);
+///     global.chance(0.5);
+///     global.bool();
+/// }
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins(RngPlugin::new().with_rng_seed(1))
+///     .add_systems(Update, (reset_rng_call_counts, roll).chain());
+///
+/// app.update();
+///
+/// assert_eq!(GlobalRng::call_count(), 3);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "diagnostics")))]
+pub fn reset_rng_call_counts() {
+    for (counter, _) in counters() {
+        counter.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Registers the call count diagnostics of all RNG wrappers.
+pub(crate) fn register_diagnostics(app: &mut App) {
+    for (_, path) in counters() {
+        app.register_diagnostic(Diagnostic::new(path));
+    }
+
+    app.add_systems(Last, measure_rng_calls);
+}
+
+/// Measures the number of calls made since the last time this system ran. Only the
+/// difference is measured, so that resetting the counts doesn't skew the diagnostics.
+fn measure_rng_calls(mut diagnostics: Diagnostics<'_, '_>, mut last: Local<'_, Vec<u64>>) {
+    last.resize(counters().count(), 0);
+
+    for ((counter, path), last) in counters().zip(last.iter_mut()) {
+        let current = counter.load(Ordering::Relaxed);
+        let calls = current.checked_sub(*last).unwrap_or(current);
+
+        diagnostics.add_measurement(&path, || calls as f64);
+        *last = current;
+    }
+}
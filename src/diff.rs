@@ -0,0 +1,279 @@
+use crate::{hash::StableHasher, *};
+use std::fmt;
+
+/// The largest number of draws encoded as an advancement of a [`Rng`]. Any two [`Rng`]
+/// states are on the same stream, so past this the states are treated as unrelated, as a
+/// legitimate advancement of this size is far less likely than a reseed.
+#[cfg(feature = "wyrand")]
+const MAX_WYRAND_DRAWS: u64 = u32::MAX as u64;
+
+/// The increment applied to the state of a [`Rng`] for every draw.
+#[cfg(feature = "wyrand")]
+const WYRAND_INCREMENT: u64 = 0xa076_1d64_78bd_642f;
+
+/// Size of the ChaCha8 state words, in bytes.
+#[cfg(feature = "chacha")]
+const CHACHA_STATE_WORDS: usize = 64;
+
+/// Byte range of the block counter in the state of a [`ChaChaRng`].
+#[cfg(feature = "chacha")]
+const CHACHA_COUNTER: std::ops::Range<usize> = 48..56;
+/// Byte range of the entropy buffer cursor in the state of a [`ChaChaRng`].
+#[cfg(feature = "chacha")]
+const CHACHA_CURSOR: std::ops::Range<usize> = 128..136;
+/// Size of a ChaCha8 block, in bytes.
+#[cfg(feature = "chacha")]
+const CHACHA_BLOCK: u128 = 64;
+
+/// The change between two states of an RNG source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "state-bytes")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub enum RngStateChange {
+    /// The source was advanced along the same stream by a number of draws. A draw is a
+    /// 64-bit output for [`Rng`], and a byte of output for [`ChaChaRng`].
+    Advanced {
+        /// The number of draws the source was advanced by.
+        draws: u64,
+    },
+    /// The source is no longer on the same stream, such as after being reseeded, so its full
+    /// state is sent instead, as obtained from [`StateBytes::state_bytes`].
+    Full(Vec<u8>),
+}
+
+/// A compact description of how the state of an RNG source changed since a baseline state,
+/// for syncing RNGs over the network without sending their full state every time. Created
+/// with [`DelegatedRng::diff`] and applied with [`DelegatedRng::apply_diff`].
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// let mut server = ChaChaRngComponent::with_seed([1; 40]);
+/// let mut client = server.clone();
+///
+/// let baseline = server.clone();
+///
+/// server.u64(..);
+/// server.u32(..);
+///
+/// let diff = server.diff(&baseline);
+///
+/// assert_eq!(diff.change, RngStateChange::Advanced { draws: 12 });
+///
+/// client.apply_diff(&diff).unwrap();
+///
+/// assert_eq!(client, server);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "state-bytes")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct RngStateDiff {
+    /// A fingerprint of the baseline state the diff was made against, so that the diff is
+    /// never applied to any other state.
+    pub baseline: u64,
+    /// The change from the baseline state.
+    pub change: RngStateChange,
+}
+
+impl RngStateDiff {
+    /// Creates a new [`RngStateDiff`] describing the change from `baseline` to `current`.
+    #[must_use]
+    pub fn between<S: StateDiff + PartialEq>(baseline: &S, current: &S) -> Self {
+        let baseline_bytes = baseline.state_bytes();
+
+        // An advancement is only used once confirmed to reproduce the exact same state.
+        let change = match current.draws_since(baseline) {
+            Some(draws) if baseline.advanced_by(draws).ok().as_ref() == Some(current) => {
+                RngStateChange::Advanced { draws }
+            }
+            _ => RngStateChange::Full(current.state_bytes()),
+        };
+
+        Self {
+            baseline: fingerprint(&baseline_bytes),
+            change,
+        }
+    }
+
+    /// Applies the diff to `source`, which must be in the baseline state the diff was made
+    /// against. If the diff can't be applied, `source` is left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RngDiffError::BaselineMismatch`] if `source` is not in the baseline state,
+    /// or [`RngDiffError::InvalidState`] if the diff does not describe a valid state.
+    pub fn apply_to<S: StateDiff>(&self, source: &mut S) -> Result<(), RngDiffError> {
+        let found = fingerprint(&source.state_bytes());
+
+        if found != self.baseline {
+            return Err(RngDiffError::BaselineMismatch {
+                expected: self.baseline,
+                found,
+            });
+        }
+
+        *source = match &self.change {
+            RngStateChange::Advanced { draws } => source.advanced_by(*draws)?,
+            RngStateChange::Full(bytes) => S::from_state_bytes(bytes)?,
+        };
+
+        Ok(())
+    }
+}
+
+fn fingerprint(state: &[u8]) -> u64 {
+    StableHasher::hash_one(state)
+}
+
+/// Errors that can occur when applying a [`RngStateDiff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "state-bytes")))]
+pub enum RngDiffError {
+    /// The diff was made against a different baseline state than the one it was applied to.
+    BaselineMismatch {
+        /// The fingerprint of the baseline state the diff was made against.
+        expected: u64,
+        /// The fingerprint of the state the diff was applied to.
+        found: u64,
+    },
+    /// The diff does not describe a valid state for the RNG source.
+    InvalidState(RngStateError),
+}
+
+impl fmt::Display for RngDiffError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BaselineMismatch { expected, found } => write!(
+                f,
+                "RNG diff baseline mismatch, expected state {expected:#018x} but found {found:#018x}"
+            ),
+            Self::InvalidState(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for RngDiffError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::BaselineMismatch { .. } => None,
+            Self::InvalidState(error) => Some(error),
+        }
+    }
+}
+
+impl From<RngStateError> for RngDiffError {
+    #[inline]
+    fn from(error: RngStateError) -> Self {
+        Self::InvalidState(error)
+    }
+}
+
+/// A trait for RNG sources whose advancement along the same stream can be measured and
+/// replayed, for use with [`RngStateDiff`].
+#[cfg_attr(docsrs, doc(cfg(feature = "state-bytes")))]
+pub trait StateDiff: StateBytes {
+    /// Returns the number of draws `self` is ahead of `baseline` on the same stream, or
+    /// `None` if it is not on the same stream or not ahead of it.
+    fn draws_since(&self, baseline: &Self) -> Option<u64>;
+
+    /// Returns a copy of the source advanced by the given number of draws, without
+    /// generating all the skipped output.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RngStateError::InvalidState`] if the advanced state can't be represented.
+    fn advanced_by(&self, draws: u64) -> Result<Self, RngStateError>;
+}
+
+#[cfg(feature = "wyrand")]
+impl StateDiff for Rng {
+    fn draws_since(&self, baseline: &Self) -> Option<u64> {
+        // The increment is odd, and so always has a multiplicative inverse modulo 2^64.
+        let inverse = (0..5).fold(WYRAND_INCREMENT, |inverse: u64, _| {
+            inverse.wrapping_mul(2u64.wrapping_sub(WYRAND_INCREMENT.wrapping_mul(inverse)))
+        });
+
+        let draws = wyrand_state(self)
+            .wrapping_sub(wyrand_state(baseline))
+            .wrapping_mul(inverse);
+
+        (draws <= MAX_WYRAND_DRAWS).then_some(draws)
+    }
+
+    fn advanced_by(&self, draws: u64) -> Result<Self, RngStateError> {
+        let state = wyrand_state(self).wrapping_add(draws.wrapping_mul(WYRAND_INCREMENT));
+
+        Self::from_state_bytes(&state.to_le_bytes())
+    }
+}
+
+#[cfg(feature = "wyrand")]
+fn wyrand_state(rng: &Rng) -> u64 {
+    read_u64(&rng.state_bytes(), 0..8)
+}
+
+#[cfg(feature = "chacha")]
+impl StateDiff for ChaChaRng {
+    fn draws_since(&self, baseline: &Self) -> Option<u64> {
+        let current = self.state_bytes();
+        let baseline = baseline.state_bytes();
+
+        // Same key, constants and nonce, so only the counter and buffered output may differ.
+        let same_stream = current[..CHACHA_COUNTER.start] == baseline[..CHACHA_COUNTER.start]
+            && current[CHACHA_COUNTER.end..CHACHA_STATE_WORDS]
+                == baseline[CHACHA_COUNTER.end..CHACHA_STATE_WORDS];
+
+        if !same_stream {
+            return None;
+        }
+
+        let draws = chacha_position(&current).checked_sub(chacha_position(&baseline))?;
+
+        u64::try_from(draws).ok()
+    }
+
+    fn advanced_by(&self, draws: u64) -> Result<Self, RngStateError> {
+        if draws == 0 {
+            return Ok(self.clone());
+        }
+
+        let mut state = self.state_bytes();
+        let position = chacha_position(&state) + u128::from(draws);
+
+        // The block holding the last drawn byte, which becomes the buffered output.
+        let block = (position - 1) / CHACHA_BLOCK;
+        let cursor = position - block * CHACHA_BLOCK;
+
+        let block = u64::try_from(block).map_err(|_| RngStateError::InvalidState)?;
+
+        state[CHACHA_COUNTER].copy_from_slice(&block.to_le_bytes());
+        state[CHACHA_CURSOR].copy_from_slice(&(CHACHA_BLOCK as u64).to_le_bytes());
+
+        // Drawing a single byte from an exhausted buffer generates the block into it.
+        let rng = Self::from_state_bytes(&state)?;
+        rng.gen_u8();
+
+        let mut state = rng.state_bytes();
+        state[CHACHA_CURSOR].copy_from_slice(&(cursor as u64).to_le_bytes());
+
+        Self::from_state_bytes(&state)
+    }
+}
+
+/// Returns the number of bytes of output a [`ChaChaRng`] has produced since its counter was
+/// zero, given its state bytes.
+#[cfg(feature = "chacha")]
+fn chacha_position(state: &[u8]) -> u128 {
+    let counter = u128::from(read_u64(state, CHACHA_COUNTER));
+    let cursor = u128::from(read_u64(state, CHACHA_CURSOR));
+
+    (counter * CHACHA_BLOCK + cursor).saturating_sub(CHACHA_BLOCK)
+}
+
+fn read_u64(bytes: &[u8], range: std::ops::Range<usize>) -> u64 {
+    let mut value = [0u8; 8];
+    value.copy_from_slice(&bytes[range]);
+
+    u64::from_le_bytes(value)
+}
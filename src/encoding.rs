@@ -0,0 +1,40 @@
+//! Minimal text encodings for random tokens, to avoid depending on encoding crates.
+
+/// Encodes `bytes` as lowercase hexadecimal.
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let mut encoded = String::with_capacity(bytes.len() * 2);
+
+    for &byte in bytes {
+        encoded.push(char::from(DIGITS[usize::from(byte >> 4)]));
+        encoded.push(char::from(DIGITS[usize::from(byte & 0xf)]));
+    }
+
+    encoded
+}
+
+/// Encodes `bytes` as URL-safe base64, as defined by RFC 4648, without padding.
+pub(crate) fn base64url(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+    let mut encoded = String::with_capacity((bytes.len() * 4).div_ceil(3));
+
+    for chunk in bytes.chunks(3) {
+        let group = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |group, (index, &byte)| {
+                group | u32::from(byte) << (16 - 8 * index)
+            });
+
+        // Each byte of the chunk spreads over one more character.
+        for index in 0..=chunk.len() {
+            let sextet = (group >> (18 - 6 * index)) & 0x3f;
+
+            encoded.push(char::from(ALPHABET[sextet as usize]));
+        }
+    }
+
+    encoded
+}
@@ -0,0 +1,242 @@
+use crate::*;
+
+/// A chance that grows with every miss and resets on a hit, following the pseudo-random
+/// distribution used by games such as Dota 2, so that long streaks of misses or hits are
+/// much rarer than with independent rolls, while hitting at the same overall rate.
+///
+/// The `n`th roll since the last hit succeeds with a chance of `C * n`, where `C` is
+/// computed once on construction so that the long-run rate of hits matches the target
+/// probability, or approximated in closed form for probabilities below `0.001`. This bounds
+/// the longest possible streak of misses to `ceil(1 / C) - 1`.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let mut critical = PityChance::new(0.25);
+///
+/// let hits = (0..1000).filter(|_| critical.roll(&mut rng)).count();
+///
+/// assert!((200..300).contains(&hits));
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct PityChance {
+    probability: f64,
+    constant: f64,
+    misses: u32,
+}
+
+impl PityChance {
+    /// Creates a new [`PityChance`] hitting at the target `probability` in the long run.
+    /// The probability is clamped to the range of `0.0..=1.0`, with NaN being treated as
+    /// `0.0`.
+    #[must_use]
+    pub fn new(probability: f64) -> Self {
+        let probability = if probability.is_nan() {
+            0.0
+        } else {
+            probability.clamp(0.0, 1.0)
+        };
+
+        Self {
+            probability,
+            constant: prd_constant(probability),
+            misses: 0,
+        }
+    }
+
+    /// Rolls the chance, returning `true` on a hit. A hit resets the chance to its starting
+    /// value, while a miss increases it for the next roll.
+    pub fn roll(&mut self, rng: &mut impl DelegatedRng) -> bool {
+        let hit = rng.chance(self.current_chance());
+
+        if hit {
+            self.misses = 0;
+        } else {
+            self.misses = self.misses.saturating_add(1);
+        }
+
+        hit
+    }
+
+    /// Returns the chance of the next roll hitting.
+    #[inline]
+    #[must_use]
+    pub fn current_chance(&self) -> f64 {
+        (self.constant * (f64::from(self.misses) + 1.0)).min(1.0)
+    }
+
+    /// Returns the target probability of hitting in the long run.
+    #[inline]
+    #[must_use]
+    pub const fn probability(&self) -> f64 {
+        self.probability
+    }
+
+    /// Returns the constant `C` by which the chance grows with every miss, which is also
+    /// the chance of the first roll after a hit.
+    #[inline]
+    #[must_use]
+    pub const fn constant(&self) -> f64 {
+        self.constant
+    }
+
+    /// Returns the amount of misses since the last hit.
+    #[inline]
+    #[must_use]
+    pub const fn misses(&self) -> u32 {
+        self.misses
+    }
+
+    /// Resets the chance to its starting value, as if the last roll had hit.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.misses = 0;
+    }
+}
+
+/// Below this probability, [`prd_constant`] uses its closed-form approximation, which is
+/// within a relative error of `1e-6` there, instead of bisecting, as the amount of rolls to
+/// sum over grows with `1 / probability`.
+const PRD_APPROXIMATION_THRESHOLD: f64 = 1e-3;
+
+/// Returns the long-run hit rate of a pseudo-random distribution with the given constant.
+/// Stops summing once the chance of still missing is negligible, rather than only once the
+/// chance reaches `1.0`, which for small constants would take `1 / constant` rolls.
+fn prd_probability(constant: f64) -> f64 {
+    let mut expected_rolls = 0.0;
+    let mut all_missed = 1.0;
+    let mut roll = 1.0;
+
+    loop {
+        let chance = (constant * roll).min(1.0);
+
+        expected_rolls += roll * chance * all_missed;
+        all_missed *= 1.0 - chance;
+
+        if chance >= 1.0 || all_missed < 1e-20 {
+            return 1.0 / expected_rolls;
+        }
+
+        roll += 1.0;
+    }
+}
+
+/// Finds the constant whose pseudo-random distribution hits at `probability` in the long
+/// run, by bisection, as the hit rate grows with the constant. Small probabilities use the
+/// approximation `C = π / (2 * (1 / p + 1 / 3)²)` instead, from the expected amount of rolls
+/// of the distribution approaching `sqrt(π / (2 * C)) - 1 / 3` as `C` shrinks.
+fn prd_constant(probability: f64) -> f64 {
+    if probability <= 0.0 || probability >= 1.0 {
+        return probability;
+    }
+
+    if probability < PRD_APPROXIMATION_THRESHOLD {
+        return std::f64::consts::PI / (2.0 * (1.0 / probability + 1.0 / 3.0).powi(2));
+    }
+
+    let (mut lower, mut upper) = (0.0, probability);
+
+    for _ in 0..64 {
+        let middle = (lower + upper) / 2.0;
+
+        if prd_probability(middle) < probability {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+
+    upper
+}
+
+/// A bag of hits and misses drawn without replacement, guaranteeing exactly `successes` hits
+/// in every `draws` rolls, such as a drop that happens exactly once every ten kills, in a
+/// random position among them. The bag is refilled and shuffled whenever it runs empty.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let mut drops = BagRandom::new(1, 10);
+///
+/// let hits = (0..10).filter(|_| drops.roll(&mut rng)).count();
+///
+/// assert_eq!(hits, 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct BagRandom {
+    successes: u32,
+    draws: u32,
+    /// The outcomes left in the current bag, with the next one to be drawn last.
+    bag: Vec<bool>,
+}
+
+impl BagRandom {
+    /// Creates a new [`BagRandom`] with `successes` hits in every `draws` rolls. The first
+    /// bag is filled on the first roll.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `draws` is zero or if `successes` is greater than `draws`.
+    #[inline]
+    #[must_use]
+    pub fn new(successes: u32, draws: u32) -> Self {
+        assert!(
+            draws > 0 && successes <= draws,
+            "bag must have at least one draw and no more successes than draws"
+        );
+
+        Self {
+            successes,
+            draws,
+            bag: Vec::new(),
+        }
+    }
+
+    /// Draws the next outcome from the bag, returning `true` on a hit. An empty bag is
+    /// refilled and shuffled first.
+    pub fn roll(&mut self, rng: &mut impl DelegatedRng) -> bool {
+        if self.bag.is_empty() {
+            self.refill(rng);
+        }
+
+        self.bag.pop().unwrap_or_default()
+    }
+
+    /// Empties the bag, refills it with all its outcomes and shuffles it, starting a new
+    /// run of `draws` rolls.
+    pub fn refill(&mut self, rng: &mut impl DelegatedRng) {
+        self.bag.clear();
+        self.bag
+            .extend((0..self.draws).map(|draw| draw < self.successes));
+        rng.shuffle(&mut self.bag);
+    }
+
+    /// Returns the amount of outcomes left in the current bag.
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.bag.len()
+    }
+
+    /// Returns the amount of hits in every bag.
+    #[inline]
+    #[must_use]
+    pub const fn successes(&self) -> u32 {
+        self.successes
+    }
+
+    /// Returns the amount of rolls in every bag.
+    #[inline]
+    #[must_use]
+    pub const fn draws(&self) -> u32 {
+        self.draws
+    }
+}
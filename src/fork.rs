@@ -0,0 +1,233 @@
+use crate::*;
+#[cfg(feature = "bevy_hierarchy")]
+use bevy_hierarchy::Parent;
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    marker::PhantomData,
+};
+
+/// A marker [`Component`] for re-forking RNG components as soon as they are added
+/// to an entity, so that entities spawned from the same serialized state (such as
+/// instances of a scene) don't all end up with identical RNG states.
+///
+/// When an [`RngComponent`] (or a [`ChaChaRngComponent`]) is added to an entity carrying
+/// this marker, [`RngPlugin`] replaces its state with a fork of the RNG component on the
+/// entity's [`Parent`], or of the global RNG resource if there is no parent with one, keeping
+/// the audit log it records into, if any. Newly added components are processed parents first,
+/// so that children fork from the re-forked state of their parents, and otherwise in
+/// [`Entity`] order, so that the result is deterministic regardless of query iteration order
+/// or of how entity ids were recycled. This happens during [`PreUpdate`], within the
+/// [`RngSystems::ForkOnSpawn`] set.
+///
+/// Parents are only looked up with the `bevy_hierarchy` feature. Without it, every component
+/// forks from the global RNG resource, in [`Entity`] order.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Debug, Component, Default)]
+/// struct Goblin;
+///
+/// fn spawn_goblins(mut commands: Commands) {
+///     let template = RngComponent::with_seed(12345);
+///
+///     for _ in 0..50 {
+///         // Each goblin receives its own fork instead of the template's state.
+///         commands.spawn((Goblin, template.clone(), ForkOnSpawn));
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Component, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[reflect(Debug, Default, PartialEq, Component)]
+pub struct ForkOnSpawn;
+
+/// The parent of a newly added RNG component, if any.
+#[cfg(feature = "bevy_hierarchy")]
+type SpawnedParent = Option<&'static Parent>;
+/// Parents aren't tracked without the `bevy_hierarchy` feature.
+#[cfg(not(feature = "bevy_hierarchy"))]
+type SpawnedParent = ();
+
+/// Newly added RNG components marked with [`ForkOnSpawn`], alongside mutable access
+/// to all RNG components of the same kind, for forking from parents.
+type ForkQueries<'w, 's, C> = ParamSet<
+    'w,
+    's,
+    (
+        Query<'static, 'static, (Entity, SpawnedParent), (With<ForkOnSpawn>, Added<C>)>,
+        Query<'static, 'static, &'static mut C>,
+    ),
+>;
+
+#[cfg(feature = "bevy_hierarchy")]
+#[inline]
+fn parent_entity(parent: Option<&Parent>) -> Option<Entity> {
+    parent.map(Parent::get)
+}
+
+#[cfg(not(feature = "bevy_hierarchy"))]
+#[inline]
+fn parent_entity((): ()) -> Option<Entity> {
+    None
+}
+
+/// Orders newly spawned entities and their parents so that every parent that was also just
+/// spawned comes before its children, breaking ties by [`Entity`].
+fn fork_order(
+    spawned: impl Iterator<Item = (Entity, Option<Entity>)>,
+) -> Vec<(Entity, Option<Entity>)> {
+    let spawned: Vec<_> = spawned.collect();
+    let pending: HashSet<Entity> = spawned.iter().map(|&(entity, _)| entity).collect();
+
+    let mut ready = BTreeSet::new();
+    let mut children: HashMap<Entity, Vec<(Entity, Option<Entity>)>> = HashMap::new();
+
+    for (entity, parent) in spawned {
+        match parent.filter(|parent| pending.contains(parent)) {
+            Some(parent) => children
+                .entry(parent)
+                .or_default()
+                .push((entity, Some(parent))),
+            None => {
+                ready.insert((entity, parent));
+            }
+        }
+    }
+
+    let mut order = Vec::with_capacity(pending.len());
+
+    while let Some(next) = ready.pop_first() {
+        if let Some(children) = children.remove(&next.0) {
+            ready.extend(children);
+        }
+
+        order.push(next);
+    }
+
+    order
+}
+
+/// Re-forks newly added [`RngComponent`]s marked with [`ForkOnSpawn`].
+#[cfg(feature = "wyrand")]
+pub(crate) fn fork_rng_on_spawn(
+    queries: ForkQueries<'_, '_, RngComponent>,
+    mut global: ResMut<'_, GlobalRng>,
+) {
+    fork_rng_components(queries, || RngComponent::from(&mut global));
+}
+
+/// Re-forks newly added [`RngComponent`]s marked with [`ForkOnSpawn`], in strict mode.
+#[cfg(feature = "wyrand")]
+pub(crate) fn fork_rng_on_spawn_strict(
+    queries: ForkQueries<'_, '_, RngComponent>,
+    mut global: ResMut<'_, StrictGlobalRng>,
+) {
+    fork_rng_components(queries, || global.seed_component());
+}
+
+#[cfg(feature = "wyrand")]
+fn fork_rng_components(
+    mut queries: ForkQueries<'_, '_, RngComponent>,
+    mut from_global: impl FnMut() -> RngComponent,
+) {
+    let spawned = fork_order(
+        queries
+            .p0()
+            .iter()
+            .map(|(entity, parent)| (entity, parent_entity(parent))),
+    );
+
+    let mut q_rng = queries.p1();
+
+    for (entity, parent) in spawned {
+        let forked = parent
+            .and_then(|parent| q_rng.get_mut(parent).ok())
+            .map_or_else(&mut from_global, |mut parent| {
+                RngComponent::from(&mut parent)
+            });
+
+        if let Ok(mut rng) = q_rng.get_mut(entity) {
+            rng.set_source(forked.into_inner());
+        }
+    }
+}
+
+/// Re-forks newly added [`ChaChaRngComponent`]s marked with [`ForkOnSpawn`].
+#[cfg(feature = "chacha")]
+pub(crate) fn fork_chacha_on_spawn(
+    mut queries: ForkQueries<'_, '_, ChaChaRngComponent>,
+    mut global: ResMut<'_, GlobalChaChaRng>,
+) {
+    let spawned = fork_order(
+        queries
+            .p0()
+            .iter()
+            .map(|(entity, parent)| (entity, parent_entity(parent))),
+    );
+
+    let mut q_rng = queries.p1();
+
+    for (entity, parent) in spawned {
+        let forked = parent
+            .and_then(|parent| q_rng.get_mut(parent).ok())
+            .map_or_else(
+                || ChaChaRngComponent::from(&mut global),
+                |mut parent| ChaChaRngComponent::from(&mut parent),
+            );
+
+        if let Ok(mut rng) = q_rng.get_mut(entity) {
+            rng.set_source(forked.into_inner());
+        }
+    }
+}
+
+/// An RNG forked from the RNG resource `R` on creation, for use as a [`Local`] in systems
+/// and run conditions. The fork happens when the system is initialised, so that drawing from
+/// it afterwards never requires access to `R`, allowing the system to run in parallel with any
+/// other users of `R`, while still being deterministic as long as `R` is seeded and systems are
+/// initialised in the same order.
+///
+/// # Panics
+///
+/// Panics on creation if the `R` resource is not present in the [`World`].
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn spawn_sparks(mut rng: Local<ForkedRng<GlobalRng>>) {
+///     let sparks = rng.usize(1..=5);
+/// }
+/// ```
+pub struct ForkedRng<R: DelegatedRng + Resource> {
+    source: R::Source,
+    _resource: PhantomData<fn() -> R>,
+}
+
+impl<R: DelegatedRng + Resource> FromWorld for ForkedRng<R> {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            source: world.resource_mut::<R>().fork(),
+            _resource: PhantomData,
+        }
+    }
+}
+
+impl<R: DelegatedRng + Resource> DelegatedRng for ForkedRng<R> {
+    type Source = R::Source;
+
+    #[inline]
+    fn get_mut(&mut self) -> &mut Self::Source {
+        &mut self.source
+    }
+}
+
+impl<R: DelegatedRng + Resource> std::fmt::Debug for ForkedRng<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ForkedRng").field(&self.source).finish()
+    }
+}
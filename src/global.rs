@@ -1,5 +1,14 @@
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub mod entropy;
+
 #[cfg(feature = "wyrand")]
 pub mod rng;
 
+#[cfg(feature = "wyrand")]
+pub mod startup;
+
+#[cfg(feature = "wyrand")]
+pub mod strict;
+
 #[cfg(feature = "chacha")]
 pub mod chacha;
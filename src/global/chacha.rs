@@ -1,34 +1,146 @@
+#[cfg(feature = "audit")]
+use crate::audit::{AuditSink, AuditedRng};
+use crate::sync::SyncRng;
 use crate::*;
+use std::ops::RangeBounds;
 
 /// A Global [`ChaChaRng`] instance, meant for use as a Resource. Gets
 /// created automatically with [`RngPlugin`], or can be created
 /// and added manually.
-#[derive(Debug, Clone, Resource, PartialEq, Reflect)]
+///
+/// The seed a [`GlobalChaChaRng`] was created with is recorded alongside its state, such as
+/// for attaching the session seed to a bug report, and can be read with
+/// [`GlobalChaChaRng::initial_seed`]. Comparing two instances only compares their states.
+#[derive(Debug, Clone, Resource, Reflect)]
 #[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serialize",
-    reflect(opaque, Debug, PartialEq, Default, Serialize, Deserialize)
+    reflect(opaque, Debug, PartialEq, Default, Resource, Serialize, Deserialize)
 )]
-#[cfg_attr(not(feature = "serialize"), reflect(opaque, Debug, PartialEq, Default))]
-pub struct GlobalChaChaRng(ChaChaRng);
-
-unsafe impl Sync for GlobalChaChaRng {}
+#[cfg_attr(
+    not(feature = "serialize"),
+    reflect(opaque, Debug, PartialEq, Default, Resource)
+)]
+pub struct GlobalChaChaRng(
+    SyncRng<ChaChaRng>,
+    #[cfg_attr(feature = "serialize", serde(default))] Option<ChaChaSeed>,
+    #[cfg(feature = "audit")]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    AuditSink,
+);
 
 impl GlobalChaChaRng {
     /// Create a new [`GlobalChaChaRng`] instance with a randomised seed.
     #[inline]
     #[must_use]
     pub fn new() -> Self {
-        Self(ChaChaRng::new())
+        Self(
+            SyncRng::new(ChaChaRng::new()),
+            None,
+            #[cfg(feature = "audit")]
+            AuditSink::DETACHED,
+        )
     }
 
     /// Create a new [`GlobalChaChaRng`] instance with a given seed.
     #[inline]
     #[must_use]
     pub fn with_seed(seed: [u8; 40]) -> Self {
-        Self(ChaChaRng::with_seed(seed))
+        Self(
+            SyncRng::new(ChaChaRng::with_seed(seed)),
+            Some(ChaChaSeed(seed)),
+            #[cfg(feature = "audit")]
+            AuditSink::DETACHED,
+        )
+    }
+
+    /// Create a new [`GlobalChaChaRng`] instance with a randomised seed, which is drawn before
+    /// constructing the [`ChaChaRng`] so that it is recorded, unlike with
+    /// [`GlobalChaChaRng::new`].
+    #[inline]
+    #[must_use]
+    pub fn with_recorded_entropy() -> Self {
+        let mut seed = [0; 40];
+        ChaChaRng::new().fill_bytes(&mut seed);
+
+        Self::with_seed(seed)
     }
+
+    /// Create a new [`GlobalChaChaRng`] instance from a human-friendly seed string, such as
+    /// one shared between players. See [`chacha_seed_from_str`] for how the string maps to
+    /// a seed.
+    #[inline]
+    #[must_use]
+    pub fn from_seed_str(seed: &str) -> Self {
+        Self::with_seed(chacha_seed_from_str(seed))
+    }
+
+    /// Forks a new [`ChaChaRngComponent`] from the [`GlobalChaChaRng`] with
+    /// [`ForkableCore::fork`], the same as wrapping the source from [`DelegatedRng::fork`]
+    /// would.
+    #[inline]
+    #[must_use]
+    pub fn fork_component(&mut self) -> ChaChaRngComponent {
+        ChaChaRngComponent::from(self.fork())
+    }
+
+    /// Forks `n` new [`ChaChaRngComponent`]s from the [`GlobalChaChaRng`] in a single call,
+    /// such as for spawning many entities at once, yielding the same components as `n`
+    /// sequential calls to [`GlobalChaChaRng::fork_component`].
+    #[inline]
+    #[must_use]
+    pub fn fork_components(&mut self, n: usize) -> Vec<ChaChaRngComponent> {
+        self.fork_many(n)
+            .into_iter()
+            .map(ChaChaRngComponent::from)
+            .collect()
+    }
+
+    /// Reseeds the [`GlobalChaChaRng`] with the given seed, recording it as its new initial
+    /// seed, the same as [`DelegatedRng::reseed`] does, without needing to import
+    /// [`DelegatedRng`].
+    #[inline]
+    pub fn reseed(&mut self, seed: [u8; 40]) {
+        <Self as DelegatedRng>::reseed(self, seed);
+    }
+
+    /// Reseeds the [`GlobalChaChaRng`] with fresh entropy, pulled from the same source as
+    /// [`GlobalChaChaRng::new`], so that its stream can no longer be predicted from any previous seed
+    /// or state. Works on `wasm32` the same way [`GlobalChaChaRng::new`] does.
+    /// If a seed was recorded, the drawn seed is recorded in its place, otherwise none is.
+    #[inline]
+    pub fn reseed_from_entropy(&mut self) {
+        if self.1.is_some() {
+            let mut seed = [0; 40];
+            ChaChaRng::new().fill_bytes(&mut seed);
+
+            self.reseed(seed);
+        } else {
+            *self.get_mut() = ChaChaRng::new();
+        }
+    }
+
+    /// Returns the seed the [`GlobalChaChaRng`] was created or last reseeded with, or `None`
+    /// if it was seeded from entropy without recording the seed, or wraps an existing
+    /// [`ChaChaRng`].
+    #[inline]
+    #[must_use]
+    pub fn initial_seed(&self) -> Option<[u8; 40]> {
+        self.1.map(|seed| seed.0)
+    }
+
+    /// Consumes the [`GlobalChaChaRng`], returning the wrapped [`ChaChaRng`] with its state intact.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> ChaChaRng {
+        self.0.into_inner()
+    }
+}
+
+impl GlobalChaChaRng {
+    delegated_methods!(delegate_inherent_methods);
+    inherent_methods!(delegate_inherent_methods);
 }
 
 impl DelegatedRng for GlobalChaChaRng {
@@ -55,7 +167,32 @@ impl DelegatedRng for GlobalChaChaRng {
     /// ```
     #[inline]
     fn get_mut(&mut self) -> &mut Self::Source {
-        &mut self.0
+        #[cfg(feature = "diagnostics")]
+        Self::count_call();
+
+        self.0.get_mut()
+    }
+
+    /// Reseeds the [`GlobalChaChaRng`] with the given seed, recording it as its new initial
+    /// seed.
+    #[inline]
+    fn reseed(&mut self, seed: [u8; 40]) {
+        self.get_mut().reseed(seed);
+        self.1 = Some(ChaChaSeed(seed));
+    }
+
+    #[cfg(feature = "audit")]
+    #[inline]
+    fn audit_log(&self) -> Option<&RngAuditLog> {
+        self.2.log()
+    }
+}
+
+#[cfg(feature = "audit")]
+impl AuditedRng for GlobalChaChaRng {
+    #[inline]
+    fn audit_sink_mut(&mut self) -> &mut AuditSink {
+        &mut self.2
     }
 }
 
@@ -69,6 +206,27 @@ impl Default for GlobalChaChaRng {
     }
 }
 
+impl PartialEq for GlobalChaChaRng {
+    /// Compares the states of both instances, ignoring their recorded seeds.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl From<ChaChaRng> for GlobalChaChaRng {
+    /// Wraps an existing [`ChaChaRng`] as is, taking ownership of it without reseeding or forking.
+    #[inline]
+    fn from(rng: ChaChaRng) -> Self {
+        Self(
+            SyncRng::new(rng),
+            None,
+            #[cfg(feature = "audit")]
+            AuditSink::DETACHED,
+        )
+    }
+}
+
 impl AsMut<ChaChaRng> for GlobalChaChaRng {
     fn as_mut(&mut self) -> &mut ChaChaRng {
         self.get_mut()
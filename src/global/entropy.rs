@@ -0,0 +1,43 @@
+use crate::*;
+use bevy_ecs::world::Command;
+
+/// A [`Command`] that reseeds the global RNG resources from fresh entropy, such as for
+/// periodically re-randomising them at runtime. Each of [`GlobalRng`] and [`GlobalChaChaRng`]
+/// is reseeded via its `reseed_from_entropy` method if present in the [`World`], and skipped
+/// otherwise.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn rerandomize(mut commands: Commands) {
+///     commands.queue(ReseedFromEntropy);
+/// }
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins(RngPlugin::new().with_rng_seed(5))
+///     .add_systems(Update, rerandomize);
+///
+/// app.update();
+///
+/// assert_ne!(app.world().resource::<GlobalRng>(), &GlobalRng::with_seed(5));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "wyrand", feature = "chacha"))))]
+pub struct ReseedFromEntropy;
+
+impl Command for ReseedFromEntropy {
+    fn apply(self, world: &mut World) {
+        #[cfg(feature = "wyrand")]
+        if let Some(mut global) = world.get_resource_mut::<GlobalRng>() {
+            global.reseed_from_entropy();
+        }
+
+        #[cfg(feature = "chacha")]
+        if let Some(mut global) = world.get_resource_mut::<GlobalChaChaRng>() {
+            global.reseed_from_entropy();
+        }
+    }
+}
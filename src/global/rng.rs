@@ -1,34 +1,153 @@
+#[cfg(feature = "audit")]
+use crate::audit::{AuditSink, AuditedRng};
+use crate::sync::SyncRng;
 use crate::*;
+use std::ops::RangeBounds;
 
 /// A Global [`Rng`] instance, meant for use as a Resource. Gets
 /// created automatically with [`RngPlugin`], or can be created
 /// and added manually.
-#[derive(Debug, Clone, Resource, PartialEq, Reflect)]
+///
+/// The seed a [`GlobalRng`] was created with is recorded alongside its state, such as for
+/// attaching the session seed to a bug report, and can be read with
+/// [`GlobalRng::initial_seed`]. Comparing two instances only compares their states.
+#[derive(Debug, Clone, Resource, Reflect)]
 #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serialize",
-    reflect(opaque, Debug, PartialEq, Default, Serialize, Deserialize)
+    reflect(opaque, Debug, PartialEq, Default, Resource, Serialize, Deserialize)
 )]
-#[cfg_attr(not(feature = "serialize"), reflect(opaque, Debug, PartialEq, Default))]
-pub struct GlobalRng(#[reflect(default)] Rng);
-
-unsafe impl Sync for GlobalRng {}
+#[cfg_attr(
+    not(feature = "serialize"),
+    reflect(opaque, Debug, PartialEq, Default, Resource)
+)]
+pub struct GlobalRng(
+    #[reflect(default)] SyncRng<Rng>,
+    #[cfg_attr(feature = "serialize", serde(default))] Option<u64>,
+    #[cfg(feature = "audit")]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    AuditSink,
+);
 
 impl GlobalRng {
     /// Create a new [`GlobalRng`] instance with a randomised seed.
     #[inline]
     #[must_use]
     pub fn new() -> Self {
-        Self(Rng::new())
+        Self(
+            SyncRng::new(Rng::new()),
+            None,
+            #[cfg(feature = "audit")]
+            AuditSink::DETACHED,
+        )
     }
 
     /// Create a new [`GlobalRng`] instance with a given seed.
     #[inline]
     #[must_use]
     pub fn with_seed(seed: u64) -> Self {
-        Self(Rng::with_seed(seed))
+        Self(
+            SyncRng::new(Rng::with_seed(seed)),
+            Some(seed),
+            #[cfg(feature = "audit")]
+            AuditSink::DETACHED,
+        )
     }
+
+    /// Create a new [`GlobalRng`] instance with a randomised seed, which is drawn before
+    /// constructing the [`Rng`] so that it is recorded, unlike with [`GlobalRng::new`].
+    #[inline]
+    #[must_use]
+    pub fn with_recorded_entropy() -> Self {
+        Self::with_seed(Rng::new().gen_u64())
+    }
+
+    /// Create a new [`GlobalRng`] instance with the seed derived by a [`SeedMixer`].
+    #[inline]
+    #[must_use]
+    pub fn with_mixed_seed(mixer: SeedMixer) -> Self {
+        Self::with_seed(mixer.finish())
+    }
+
+    /// Create a new [`GlobalRng`] instance from a human-friendly seed string, such as one
+    /// shared between players. See [`seed_from_str`] for how the string maps to a seed.
+    #[inline]
+    #[must_use]
+    pub fn from_seed_str(seed: &str) -> Self {
+        Self::with_seed(seed_from_str(seed))
+    }
+
+    /// Create a new [`GlobalRng`] with an exact copy of the state of `component`, so that both
+    /// yield the same outputs from then on, such as for promoting an entity's stream to drive
+    /// world events. Unlike seeding an [`RngComponent`] with [`RngComponent::from`], which
+    /// forks a new stream by drawing a seed from its parent, this neither advances
+    /// `component` nor starts a different stream. No initial seed is recorded.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut player = RngComponent::with_seed(5);
+    /// let mut global = GlobalRng::from_component(&player);
+    ///
+    /// assert_eq!(global.u64(..), player.u64(..));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_component(component: &RngComponent) -> Self {
+        Self::from(component.clone().into_inner())
+    }
+
+    /// Exchanges the states of the [`GlobalRng`] and `other`, such as an [`RngComponent`], so
+    /// that each continues the stream of the other, without advancing either. Only the
+    /// states are exchanged, so the recorded initial seed is left as is, the same as when
+    /// swapping from the other side with [`RngComponent::swap_state`].
+    #[inline]
+    pub fn swap_state(&mut self, other: &mut impl DelegatedRng<Source = Rng>) {
+        std::mem::swap(self.get_mut(), other.get_mut());
+    }
+
+    /// Reseeds the [`GlobalRng`] with the given seed, recording it as its new initial seed,
+    /// the same as [`DelegatedRng::reseed`] does, without needing to import
+    /// [`DelegatedRng`]. Leaves it in the same state as [`GlobalRng::with_seed`] would.
+    #[inline]
+    pub fn reseed(&mut self, seed: u64) {
+        <Self as DelegatedRng>::reseed(self, seed);
+    }
+
+    /// Reseeds the [`GlobalRng`] with fresh entropy, pulled from the same source as
+    /// [`GlobalRng::new`], so that its stream can no longer be predicted from any previous seed
+    /// or state. Works on `wasm32` the same way [`GlobalRng::new`] does. If a seed was
+    /// recorded, the drawn seed is recorded in its place, otherwise none is.
+    #[inline]
+    pub fn reseed_from_entropy(&mut self) {
+        if self.1.is_some() {
+            self.reseed(Rng::new().gen_u64());
+        } else {
+            *self.get_mut() = Rng::new();
+        }
+    }
+
+    /// Returns the seed the [`GlobalRng`] was created or last reseeded with, or `None` if it
+    /// was seeded from entropy without recording the seed, or wraps an existing [`Rng`].
+    #[inline]
+    #[must_use]
+    pub const fn initial_seed(&self) -> Option<u64> {
+        self.1
+    }
+
+    /// Consumes the [`GlobalRng`], returning the wrapped [`Rng`] with its state intact.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Rng {
+        self.0.into_inner()
+    }
+}
+
+impl GlobalRng {
+    delegated_methods!(delegate_inherent_methods);
+    inherent_methods!(delegate_inherent_methods);
 }
 
 impl DelegatedRng for GlobalRng {
@@ -55,7 +174,33 @@ impl DelegatedRng for GlobalRng {
     /// ```
     #[inline]
     fn get_mut(&mut self) -> &mut Self::Source {
-        &mut self.0
+        #[cfg(feature = "diagnostics")]
+        Self::count_call();
+
+        self.0.get_mut()
+    }
+
+    /// Reseeds the [`GlobalRng`] with the given seed, recording it as its new initial seed.
+    /// Unlike reseeding the wrapped [`Rng`] directly, this leaves it in the same state as
+    /// [`GlobalRng::with_seed`] would, so that the recorded seed reproduces the stream.
+    #[inline]
+    fn reseed(&mut self, seed: u64) {
+        *self.get_mut() = Rng::with_seed(seed);
+        self.1 = Some(seed);
+    }
+
+    #[cfg(feature = "audit")]
+    #[inline]
+    fn audit_log(&self) -> Option<&RngAuditLog> {
+        self.2.log()
+    }
+}
+
+#[cfg(feature = "audit")]
+impl AuditedRng for GlobalRng {
+    #[inline]
+    fn audit_sink_mut(&mut self) -> &mut AuditSink {
+        &mut self.2
     }
 }
 
@@ -69,6 +214,27 @@ impl Default for GlobalRng {
     }
 }
 
+impl PartialEq for GlobalRng {
+    /// Compares the states of both instances, ignoring their recorded seeds.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl From<Rng> for GlobalRng {
+    /// Wraps an existing [`Rng`] as is, taking ownership of it without reseeding or forking.
+    #[inline]
+    fn from(rng: Rng) -> Self {
+        Self(
+            SyncRng::new(rng),
+            None,
+            #[cfg(feature = "audit")]
+            AuditSink::DETACHED,
+        )
+    }
+}
+
 impl AsMut<Rng> for GlobalRng {
     fn as_mut(&mut self) -> &mut Rng {
         self.get_mut()
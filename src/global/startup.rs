@@ -0,0 +1,54 @@
+use crate::*;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::SystemTime;
+
+#[cfg(target_arch = "wasm32")]
+use instant::SystemTime;
+
+/// The seed the [`RngPlugin`] seeded the [`GlobalRng`] (or [`StrictGlobalRng`]) with at
+/// startup, for displaying it in a UI or logging it, so that a player can report the seed of
+/// a session for reproducing a bug. Inserted by the plugin whenever it seeds the global
+/// resource with a known seed, such as with [`RngPlugin::with_time_seed`] or
+/// [`RngPlugin::with_rng_seed`], and absent when the seed was randomised.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins(RngPlugin::new().with_time_seed());
+///
+/// let seed = app.world().resource::<RngStartupSeed>().get();
+///
+/// assert_eq!(app.world().resource::<GlobalRng>(), &GlobalRng::with_seed(seed));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Resource, Reflect)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, PartialEq, Hash, Resource, Serialize, Deserialize)
+)]
+#[cfg_attr(not(feature = "serialize"), reflect(Debug, PartialEq, Hash, Resource))]
+pub struct RngStartupSeed(pub u64);
+
+impl RngStartupSeed {
+    /// Returns the seed.
+    #[inline]
+    #[must_use]
+    pub const fn get(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Derives a seed from the current wall clock time, as the amount of whole seconds since the
+/// Unix epoch, so that it reads as a plain timestamp such as `1718822400`. Uses
+/// `Date.now()` on `wasm32`. Should the clock be set before the epoch, a random seed is
+/// returned instead.
+pub(crate) fn time_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or_else(|_| Rng::new().gen_u64(), |elapsed| elapsed.as_secs())
+}
@@ -0,0 +1,197 @@
+use crate::sync::SyncRng;
+use crate::*;
+
+/// A strict alternative to [`GlobalRng`], for catching accidental use of the global RNG as
+/// a general source of entropy. It does not implement [`DelegatedRng`], so no values can be
+/// drawn from it directly: it can only seed other RNGs, such as with
+/// [`StrictGlobalRng::seed_component`]. Inserted by [`RngPlugin`] in place of [`GlobalRng`]
+/// when set up with [`RngPlugin::with_strict_global`], alongside a [`GlobalEntropy`] for the
+/// rare cases where drawing from a global RNG is intended.
+///
+/// # Migrating from [`GlobalRng`]
+///
+/// Systems taking a `ResMut<GlobalRng>` take a `ResMut<StrictGlobalRng>` instead.
+/// `RngComponent::from(&mut global)` becomes `global.seed_component()`, and
+/// `global.fork()` stays as is, with both giving the same results as with a [`GlobalRng`]
+/// in the same state. Systems drawing values directly move to [`GlobalEntropy`], or better
+/// yet, to a [`ForkedRng`] or an [`RngComponent`] of their own.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// fn setup_player(mut commands: Commands, mut global: ResMut<StrictGlobalRng>) {
+///     commands.spawn((Player, global.seed_component()));
+/// }
+///
+/// App::new()
+///     .add_plugins(RngPlugin::new().with_rng_seed(12345).with_strict_global())
+///     .add_systems(Startup, setup_player)
+///     .run();
+/// ```
+///
+/// Drawing values directly does not compile:
+///
+/// ```compile_fail
+/// use bevy_turborand::prelude::*;
+///
+/// let mut global = StrictGlobalRng::with_seed(12345);
+///
+/// let value = global.u32(..);
+/// ```
+#[derive(Debug, Clone, Resource, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct StrictGlobalRng(SyncRng<Rng>);
+
+impl StrictGlobalRng {
+    /// Create a new [`StrictGlobalRng`] instance with a randomised seed.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self(SyncRng::new(Rng::new()))
+    }
+
+    /// Create a new [`StrictGlobalRng`] instance with a given seed.
+    #[inline]
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self(SyncRng::new(Rng::with_seed(seed)))
+    }
+
+    /// Create a new [`StrictGlobalRng`] instance from a human-friendly seed string. See
+    /// [`seed_from_str`] for how the string maps to a seed.
+    #[inline]
+    #[must_use]
+    pub fn from_seed_str(seed: &str) -> Self {
+        Self::with_seed(seed_from_str(seed))
+    }
+
+    /// Forks a new [`Rng`] instance from the global RNG.
+    #[inline]
+    #[must_use]
+    pub fn fork(&mut self) -> Rng {
+        self.0.get_mut().fork()
+    }
+
+    /// Forks `n` new [`Rng`] instances from the global RNG in a single call.
+    #[inline]
+    #[must_use]
+    pub fn fork_many(&mut self, n: usize) -> Vec<Rng> {
+        (0..n).map(|_| self.0.get_mut().fork()).collect()
+    }
+
+    /// Creates a new [`RngComponent`] seeded from the global RNG, the same way as
+    /// `RngComponent::from(&mut global)` does with a [`GlobalRng`].
+    #[inline]
+    #[must_use]
+    pub fn seed_component(&mut self) -> RngComponent {
+        RngComponent::from(&*self.0.get_mut())
+    }
+
+    /// Creates a new [`GlobalEntropy`] seeded from the global RNG.
+    #[inline]
+    #[must_use]
+    pub fn seed_entropy(&mut self) -> GlobalEntropy {
+        GlobalEntropy(SyncRng::new(Rng::with_seed(self.0.get_mut().gen_u64())))
+    }
+
+    /// Reseeds the [`StrictGlobalRng`] with the given seed, leaving it in the same state as
+    /// [`StrictGlobalRng::with_seed`] would.
+    #[inline]
+    pub fn reseed(&mut self, seed: u64) {
+        *self.0.get_mut() = Rng::with_seed(seed);
+    }
+
+    /// Reseeds the [`StrictGlobalRng`] with fresh entropy, pulled from the same source as
+    /// [`StrictGlobalRng::new`].
+    #[inline]
+    pub fn reseed_from_entropy(&mut self) {
+        *self.0.get_mut() = Rng::new();
+    }
+
+    /// Consumes the [`StrictGlobalRng`], returning the wrapped [`Rng`] with its state intact.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Rng {
+        self.0.into_inner()
+    }
+}
+
+impl Default for StrictGlobalRng {
+    /// Creates a default [`StrictGlobalRng`] instance. The instance will
+    /// be initialised with a randomised seed, so this is **not**
+    /// deterministic.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<GlobalRng> for StrictGlobalRng {
+    /// Converts a [`GlobalRng`] into a [`StrictGlobalRng`], keeping its state intact.
+    #[inline]
+    fn from(rng: GlobalRng) -> Self {
+        Self(SyncRng::new(rng.into_inner()))
+    }
+}
+
+/// A global [`Rng`] for drawing values directly, for use alongside a [`StrictGlobalRng`].
+/// It is a separate stream from the [`StrictGlobalRng`], so drawing from it never changes
+/// what the [`StrictGlobalRng`] seeds. Inserted by [`RngPlugin`] in strict mode, seeded
+/// from the [`StrictGlobalRng`].
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn shuffle_playlist(mut entropy: ResMut<GlobalEntropy>) {
+///     let mut tracks = [1, 2, 3, 4, 5];
+///
+///     entropy.shuffle(&mut tracks);
+/// }
+/// ```
+#[derive(Debug, Clone, Resource, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct GlobalEntropy(SyncRng<Rng>);
+
+impl GlobalEntropy {
+    /// Create a new [`GlobalEntropy`] instance with a randomised seed.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self(SyncRng::new(Rng::new()))
+    }
+
+    /// Create a new [`GlobalEntropy`] instance with a given seed.
+    #[inline]
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self(SyncRng::new(Rng::with_seed(seed)))
+    }
+}
+
+impl DelegatedRng for GlobalEntropy {
+    type Source = Rng;
+
+    #[inline]
+    fn get_mut(&mut self) -> &mut Self::Source {
+        self.0.get_mut()
+    }
+}
+
+impl Default for GlobalEntropy {
+    /// Creates a default [`GlobalEntropy`] instance. The instance will
+    /// be initialised with a randomised seed, so this is **not**
+    /// deterministic.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,183 @@
+use crate::*;
+
+/// A perfect maze over a grid of cells, where every cell can be reached from every other
+/// by exactly one path, such as for dungeon layouts. Cells are addressed by their `x` and
+/// `y` coordinates, from `(0, 0)` up to `(width - 1, height - 1)`, and adjacent cells are
+/// either joined by a passage or separated by a wall.
+///
+/// # Example
+/// ```
+/// use bevy::math::UVec2;
+/// use bevy_turborand::{grid::GridMaze, prelude::*};
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let maze = GridMaze::generate(8, 8, &mut rng);
+///
+/// let entrance = maze.random_cell(&mut rng);
+///
+/// assert!(entrance.x < 8 && entrance.y < 8);
+/// assert!(!maze.passable(UVec2::new(0, 0), UVec2::new(5, 5)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "gen-utils")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct GridMaze {
+    width: u32,
+    height: u32,
+    /// Whether each cell has a passage to the cell after it along `x`.
+    east: Vec<bool>,
+    /// Whether each cell has a passage to the cell after it along `y`.
+    south: Vec<bool>,
+}
+
+impl GridMaze {
+    /// Generates a new [`GridMaze`] of `width` by `height` cells with a randomized depth-first
+    /// search. The search starts from a random cell, and at each step carves a passage to one
+    /// of the unvisited neighbours of the current cell, picked with [`DelegatedRng::index`]
+    /// from the neighbours in the order of `-x`, `+x`, `-y`, `+y`, backtracking once there
+    /// are none left. The maze thus only depends on the RNG's state, and not on its type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is zero.
+    #[must_use]
+    pub fn generate(width: u32, height: u32, rng: &mut impl DelegatedRng) -> Self {
+        assert!(
+            width > 0 && height > 0,
+            "maze width and height must not be zero"
+        );
+
+        let cells = width as usize * height as usize;
+
+        let mut maze = Self {
+            width,
+            height,
+            east: vec![false; cells],
+            south: vec![false; cells],
+        };
+
+        let mut visited = vec![false; cells];
+        let mut stack = vec![maze.random_cell(rng)];
+        let mut unvisited = Vec::with_capacity(4);
+
+        visited[maze.index(stack[0])] = true;
+
+        while let Some(&cell) = stack.last() {
+            unvisited.clear();
+            unvisited.extend(
+                maze.neighbours(cell)
+                    .filter(|&neighbour| !visited[maze.index(neighbour)]),
+            );
+
+            if unvisited.is_empty() {
+                stack.pop();
+                continue;
+            }
+
+            let next = unvisited[rng.index(..unvisited.len())];
+
+            maze.carve(cell, next);
+            visited[maze.index(next)] = true;
+            stack.push(next);
+        }
+
+        maze
+    }
+
+    /// Returns the width of the maze, in cells.
+    #[inline]
+    #[must_use]
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the maze, in cells.
+    #[inline]
+    #[must_use]
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns whether there is a passage between cells `a` and `b`, in either order. Cells
+    /// that aren't adjacent or are out of bounds are never passable.
+    #[must_use]
+    pub fn passable(&self, a: UVec2, b: UVec2) -> bool {
+        if !self.contains(a) || !self.contains(b) {
+            return false;
+        }
+
+        let (first, second) = if (a.y, a.x) <= (b.y, b.x) {
+            (a, b)
+        } else {
+            (b, a)
+        };
+
+        if first.y == second.y && first.x + 1 == second.x {
+            self.east[self.index(first)]
+        } else if first.x == second.x && first.y + 1 == second.y {
+            self.south[self.index(first)]
+        } else {
+            false
+        }
+    }
+
+    /// Returns the walls between adjacent cells, as the pairs of cells they separate, with the
+    /// lower cell along the separated axis first. Walls along the outer edge of the grid are
+    /// not included. Walls are listed row by row, and within each cell, the wall along `x`
+    /// before the one along `y`.
+    pub fn walls(&self) -> impl Iterator<Item = (UVec2, UVec2)> + '_ {
+        (0..self.height)
+            .flat_map(move |y| (0..self.width).map(move |x| UVec2::new(x, y)))
+            .flat_map(move |cell| {
+                let index = self.index(cell);
+
+                let east =
+                    (cell.x + 1 < self.width && !self.east[index]).then(|| (cell, cell + UVec2::X));
+                let south = (cell.y + 1 < self.height && !self.south[index])
+                    .then(|| (cell, cell + UVec2::Y));
+
+                east.into_iter().chain(south)
+            })
+    }
+
+    /// Returns a random cell of the maze, such as for placing an entrance or treasure.
+    #[inline]
+    pub fn random_cell(&self, rng: &mut impl DelegatedRng) -> UVec2 {
+        UVec2::new(rng.u32(..self.width), rng.u32(..self.height))
+    }
+
+    #[inline]
+    fn contains(&self, cell: UVec2) -> bool {
+        cell.x < self.width && cell.y < self.height
+    }
+
+    #[inline]
+    fn index(&self, cell: UVec2) -> usize {
+        cell.y as usize * self.width as usize + cell.x as usize
+    }
+
+    fn neighbours(&self, cell: UVec2) -> impl Iterator<Item = UVec2> {
+        let (width, height) = (self.width, self.height);
+
+        [
+            (cell.x > 0).then(|| cell - UVec2::X),
+            (cell.x + 1 < width).then(|| cell + UVec2::X),
+            (cell.y > 0).then(|| cell - UVec2::Y),
+            (cell.y + 1 < height).then(|| cell + UVec2::Y),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    fn carve(&mut self, a: UVec2, b: UVec2) {
+        let first = a.min(b);
+        let index = self.index(first);
+
+        if a.y == b.y {
+            self.east[index] = true;
+        } else {
+            self.south[index] = true;
+        }
+    }
+}
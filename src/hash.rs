@@ -0,0 +1,107 @@
+//! Stable hashing primitives, based on the `wymix` function from
+//! [wyhash](https://github.com/wangyi-fudan/wyhash). Outputs from these
+//! functions are relied upon to be stable across releases, so they must
+//! never be changed.
+
+pub(crate) const P0: u64 = 0xa076_1d64_78bd_642f;
+pub(crate) const P1: u64 = 0xe703_7ed1_a0b4_28db;
+
+/// Multiplies both values into a 128-bit product and folds it back into 64 bits.
+#[inline(always)]
+pub(crate) const fn wymix(a: u64, b: u64) -> u64 {
+    let r = (a as u128).wrapping_mul(b as u128);
+    ((r >> 64) ^ r) as u64
+}
+
+/// Mixes a `key` into a `seed`, yielding a well distributed value. Adjacent keys
+/// produce uncorrelated outputs.
+#[inline(always)]
+pub(crate) const fn mix(seed: u64, key: u64) -> u64 {
+    wymix(seed ^ P0, wymix(key ^ P1, seed ^ key ^ P0))
+}
+
+/// Converts a hash into a `f32` in the range of `-1.0..1.0`.
+#[inline(always)]
+pub(crate) fn to_signed_f32(hash: u64) -> f32 {
+    // Use the top 24 bits, as that is the precision of a f32 mantissa.
+    ((hash >> 40) as f32 / (1u64 << 23) as f32) - 1.0
+}
+
+/// A [`Hasher`](std::hash::Hasher) built on [`mix`], for deriving keys from arbitrary
+/// hashable values. Unlike the std hashers, its output is stable across releases and
+/// platforms, as all integers are hashed in little endian order.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StableHasher(u64);
+
+impl Default for StableHasher {
+    #[inline]
+    fn default() -> Self {
+        Self(P0)
+    }
+}
+
+impl StableHasher {
+    /// Creates a [`StableHasher`] continuing from the given state.
+    #[inline]
+    pub(crate) const fn from_state(state: u64) -> Self {
+        Self(state)
+    }
+
+    /// Hashes `value` with a fresh [`StableHasher`].
+    #[cfg(any(feature = "wyrand", feature = "state-bytes"))]
+    #[inline]
+    pub(crate) fn hash_one(value: impl std::hash::Hash) -> u64 {
+        use std::hash::Hasher;
+
+        let mut hasher = Self::default();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl std::hash::Hasher for StableHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(8) {
+            let mut word = [0; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            self.0 = mix(self.0, u64::from_le_bytes(word));
+        }
+        self.0 = mix(self.0, bytes.len() as u64);
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.write_u64(u64::from(i));
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.write_u64(u64::from(i));
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.write_u64(u64::from(i));
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.0 = mix(self.0, i);
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.write_u64(i as u64);
+        self.write_u64((i >> 64) as u64);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+}
@@ -0,0 +1,251 @@
+//! A vendored copy of the WyRand algorithm as shipped by `turborand` 0.10, pinned so that
+//! stored states and golden values keep reproducing the exact same streams, no matter how
+//! future `turborand` releases change their own implementation. The step function, seeding
+//! and forking here must never be changed.
+
+use crate::sync::SyncRng;
+use crate::*;
+use std::{cell::Cell, fmt};
+use turborand::TurboKind;
+
+/// The WyRand increment, added to the state on every step.
+const INCREMENT: u64 = 0xa076_1d64_78bd_642f;
+/// The WyRand multiplier, mixed into the state on every step.
+const MULTIPLIER: u64 = 0xe703_7ed1_a0b4_28db;
+
+/// A pinned copy of the WyRand source behind [`Rng`], as of `turborand` 0.10. Produces the
+/// exact same streams as [`Rng`] currently does, and is guaranteed to keep producing them
+/// after any upgrade of `turborand`, so that archived states and replays stay valid.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// let legacy = LegacyRng::with_seed(5);
+/// let current = Rng::with_seed(5);
+///
+/// assert_eq!(legacy.gen_u64(), current.gen_u64());
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "compat")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct LegacyRng {
+    state: Cell<u64>,
+}
+
+impl LegacyRng {
+    /// Create a new [`LegacyRng`] with a randomised seed.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_seed(Rng::new().gen_u64())
+    }
+
+    #[inline(always)]
+    fn generate(&self) -> [u8; 8] {
+        let state = self.state.get().wrapping_add(INCREMENT);
+        self.state.set(state);
+
+        let t = u128::from(state).wrapping_mul(u128::from(state ^ MULTIPLIER));
+
+        ((t.wrapping_shr(64) ^ t) as u64).to_le_bytes()
+    }
+}
+
+impl fmt::Debug for LegacyRng {
+    /// Formats the [`LegacyRng`] without leaking its state.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LegacyRng").finish_non_exhaustive()
+    }
+}
+
+impl Default for LegacyRng {
+    /// Initialises a default instance of [`LegacyRng`]. Warning, the default is
+    /// seeded with a randomly generated state, so this is **not** deterministic.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TurboCore for LegacyRng {
+    #[inline]
+    fn fill_bytes(&self, buffer: &mut [u8]) {
+        let mut output = buffer;
+
+        while output.len() >= 8 {
+            let (target, remainder) = output.split_at_mut(8);
+
+            target.copy_from_slice(&self.generate());
+
+            output = remainder;
+        }
+
+        if !output.is_empty() {
+            let input = self.generate();
+            let fill = output.len();
+
+            output.copy_from_slice(&input[..fill]);
+        }
+    }
+}
+
+impl GenCore for LegacyRng {
+    const GEN_KIND: TurboKind = TurboKind::FAST;
+
+    #[inline]
+    fn gen<const SIZE: usize>(&self) -> [u8; SIZE] {
+        let mut output = [0u8; SIZE];
+
+        self.fill_bytes(&mut output);
+
+        output
+    }
+}
+
+impl SeededCore for LegacyRng {
+    type Seed = u64;
+
+    #[inline]
+    fn with_seed(seed: Self::Seed) -> Self {
+        Self {
+            state: Cell::new(seed << 1 | 1),
+        }
+    }
+
+    /// Reseeds the [`LegacyRng`], setting its state to `seed` as is, without the adjustment
+    /// made by [`LegacyRng::with_seed`].
+    #[inline]
+    fn reseed(&self, seed: Self::Seed) {
+        self.state.set(seed);
+    }
+}
+
+impl ForkableCore for LegacyRng {
+    #[inline]
+    fn fork(&self) -> Self {
+        Self {
+            state: Cell::new(u64::from_le_bytes(self.gen())),
+        }
+    }
+}
+
+impl StateBytes for LegacyRng {
+    const STATE_SIZE: usize = 8;
+
+    #[inline]
+    fn state_bytes(&self) -> Vec<u8> {
+        self.state.get().to_le_bytes().to_vec()
+    }
+
+    #[inline]
+    fn from_state_bytes(bytes: &[u8]) -> Result<Self, RngStateError> {
+        let state: [u8; 8] = bytes.try_into().map_err(|_| RngStateError::InvalidLength {
+            expected: Self::STATE_SIZE,
+            found: bytes.len(),
+        })?;
+
+        Ok(Self {
+            state: Cell::new(u64::from_le_bytes(state)),
+        })
+    }
+}
+
+/// A component wrapping a [`LegacyRng`], for entities whose streams must keep reproducing
+/// exactly across `turborand` upgrades, such as those loaded from archived saves. Works the
+/// same as an [`RngComponent`], with the algorithm chosen per entity by which of the two
+/// components it has.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// // The state bytes of an `RngComponent` stored by an older release.
+/// let stored = RngComponent::with_seed(5).state_bytes();
+///
+/// let mut legacy = LegacyRngComponent::from_legacy_state(&stored).unwrap();
+///
+/// assert_eq!(legacy.u64(..), RngComponent::with_seed(5).u64(..));
+/// ```
+#[derive(Debug, Clone, Component, PartialEq, Reflect)]
+#[cfg_attr(docsrs, doc(cfg(feature = "compat")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(opaque, Debug, PartialEq, Default, Component, Serialize, Deserialize)
+)]
+#[cfg_attr(
+    not(feature = "serialize"),
+    reflect(opaque, Debug, PartialEq, Default, Component)
+)]
+pub struct LegacyRngComponent(SyncRng<LegacyRng>);
+
+impl LegacyRngComponent {
+    /// Create a new [`LegacyRngComponent`] with a randomised seed.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self(SyncRng::new(LegacyRng::new()))
+    }
+
+    /// Create a new [`LegacyRngComponent`] with a given seed.
+    #[inline]
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self(SyncRng::new(LegacyRng::with_seed(seed)))
+    }
+
+    /// Restores a [`LegacyRngComponent`] from the state bytes of an [`Rng`], as stored by
+    /// this or any older release, continuing its stream exactly.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RngStateError::InvalidLength`] if `bytes` is not a valid [`Rng`] state.
+    #[inline]
+    pub fn from_legacy_state(bytes: &[u8]) -> Result<Self, RngStateError> {
+        LegacyRng::from_state_bytes(bytes).map(|rng| Self(SyncRng::new(rng)))
+    }
+
+    /// Consumes the [`LegacyRngComponent`], returning the wrapped [`LegacyRng`] with its
+    /// state intact.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> LegacyRng {
+        self.0.into_inner()
+    }
+}
+
+impl DelegatedRng for LegacyRngComponent {
+    type Source = LegacyRng;
+
+    #[inline]
+    fn get_mut(&mut self) -> &mut Self::Source {
+        self.0.get_mut()
+    }
+}
+
+impl Default for LegacyRngComponent {
+    /// Creates a default [`LegacyRngComponent`] instance. The instance will
+    /// be initialised with a randomised seed, so this is **not**
+    /// deterministic.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: DelegatedRng> From<&mut T> for LegacyRngComponent {
+    #[inline]
+    fn from(rng: &mut T) -> Self {
+        Self(SyncRng::new(LegacyRng::with_seed(rng.get_mut().gen_u64())))
+    }
+}
+
+impl From<LegacyRng> for LegacyRngComponent {
+    /// Wraps an existing [`LegacyRng`] as is, taking ownership of it without reseeding or
+    /// forking.
+    #[inline]
+    fn from(rng: LegacyRng) -> Self {
+        Self(SyncRng::new(rng))
+    }
+}
@@ -0,0 +1,179 @@
+//! Lenient deserialization of RNG sources, for loading payloads written by older releases.
+//!
+//! The internal state of a source is made up of integers only, so a payload is read by
+//! flattening all its integers in order, ignoring how they were nested and named, and then
+//! feeding them back into the source's own [`Deserialize`] impl. This way, payloads with
+//! fewer newtype wrappers than the current ones, such as `((state:(24691)))` or
+//! `(state:24691)` instead of `(((state:(24691))))`, restore to the same state.
+
+use serde::{
+    de::{self, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+use std::fmt;
+
+/// Deserializes a source from a self-describing `deserializer`, accepting any nesting of its
+/// integers.
+pub(crate) fn deserialize_lenient<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let mut integers = Vec::new();
+
+    deserializer.deserialize_any(Flatten(&mut integers))?;
+
+    let mut reader = IntegerReader(integers.iter());
+    let source = T::deserialize(&mut reader).map_err(de::Error::custom)?;
+
+    match reader.0.len() {
+        0 => Ok(source),
+        trailing => Err(de::Error::custom(format_args!(
+            "{trailing} unexpected trailing integers in RNG state"
+        ))),
+    }
+}
+
+/// Collects all integers of a value, in order.
+struct Flatten<'a>(&'a mut Vec<u64>);
+
+impl<'de> DeserializeSeed<'de> for Flatten<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Flatten<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an RNG state made up of non-negative integers")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<(), E> {
+        self.0.push(v);
+        Ok(())
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<(), E> {
+        let v = u64::try_from(v).map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))?;
+
+        self.visit_u64(v)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<(), E> {
+        let v = u64::try_from(v)
+            .map_err(|_| E::invalid_value(de::Unexpected::Other("a 128-bit integer"), &self))?;
+
+        self.visit_u64(v)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while seq.next_element_seed(Flatten(self.0))?.is_some() {}
+
+        Ok(())
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while map.next_key::<IgnoredAny>()?.is_some() {
+            map.next_value_seed(Flatten(self.0))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Feeds collected integers into a source's [`Deserialize`] impl, in order.
+struct IntegerReader<'a>(std::slice::Iter<'a, u64>);
+
+impl IntegerReader<'_> {
+    fn next<T: TryFrom<u64>>(&mut self) -> Result<T, de::value::Error> {
+        let v = self
+            .0
+            .next()
+            .ok_or_else(|| de::Error::custom("missing integers in RNG state"))?;
+
+        T::try_from(*v).map_err(|_| de::Error::custom("integer out of range in RNG state"))
+    }
+}
+
+macro_rules! read_int {
+    ($method:ident, $visit:ident) => {
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            visitor.$visit(self.next()?)
+        }
+    };
+}
+
+impl<'de> Deserializer<'de> for &mut IntegerReader<'_> {
+    type Error = de::value::Error;
+
+    read_int!(deserialize_u8, visit_u8);
+    read_int!(deserialize_u16, visit_u16);
+    read_int!(deserialize_u32, visit_u32);
+    read_int!(deserialize_u64, visit_u64);
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(de::Error::custom("RNG states only contain integers"))
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(self)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(self)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u128 f32 f64 char str string bytes byte_buf option unit
+        unit_struct map enum identifier ignored_any
+    }
+}
+
+impl<'de> SeqAccess<'de> for &mut IntegerReader<'_> {
+    type Error = de::value::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        seed.deserialize(&mut **self).map(Some)
+    }
+}
@@ -26,7 +26,7 @@
 //! entity, it then makes the question of stable ordering in queries moot.
 //! Thus, determinism can be achieved regardless of unstable query ordering
 //! and multi-threaded execution.
-//! 
+//!
 //! ## Notice
 //!
 //! For all intents and purposes, `bevy_turborand` will no longer receive new features
@@ -148,42 +148,280 @@
 //!   Having this feature flag enabled also enables [`RngPlugin`].
 //! - **`chacha`** - Enables [`GlobalChaChaRng`] & [`ChaChaRngComponent`]. Having this
 //!   feature flag enabled also enables [`RngPlugin`].
-//! - **`rand`** - Provides [`RandBorrowed`], which implements `RngCore`
+//! - **`rand`** - Provides [`RandBorrowed`] and [`RandOwned`], which implement `RngCore`
 //!   so to allow for compatibility with `rand` ecosystem of crates.
-//! - **`serialize`** - Enables [`Serialize`] and [`Deserialize`] derives.
+//! - **`bevy_rand-compat`** - Enables `RngComponent::to_wyrand_entropy_bytes` &
+//!   `RngComponent::from_entropy_bytes`, for transferring the exact state of an
+//!   [`RngComponent`] to and from a `WyRand` source of `bevy_rand`, such as when migrating
+//!   to it gradually, along with [`WyRandCompat`], a `rand_core` adapter seeded the same
+//!   way as `bevy_rand`'s `WyRand`. Also enables `rand`, `wyrand` and `state-bytes`.
+//! - **`serialize`** - Enables [`Serialize`] and [`Deserialize`] derives. Also enables
+//!   [`RngWorldSnapshot`], for capturing and restoring the entire RNG state of a [`World`].
+//! - **`state-bytes`** - Enables [`DelegatedRng::state_bytes`] & [`DelegatedRng::restore_from_bytes`]
+//!   for saving and restoring the exact state of an RNG as plain bytes, without requiring any
+//!   serialization format. Also enables [`DelegatedRng::diff`] & [`DelegatedRng::apply_diff`]
+//!   for syncing RNG state changes compactly. As `turborand` only exposes the state of its
+//!   sources through its own `serialize` feature, this still pulls in the `serde` crate, though
+//!   neither its derives nor any format crate.
+//! - **`serialize-compact`** - Enables the [`compact`] module, for serializing RNG wrappers as
+//!   versioned state bytes instead of the internal field layout of their sources. Also enables
+//!   `serialize` and `state-bytes`.
+//! - **`compat`** - Enables [`LegacyRng`] & [`LegacyRngComponent`], a pinned copy of the
+//!   WyRand algorithm that keeps reproducing the streams of archived states and golden values
+//!   across `turborand` upgrades. Also enables `wyrand` and `state-bytes`.
+//! - **`audit`** - Enables [`RngAuditLog`], which records the input and outcome of every
+//!   `chance`/`ratio`/`weighted_sample` call made through [`DelegatedRng`] for balancing purposes.
+//!   Without this feature, the recording hooks compile to nothing. Also enables the
+//!   [`RngAuditConfig`] check for RNG components seeded from entropy in release builds, which
+//!   is otherwise only available in debug builds.
+//! - **`diagnostics`** - Counts the calls made through each RNG wrapper, readable via
+//!   `call_count()` on the wrappers, and registers them as Bevy diagnostics in [`RngPlugin`],
+//!   such as `bevy_turborand/global_rng_calls`. Also enables [`RngBudget`], for limiting the
+//!   calls made per frame. Without this feature, the counters compile to nothing.
+//! - **`rng-log`** - Enables [`RngLog`] & [`LoggedRng`], for recording the values drawn through
+//!   wrapped RNGs along with the frame they were drawn in, and finding the first draw at which
+//!   a replay diverges from its recording.
+//! - **`bevy_color`** - Enables the [`DelegatedRng`] methods for generating random `Color`s.
+//! - **`bevy_hierarchy`** - Makes [`ForkOnSpawn`] fork RNG components from the RNG component
+//!   of their entity's parent. Is enabled by default.
+//! - **`bevy_math`** - Enables the [`DelegatedRng`] methods working with vectors, such as
+//!   `fill_vec2`, along with `RandomWalk`, `HaltonSequence`, `ChunkRngProvider` and the
+//!   offsets of [`ShakeSampler`]. Is enabled by default.
+//! - **`bevy_state`** - Enables `RngPlugin::reseed_on_enter`, for reseeding the [`GlobalRng`]
+//!   and the [`RngComponent`]s marked with `ReseedOnState` whenever a state is entered. Also
+//!   enables `wyrand`.
+//! - **`bevy_time`** - Enables `JitteredTimer`, `RandomInterval` and
+//!   `RngPlugin::with_interval_resource`. Is enabled by default.
+//! - **`shapes`** - Enables the [`shapes`] module, for uniformly sampling points in and on
+//!   Bevy's math primitives. Also enables `bevy_math`.
+//! - **`gen-utils`** - Enables the [`grid`] module, for generating grid layouts such as mazes,
+//!   and the [`names`] module, for generating names. Also enables `bevy_math`.
+//! - **`uuid`** - Enables [`DelegatedRng::gen_uuid_v4`], for generating reproducible `Uuid`s.
+//! - **`web-persist`** - On `wasm32` only, enables `RngPlugin::with_persisted_seed`, for
+//!   keeping the seed of [`GlobalRng`] in the page's `localStorage` so that refreshing the
+//!   page resumes the same world. Also enables `wyrand`.
+//! - **`testing`** - Enables the [`stats`] module, with helpers for asserting the frequencies
+//!   of random outcomes in tests, and [`WorldSeeder`] for deterministically setting up the
+//!   starting state of a test [`World`]. Also enables `wyrand`.
 #![warn(missing_docs, rust_2018_idioms)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(docsrs, allow(unused_attributes))]
 
-use bevy::prelude::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+#[cfg(feature = "bevy_math")]
+use bevy_math::prelude::*;
+use bevy_reflect::prelude::*;
 #[cfg(feature = "chacha")]
 use turborand::prelude::ChaChaRng;
 #[cfg(feature = "wyrand")]
 use turborand::prelude::Rng;
-pub use turborand::{ForkableCore, GenCore, SecureCore, SeededCore, TurboCore, TurboRand};
+pub use turborand::{
+    ForkableCore, GenCore, SecureCore, SeededCore, TurboCore, TurboKind, TurboRand,
+};
 
-#[cfg(all(any(feature = "chacha", feature = "wyrand"), feature = "serialize"))]
+#[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
+pub use affix::*;
+#[cfg(feature = "audit")]
+pub use audit::*;
+pub use audited::*;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub use authoring::*;
+#[cfg(all(feature = "diagnostics", any(feature = "wyrand", feature = "chacha")))]
+pub use budget::*;
+pub use bytes::*;
+#[cfg(all(feature = "wyrand", feature = "bevy_math"))]
+pub use chunks::*;
+pub use collections::*;
 #[cfg(feature = "chacha")]
 pub use component::chacha::*;
 #[cfg(feature = "wyrand")]
 pub use component::rng::*;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub use determinism::DeterminismSettings;
+#[cfg(all(feature = "diagnostics", any(feature = "wyrand", feature = "chacha")))]
+pub use diagnostics::*;
+#[cfg(all(feature = "state-bytes", any(feature = "wyrand", feature = "chacha")))]
+pub use diff::*;
+pub use fairness::*;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub use fork::*;
 #[cfg(feature = "chacha")]
 pub use global::chacha::*;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub use global::entropy::*;
 #[cfg(feature = "wyrand")]
 pub use global::rng::*;
+#[cfg(feature = "wyrand")]
+pub use global::startup::RngStartupSeed;
+#[cfg(feature = "wyrand")]
+pub use global::strict::*;
+#[cfg(feature = "compat")]
+pub use legacy::*;
+pub use lines::*;
+pub use modifiers::*;
+pub use no_repeat::*;
+#[cfg(feature = "wyrand")]
+pub use par::*;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub use plugin::*;
+#[cfg(feature = "wyrand")]
+pub use preview::*;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub use provenance::RngAuditConfig;
+pub use query::*;
+pub use quests::*;
+#[cfg(feature = "rand")]
+pub use rand_owned::*;
+#[cfg(feature = "wyrand")]
+pub use randomize::*;
+pub use range::SampleRange;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub use reflect_reseed::*;
+pub use reflected::*;
+#[cfg(feature = "bevy_state")]
+pub use reseed::{NextWorldSeed, ReseedOnState, SeedSource};
+pub use retry::*;
+#[cfg(feature = "rng-log")]
+pub use rng_log::*;
+pub use sample::*;
+#[cfg(feature = "wyrand")]
+pub use schedule::*;
+pub use seed::{chacha_seed_from_str, seed_from_str, SeedMixer, EMPTY_SEED};
+#[cfg(feature = "testing")]
+pub use seeder::*;
+pub use send::*;
+#[cfg(feature = "bevy_math")]
+pub use sequences::*;
+pub use shake::*;
+#[cfg(feature = "wyrand")]
+pub use sliced::*;
+pub use slots::*;
+#[cfg(all(feature = "serialize", any(feature = "wyrand", feature = "chacha")))]
+pub use snapshot::*;
+#[cfg(all(feature = "state-bytes", any(feature = "wyrand", feature = "chacha")))]
+pub use state::*;
+pub use stateless::*;
+#[cfg(feature = "wyrand")]
+pub use streams::*;
+pub use tables::*;
+#[cfg(feature = "bevy_time")]
+pub use timers::*;
 pub use traits::*;
+#[cfg(feature = "wyrand")]
+pub use variance::*;
+pub use variants::*;
+#[cfg(feature = "bevy_math")]
+pub use walks::*;
+#[cfg(all(target_arch = "wasm32", feature = "web-persist"))]
+pub use web::{clear_persisted_seed, persisted_seed};
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub use world::*;
+pub use wrapper::*;
+#[cfg(feature = "bevy_rand-compat")]
+pub use wyrand_compat::*;
 
 #[macro_use]
 mod delegate;
+mod affix;
+#[cfg(feature = "audit")]
+mod audit;
+mod audited;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+mod authoring;
+#[cfg(all(feature = "diagnostics", any(feature = "wyrand", feature = "chacha")))]
+mod budget;
+mod bytes;
+#[cfg(all(feature = "wyrand", feature = "bevy_math"))]
+mod chunks;
+mod collections;
 #[cfg(any(feature = "chacha", feature = "wyrand"))]
 mod component;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+mod determinism;
+#[cfg(all(feature = "diagnostics", any(feature = "wyrand", feature = "chacha")))]
+mod diagnostics;
+#[cfg(all(feature = "state-bytes", any(feature = "wyrand", feature = "chacha")))]
+mod diff;
+mod encoding;
+mod fairness;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+mod fork;
 #[cfg(any(feature = "chacha", feature = "wyrand"))]
 mod global;
+mod hash;
+#[cfg(feature = "compat")]
+mod legacy;
+#[cfg(all(feature = "serialize", any(feature = "wyrand", feature = "chacha")))]
+mod lenient;
+mod lines;
+mod modifiers;
+mod no_repeat;
+#[cfg(feature = "wyrand")]
+mod par;
 #[cfg(any(feature = "wyrand", feature = "chacha"))]
 mod plugin;
+#[cfg(feature = "wyrand")]
+mod preview;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+mod provenance;
+mod query;
+mod quests;
+#[cfg(feature = "rand")]
+mod rand_owned;
+#[cfg(feature = "wyrand")]
+mod randomize;
+mod range;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+mod reflect_reseed;
+mod reflected;
+#[cfg(feature = "bevy_state")]
+mod reseed;
+mod retry;
+#[cfg(feature = "rng-log")]
+mod rng_log;
+mod sample;
+#[cfg(feature = "wyrand")]
+mod schedule;
+mod seed;
+#[cfg(feature = "testing")]
+mod seeder;
+mod send;
+#[cfg(feature = "bevy_math")]
+mod separated;
+#[cfg(feature = "bevy_math")]
+mod sequences;
+mod shake;
+#[cfg(feature = "wyrand")]
+mod sliced;
+mod slots;
+#[cfg(all(feature = "serialize", any(feature = "wyrand", feature = "chacha")))]
+mod snapshot;
+#[cfg(all(feature = "state-bytes", any(feature = "wyrand", feature = "chacha")))]
+mod state;
+mod stateless;
+#[cfg(feature = "wyrand")]
+mod streams;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+mod sync;
+mod tables;
+#[cfg(feature = "bevy_time")]
+mod timers;
 mod traits;
+#[cfg(feature = "wyrand")]
+mod variance;
+mod variants;
+#[cfg(feature = "bevy_math")]
+mod walks;
+#[cfg(all(target_arch = "wasm32", feature = "web-persist"))]
+mod web;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+mod world;
+mod wrapper;
+#[cfg(feature = "bevy_rand-compat")]
+mod wyrand_compat;
 
 /// Prelude for `bevy_turborand`, exposing all necessary traits for default usage of the
 /// crate, as well as whatever component/resources are configured to be exposed by whichever
@@ -229,3 +467,137 @@ pub mod prelude;
 pub mod rng {
     pub use turborand::prelude::*;
 }
+
+/// Module for uniformly sampling points within or along the boundary of Bevy's math
+/// primitives, such as [`Circle`] or [`Cuboid`], via the
+/// [`SampleShape`](shapes::SampleShape) trait.
+///
+/// # Example
+///
+/// ```
+/// use bevy::math::primitives::Annulus;
+/// use bevy_turborand::prelude::*;
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let ring = Annulus::new(1.0, 2.0);
+///
+/// let points: Vec<_> = (0..10).map(|_| ring.sample_interior(&mut rng)).collect();
+///
+/// assert!(points.iter().all(|point| (1.0..=2.0).contains(&point.length())));
+/// ```
+#[cfg(feature = "shapes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "shapes")))]
+pub mod shapes;
+
+/// Module for procedurally generating grid layouts from a [`DelegatedRng`], such as the
+/// perfect mazes of [`GridMaze`](grid::GridMaze) for dungeons. Generation only depends on
+/// the RNG's state, so the same layouts come out of [`RngComponent`]s, the [`GlobalRng`]
+/// or ChaCha RNGs alike.
+///
+/// # Example
+///
+/// ```
+/// use bevy_turborand::{grid::GridMaze, prelude::*};
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let maze = GridMaze::generate(16, 16, &mut rng);
+///
+/// // A perfect maze has one passage fewer than it has cells, leaving the rest as walls.
+/// assert_eq!(maze.walls().count(), 2 * 16 * 15 - (16 * 16 - 1));
+/// ```
+#[cfg(feature = "gen-utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gen-utils")))]
+pub mod grid;
+
+/// Module for generating random names from a [`DelegatedRng`], such as throwaway names for
+/// NPCs, either by composing syllables or by following consonant and vowel patterns with
+/// [`NameGenerator`](names::NameGenerator).
+///
+/// # Example
+///
+/// ```
+/// use bevy_turborand::{names::NameGenerator, prelude::*};
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let names = NameGenerator::default().with_syllables(["ka", "ri", "zu"]);
+///
+/// let name = names.generate(&mut rng);
+///
+/// assert!((4..=8).contains(&name.len()));
+/// ```
+#[cfg(feature = "gen-utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gen-utils")))]
+pub mod names;
+
+/// Module for serializing RNG wrappers in a compact, versioned representation, for use
+/// with `#[serde(with = "bevy_turborand::compact")]`. The compact representation is the
+/// full state of the RNG source as bytes, prefixed with a version byte, so it doesn't
+/// depend on the internal field layout of the `turborand` sources. Deserializing also
+/// accepts the default representation, for migrating existing save data.
+///
+/// # Example
+///
+/// ```
+/// use bevy_turborand::prelude::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Save {
+///     #[serde(with = "bevy_turborand::compact")]
+///     rng: RngComponent,
+/// }
+///
+/// let save = Save { rng: RngComponent::with_seed(5) };
+///
+/// assert_eq!(ron::to_string(&save).unwrap(), "(rng:[1,11,0,0,0,0,0,0,0])");
+/// ```
+#[cfg(all(
+    feature = "serialize-compact",
+    any(feature = "wyrand", feature = "chacha")
+))]
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize-compact")))]
+pub mod compact;
+
+/// Module with run conditions driven by RNG resources, for running systems randomly.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::{conditions::ratio_global, prelude::*};
+///
+/// fn critical_weather() {}
+///
+/// App::new()
+///     .add_plugins(RngPlugin::new().with_rng_seed(12345))
+///     .add_systems(Update, critical_weather.run_if(ratio_global(1, 600)));
+/// ```
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "wyrand", feature = "chacha"))))]
+pub mod conditions;
+
+/// Module with statistical assertion helpers, for testing that random outcomes
+/// occur at their intended frequencies without having to write the math by hand.
+///
+/// # Example
+///
+/// Check that a loot table drops its epic item 2% of the time:
+///
+/// ```
+/// use bevy_turborand::{prelude::*, stats::expect_frequency};
+///
+/// let loot = [("common", 0.78), ("rare", 0.2), ("epic", 0.02)];
+///
+/// expect_frequency(
+///     |rng| rng.weighted_sample(&loot, |((_, weight), _)| *weight).unwrap().0,
+///     100_000,
+///     &loot,
+///     0.005,
+/// );
+/// ```
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod stats;
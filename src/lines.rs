@@ -0,0 +1,223 @@
+use crate::*;
+use std::collections::BTreeMap;
+
+/// A line in a [`LineSelector`], identified by its localization key.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct LineEntry {
+    /// The localization key of the line, returned when it is selected.
+    pub key: String,
+    /// The relative weight of the line. Lines with a weight of `0.0` or less are never selected.
+    pub weight: f64,
+    /// How many selections must be made after the line is selected before it can be
+    /// selected again. If `None`, it defaults to `1`, so that the line is never selected
+    /// twice in a row. `Some(0)` allows the line to be repeated immediately.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub cooldown: Option<u32>,
+    /// The id of the condition the line requires, checked with the context given to
+    /// [`LineSelector::select`]. Lines without a predicate are always eligible.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub predicate: Option<String>,
+}
+
+impl LineEntry {
+    /// Creates a new [`LineEntry`] with the default cooldown and no predicate.
+    #[inline]
+    #[must_use]
+    pub fn new(key: impl Into<String>, weight: f64) -> Self {
+        Self {
+            key: key.into(),
+            weight,
+            cooldown: None,
+            predicate: None,
+        }
+    }
+
+    /// Builder function to set the cooldown of the line, in selections.
+    #[inline]
+    #[must_use]
+    pub fn with_cooldown(mut self, cooldown: u32) -> Self {
+        self.cooldown = Some(cooldown);
+        self
+    }
+
+    /// Builder function to set the id of the condition the line requires.
+    #[inline]
+    #[must_use]
+    pub fn with_predicate(mut self, predicate: impl Into<String>) -> Self {
+        self.predicate = Some(predicate.into());
+        self
+    }
+
+    #[inline]
+    fn cooldown(&self) -> u64 {
+        u64::from(self.cooldown.unwrap_or(1))
+    }
+}
+
+/// The selection history of a [`LineSelector`], which decides which lines are still on
+/// cooldown. Kept apart from the lines themselves, so that it can be saved and restored
+/// without resetting cooldowns.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct LineHistory {
+    /// The number of selections made so far.
+    pub selections: u64,
+    /// The selection at which each line was last selected, keyed by localization key.
+    pub last_selected: BTreeMap<String, u64>,
+}
+
+impl LineHistory {
+    /// Returns the number of selections made since the line was last selected, or `None`
+    /// if it was never selected.
+    #[inline]
+    #[must_use]
+    pub fn selections_since(&self, key: &str) -> Option<u64> {
+        self.last_selected
+            .get(key)
+            .map(|&selected| self.selections - selected - 1)
+    }
+}
+
+/// A weighted selector of lines, such as barks or dialogue, identified by their
+/// localization keys. Each selection first filters out lines whose predicate doesn't hold
+/// and lines still on cooldown, then picks one of the remaining lines by weight.
+///
+/// Weights are relative to each other. Selections are deterministic for a given RNG state,
+/// line order and [`LineHistory`]. With the `serialize` feature, selectors can be authored
+/// in RON or any other format, in which case the history can be left out.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// let mut selector = LineSelector::new()
+///     .with_line(LineEntry::new("bark.greeting", 3.0))
+///     .with_line(LineEntry::new("bark.weather.rain", 5.0).with_predicate("raining"))
+///     .with_line(LineEntry::new("bark.idle", 1.0).with_cooldown(3));
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let raining = false;
+/// let context = |predicate: &str| predicate == "raining" && raining;
+///
+/// let first = selector.select(&mut rng, &context).unwrap().to_owned();
+/// let second = selector.select(&mut rng, &context).unwrap();
+///
+/// assert_ne!(first, second);
+/// assert_ne!(second, "bark.weather.rain");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct LineSelector {
+    lines: Vec<LineEntry>,
+    #[cfg_attr(feature = "serialize", serde(default))]
+    history: LineHistory,
+}
+
+impl LineSelector {
+    /// Creates a new [`LineSelector`] with no lines.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            lines: Vec::new(),
+            history: LineHistory {
+                selections: 0,
+                last_selected: BTreeMap::new(),
+            },
+        }
+    }
+
+    /// Builder function to add a line to the selector.
+    #[inline]
+    #[must_use]
+    pub fn with_line(mut self, line: LineEntry) -> Self {
+        self.add_line(line);
+        self
+    }
+
+    /// Adds a line to the selector.
+    #[inline]
+    pub fn add_line(&mut self, line: LineEntry) {
+        self.lines.push(line);
+    }
+
+    /// Returns the lines of the selector.
+    #[inline]
+    #[must_use]
+    pub fn lines(&self) -> &[LineEntry] {
+        &self.lines
+    }
+
+    /// Returns the selection history of the selector.
+    #[inline]
+    #[must_use]
+    pub fn history(&self) -> &LineHistory {
+        &self.history
+    }
+
+    /// Replaces the selection history of the selector, such as with one restored from a save.
+    #[inline]
+    pub fn set_history(&mut self, history: LineHistory) {
+        self.history = history;
+    }
+
+    /// Clears the selection history, taking all lines off cooldown.
+    #[inline]
+    pub fn reset_history(&mut self) {
+        self.history = LineHistory::default();
+    }
+
+    /// Selects a line, returning its localization key, or `None` if no line is eligible.
+    /// `context` is called with the predicate id of each line that has one, and the line
+    /// is only eligible if it returns `true`.
+    pub fn select(
+        &mut self,
+        rng: &mut impl DelegatedRng,
+        context: &dyn Fn(&str) -> bool,
+    ) -> Option<&str> {
+        let history = &self.history;
+
+        let eligible: Vec<usize> = self
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.weight > 0.0 && line.weight.is_finite())
+            .filter(|(_, line)| {
+                history
+                    .selections_since(&line.key)
+                    .is_none_or(|since| since >= line.cooldown())
+            })
+            .filter(|(_, line)| line.predicate.as_deref().is_none_or(context))
+            .map(|(index, _)| index)
+            .collect();
+
+        if eligible.is_empty() {
+            return None;
+        }
+
+        let total: f64 = eligible.iter().map(|&index| self.lines[index].weight).sum();
+
+        let mut target = rng.get_mut().f64() * total;
+
+        // Falls back to the last line should rounding leave the target past the total.
+        let picked = eligible
+            .iter()
+            .copied()
+            .find(|&index| {
+                target -= self.lines[index].weight;
+                target < 0.0
+            })
+            .unwrap_or(eligible[eligible.len() - 1]);
+
+        let key = &self.lines[picked].key;
+
+        self.history
+            .last_selected
+            .insert(key.clone(), self.history.selections);
+        self.history.selections += 1;
+
+        Some(key)
+    }
+}
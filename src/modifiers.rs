@@ -0,0 +1,148 @@
+use crate::*;
+use std::collections::BTreeMap;
+
+/// A modifier for the probabilities of tagged calls, such as
+/// [`DelegatedRng::chance_tagged`]. A probability `p` is modified into
+/// `p * multiplier + offset`, clamped to `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct ProbabilityModifier {
+    /// The multiplier applied to the probability.
+    pub multiplier: f64,
+    /// The offset added to the probability, after applying the multiplier.
+    pub offset: f64,
+}
+
+impl ProbabilityModifier {
+    /// The identity modifier, which leaves all probabilities as they are.
+    pub const IDENTITY: Self = Self {
+        multiplier: 1.0,
+        offset: 0.0,
+    };
+
+    /// Creates a new [`ProbabilityModifier`] that only scales probabilities.
+    #[inline]
+    #[must_use]
+    pub const fn multiply(multiplier: f64) -> Self {
+        Self {
+            multiplier,
+            offset: 0.0,
+        }
+    }
+
+    /// Creates a new [`ProbabilityModifier`] that only offsets probabilities.
+    #[inline]
+    #[must_use]
+    pub const fn offset(offset: f64) -> Self {
+        Self {
+            multiplier: 1.0,
+            offset,
+        }
+    }
+
+    /// Applies the modifier to the given probability.
+    #[inline]
+    #[must_use]
+    pub fn apply(&self, probability: f64) -> f64 {
+        (probability * self.multiplier + self.offset).clamp(0.0, 1.0)
+    }
+}
+
+impl Default for ProbabilityModifier {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// A [`Resource`] of [`ProbabilityModifier`]s keyed by user-defined tags, for tuning
+/// randomness live, such as raising all loot chances by 20% during a balancing session.
+/// The modifiers are given to the tagged [`DelegatedRng`] methods, such as
+/// [`DelegatedRng::chance_tagged`], with tags without a modifier left unmodified.
+///
+/// The modifiers are kept ordered by tag, and can be serialized with the `serialize` feature,
+/// so that replays can record the values they were played with.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn open_chest(mut rng: ResMut<GlobalRng>, modifiers: Option<Res<ProbabilityModifiers>>) {
+///     if rng.chance_tagged("loot", 0.25, modifiers.as_deref()) {
+///         // Spawn an item
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins(RngPlugin::default())
+///     .insert_resource(
+///         ProbabilityModifiers::new().with_modifier("loot", ProbabilityModifier::multiply(1.2)),
+///     )
+///     .add_systems(Update, open_chest);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Resource)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(transparent))]
+pub struct ProbabilityModifiers {
+    modifiers: BTreeMap<String, ProbabilityModifier>,
+}
+
+impl ProbabilityModifiers {
+    /// Creates a new, empty [`ProbabilityModifiers`].
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            modifiers: BTreeMap::new(),
+        }
+    }
+
+    /// Builder function to set the modifier for a tag.
+    #[inline]
+    #[must_use]
+    pub fn with_modifier(mut self, tag: impl Into<String>, modifier: ProbabilityModifier) -> Self {
+        self.set(tag, modifier);
+        self
+    }
+
+    /// Sets the modifier for a tag, replacing any previous one.
+    #[inline]
+    pub fn set(&mut self, tag: impl Into<String>, modifier: ProbabilityModifier) {
+        self.modifiers.insert(tag.into(), modifier);
+    }
+
+    /// Removes the modifier for a tag, returning it if there was one.
+    #[inline]
+    pub fn remove(&mut self, tag: &str) -> Option<ProbabilityModifier> {
+        self.modifiers.remove(tag)
+    }
+
+    /// Returns the modifier for a tag, or [`ProbabilityModifier::IDENTITY`] if it has none.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, tag: &str) -> ProbabilityModifier {
+        self.modifiers
+            .get(tag)
+            .copied()
+            .unwrap_or(ProbabilityModifier::IDENTITY)
+    }
+
+    /// Applies the modifier for a tag to the given probability.
+    #[inline]
+    #[must_use]
+    pub fn apply(&self, tag: &str, probability: f64) -> f64 {
+        match self.modifiers.get(tag) {
+            Some(modifier) => modifier.apply(probability),
+            None => probability,
+        }
+    }
+
+    /// Returns an iterator over all tags and their modifiers, ordered by tag.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ProbabilityModifier)> {
+        self.modifiers
+            .iter()
+            .map(|(tag, modifier)| (tag.as_str(), modifier))
+    }
+}
@@ -0,0 +1,208 @@
+use crate::*;
+use std::fmt;
+
+const DEFAULT_SYLLABLES: &[&str] = &[
+    "al", "an", "ar", "bel", "bor", "da", "del", "dor", "el", "en", "fa", "gar", "gor", "ka",
+    "kor", "la", "lin", "ma", "mir", "na", "nor", "o", "ra", "ren", "ri", "sa", "sel", "tha",
+    "tor", "va", "wen", "ya", "zan",
+];
+
+const DEFAULT_CONSONANTS: &[&str] = &[
+    "b", "d", "f", "g", "h", "k", "l", "m", "n", "p", "r", "s", "t", "v", "z", "th",
+];
+
+const DEFAULT_VOWELS: &[&str] = &["a", "e", "i", "o", "u", "ae", "ia"];
+
+/// Generates random names, such as throwaway names for NPCs, from syllable, consonant and
+/// vowel lists. Names only depend on the lists and the RNG's state, so an entity with a
+/// seeded [`RngComponent`] is given the same name on every run.
+///
+/// The [`Default`] generator embeds a small set of fantasy-like syllables. Custom sets can
+/// be configured with the builder methods, or, with the `serialize` feature, deserialized
+/// from an asset, where any missing list falls back to its default:
+///
+/// ```ron
+/// (syllables: ["ka", "ri", "zu"], vowels: ["a", "u"])
+/// ```
+///
+/// # Example
+/// ```
+/// use bevy_turborand::{names::NameGenerator, prelude::*};
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let names = NameGenerator::default();
+///
+/// let name = names.generate(&mut rng);
+///
+/// assert!(name.starts_with(char::is_uppercase));
+///
+/// let name = names.generate_with_pattern(&mut rng, "CvcVc").unwrap();
+///
+/// assert!(name.starts_with(char::is_uppercase));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "gen-utils")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(default))]
+pub struct NameGenerator {
+    syllables: Vec<String>,
+    consonants: Vec<String>,
+    vowels: Vec<String>,
+}
+
+/// The error returned by [`NameGenerator::generate_with_pattern`] for a pattern with a
+/// character other than `C`, `c`, `V` or `v`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidNamePattern {
+    /// The invalid character.
+    pub character: char,
+    /// The byte index of the invalid character in the pattern.
+    pub index: usize,
+}
+
+impl fmt::Display for InvalidNamePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid character {:?} at index {} of the name pattern, expected one of C, c, V or v",
+            self.character, self.index
+        )
+    }
+}
+
+impl std::error::Error for InvalidNamePattern {}
+
+impl NameGenerator {
+    /// The smallest number of syllables in a name from [`NameGenerator::generate`].
+    pub const MIN_SYLLABLES: usize = 2;
+    /// The largest number of syllables in a name from [`NameGenerator::generate`].
+    pub const MAX_SYLLABLES: usize = 4;
+
+    /// Replaces the syllables composed by [`NameGenerator::generate`].
+    #[inline]
+    #[must_use]
+    pub fn with_syllables(
+        mut self,
+        syllables: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.syllables = syllables.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Replaces the consonants picked for the `C` and `c` characters of a pattern.
+    #[inline]
+    #[must_use]
+    pub fn with_consonants(
+        mut self,
+        consonants: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.consonants = consonants.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Replaces the vowels picked for the `V` and `v` characters of a pattern.
+    #[inline]
+    #[must_use]
+    pub fn with_vowels(mut self, vowels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.vowels = vowels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Generates a name composed of two to four random syllables, with its first letter
+    /// capitalized. Draws the number of syllables first, then each syllable in turn.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no syllables.
+    #[must_use]
+    pub fn generate(&self, rng: &mut impl DelegatedRng) -> String {
+        assert!(
+            !self.syllables.is_empty(),
+            "NameGenerator needs at least one syllable"
+        );
+
+        let count = rng.usize(Self::MIN_SYLLABLES..=Self::MAX_SYLLABLES);
+
+        let name: String = (0..count)
+            .map(|_| self.syllables[rng.index(..self.syllables.len())].as_str())
+            .collect();
+
+        capitalize(&name)
+    }
+
+    /// Generates a name following `pattern`, drawing a random consonant for each `c` and a
+    /// random vowel for each `v`, from left to right. Their uppercase forms, `C` and `V`,
+    /// capitalize the picked letters, so `"CvcVc"` yields names such as `"TorAl"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidNamePattern`] for the first character of the pattern that isn't one
+    /// of `C`, `c`, `V` or `v`, without drawing from `rng`, as the pattern is validated up
+    /// front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern asks for a consonant or vowel while there are none.
+    pub fn generate_with_pattern(
+        &self,
+        rng: &mut impl DelegatedRng,
+        pattern: &str,
+    ) -> Result<String, InvalidNamePattern> {
+        if let Some((index, character)) = pattern
+            .char_indices()
+            .find(|(_, character)| !matches!(character, 'C' | 'c' | 'V' | 'v'))
+        {
+            return Err(InvalidNamePattern { character, index });
+        }
+
+        let mut name = String::with_capacity(pattern.len());
+
+        for character in pattern.chars() {
+            let letters = if character.eq_ignore_ascii_case(&'c') {
+                assert!(
+                    !self.consonants.is_empty(),
+                    "NameGenerator needs at least one consonant"
+                );
+                &self.consonants
+            } else {
+                assert!(
+                    !self.vowels.is_empty(),
+                    "NameGenerator needs at least one vowel"
+                );
+                &self.vowels
+            };
+
+            let picked = &letters[rng.index(..letters.len())];
+
+            if character.is_ascii_uppercase() {
+                name.push_str(&capitalize(picked));
+            } else {
+                name.push_str(picked);
+            }
+        }
+
+        Ok(name)
+    }
+}
+
+impl Default for NameGenerator {
+    fn default() -> Self {
+        let owned = |list: &[&str]| list.iter().map(ToString::to_string).collect();
+
+        Self {
+            syllables: owned(DEFAULT_SYLLABLES),
+            consonants: owned(DEFAULT_CONSONANTS),
+            vowels: owned(DEFAULT_VOWELS),
+        }
+    }
+}
+
+/// Returns `text` with its first character in uppercase.
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
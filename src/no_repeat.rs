@@ -0,0 +1,156 @@
+use crate::*;
+use std::collections::VecDeque;
+
+/// A sampler that picks items from a list while avoiding the ones it returned recently, such
+/// as for NPC dialogue beats that shouldn't repeat back to back. The items returned by the
+/// last `memory` samples have their weight reduced by `penalty`, with a penalty of `1.0`
+/// excluding them entirely and `0.0` leaving them as likely as any other item.
+///
+/// Sampling is done with [`DelegatedRng::weighted_sample`]. A list with a single item always
+/// returns it, and at least one item is always left eligible, even when the memory covers
+/// the whole list with a full penalty, in which case the least recently returned item is
+/// picked.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let mut beats = NoRepeatSampler::new(vec!["greet", "complain", "gossip"]);
+///
+/// let first = *beats.sample(&mut rng);
+/// let second = *beats.sample(&mut rng);
+///
+/// assert_ne!(first, second);
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct NoRepeatSampler<T> {
+    items: Vec<T>,
+    penalty: f64,
+    memory: usize,
+    recent: VecDeque<usize>,
+}
+
+impl<T> NoRepeatSampler<T> {
+    /// Creates a new [`NoRepeatSampler`] over the given items, with a full penalty and a
+    /// memory of `1`, so that no item is returned twice in a row.
+    #[inline]
+    #[must_use]
+    pub fn new(items: Vec<T>) -> Self {
+        Self {
+            items,
+            penalty: 1.0,
+            memory: 1,
+            recent: VecDeque::new(),
+        }
+    }
+
+    /// Builder function to set the penalty applied to recently returned items, clamped to
+    /// the range of `0.0..=1.0`.
+    #[inline]
+    #[must_use]
+    pub fn with_penalty(mut self, penalty: f64) -> Self {
+        self.penalty = penalty.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Builder function to set how many of the most recent samples are penalised.
+    #[inline]
+    #[must_use]
+    pub fn with_memory(mut self, memory: usize) -> Self {
+        self.memory = memory;
+        self.recent.truncate(memory);
+        self
+    }
+
+    /// Samples an item, penalising the recently returned ones, and remembers it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sampler holds no items.
+    pub fn sample(&mut self, rng: &mut impl DelegatedRng) -> &T {
+        assert!(
+            !self.items.is_empty(),
+            "cannot sample from a NoRepeatSampler without items"
+        );
+
+        // Only the most recent samples that leave at least one item unpenalised count.
+        let recent = self.recent.len().min(self.items.len() - 1);
+        let weight = 1.0 - self.penalty;
+
+        let sampled = rng
+            .weighted_sample(&self.items, |(_, index)| {
+                if self.recent.range(..recent).any(|&other| other == index) {
+                    weight
+                } else {
+                    1.0
+                }
+            })
+            .expect("the sampler holds items");
+
+        let index = self
+            .items
+            .iter()
+            .position(|item| std::ptr::eq(item, sampled))
+            .unwrap_or_default();
+
+        self.remember(index);
+
+        &self.items[index]
+    }
+
+    /// Forgets the recently returned items, making every item equally likely again.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.recent.clear();
+    }
+
+    /// Returns the items of the sampler.
+    #[inline]
+    #[must_use]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns the penalty applied to recently returned items.
+    #[inline]
+    #[must_use]
+    pub const fn penalty(&self) -> f64 {
+        self.penalty
+    }
+
+    /// Returns how many of the most recent samples are penalised.
+    #[inline]
+    #[must_use]
+    pub const fn memory(&self) -> usize {
+        self.memory
+    }
+
+    fn remember(&mut self, index: usize) {
+        if self.memory == 0 {
+            return;
+        }
+
+        if self.recent.len() == self.memory {
+            self.recent.pop_back();
+        }
+
+        self.recent.push_front(index);
+    }
+}
+
+impl<T> From<Vec<T>> for NoRepeatSampler<T> {
+    #[inline]
+    fn from(items: Vec<T>) -> Self {
+        Self::new(items)
+    }
+}
+
+impl<T: Clone> From<&[T]> for NoRepeatSampler<T> {
+    #[inline]
+    fn from(items: &[T]) -> Self {
+        Self::new(items.to_vec())
+    }
+}
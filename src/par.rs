@@ -0,0 +1,139 @@
+use crate::*;
+use bevy_ecs::{
+    batching::BatchingStrategy,
+    query::{QueryData, QueryFilter},
+};
+
+/// Runs `f` for every [`RngComponent`] matched by the query alongside its other data, in
+/// parallel batches of `batch_size` entities. Each entity only ever draws from its own
+/// [`RngComponent`], so the outcome for each entity is the same no matter how the batches
+/// are split up or in which order they run.
+///
+/// Systems using this should not also take a [`ResMut<GlobalRng>`], as accessing it from
+/// within `f` is not possible, and taking it anyway prevents the system from running in
+/// parallel with any other system using it.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn jitter(mut q_particles: Query<(&mut RngComponent, &mut Transform)>) {
+///     par_for_each_rng(&mut q_particles, 64, |mut rng, mut transform| {
+///         transform.translation.x += rng.f32_normalized();
+///     });
+/// }
+///
+/// // The same, assembled by hand.
+/// fn jitter_by_hand(mut q_particles: Query<(&mut RngComponent, &mut Transform)>) {
+///     q_particles
+///         .par_iter_mut()
+///         .for_each(|(mut rng, mut transform)| {
+///             transform.translation.x += rng.f32_normalized();
+///         });
+/// }
+///
+/// App::new().add_systems(Update, (jitter, jitter_by_hand));
+/// ```
+#[inline]
+pub fn par_for_each_rng<D, F>(
+    query: &mut Query<'_, '_, (&mut RngComponent, D), F>,
+    batch_size: usize,
+    f: impl for<'w> Fn(Mut<'w, RngComponent>, D::Item<'w>) + Send + Sync,
+) where
+    D: QueryData,
+    F: QueryFilter,
+{
+    query
+        .par_iter_mut()
+        .batching_strategy(BatchingStrategy::fixed(batch_size.max(1)))
+        .for_each(|(rng, data)| f(rng, data));
+}
+
+/// A batch of independent [`RngComponent`]s forked up front, for generating data outside
+/// the ECS in parallel, such as meshing chunks in [`ComputeTaskPool`] tasks. The source at
+/// each index of the batch only depends on the state of the RNG it was forked from and the
+/// index, so assigning sources to tasks by index keeps the results deterministic, regardless
+/// of the order in which the tasks run.
+///
+/// [`ComputeTaskPool`]: https://docs.rs/bevy_tasks/0.15/bevy_tasks/struct.ComputeTaskPool.html
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy::tasks::{ComputeTaskPool, TaskPool};
+/// use bevy_turborand::prelude::*;
+///
+/// let mut global = GlobalRng::with_seed(5);
+/// let batch = RngBatch::from_global(&mut global, 4);
+///
+/// let pool = ComputeTaskPool::get_or_init(TaskPool::default);
+///
+/// let heights: Vec<Vec<u8>> = pool.scope(|scope| {
+///     for mut rng in batch {
+///         scope.spawn(async move { (0..16).map(|_| rng.u8(..)).collect() });
+///     }
+/// });
+///
+/// assert_eq!(heights.len(), 4);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub struct RngBatch {
+    sources: Vec<RngComponent>,
+}
+
+impl RngBatch {
+    /// Forks `n` sources from the [`GlobalRng`].
+    #[inline]
+    #[must_use]
+    pub fn from_global(global: &mut GlobalRng, n: usize) -> Self {
+        Self::from_rng(global, n)
+    }
+
+    /// Forks `n` sources from any [`DelegatedRng`] backed by a [`Rng`], the same as
+    /// [`DelegatedRng::fork_many`] would.
+    #[inline]
+    #[must_use]
+    pub fn from_rng(rng: &mut impl DelegatedRng<Source = Rng>, n: usize) -> Self {
+        Self {
+            sources: rng
+                .fork_many(n)
+                .into_iter()
+                .map(RngComponent::from)
+                .collect(),
+        }
+    }
+
+    /// Returns the amount of sources in the batch.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// Returns `true` if the batch has no sources.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// Returns the source at `index`, if any.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut RngComponent> {
+        self.sources.get_mut(index)
+    }
+}
+
+impl IntoIterator for RngBatch {
+    type Item = RngComponent;
+    type IntoIter = std::vec::IntoIter<RngComponent>;
+
+    /// Returns the sources of the batch, in index order.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.sources.into_iter()
+    }
+}
@@ -1,9 +1,21 @@
 use crate::*;
+#[cfg(feature = "bevy_state")]
+use bevy_state::state::States;
+#[cfg(all(feature = "wyrand", feature = "bevy_time"))]
+use std::time::Duration;
+#[cfg(feature = "wyrand")]
+use tracing::info;
 
 /// A [`Plugin`] for initialising a [`GlobalRng`] & [`GlobalChaChaRng`]
 /// (if the feature flags are enabled for either of them) into a Bevy `App`.
-/// Also registers the types for reflection support if `serialize` feature flag
-/// is enabled.
+/// Also registers the types for reflection support, along with their
+/// [`ReflectComponent`] or [`ReflectResource`] data, and their serde reflection data
+/// if the `serialize` feature flag is enabled.
+///
+/// A resource that is already present in the `App` is never overwritten, so
+/// inserting a seeded [`GlobalRng`] before adding the plugin keeps its state, in
+/// which case any seed given to the plugin for it is ignored. To manage the resources
+/// entirely by hand, use [`RngPlugin::without_resources`].
 ///
 /// # Example
 /// ```
@@ -20,9 +32,38 @@ pub struct RngPlugin {
     #[cfg(feature = "wyrand")]
     #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
     rng: Option<u64>,
+    #[cfg(feature = "wyrand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+    time_seed: bool,
+    #[cfg(feature = "wyrand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+    strict_global: bool,
+    #[cfg(all(target_arch = "wasm32", feature = "web-persist"))]
+    #[cfg_attr(docsrs, doc(cfg(all(target_arch = "wasm32", feature = "web-persist"))))]
+    persisted_seed_key: Option<String>,
     #[cfg(feature = "chacha")]
     #[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
     chacha: Option<[u8; 40]>,
+    #[cfg(feature = "compat")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "compat")))]
+    legacy_components: bool,
+    #[cfg(feature = "audit")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "audit")))]
+    audit_capacity: usize,
+    #[cfg(feature = "rng-log")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rng-log")))]
+    rng_log_capacity: usize,
+    #[cfg(feature = "bevy_state")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_state")))]
+    state_reseeds: Vec<crate::reseed::StateReseed>,
+    #[cfg(all(feature = "wyrand", feature = "bevy_time"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "wyrand", feature = "bevy_time"))))]
+    intervals: Vec<crate::timers::IntervalRegistration>,
+    deterministic: Option<u64>,
+    record_entropy_seed: bool,
+    keep_seed_components: bool,
+    strict_ordering: bool,
+    insert_resources: bool,
 }
 
 impl RngPlugin {
@@ -34,12 +75,69 @@ impl RngPlugin {
         Self {
             #[cfg(feature = "wyrand")]
             rng: None,
+            #[cfg(feature = "wyrand")]
+            time_seed: false,
+            #[cfg(feature = "wyrand")]
+            strict_global: false,
+            #[cfg(all(target_arch = "wasm32", feature = "web-persist"))]
+            persisted_seed_key: None,
             #[cfg(feature = "chacha")]
             chacha: None,
+            #[cfg(feature = "compat")]
+            legacy_components: false,
+            #[cfg(feature = "audit")]
+            audit_capacity: DEFAULT_AUDIT_CAPACITY,
+            #[cfg(feature = "rng-log")]
+            rng_log_capacity: DEFAULT_RNG_LOG_CAPACITY,
+            #[cfg(feature = "bevy_state")]
+            state_reseeds: Vec::new(),
+            #[cfg(all(feature = "wyrand", feature = "bevy_time"))]
+            intervals: Vec::new(),
+            deterministic: None,
+            record_entropy_seed: false,
+            keep_seed_components: false,
+            strict_ordering: false,
+            insert_resources: true,
         }
     }
 
-    /// Builder function to set a seed value for a [`GlobalRng`].
+    /// Create a new [`RngPlugin`] instance for a fully deterministic app, seeding every global
+    /// RNG from `seed` and enabling checks for common determinism hazards. The [`GlobalRng`]
+    /// is seeded with `seed` as is, while the [`GlobalChaChaRng`] is seeded with
+    /// `SeedMixer::new(seed).finish_chacha()`, the 40 byte extension of `seed` described in
+    /// [`SeedMixer::finish_chacha`].
+    ///
+    /// The plugin inserts [`DeterminismSettings`] for the seed, which warns about systems
+    /// accessing the same global RNG without being ordered against each other, as well as an
+    /// [`RngAuditConfig`] that warns about RNG components seeded from entropy, unless either
+    /// resource is already present. The [`RngAuditConfig`] check is only available in debug
+    /// builds, or with the `audit` feature enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// App::new().add_plugins(RngPlugin::deterministic(12345));
+    /// ```
+    #[must_use]
+    pub fn deterministic(seed: u64) -> Self {
+        let plugin = Self::new();
+
+        #[cfg(feature = "wyrand")]
+        let plugin = plugin.with_rng_seed(seed);
+
+        #[cfg(feature = "chacha")]
+        let plugin = plugin.with_chacha_seed(SeedMixer::new(seed).finish_chacha());
+
+        Self {
+            deterministic: Some(seed),
+            ..plugin
+        }
+    }
+
+    /// Builder function to set a seed value for a [`GlobalRng`], which the [`StatelessRng`]
+    /// is seeded with as well.
     #[cfg(feature = "wyrand")]
     #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
     #[inline]
@@ -49,6 +147,60 @@ impl RngPlugin {
         self
     }
 
+    /// Builder function to set a seed value for a [`GlobalRng`] from a human-friendly
+    /// seed string. See [`seed_from_str`] for how the string maps to a seed.
+    #[cfg(feature = "wyrand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+    #[inline]
+    #[must_use]
+    pub fn with_rng_seed_str(self, seed: &str) -> Self {
+        self.with_rng_seed(seed_from_str(seed))
+    }
+
+    /// Builder function to seed the [`GlobalRng`] from the wall clock time when the plugin
+    /// is built, for sessions that vary yet can still be reproduced. The seed is the amount of
+    /// whole seconds since the Unix epoch, such as `1718822400`, and is logged and inserted as
+    /// an [`RngStartupSeed`] resource so that it can be displayed to players. Works on `wasm32`
+    /// without any further setup. Takes precedence over [`RngPlugin::with_rng_seed`].
+    #[cfg(feature = "wyrand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+    #[inline]
+    #[must_use]
+    pub const fn with_time_seed(mut self) -> Self {
+        self.time_seed = true;
+        self
+    }
+
+    /// Builder function to seed the [`GlobalRng`] from a seed persisted in the page's
+    /// `localStorage` under the given key, so that refreshing the page resumes the same world.
+    /// If no seed is stored yet, a random one is generated and stored when the plugin is
+    /// built. Should storage be unavailable, such as when browsing privately, a random seed is
+    /// used instead, without panicking. Takes precedence over [`RngPlugin::with_rng_seed`] and
+    /// [`RngPlugin::with_time_seed`].
+    ///
+    /// The stored seed can be read with [`persisted_seed`], such as for displaying it in a UI.
+    #[cfg(all(target_arch = "wasm32", feature = "web-persist"))]
+    #[cfg_attr(docsrs, doc(cfg(all(target_arch = "wasm32", feature = "web-persist"))))]
+    #[inline]
+    #[must_use]
+    pub fn with_persisted_seed(mut self, key: &str) -> Self {
+        self.persisted_seed_key = Some(key.to_owned());
+        self
+    }
+
+    /// Builder function to switch the plugin to strict mode, inserting a
+    /// [`StrictGlobalRng`] and a [`GlobalEntropy`] instead of a [`GlobalRng`]. Any seed
+    /// given for the [`GlobalRng`] seeds the [`StrictGlobalRng`] instead, with the
+    /// [`GlobalEntropy`] seeded from it. [`ForkOnSpawn`] forks from the [`StrictGlobalRng`].
+    #[cfg(feature = "wyrand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+    #[inline]
+    #[must_use]
+    pub const fn with_strict_global(mut self) -> Self {
+        self.strict_global = true;
+        self
+    }
+
     /// Builder function to set a seed value for a [`GlobalChaChaRng`].
     #[cfg(feature = "chacha")]
     #[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
@@ -58,6 +210,157 @@ impl RngPlugin {
         self.chacha = Some(seed);
         self
     }
+
+    /// Builder function to set a seed value for a [`GlobalChaChaRng`] from a human-friendly
+    /// seed string. See [`chacha_seed_from_str`] for how the string maps to a seed.
+    #[cfg(feature = "chacha")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
+    #[inline]
+    #[must_use]
+    pub fn with_chacha_seed_str(self, seed: &str) -> Self {
+        self.with_chacha_seed(chacha_seed_from_str(seed))
+    }
+
+    /// Builder function to record the seeds drawn for any global RNGs seeded from entropy, so
+    /// that they can be read back with [`GlobalRng::initial_seed`] or
+    /// [`GlobalChaChaRng::initial_seed`], such as for attaching the session seed to a crash
+    /// report. Without it, only seeds given to the plugin are recorded.
+    #[inline]
+    #[must_use]
+    pub const fn with_recorded_entropy_seed(mut self) -> Self {
+        self.record_entropy_seed = true;
+        self
+    }
+
+    /// Builder function to keep loading [`LegacyRngComponent`]s from saves, by registering
+    /// the type for reflection. Entities loaded with one keep using the pinned legacy
+    /// algorithm, while newly spawned content uses [`RngComponent`] and the current one.
+    #[cfg(feature = "compat")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "compat")))]
+    #[inline]
+    #[must_use]
+    pub const fn with_legacy_components(mut self) -> Self {
+        self.legacy_components = true;
+        self
+    }
+
+    /// Builder function to set how many entries the [`RngAuditLog`] inserted
+    /// by the plugin retains.
+    #[cfg(feature = "audit")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "audit")))]
+    #[inline]
+    #[must_use]
+    pub const fn with_audit_capacity(mut self, capacity: usize) -> Self {
+        self.audit_capacity = capacity;
+        self
+    }
+
+    /// Builder function to set how many entries the [`RngLog`] inserted
+    /// by the plugin retains.
+    #[cfg(feature = "rng-log")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rng-log")))]
+    #[inline]
+    #[must_use]
+    pub const fn with_rng_log_capacity(mut self, capacity: usize) -> Self {
+        self.rng_log_capacity = capacity;
+        self
+    }
+
+    /// Builder function to reseed the [`GlobalRng`] from the given [`SeedSource`] every time
+    /// `state` is entered, such as for resetting all gameplay RNG to the chosen world seed
+    /// when going from a menu into the game. [`RngComponent`]s marked with [`ReseedOnState`]
+    /// are then re-forked from the reseeded [`GlobalRng`], while all others are left as is.
+    /// In strict mode, the [`StrictGlobalRng`] is reseeded instead. Can be called several
+    /// times, for different states.
+    #[cfg(feature = "bevy_state")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_state")))]
+    #[inline]
+    #[must_use]
+    pub fn reseed_on_enter<S: States>(mut self, state: S, source: SeedSource) -> Self {
+        self.state_reseeds
+            .push(crate::reseed::reseed_on_enter(state, source));
+        self
+    }
+
+    /// Builder function to insert a [`RandomInterval`] resource tagged with `Tag`, elapsing
+    /// every `base ± jitter`, along with a system ticking it by the [`Time`] delta in
+    /// [`PreUpdate`]. Systems can then be rate limited with
+    /// `run_if(interval_elapsed::<Tag>)`. The intervals are drawn from a [`ForkedRng`] of the
+    /// [`GlobalRng`], or of the [`StrictGlobalRng`] in strict mode, so they are deterministic
+    /// when it is seeded, but it must be present. Can be called several times, for different
+    /// tags.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` is zero or `jitter` is greater than `base`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// struct EnemySpawns;
+    ///
+    /// fn spawn_enemy() {
+    ///     // Runs every 3 seconds, give or take one
+    /// }
+    ///
+    /// App::new()
+    ///     .add_plugins(RngPlugin::new().with_interval_resource::<EnemySpawns>(
+    ///         Duration::from_secs(3),
+    ///         Duration::from_secs(1),
+    ///     ))
+    ///     .add_systems(Update, spawn_enemy.run_if(interval_elapsed::<EnemySpawns>));
+    /// ```
+    ///
+    /// [`Time`]: bevy_time::Time
+    #[cfg(all(feature = "wyrand", feature = "bevy_time"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "wyrand", feature = "bevy_time"))))]
+    #[inline]
+    #[must_use]
+    pub fn with_interval_resource<Tag: Send + Sync + 'static>(
+        mut self,
+        base: Duration,
+        jitter: Duration,
+    ) -> Self {
+        self.intervals
+            .push(crate::timers::interval_resource::<Tag>(base, jitter));
+        self
+    }
+
+    /// Builder function to keep [`RngSeed`] and [`ChaChaSeed`] components on entities once
+    /// they have been resolved into RNG components, instead of removing them, such as for
+    /// saving scenes back out with their authored seeds.
+    #[inline]
+    #[must_use]
+    pub const fn keep_seed_components(mut self) -> Self {
+        self.keep_seed_components = true;
+        self
+    }
+
+    /// Builder function to panic instead of warning when two systems in the
+    /// [`RngSystems::SeedSpawning`] set both access a global RNG without being ordered
+    /// against each other. The check runs once right before [`PreStartup`], building the
+    /// schedules the set is configured in ahead of time, so an ambiguous setup fails before
+    /// anything is seeded rather than only seeding differently on some runs. Schedules the set
+    /// is only added to after startup aren't checked.
+    #[inline]
+    #[must_use]
+    pub const fn strict_ordering(mut self, strict: bool) -> Self {
+        self.strict_ordering = strict;
+        self
+    }
+
+    /// Builder function to stop the plugin from inserting any resources, for when they
+    /// are inserted manually instead, such as from a loaded save. The types are still
+    /// registered and the systems still added.
+    #[inline]
+    #[must_use]
+    pub const fn without_resources(mut self) -> Self {
+        self.insert_resources = false;
+        self
+    }
 }
 
 impl Default for RngPlugin {
@@ -70,23 +373,301 @@ impl Default for RngPlugin {
     }
 }
 
+/// [`SystemSet`]s for the systems registered by [`RngPlugin`], along with
+/// [`RngSystems::SeedSpawning`] for systems of the app itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "wyrand", feature = "chacha"))))]
+pub enum RngSystems {
+    /// Re-forks newly added RNG components marked with [`ForkOnSpawn`]. Runs in [`PreUpdate`].
+    ForkOnSpawn,
+    /// Drives all unfinished [`SlicedGenerator`]s. Runs in [`Update`].
+    #[cfg(feature = "wyrand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+    SlicedGeneration,
+    /// Put all systems that consume a global RNG for seeding into this set, such as
+    /// [`Startup`] systems spawning entities with RNG components forked from the
+    /// [`GlobalRng`]. Bevy may run unordered systems in either order, so each pair of
+    /// systems in the set accessing the same global RNG must be ordered against each other,
+    /// such as by chaining them, for the seeds to be deterministic. Unordered pairs are
+    /// warned about right before [`PreStartup`], before any of them run, or cause a panic with
+    /// [`RngPlugin::strict_ordering`].
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// fn spawn_player(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+    ///     commands.spawn(RngComponent::from(&mut global));
+    /// }
+    ///
+    /// fn spawn_enemies(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+    ///     commands.spawn(RngComponent::from(&mut global));
+    /// }
+    ///
+    /// App::new()
+    ///     .add_plugins(RngPlugin::new().with_rng_seed(12345).strict_ordering(true))
+    ///     .add_systems(
+    ///         Startup,
+    ///         (spawn_player, spawn_enemies)
+    ///             .chain()
+    ///             .in_set(RngSystems::SeedSpawning),
+    ///     )
+    ///     .run();
+    /// ```
+    SeedSpawning,
+}
+
 impl Plugin for RngPlugin {
     fn build(&self, app: &mut App) {
-        #[cfg(all(feature = "wyrand", feature = "serialize"))]
+        app.register_type::<DeterminismSettings>()
+            .register_type::<ForkOnSpawn>()
+            .register_type::<RngAuditConfig>()
+            .register_type::<ShakeSampler>()
+            .register_type::<StatelessRng>();
+
+        #[cfg(feature = "bevy_math")]
+        app.register_type::<RandomWalk>()
+            .register_type::<WalkMode>();
+
+        #[cfg(feature = "bevy_time")]
+        app.register_type::<JitteredTimer>();
+
+        #[cfg(feature = "wyrand")]
+        if self.strict_global {
+            app.add_systems(
+                PreUpdate,
+                crate::fork::fork_rng_on_spawn_strict.in_set(RngSystems::ForkOnSpawn),
+            );
+        } else {
+            app.add_systems(
+                PreUpdate,
+                crate::fork::fork_rng_on_spawn.in_set(RngSystems::ForkOnSpawn),
+            );
+        }
+
+        let keep_seeds = self.keep_seed_components;
+        let strict_ordering = self.strict_ordering;
+
+        app.configure_sets(Startup, RngSystems::SeedSpawning);
+        crate::determinism::register_seed_spawning_check(app, strict_ordering);
+
+        #[cfg(feature = "wyrand")]
+        app.register_type::<RngSeed>().add_systems(
+            PreUpdate,
+            (move |commands: Commands<'_, '_>,
+                   q_seeds: Query<'_, '_, (Entity, &RngSeed), Without<RngComponent>>| {
+                crate::authoring::resolve_rng_seeds(keep_seeds, commands, q_seeds);
+            })
+            .before(RngSystems::ForkOnSpawn),
+        );
+
+        #[cfg(feature = "chacha")]
+        app.register_type::<ChaChaSeed>().add_systems(
+            PreUpdate,
+            (move |commands: Commands<'_, '_>,
+                   q_seeds: Query<'_, '_, (Entity, &ChaChaSeed), Without<ChaChaRngComponent>>| {
+                crate::authoring::resolve_chacha_seeds(keep_seeds, commands, q_seeds);
+            })
+            .before(RngSystems::ForkOnSpawn),
+        );
+
+        #[cfg(all(feature = "wyrand", any(debug_assertions, feature = "audit")))]
+        app.add_systems(
+            PreUpdate,
+            crate::provenance::audit_unseeded::<RngComponent>
+                .after(RngSystems::ForkOnSpawn)
+                .run_if(resource_exists::<RngAuditConfig>),
+        );
+
+        #[cfg(all(feature = "chacha", any(debug_assertions, feature = "audit")))]
+        app.add_systems(
+            PreUpdate,
+            crate::provenance::audit_unseeded::<ChaChaRngComponent>
+                .after(RngSystems::ForkOnSpawn)
+                .run_if(resource_exists::<RngAuditConfig>),
+        );
+
+        if let Some(seed) = self.deterministic {
+            if !app.world().contains_resource::<DeterminismSettings>() {
+                app.insert_resource(DeterminismSettings::new(seed));
+            }
+
+            if !app.world().contains_resource::<RngAuditConfig>() {
+                app.insert_resource(RngAuditConfig::default());
+            }
+        }
+
+        app.add_systems(
+            Last,
+            crate::determinism::warn_ambiguous_rng_access
+                .run_if(resource_exists::<DeterminismSettings>),
+        );
+
+        #[cfg(feature = "wyrand")]
+        app.add_event::<GenerationComplete>().add_systems(
+            Update,
+            crate::sliced::drive_sliced_generators.in_set(RngSystems::SlicedGeneration),
+        );
+
+        #[cfg(feature = "chacha")]
+        app.add_systems(
+            PreUpdate,
+            crate::fork::fork_chacha_on_spawn.in_set(RngSystems::ForkOnSpawn),
+        );
+
+        #[cfg(feature = "wyrand")]
         app.register_type::<RngComponent>()
-            .register_type::<GlobalRng>();
+            .register_type::<GlobalRng>()
+            .register_type::<RngStartupSeed>()
+            .register_type::<RngStreams>()
+            .register_type_data::<RngComponent, ReflectRngReseed>()
+            .register_type_data::<GlobalRng, ReflectRngReseed>();
+
+        #[cfg(feature = "wyrand")]
+        let rng_seed = if self.time_seed {
+            let seed = crate::global::startup::time_seed();
+            info!("Seeding the global RNG from the startup time, with seed {seed}");
+            Some(seed)
+        } else {
+            self.rng
+        };
+
+        #[cfg(all(target_arch = "wasm32", feature = "web-persist"))]
+        let rng_seed = self
+            .persisted_seed_key
+            .as_deref()
+            .map(crate::web::load_or_persist_seed)
+            .or(rng_seed);
 
         #[cfg(feature = "wyrand")]
-        app.insert_resource(self.rng.map_or_else(GlobalRng::new, GlobalRng::with_seed));
+        let rng_seed = rng_seed.or_else(|| self.record_entropy_seed.then(|| Rng::new().gen_u64()));
+
+        #[cfg(feature = "wyrand")]
+        if self.insert_resources && self.strict_global {
+            if !app.world().contains_resource::<StrictGlobalRng>() {
+                app.insert_resource(
+                    rng_seed.map_or_else(StrictGlobalRng::new, StrictGlobalRng::with_seed),
+                );
+
+                if let Some(seed) = rng_seed {
+                    app.insert_resource(RngStartupSeed(seed));
+                }
+            }
+
+            if !app.world().contains_resource::<GlobalEntropy>() {
+                let entropy = app
+                    .world_mut()
+                    .resource_mut::<StrictGlobalRng>()
+                    .seed_entropy();
+
+                app.insert_resource(entropy);
+            }
+        } else if self.insert_resources && !app.world().contains_resource::<GlobalRng>() {
+            app.insert_resource(rng_seed.map_or_else(GlobalRng::new, GlobalRng::with_seed));
 
-        #[cfg(all(feature = "chacha", feature = "serialize"))]
+            if let Some(seed) = rng_seed {
+                app.insert_resource(RngStartupSeed(seed));
+            }
+        }
+
+        #[cfg(feature = "wyrand")]
+        if self.insert_resources && !app.world().contains_resource::<StatelessRng>() {
+            // Follows the seed of an existing `GlobalRng` over the one of the plugin, as
+            // that is the seed the global RNG ends up with.
+            let seed = app
+                .world()
+                .get_resource::<GlobalRng>()
+                .and_then(GlobalRng::initial_seed)
+                .or(rng_seed)
+                .unwrap_or_else(|| Rng::new().gen_u64());
+
+            app.insert_resource(StatelessRng::new(seed));
+        }
+
+        #[cfg(feature = "bevy_state")]
+        {
+            app.register_type::<NextWorldSeed>()
+                .register_type::<ReseedOnState>();
+
+            for reseed in &self.state_reseeds {
+                reseed(app);
+            }
+        }
+
+        #[cfg(all(feature = "wyrand", feature = "bevy_time"))]
+        for interval in &self.intervals {
+            interval(app, self.insert_resources, self.strict_global);
+        }
+
+        #[cfg(feature = "compat")]
+        if self.legacy_components {
+            app.register_type::<LegacyRngComponent>();
+        }
+
+        #[cfg(feature = "chacha")]
         app.register_type::<ChaChaRngComponent>()
-            .register_type::<GlobalChaChaRng>();
+            .register_type::<GlobalChaChaRng>()
+            .register_type_data::<ChaChaRngComponent, ReflectChaChaReseed>()
+            .register_type_data::<GlobalChaChaRng, ReflectChaChaReseed>();
 
         #[cfg(feature = "chacha")]
-        app.insert_resource(
-            self.chacha
-                .map_or_else(GlobalChaChaRng::new, GlobalChaChaRng::with_seed),
-        );
+        if self.insert_resources && !app.world().contains_resource::<GlobalChaChaRng>() {
+            app.insert_resource(match self.chacha {
+                Some(seed) => GlobalChaChaRng::with_seed(seed),
+                None if self.record_entropy_seed => GlobalChaChaRng::with_recorded_entropy(),
+                None => GlobalChaChaRng::new(),
+            });
+        }
+
+        #[cfg(feature = "diagnostics")]
+        {
+            crate::diagnostics::register_diagnostics(app);
+            crate::budget::register_budget_checks(app);
+        }
+
+        #[cfg(feature = "audit")]
+        {
+            if self.insert_resources && !app.world().contains_resource::<RngAuditLog>() {
+                app.insert_resource(RngAuditLog::new(self.audit_capacity));
+            }
+
+            // Components are handed the log as they are added, while the globals are handed
+            // it here, and again whenever either them or the log are replaced.
+            #[cfg(feature = "wyrand")]
+            app.add_systems(
+                First,
+                crate::audit::attach_to_resource::<GlobalRng>.run_if(
+                    resource_exists::<RngAuditLog>
+                        .and(resource_added::<GlobalRng>.or(resource_changed::<RngAuditLog>)),
+                ),
+            );
+
+            #[cfg(feature = "chacha")]
+            app.add_systems(
+                First,
+                crate::audit::attach_to_resource::<GlobalChaChaRng>
+                    .run_if(resource_exists::<RngAuditLog>.and(
+                        resource_added::<GlobalChaChaRng>.or(resource_changed::<RngAuditLog>),
+                    )),
+            );
+
+            #[cfg(feature = "wyrand")]
+            crate::audit::attach_in_world::<GlobalRng>(app.world_mut());
+            #[cfg(feature = "chacha")]
+            crate::audit::attach_in_world::<GlobalChaChaRng>(app.world_mut());
+        }
+
+        #[cfg(feature = "rng-log")]
+        {
+            if self.insert_resources && !app.world().contains_resource::<RngLog>() {
+                app.insert_resource(RngLog::new(self.rng_log_capacity));
+            }
+
+            app.add_systems(
+                Last,
+                crate::rng_log::advance_rng_log_frame.run_if(resource_exists::<RngLog>),
+            );
+        }
     }
 }
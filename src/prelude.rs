@@ -1,4 +1,6 @@
-pub use turborand::{ForkableCore, GenCore, SecureCore, SeededCore, TurboCore, TurboRand};
+pub use turborand::{
+    ForkableCore, GenCore, SecureCore, SeededCore, TurboCore, TurboKind, TurboRand,
+};
 
 #[cfg(feature = "wyrand")]
 pub use turborand::prelude::Rng;
@@ -9,14 +11,122 @@ pub use turborand::prelude::ChaChaRng;
 #[cfg(feature = "rand")]
 pub use turborand::prelude::RandBorrowed;
 
+pub use crate::affix::{AffixEntry, AffixRoller, RolledAffixes};
+#[cfg(feature = "audit")]
+pub use crate::audit::{audit_label, AuditEntry, AuditOutcome, RngAuditLog};
+pub use crate::audited::{AuditedRng, AuditedRngPlugin};
+#[cfg(feature = "chacha")]
+pub use crate::authoring::ChaChaSeed;
+#[cfg(feature = "wyrand")]
+pub use crate::authoring::RngSeed;
+#[cfg(all(feature = "diagnostics", any(feature = "wyrand", feature = "chacha")))]
+pub use crate::budget::{RngBudget, RngBudgetAction, RngBudgetExceeded};
+pub use crate::bytes::RandomByteStream;
+#[cfg(all(feature = "wyrand", feature = "bevy_math"))]
+pub use crate::chunks::ChunkRngProvider;
+pub use crate::collections::{RandomDeck, RandomDeckError};
 #[cfg(feature = "chacha")]
 pub use crate::component::chacha::ChaChaRngComponent;
 #[cfg(feature = "wyrand")]
 pub use crate::component::rng::RngComponent;
+#[cfg(all(feature = "bevy_time", any(feature = "wyrand", feature = "chacha")))]
+pub use crate::conditions::interval_elapsed;
+#[cfg(feature = "wyrand")]
+pub use crate::conditions::{chance_global, ratio_global};
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub use crate::determinism::DeterminismSettings;
+#[cfg(all(feature = "diagnostics", any(feature = "wyrand", feature = "chacha")))]
+pub use crate::diagnostics::reset_rng_call_counts;
+#[cfg(all(feature = "state-bytes", any(feature = "wyrand", feature = "chacha")))]
+pub use crate::diff::{RngDiffError, RngStateChange, RngStateDiff, StateDiff};
+pub use crate::fairness::{BagRandom, PityChance};
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub use crate::fork::{ForkOnSpawn, ForkedRng};
 #[cfg(feature = "chacha")]
 pub use crate::global::chacha::GlobalChaChaRng;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub use crate::global::entropy::ReseedFromEntropy;
 #[cfg(feature = "wyrand")]
 pub use crate::global::rng::GlobalRng;
+#[cfg(feature = "wyrand")]
+pub use crate::global::startup::RngStartupSeed;
+#[cfg(feature = "wyrand")]
+pub use crate::global::strict::{GlobalEntropy, StrictGlobalRng};
+#[cfg(feature = "gen-utils")]
+pub use crate::grid::GridMaze;
+#[cfg(feature = "compat")]
+pub use crate::legacy::{LegacyRng, LegacyRngComponent};
+pub use crate::lines::{LineEntry, LineHistory, LineSelector};
+pub use crate::modifiers::{ProbabilityModifier, ProbabilityModifiers};
+#[cfg(feature = "gen-utils")]
+pub use crate::names::NameGenerator;
+pub use crate::no_repeat::NoRepeatSampler;
+#[cfg(feature = "wyrand")]
+pub use crate::par::{par_for_each_rng, RngBatch};
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub use crate::plugin::{RngPlugin, RngSystems};
+#[cfg(feature = "wyrand")]
+pub use crate::preview::{PreviewExtractor, SeedPreview, SeedPreviewResult, SubsystemKey};
 #[cfg(any(feature = "wyrand", feature = "chacha"))]
-pub use crate::plugin::RngPlugin;
+pub use crate::provenance::RngAuditConfig;
+pub use crate::query::{pick_weighted_entity, QueryRngExt};
+pub use crate::quests::{QuestEntry, QuestGraphError, QuestRoll, QuestRoller, QuestShortfall};
+#[cfg(feature = "rand")]
+pub use crate::rand_owned::RandOwned;
+#[cfg(feature = "wyrand")]
+pub use crate::randomize::{randomize_field, randomize_field_keyed, RandomizeField};
+pub use crate::range::SampleRange;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub use crate::reflect_reseed::ReflectReseedError;
+#[cfg(feature = "wyrand")]
+pub use crate::reflect_reseed::{reseed_reflected, ReflectRngReseed};
+#[cfg(feature = "chacha")]
+pub use crate::reflect_reseed::{reseed_reflected_chacha, ReflectChaChaReseed};
+pub use crate::reflected::{randomize_reflect, NoRandomize, RandomizeOptions, RandomizeSummary};
+#[cfg(feature = "bevy_state")]
+pub use crate::reseed::{NextWorldSeed, ReseedOnState, SeedSource};
+pub use crate::retry::RetryExhausted;
+#[cfg(feature = "rng-log")]
+pub use crate::rng_log::{LoggedRng, RngLog, RngLogEntry, DEFAULT_RNG_LOG_CAPACITY};
+pub use crate::sample::SampleError;
+#[cfg(feature = "wyrand")]
+pub use crate::schedule::{
+    DaySchedule, ScheduleEntry, ScheduleGenerator, ScheduleSlot, MINUTES_PER_DAY,
+};
+#[cfg(feature = "testing")]
+pub use crate::seeder::WorldSeeder;
+pub use crate::send::SendRng;
+#[cfg(feature = "bevy_math")]
+pub use crate::sequences::HaltonSequence;
+pub use crate::shake::ShakeSampler;
+#[cfg(feature = "shapes")]
+pub use crate::shapes::SampleShape;
+#[cfg(feature = "wyrand")]
+pub use crate::sliced::{Budget, GenProgress, GenerationComplete, SlicedGenerator};
+pub use crate::slots::{SlotAssignment, SlotExclusion, SlotItem, SlotPick, SlotPool, SlotRoller};
+#[cfg(all(feature = "serialize", any(feature = "wyrand", feature = "chacha")))]
+pub use crate::snapshot::{
+    insert_reflected_component, insert_reflected_resource, ReflectInsertError, RngEntitySnapshot,
+    RngWorldSnapshot, SnapshotError,
+};
+#[cfg(all(feature = "state-bytes", any(feature = "wyrand", feature = "chacha")))]
+pub use crate::state::{RngStateError, StateBytes};
+pub use crate::stateless::StatelessRng;
+#[cfg(feature = "wyrand")]
+pub use crate::streams::RngStreams;
+pub use crate::tables::{Cdf, CdfError, TieredOutcome};
+#[cfg(feature = "bevy_time")]
+pub use crate::timers::{JitteredTimer, RandomInterval};
 pub use crate::traits::DelegatedRng;
+#[cfg(feature = "wyrand")]
+pub use crate::variance::{ResolvedVariance, VarianceDistribution, VarianceProfile};
+pub use crate::variants::VariantSampler;
+#[cfg(feature = "bevy_math")]
+pub use crate::walks::{RandomWalk, WalkMode};
+#[cfg(all(target_arch = "wasm32", feature = "web-persist"))]
+pub use crate::web::{clear_persisted_seed, persisted_seed};
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub use crate::world::{MissingGlobalRng, WorldRngExt};
+pub use crate::wrapper::{GlobalRngWrapper, RngComponentWrapper, RngSource, RngSourceAppExt};
+#[cfg(feature = "bevy_rand-compat")]
+pub use crate::wyrand_compat::WyRandCompat;
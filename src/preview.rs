@@ -0,0 +1,189 @@
+use crate::{
+    hash::{mix, StableHasher},
+    *,
+};
+
+/// The key of a subsystem's RNG stream, such as world or boss generation, derived from a
+/// master seed. Keys are meant to be declared as constants shared by the game and its
+/// [`SeedPreview`], so that both derive the exact same stream for a subsystem.
+///
+/// Streams are derived purely from the master seed and the key's name, so they never
+/// depend on one another or on the order they are derived in.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// const STARTING_BIOME: SubsystemKey = SubsystemKey::new("starting_biome");
+///
+/// let rng = STARTING_BIOME.rng(12345);
+///
+/// assert_eq!(rng.u32(..), STARTING_BIOME.rng(12345).u32(..));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub struct SubsystemKey(&'static str);
+
+impl SubsystemKey {
+    /// Creates a new [`SubsystemKey`] with the given name.
+    #[inline]
+    #[must_use]
+    pub const fn new(name: &'static str) -> Self {
+        Self(name)
+    }
+
+    /// Returns the name of the key.
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.0
+    }
+
+    /// Returns the seed of the subsystem's stream for the given master seed.
+    #[inline]
+    #[must_use]
+    pub fn seed_for(&self, master_seed: u64) -> u64 {
+        mix(master_seed, StableHasher::hash_one(self.0))
+    }
+
+    /// Returns the subsystem's stream for the given master seed.
+    #[inline]
+    #[must_use]
+    pub fn rng(&self, master_seed: u64) -> Rng {
+        Rng::with_seed(self.seed_for(master_seed))
+    }
+
+    /// Returns the subsystem's stream for the given master seed, as an [`RngComponent`].
+    #[inline]
+    #[must_use]
+    pub fn rng_component(&self, master_seed: u64) -> RngComponent {
+        RngComponent::from(self.rng(master_seed))
+    }
+}
+
+/// An extractor of a [`SeedPreview`], generating a preview item from a subsystem's stream.
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub type PreviewExtractor<T> = fn(&mut Rng) -> T;
+
+/// The outcome of [`SeedPreview::preview`], with the preview items in the order their
+/// extractors were added.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct SeedPreviewResult<T> {
+    /// The master seed that was previewed.
+    pub seed: u64,
+    /// The preview items, by the name of the [`SubsystemKey`] they were extracted with.
+    pub items: Vec<(String, T)>,
+}
+
+impl<T> SeedPreviewResult<T> {
+    /// Returns the preview item extracted with the given key, if any.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, key: SubsystemKey) -> Option<&T> {
+        self.items
+            .iter()
+            .find(|(name, _)| name == key.name())
+            .map(|(_, item)| item)
+    }
+}
+
+/// Previews what a master seed generates, such as the starting biome or first boss shown on
+/// a main menu, without running the game itself.
+///
+/// Each extractor runs against the stream of its [`SubsystemKey`], derived from the master
+/// seed the same way the game derives it with [`SubsystemKey::rng`]. As long as the game
+/// generates the previewed content from that stream, the preview is guaranteed to match,
+/// and extractors can't perturb each other or the game.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// const STARTING_BIOME: SubsystemKey = SubsystemKey::new("starting_biome");
+/// const FIRST_BOSS: SubsystemKey = SubsystemKey::new("first_boss");
+///
+/// fn starting_biome(rng: &mut Rng) -> &'static str {
+///     *rng.sample(&["forest", "desert", "tundra"]).unwrap()
+/// }
+///
+/// fn first_boss(rng: &mut Rng) -> &'static str {
+///     *rng.sample(&["dragon", "lich"]).unwrap()
+/// }
+///
+/// let preview = SeedPreview::new()
+///     .with_extractor(STARTING_BIOME, starting_biome)
+///     .with_extractor(FIRST_BOSS, first_boss);
+///
+/// let result = preview.preview(12345);
+///
+/// // The game generates the starting biome from the same stream.
+/// let mut rng = STARTING_BIOME.rng(12345);
+///
+/// assert_eq!(result.get(STARTING_BIOME), Some(&starting_biome(&mut rng)));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub struct SeedPreview<T> {
+    extractors: Vec<(SubsystemKey, PreviewExtractor<T>)>,
+}
+
+impl<T> SeedPreview<T> {
+    /// Creates a new [`SeedPreview`] without any extractors.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            extractors: Vec::new(),
+        }
+    }
+
+    /// Builder function to add an extractor, running against the stream of the given key.
+    /// Replaces any previous extractor for the same key.
+    #[inline]
+    #[must_use]
+    pub fn with_extractor(mut self, key: SubsystemKey, extractor: PreviewExtractor<T>) -> Self {
+        self.add_extractor(key, extractor);
+        self
+    }
+
+    /// Adds an extractor, running against the stream of the given key. Replaces any previous
+    /// extractor for the same key.
+    pub fn add_extractor(&mut self, key: SubsystemKey, extractor: PreviewExtractor<T>) {
+        match self.extractors.iter_mut().find(|(other, _)| *other == key) {
+            Some((_, existing)) => *existing = extractor,
+            None => self.extractors.push((key, extractor)),
+        }
+    }
+
+    /// Returns the keys of the extractors, in the order they were added.
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = SubsystemKey> + '_ {
+        self.extractors.iter().map(|&(key, _)| key)
+    }
+
+    /// Runs every extractor against its stream for the given master seed.
+    #[must_use]
+    pub fn preview(&self, master_seed: u64) -> SeedPreviewResult<T> {
+        SeedPreviewResult {
+            seed: master_seed,
+            items: self
+                .extractors
+                .iter()
+                .map(|(key, extractor)| {
+                    let mut rng = key.rng(master_seed);
+
+                    (key.name().to_owned(), extractor(&mut rng))
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<T> Default for SeedPreview<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
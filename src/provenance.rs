@@ -0,0 +1,78 @@
+use crate::*;
+use tracing::warn;
+
+/// Enables checking for RNG components seeded from entropy, such as by
+/// [`RngComponent::new`] or [`RngComponent::default`], which silently break deterministic
+/// replays. Components created from a seed, forked from another RNG or deserialized count as
+/// seeded, while those created from entropy are reported the first frame they appear on an
+/// entity, listing every offending entity at once.
+///
+/// The check is added by [`RngPlugin`] and only runs while this resource is present. It is
+/// available in debug builds, or in all builds with the `audit` feature enabled, and runs in
+/// [`PreUpdate`] after the [`RngSystems::ForkOnSpawn`] set, so components re-forked by
+/// [`ForkOnSpawn`] are never reported.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// App::new()
+///     .add_plugins(RngPlugin::new().with_rng_seed(12345))
+///     .insert_resource(RngAuditConfig {
+///         panic_on_unseeded: true,
+///     });
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Resource, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[reflect(Debug, Default, PartialEq, Resource)]
+pub struct RngAuditConfig {
+    /// Whether to panic when RNG components seeded from entropy are found, instead of
+    /// logging a warning.
+    pub panic_on_unseeded: bool,
+}
+
+/// How an RNG component was created, tracked for [`RngAuditConfig`].
+#[cfg(any(debug_assertions, feature = "audit"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Provenance {
+    /// Created from a seed, forked from another RNG, or restored from a saved state.
+    Seeded,
+    /// Created from entropy, and so not reproducible.
+    Entropy,
+}
+
+/// RNG components that track their [`Provenance`].
+#[cfg(any(debug_assertions, feature = "audit"))]
+pub(crate) trait AuditProvenance: Component {
+    const NAME: &'static str;
+
+    fn provenance(&self) -> Provenance;
+}
+
+/// Reports newly added `C` components that were seeded from entropy.
+#[cfg(any(debug_assertions, feature = "audit"))]
+pub(crate) fn audit_unseeded<C: AuditProvenance>(
+    config: Res<'_, RngAuditConfig>,
+    q_added: Query<'_, '_, (Entity, &C), Added<C>>,
+) {
+    let mut unseeded: Vec<Entity> = q_added
+        .iter()
+        .filter(|(_, rng)| rng.provenance() == Provenance::Entropy)
+        .map(|(entity, _)| entity)
+        .collect();
+
+    if unseeded.is_empty() {
+        return;
+    }
+
+    unseeded.sort_unstable();
+
+    let name = C::NAME;
+
+    if config.panic_on_unseeded {
+        panic!("{name}s seeded from entropy were added to entities: {unseeded:?}");
+    }
+
+    warn!("{name}s seeded from entropy were added to entities, breaking determinism: {unseeded:?}");
+}
@@ -0,0 +1,176 @@
+use crate::*;
+use bevy_ecs::query::{QueryData, QueryFilter};
+
+/// Extension methods for picking random entities out of a [`Query`], without collecting
+/// and indexing the entities by hand. Implemented for queries of [`Entity`], and of tuples
+/// starting with an [`Entity`].
+///
+/// The matching entities are sorted before picking, so that the same RNG state picks the
+/// same entities from the same set of entities, regardless of the order the query iterates
+/// them in, which depends on the order archetypes and tables were created and filled in.
+/// Use [`QueryRngExt::pick_random_unstable`] to skip sorting where that doesn't matter.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// fn pick_target(q_enemies: Query<Entity, With<Enemy>>, mut global: ResMut<GlobalRng>) {
+///     if let Some(target) = q_enemies.pick_random(&mut *global) {
+///         println!("Targeting {target}");
+///     }
+/// }
+/// ```
+pub trait QueryRngExt {
+    /// Returns a random entity matching the query, or `None` if no entity matches. The RNG
+    /// isn't advanced when no entity matches.
+    fn pick_random(&self, rng: &mut impl DelegatedRng) -> Option<Entity>;
+
+    /// Returns up to `n` distinct random entities matching the query, in the order they were
+    /// picked. Fewer entities are returned if fewer than `n` match.
+    fn pick_random_n(&self, rng: &mut impl DelegatedRng, n: usize) -> Vec<Entity>;
+
+    /// Same as [`QueryRngExt::pick_random`], but picks from the entities in the order the
+    /// query iterates them, so that the picked entity can differ between runs with the same
+    /// seed. Cheaper for large queries, as the entities aren't collected and sorted.
+    fn pick_random_unstable(&self, rng: &mut impl DelegatedRng) -> Option<Entity>;
+}
+
+impl<F: QueryFilter> QueryRngExt for Query<'_, '_, Entity, F> {
+    #[inline]
+    fn pick_random(&self, rng: &mut impl DelegatedRng) -> Option<Entity> {
+        pick_one(sorted_entities(self.iter()), rng)
+    }
+
+    #[inline]
+    fn pick_random_n(&self, rng: &mut impl DelegatedRng, n: usize) -> Vec<Entity> {
+        pick_many(sorted_entities(self.iter()), rng, n)
+    }
+
+    #[inline]
+    fn pick_random_unstable(&self, rng: &mut impl DelegatedRng) -> Option<Entity> {
+        pick_unstable(|| self.iter(), rng)
+    }
+}
+
+impl<D: QueryData, F: QueryFilter> QueryRngExt for Query<'_, '_, (Entity, D), F> {
+    #[inline]
+    fn pick_random(&self, rng: &mut impl DelegatedRng) -> Option<Entity> {
+        pick_one(sorted_entities(self.iter().map(|(entity, _)| entity)), rng)
+    }
+
+    #[inline]
+    fn pick_random_n(&self, rng: &mut impl DelegatedRng, n: usize) -> Vec<Entity> {
+        pick_many(
+            sorted_entities(self.iter().map(|(entity, _)| entity)),
+            rng,
+            n,
+        )
+    }
+
+    #[inline]
+    fn pick_random_unstable(&self, rng: &mut impl DelegatedRng) -> Option<Entity> {
+        pick_unstable(|| self.iter().map(|(entity, _)| entity), rng)
+    }
+}
+
+fn sorted_entities(entities: impl Iterator<Item = Entity>) -> Vec<Entity> {
+    let mut entities: Vec<Entity> = entities.collect();
+
+    entities.sort_unstable();
+
+    entities
+}
+
+fn pick_one(entities: Vec<Entity>, rng: &mut impl DelegatedRng) -> Option<Entity> {
+    if entities.is_empty() {
+        return None;
+    }
+
+    Some(entities[rng.index(..entities.len())])
+}
+
+fn pick_many(mut entities: Vec<Entity>, rng: &mut impl DelegatedRng, n: usize) -> Vec<Entity> {
+    let picked = rng.shuffle_prefix(&mut entities, n).0.len();
+
+    entities.truncate(picked);
+
+    entities
+}
+
+fn pick_unstable<I: Iterator<Item = Entity>>(
+    entities: impl Fn() -> I,
+    rng: &mut impl DelegatedRng,
+) -> Option<Entity> {
+    // Counting first avoids collecting, at the cost of iterating the query twice.
+    let len = entities().count();
+
+    if len == 0 {
+        return None;
+    }
+
+    entities().nth(rng.index(..len))
+}
+
+/// Returns a random entity out of `query`, with a probability proportional to the weight
+/// `weight` gives its `C` component, such as picking an enemy weighted by its aggro. Returns
+/// `None` if no entity has a weight greater than zero, in which case the RNG isn't advanced.
+/// Negative, infinite and NaN weights count as zero.
+///
+/// Like [`QueryRngExt::pick_random`], the entities are sorted before picking, so that the
+/// same RNG state picks the same entity regardless of the order the query iterates them in.
+/// The pick is then made in a single pass of weighted reservoir sampling, without collecting
+/// the weights.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Aggro(f64);
+///
+/// fn pick_attacker(q_enemies: Query<(Entity, &Aggro)>, mut global: ResMut<GlobalRng>) {
+///     if let Some(attacker) = pick_weighted_entity(&q_enemies, &mut *global, |aggro| aggro.0) {
+///         println!("{attacker} attacks");
+///     }
+/// }
+/// ```
+pub fn pick_weighted_entity<C, F, W>(
+    query: &Query<'_, '_, (Entity, &C), F>,
+    rng: &mut impl DelegatedRng,
+    weight: W,
+) -> Option<Entity>
+where
+    C: Component,
+    F: QueryFilter,
+    W: Fn(&C) -> f64,
+{
+    let mut entities: Vec<(Entity, &C)> = query.iter().collect();
+
+    entities.sort_unstable_by_key(|&(entity, _)| entity);
+
+    let mut picked = None;
+    let mut total = 0.0;
+
+    for (entity, component) in entities {
+        let weight = weight(component);
+
+        if !(weight > 0.0 && weight.is_finite()) {
+            continue;
+        }
+
+        total += weight;
+
+        // Replacing the pick with a probability of its share of the running total leaves
+        // each entity picked in proportion to its weight once all have been seen.
+        if picked.is_none() || rng.f64() * total < weight {
+            picked = Some(entity);
+        }
+    }
+
+    picked
+}
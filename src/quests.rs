@@ -0,0 +1,385 @@
+use crate::*;
+use std::fmt::{self, Debug};
+
+/// An entry in the pool of a [`QuestRoller`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(bound(deserialize = "Id: Deserialize<'de>"))
+)]
+pub struct QuestEntry<Id> {
+    /// The identifier of the entry, returned when it is rolled.
+    pub id: Id,
+    /// The relative weight of the entry being picked directly. Entries with a weight of `0.0`
+    /// or less are never picked directly, but can still be pulled in as a requirement.
+    pub weight: f64,
+    /// The entries that are pulled in alongside this entry whenever it is rolled.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub requires: Vec<Id>,
+    /// The entries that can't be rolled alongside this entry. Exclusions go both ways.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub excludes: Vec<Id>,
+}
+
+impl<Id> QuestEntry<Id> {
+    /// Creates a new [`QuestEntry`] without requirements or exclusions.
+    #[inline]
+    #[must_use]
+    pub const fn new(id: Id, weight: f64) -> Self {
+        Self {
+            id,
+            weight,
+            requires: Vec::new(),
+            excludes: Vec::new(),
+        }
+    }
+
+    /// Builder function to add an entry required by this entry.
+    #[inline]
+    #[must_use]
+    pub fn with_requirement(mut self, id: Id) -> Self {
+        self.requires.push(id);
+        self
+    }
+
+    /// Builder function to add an entry excluded by this entry.
+    #[inline]
+    #[must_use]
+    pub fn with_exclusion(mut self, id: Id) -> Self {
+        self.excludes.push(id);
+        self
+    }
+}
+
+/// The error returned when constructing a [`QuestRoller`] from an invalid set of entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuestGraphError<Id> {
+    /// More than one entry has the same identifier.
+    DuplicateId(Id),
+    /// An entry requires or excludes an identifier that no entry has.
+    DanglingReference {
+        /// The entry making the reference.
+        entry: Id,
+        /// The identifier that no entry has.
+        missing: Id,
+    },
+    /// The requirements of some entries form a cycle, listed in requirement order and
+    /// starting and ending with the same entry.
+    Cycle(Vec<Id>),
+}
+
+impl<Id: Debug> fmt::Display for QuestGraphError<Id> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateId(id) => write!(f, "quest entry {id:?} is defined more than once"),
+            Self::DanglingReference { entry, missing } => {
+                write!(
+                    f,
+                    "quest entry {entry:?} refers to unknown entry {missing:?}"
+                )
+            }
+            Self::Cycle(cycle) => write!(f, "quest requirements form a cycle: {cycle:?}"),
+        }
+    }
+}
+
+impl<Id: Debug> std::error::Error for QuestGraphError<Id> {}
+
+/// The reason a [`QuestRoll`] has fewer entries than were asked for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub enum QuestShortfall {
+    /// Every entry that could be picked directly was already rolled or excluded.
+    PoolExhausted,
+    /// Entries remain, but each one would pull in more requirements than the remaining
+    /// count allows, or requirements that are excluded.
+    RequirementsDontFit,
+}
+
+/// The outcome of [`QuestRoller::roll`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct QuestRoll<Id> {
+    /// The rolled entries, in the order they were rolled, with requirements always coming
+    /// before the entries requiring them.
+    pub entries: Vec<Id>,
+    /// Why fewer entries were rolled than were asked for, if they were.
+    pub shortfall: Option<QuestShortfall>,
+}
+
+/// A weighted roller for picking a set of objectives, such as daily quests, from a pool whose
+/// entries can require or exclude each other.
+///
+/// Entries are picked one at a time by weight. Picking an entry also pulls in everything it
+/// transitively requires, which counts towards the requested amount, and rules out everything
+/// excluded by the rolled entries. Entries are only considered if their requirements fit in
+/// the remaining amount and don't conflict with the entries rolled so far. Should no entry be
+/// left to pick, the roll ends early and reports a [`QuestShortfall`].
+///
+/// The entries are validated on construction, rejecting duplicate identifiers, references to
+/// unknown entries and requirement cycles. With the `serialize` feature, rollers are
+/// (de)serialized as a plain list of entries, and validated the same way when deserialized.
+/// Rolls are deterministic for a given RNG state and roller.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// let roller = QuestRoller::new(vec![
+///     QuestEntry::new("gather_wood", 1.0),
+///     QuestEntry::new("build_hut", 2.0).with_requirement("gather_wood"),
+///     QuestEntry::new("hunt", 1.0).with_exclusion("fish"),
+///     QuestEntry::new("fish", 1.0),
+/// ])
+/// .unwrap();
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let roll = roller.roll(3, &mut rng);
+///
+/// assert_eq!(roll.entries.len(), 3);
+/// assert!(!(roll.entries.contains(&"hunt") && roll.entries.contains(&"fish")));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(
+        try_from = "Vec<QuestEntry<Id>>",
+        into = "Vec<QuestEntry<Id>>",
+        bound(
+            serialize = "Id: Serialize + Clone",
+            deserialize = "Id: Deserialize<'de> + Clone + PartialEq + Debug"
+        )
+    )
+)]
+pub struct QuestRoller<Id> {
+    entries: Vec<QuestEntry<Id>>,
+    /// The transitive requirements of each entry, requirements first and ending with the
+    /// entry itself.
+    closures: Vec<Vec<usize>>,
+    /// The entries each entry can't be rolled with, in both directions.
+    conflicts: Vec<Vec<usize>>,
+}
+
+impl<Id: Clone + PartialEq> QuestRoller<Id> {
+    /// Creates a new [`QuestRoller`] from its entries, validating the references between them.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`QuestGraphError`] if an identifier is used by more than one entry, if an
+    /// entry refers to an identifier no entry has, or if the requirements form a cycle.
+    pub fn new(entries: Vec<QuestEntry<Id>>) -> Result<Self, QuestGraphError<Id>> {
+        for (index, entry) in entries.iter().enumerate() {
+            if entries[..index].iter().any(|other| other.id == entry.id) {
+                return Err(QuestGraphError::DuplicateId(entry.id.clone()));
+            }
+        }
+
+        let resolve = |entry: &QuestEntry<Id>, ids: &[Id]| {
+            ids.iter()
+                .map(|id| {
+                    entries
+                        .iter()
+                        .position(|other| other.id == *id)
+                        .ok_or_else(|| QuestGraphError::DanglingReference {
+                            entry: entry.id.clone(),
+                            missing: id.clone(),
+                        })
+                })
+                .collect::<Result<Vec<usize>, _>>()
+        };
+
+        let mut requires = Vec::with_capacity(entries.len());
+        let mut conflicts = vec![Vec::new(); entries.len()];
+
+        for (index, entry) in entries.iter().enumerate() {
+            requires.push(resolve(entry, &entry.requires)?);
+
+            for excluded in resolve(entry, &entry.excludes)? {
+                conflicts[index].push(excluded);
+                conflicts[excluded].push(index);
+            }
+        }
+
+        let mut closures = vec![Vec::new(); entries.len()];
+        let mut visited = vec![Visit::Unvisited; entries.len()];
+
+        for index in 0..entries.len() {
+            let mut path = Vec::new();
+
+            collect_closure(index, &requires, &mut visited, &mut path, &mut closures).map_err(
+                |cycle| {
+                    QuestGraphError::Cycle(cycle.iter().map(|&i| entries[i].id.clone()).collect())
+                },
+            )?;
+        }
+
+        Ok(Self {
+            entries,
+            closures,
+            conflicts,
+        })
+    }
+
+    /// Returns the entries of the roller.
+    #[inline]
+    #[must_use]
+    pub fn entries(&self) -> &[QuestEntry<Id>] {
+        &self.entries
+    }
+
+    /// Rolls up to `count` entries, pulling in the requirements of each picked entry and
+    /// ruling out the entries they exclude.
+    pub fn roll(&self, count: usize, rng: &mut impl DelegatedRng) -> QuestRoll<Id> {
+        let mut rolled: Vec<usize> = Vec::with_capacity(count);
+        let mut taken = vec![false; self.entries.len()];
+        let mut banned = vec![false; self.entries.len()];
+        let mut shortfall = None;
+
+        while rolled.len() < count {
+            let remaining = count - rolled.len();
+            let mut any_left = false;
+
+            let candidates: Vec<(usize, Vec<usize>)> = self
+                .entries
+                .iter()
+                .enumerate()
+                .filter(|&(index, entry)| {
+                    !taken[index]
+                        && !banned[index]
+                        && entry.weight > 0.0
+                        && entry.weight.is_finite()
+                })
+                .filter_map(|(index, _)| {
+                    any_left = true;
+
+                    let pulled: Vec<usize> = self.closures[index]
+                        .iter()
+                        .copied()
+                        .filter(|&required| !taken[required])
+                        .collect();
+
+                    let fits = pulled.len() <= remaining
+                        && pulled.iter().all(|&required| {
+                            !banned[required]
+                                && !self.conflicts[required]
+                                    .iter()
+                                    .any(|other| pulled.contains(other))
+                        });
+
+                    fits.then_some((index, pulled))
+                })
+                .collect();
+
+            if candidates.is_empty() {
+                shortfall = Some(if any_left {
+                    QuestShortfall::RequirementsDontFit
+                } else {
+                    QuestShortfall::PoolExhausted
+                });
+                break;
+            }
+
+            let total: f64 = candidates
+                .iter()
+                .map(|&(index, _)| self.entries[index].weight)
+                .sum();
+            let mut target = rng.get_mut().f64() * total;
+
+            // Falls back to the last candidate should rounding leave the target past the total.
+            let picked = candidates
+                .iter()
+                .position(|&(index, _)| {
+                    target -= self.entries[index].weight;
+                    target < 0.0
+                })
+                .unwrap_or(candidates.len() - 1);
+
+            for &index in &candidates[picked].1 {
+                taken[index] = true;
+                rolled.push(index);
+
+                for &excluded in &self.conflicts[index] {
+                    banned[excluded] = true;
+                }
+            }
+        }
+
+        QuestRoll {
+            entries: rolled
+                .into_iter()
+                .map(|index| self.entries[index].id.clone())
+                .collect(),
+            shortfall,
+        }
+    }
+}
+
+impl<Id: Clone + PartialEq> TryFrom<Vec<QuestEntry<Id>>> for QuestRoller<Id> {
+    type Error = QuestGraphError<Id>;
+
+    #[inline]
+    fn try_from(entries: Vec<QuestEntry<Id>>) -> Result<Self, Self::Error> {
+        Self::new(entries)
+    }
+}
+
+impl<Id> From<QuestRoller<Id>> for Vec<QuestEntry<Id>> {
+    #[inline]
+    fn from(roller: QuestRoller<Id>) -> Self {
+        roller.entries
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    Unvisited,
+    InProgress,
+    Done,
+}
+
+/// Depth-first walk over the requirements, filling in the closure of every entry visited.
+/// Returns the cycle as a list of indices should one be found.
+fn collect_closure(
+    index: usize,
+    requires: &[Vec<usize>],
+    visited: &mut [Visit],
+    path: &mut Vec<usize>,
+    closures: &mut [Vec<usize>],
+) -> Result<(), Vec<usize>> {
+    match visited[index] {
+        Visit::Done => return Ok(()),
+        Visit::InProgress => {
+            let start = path.iter().position(|&i| i == index).unwrap_or(0);
+            let mut cycle = path[start..].to_vec();
+            cycle.push(index);
+            return Err(cycle);
+        }
+        Visit::Unvisited => {}
+    }
+
+    visited[index] = Visit::InProgress;
+    path.push(index);
+
+    let mut closure = Vec::new();
+
+    for &required in &requires[index] {
+        collect_closure(required, requires, visited, path, closures)?;
+
+        for &pulled in &closures[required] {
+            if !closure.contains(&pulled) {
+                closure.push(pulled);
+            }
+        }
+    }
+
+    closure.push(index);
+    closures[index] = closure;
+
+    path.pop();
+    visited[index] = Visit::Done;
+
+    Ok(())
+}
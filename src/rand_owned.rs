@@ -0,0 +1,99 @@
+use crate::*;
+use rand_core::{Error, RngCore};
+
+#[cfg(feature = "wyrand")]
+use rand_core::SeedableRng;
+
+/// An owned compatibility shim for working with crates from the `rand` ecosystem. Unlike
+/// [`RandBorrowed`](turborand::prelude::RandBorrowed), it holds its own source, so it can be
+/// stored in structs or passed to APIs that take the RNG by value, such as
+/// `Distribution::sample_iter`.
+///
+/// Usually obtained with [`DelegatedRng::fork_as_rand`], which forks a new source so that the
+/// original RNG is only advanced by the fork itself.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+/// use rand_core::RngCore;
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let mut owned = rng.fork_as_rand();
+/// let mut expected = RngComponent::with_seed(5).fork_as_rand();
+///
+/// assert_eq!(owned.next_u64(), expected.next_u64());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+#[repr(transparent)]
+pub struct RandOwned<T: TurboCore + GenCore>(T);
+
+impl<T: TurboCore + GenCore> RandOwned<T> {
+    /// Consumes the [`RandOwned`], returning the wrapped source with its state intact.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: TurboCore + GenCore> From<T> for RandOwned<T> {
+    /// Wraps an existing source as is, taking ownership of it without reseeding or forking.
+    #[inline]
+    fn from(rng: T) -> Self {
+        Self(rng)
+    }
+}
+
+impl<T: TurboCore + GenCore> RngCore for RandOwned<T> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.gen_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.gen_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.0.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "wyrand")]
+impl SeedableRng for RandOwned<Rng> {
+    /// The seed of an [`Rng`], as little-endian bytes.
+    type Seed = [u8; 8];
+
+    /// Creates a new [`RandOwned`] with an [`Rng`] seeded from the little-endian `seed`
+    /// bytes, matching [`Rng::with_seed`].
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    /// use rand_core::{RngCore, SeedableRng};
+    ///
+    /// let mut owned = RandOwned::<Rng>::from_seed(5u64.to_le_bytes());
+    /// let rng = Rng::with_seed(5);
+    ///
+    /// assert_eq!(owned.next_u64(), rng.gen_u64());
+    /// ```
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self(Rng::with_seed(u64::from_le_bytes(seed)))
+    }
+
+    #[inline]
+    fn seed_from_u64(state: u64) -> Self {
+        Self(Rng::with_seed(state))
+    }
+}
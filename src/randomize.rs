@@ -0,0 +1,146 @@
+use crate::{hash::mix, *};
+use bevy_ecs::query::QueryFilter;
+use std::marker::PhantomData;
+
+/// Derives an [`Rng`] for an entity from a key, without depending on the
+/// order in which entities are visited.
+#[inline]
+pub(crate) fn entity_stream(key: u64, entity: Entity) -> Rng {
+    Rng::with_seed(mix(key, entity.to_bits()))
+}
+
+/// Applies `f` to every `C` component in the query, passing in the [`Rng`] of the
+/// [`RngComponent`] on the same entity. Each entity only ever draws from its own
+/// [`RngComponent`], so the result is deterministic regardless of query iteration order.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Speed(f32);
+///
+/// fn jitter_speed(mut q_speed: Query<(&mut Speed, &mut RngComponent)>) {
+///     randomize_field(&mut q_speed, |speed, rng| speed.0 += rng.f32_normalized() * 0.1);
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub fn randomize_field<C, Filter, F>(
+    query: &mut Query<'_, '_, (&mut C, &mut RngComponent), Filter>,
+    f: F,
+) where
+    C: Component,
+    Filter: QueryFilter,
+    F: Fn(&mut C, &mut Rng),
+{
+    for (mut component, mut rng) in query.iter_mut() {
+        f(&mut component, rng.get_mut());
+    }
+}
+
+/// Applies `f` to every `C` component in the query, for entities that have no
+/// [`RngComponent`] of their own. A single value is drawn from the [`GlobalRng`],
+/// from which a stream is then derived per entity, keyed by its [`Entity`] id. The
+/// result is deterministic regardless of query iteration order, as long as the entity
+/// ids and the [`GlobalRng`] state are the same.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Speed(f32);
+///
+/// fn jitter_speed(mut q_speed: Query<(Entity, &mut Speed)>, mut global: ResMut<GlobalRng>) {
+///     randomize_field_keyed(&mut q_speed, &mut global, |speed, rng| {
+///         speed.0 += rng.f32_normalized() * 0.1
+///     });
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub fn randomize_field_keyed<C, Filter, F>(
+    query: &mut Query<'_, '_, (Entity, &mut C), Filter>,
+    global: &mut GlobalRng,
+    f: F,
+) where
+    C: Component,
+    Filter: QueryFilter,
+    F: Fn(&mut C, &mut Rng),
+{
+    let key = global.get_mut().gen_u64();
+
+    for (entity, mut component) in query.iter_mut() {
+        f(&mut component, &mut entity_stream(key, entity));
+    }
+}
+
+/// A [`Command`] for applying `f` to every `C` component in the [`World`]. Entities
+/// with an [`RngComponent`] draw from it, like with [`randomize_field`], while all others
+/// derive a stream from the [`GlobalRng`], like with [`randomize_field_keyed`]. Thus all
+/// entities go through a single deterministic code path, whether they have their own
+/// [`RngComponent`] or not.
+///
+/// # Panics
+///
+/// Panics if the [`GlobalRng`] resource is not present in the [`World`] when applied.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Speed(f32);
+///
+/// fn jitter_speed(mut commands: Commands) {
+///     commands.queue(RandomizeField::new(|speed: &mut Speed, rng| {
+///         speed.0 += rng.f32_normalized() * 0.1
+///     }));
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub struct RandomizeField<C, F> {
+    f: F,
+    _component: PhantomData<fn(&mut C)>,
+}
+
+impl<C, F> RandomizeField<C, F>
+where
+    C: Component,
+    F: Fn(&mut C, &mut Rng) + Send + 'static,
+{
+    /// Create a new [`RandomizeField`] command, which will apply `f` to all `C` components.
+    #[inline]
+    #[must_use]
+    pub fn new(f: F) -> Self {
+        Self {
+            f,
+            _component: PhantomData,
+        }
+    }
+}
+
+impl<C, F> Command for RandomizeField<C, F>
+where
+    C: Component,
+    F: Fn(&mut C, &mut Rng) + Send + 'static,
+{
+    fn apply(self, world: &mut World) {
+        world.resource_scope(|world, mut global: Mut<'_, GlobalRng>| {
+            let mut seeded = world.query::<(&mut C, &mut RngComponent)>();
+
+            for (mut component, mut rng) in seeded.iter_mut(world) {
+                (self.f)(&mut component, rng.get_mut());
+            }
+
+            let key = global.get_mut().gen_u64();
+            let mut keyed = world.query_filtered::<(Entity, &mut C), Without<RngComponent>>();
+
+            for (entity, mut component) in keyed.iter_mut(world) {
+                (self.f)(&mut component, &mut entity_stream(key, entity));
+            }
+        });
+    }
+}
@@ -0,0 +1,166 @@
+use crate::*;
+use std::{
+    fmt::Debug,
+    ops::{Bound, RangeBounds},
+};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Types that can be sampled from a range with [`DelegatedRng::range`]. This trait is
+/// sealed, and is implemented for all integer types, `char`, `f32` and `f64`.
+pub trait SampleRange: sealed::Sealed + Sized + PartialOrd + Debug {
+    /// Samples a value from `bounds` with the given source.
+    #[doc(hidden)]
+    fn sample_range<R: TurboRand + ?Sized>(rng: &R, bounds: impl RangeBounds<Self>) -> Self;
+}
+
+macro_rules! impl_sample_range {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl SampleRange for $ty {
+                #[inline]
+                fn sample_range<R: TurboRand + ?Sized>(rng: &R, bounds: impl RangeBounds<Self>) -> Self {
+                    rng.$ty(bounds)
+                }
+            }
+        )*
+    };
+}
+
+impl_sample_range!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char);
+
+macro_rules! impl_sample_range_float {
+    ($ty:ident, $int:ident, $bits:literal) => {
+        impl sealed::Sealed for $ty {}
+
+        impl SampleRange for $ty {
+            /// Float ranges must be bounded on both ends, and respect whether each bound is
+            /// included. Excluded bounds are never returned, as values rounding onto them are
+            /// moved to the nearest float inside the range instead, while included bounds can
+            /// be returned exactly.
+            fn sample_range<R: TurboRand + ?Sized>(
+                rng: &R,
+                bounds: impl RangeBounds<Self>,
+            ) -> Self {
+                let (lower, lower_excluded) = match bounds.start_bound() {
+                    Bound::Included(&lower) => (lower, false),
+                    Bound::Excluded(&lower) => (lower, true),
+                    Bound::Unbounded => panic!("float ranges must have a lower bound"),
+                };
+
+                let value = match bounds.end_bound() {
+                    Bound::Included(&upper) => {
+                        assert!(
+                            lower <= upper && !(lower_excluded && lower == upper),
+                            "Range should not be zero sized or invalid"
+                        );
+
+                        // Sampling whole steps of the mantissa precision allows reaching
+                        // the upper bound exactly.
+                        const STEPS: $int = 1 << $bits;
+                        let t = rng.$int(0..=STEPS) as $ty / STEPS as $ty;
+
+                        (lower + (upper - lower) * t).min(upper)
+                    }
+                    Bound::Excluded(&upper) => {
+                        assert!(
+                            lower < upper && !(lower_excluded && lower.next_up() == upper),
+                            "Range should not be zero sized or invalid"
+                        );
+
+                        let value = lower + (upper - lower) * rng.$ty();
+
+                        // Rounding may land on the excluded upper bound for wide ranges.
+                        if value < upper {
+                            value
+                        } else {
+                            upper.next_down()
+                        }
+                    }
+                    Bound::Unbounded => panic!("float ranges must have an upper bound"),
+                };
+
+                if lower_excluded && value <= lower {
+                    lower.next_up()
+                } else {
+                    value
+                }
+            }
+        }
+    };
+}
+
+impl_sample_range_float!(f32, u32, 24);
+impl_sample_range_float!(f64, u64, 53);
+
+/// Panics with a message naming the wrapper `W` and the method if `bounds` is empty, such
+/// as `5..5` or `10..=5`. Ranges with a NaN bound count as empty. Only called in debug
+/// builds, as release builds leave the check to the source.
+#[cfg(debug_assertions)]
+#[track_caller]
+pub(crate) fn debug_check_range<W: ?Sized, T: PartialOrd + Debug>(
+    method: &str,
+    bounds: &impl RangeBounds<T>,
+) {
+    use std::cmp::Ordering;
+
+    let empty = match (bounds.start_bound(), bounds.end_bound()) {
+        (Bound::Included(start), Bound::Included(end)) => !matches!(
+            start.partial_cmp(end),
+            Some(Ordering::Less | Ordering::Equal)
+        ),
+        (Bound::Included(start) | Bound::Excluded(start), Bound::Excluded(end))
+        | (Bound::Excluded(start), Bound::Included(end)) => {
+            start.partial_cmp(end) != Some(Ordering::Less)
+        }
+        _ => false,
+    };
+
+    if empty {
+        panic!(
+            "{}::{method} called with empty range {}",
+            short_type_name(std::any::type_name::<W>()),
+            format_range(bounds)
+        );
+    }
+}
+
+/// Strips the module paths from a type name, such as
+/// `bevy_turborand::component::rng::RngComponent` into `RngComponent`, including those of
+/// any generic parameters.
+#[cfg(debug_assertions)]
+fn short_type_name(name: &str) -> String {
+    let last_segment = |path: &str| path.rsplit("::").next().unwrap_or(path).to_owned();
+
+    let mut short = String::with_capacity(name.len());
+    let mut path_start = 0;
+
+    for (index, character) in name.char_indices() {
+        if !(character.is_alphanumeric() || character == '_' || character == ':') {
+            short += &last_segment(&name[path_start..index]);
+            short.push(character);
+            path_start = index + character.len_utf8();
+        }
+    }
+
+    short + &last_segment(&name[path_start..])
+}
+
+/// Formats range bounds the way they are written, such as `5..5` or `'a'..='z'`.
+#[cfg(debug_assertions)]
+fn format_range<T: Debug>(bounds: &impl RangeBounds<T>) -> String {
+    let start = match bounds.start_bound() {
+        Bound::Included(start) | Bound::Excluded(start) => format!("{start:?}"),
+        Bound::Unbounded => String::new(),
+    };
+
+    match bounds.end_bound() {
+        Bound::Included(end) => format!("{start}..={end:?}"),
+        Bound::Excluded(end) => format!("{start}..{end:?}"),
+        Bound::Unbounded => format!("{start}.."),
+    }
+}
@@ -0,0 +1,215 @@
+use crate::*;
+use bevy_reflect::{FromType, TypeData, TypeRegistry};
+use std::fmt;
+
+/// Type data for reseeding an RNG wrapper with a `u64` seed through reflection alone, such as
+/// from an editor that only ever handles components as `dyn Reflect`. Registered by
+/// [`RngPlugin`] for [`RngComponent`] and [`GlobalRng`], and used by [`reseed_reflected`].
+///
+/// Reseeding leaves the RNG in the same state as its `with_seed` constructor would create it
+/// in, so an RNG reseeded with a given seed produces the same values as one created with it.
+#[cfg(feature = "wyrand")]
+#[derive(Clone, Copy)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub struct ReflectRngReseed {
+    reseed: fn(&mut dyn Reflect, u64),
+}
+
+#[cfg(feature = "wyrand")]
+impl ReflectRngReseed {
+    /// Reseeds `value` with `seed`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is not of the type the type data was created for.
+    #[inline]
+    pub fn reseed(&self, value: &mut dyn Reflect, seed: u64) {
+        (self.reseed)(value, seed);
+    }
+}
+
+#[cfg(feature = "wyrand")]
+impl fmt::Debug for ReflectRngReseed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReflectRngReseed").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "wyrand")]
+impl FromType<RngComponent> for ReflectRngReseed {
+    fn from_type() -> Self {
+        Self {
+            reseed: |value, seed| {
+                downcast::<RngComponent>(value).set_source(Rng::with_seed(seed));
+            },
+        }
+    }
+}
+
+#[cfg(feature = "wyrand")]
+impl FromType<GlobalRng> for ReflectRngReseed {
+    fn from_type() -> Self {
+        Self {
+            reseed: |value, seed| downcast::<GlobalRng>(value).reseed(seed),
+        }
+    }
+}
+
+/// Type data for reseeding a ChaCha RNG wrapper with a 40 byte seed through reflection
+/// alone, the same as [`ReflectRngReseed`] does for `u64` seeds. Registered by [`RngPlugin`]
+/// for [`ChaChaRngComponent`] and [`GlobalChaChaRng`], and used by
+/// [`reseed_reflected_chacha`].
+#[cfg(feature = "chacha")]
+#[derive(Clone, Copy)]
+#[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
+pub struct ReflectChaChaReseed {
+    reseed: fn(&mut dyn Reflect, [u8; 40]),
+}
+
+#[cfg(feature = "chacha")]
+impl ReflectChaChaReseed {
+    /// Reseeds `value` with `seed`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is not of the type the type data was created for.
+    #[inline]
+    pub fn reseed(&self, value: &mut dyn Reflect, seed: [u8; 40]) {
+        (self.reseed)(value, seed);
+    }
+}
+
+#[cfg(feature = "chacha")]
+impl fmt::Debug for ReflectChaChaReseed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReflectChaChaReseed")
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "chacha")]
+impl FromType<ChaChaRngComponent> for ReflectChaChaReseed {
+    fn from_type() -> Self {
+        Self {
+            reseed: |value, seed| downcast::<ChaChaRngComponent>(value).reseed(seed),
+        }
+    }
+}
+
+#[cfg(feature = "chacha")]
+impl FromType<GlobalChaChaRng> for ReflectChaChaReseed {
+    fn from_type() -> Self {
+        Self {
+            reseed: |value, seed| downcast::<GlobalChaChaRng>(value).reseed(seed),
+        }
+    }
+}
+
+/// The error returned by [`reseed_reflected`] and [`reseed_reflected_chacha`] when there is
+/// nothing to reseed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReflectReseedError {
+    /// The entity has no component registered with the reseeding type data.
+    NoRngComponent(Entity),
+}
+
+impl fmt::Display for ReflectReseedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoRngComponent(entity) => {
+                write!(f, "{entity} has no RNG component registered for reseeding")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReflectReseedError {}
+
+/// Reseeds every component of the entity registered with [`ReflectRngReseed`] type data, such
+/// as its [`RngComponent`], without needing to know the concrete component types. Reseeded
+/// components are flagged as changed.
+///
+/// # Errors
+///
+/// Returns [`ReflectReseedError::NoRngComponent`] if no component of the entity is
+/// registered with [`ReflectRngReseed`] type data.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins(RngPlugin::new().with_rng_seed(5));
+///
+/// let entity = app.world_mut().spawn(RngComponent::new()).id();
+///
+/// let registry = app.world().resource::<AppTypeRegistry>().clone();
+/// let mut entity = app.world_mut().entity_mut(entity);
+///
+/// reseed_reflected(&mut entity, &registry.read(), 42).unwrap();
+///
+/// assert_eq!(*entity.get::<RngComponent>().unwrap(), RngComponent::with_seed(42));
+/// ```
+#[cfg(feature = "wyrand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub fn reseed_reflected(
+    entity: &mut EntityWorldMut<'_>,
+    registry: &TypeRegistry,
+    seed: u64,
+) -> Result<(), ReflectReseedError> {
+    reseed_components::<ReflectRngReseed>(entity, registry, |data, value| {
+        data.reseed(value, seed);
+    })
+}
+
+/// Reseeds every component of the entity registered with [`ReflectChaChaReseed`] type data,
+/// such as its [`ChaChaRngComponent`], the same as [`reseed_reflected`] does for `u64` seeds.
+///
+/// # Errors
+///
+/// Returns [`ReflectReseedError::NoRngComponent`] if no component of the entity is
+/// registered with [`ReflectChaChaReseed`] type data.
+#[cfg(feature = "chacha")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
+pub fn reseed_reflected_chacha(
+    entity: &mut EntityWorldMut<'_>,
+    registry: &TypeRegistry,
+    seed: [u8; 40],
+) -> Result<(), ReflectReseedError> {
+    reseed_components::<ReflectChaChaReseed>(entity, registry, |data, value| {
+        data.reseed(value, seed);
+    })
+}
+
+fn reseed_components<D: TypeData>(
+    entity: &mut EntityWorldMut<'_>,
+    registry: &TypeRegistry,
+    reseed: impl Fn(&D, &mut dyn Reflect),
+) -> Result<(), ReflectReseedError> {
+    let mut reseeded = false;
+
+    for (registration, data) in registry.iter_with_data::<D>() {
+        let Some(component) = registration.data::<ReflectComponent>() else {
+            continue;
+        };
+
+        if let Some(mut value) = component.reflect_mut(&mut *entity) {
+            reseed(data, &mut *value);
+            reseeded = true;
+        }
+    }
+
+    if reseeded {
+        Ok(())
+    } else {
+        Err(ReflectReseedError::NoRngComponent(entity.id()))
+    }
+}
+
+fn downcast<T: Reflect>(value: &mut dyn Reflect) -> &mut T {
+    value
+        .downcast_mut()
+        .expect("reflected value is not the type its reseed type data was registered for")
+}
@@ -0,0 +1,297 @@
+use crate::*;
+use bevy_reflect::{DynamicEnum, DynamicVariant, Enum, ReflectMut, TupleStruct, VariantType};
+
+/// A custom reflection attribute for excluding a field from [`randomize_reflect`].
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Reflect)]
+/// struct Enemy {
+///     speed: f32,
+///     #[reflect(@NoRandomize)]
+///     id: u32,
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub struct NoRandomize;
+
+/// Options for [`randomize_reflect`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RandomizeOptions {
+    /// How much numeric fields are jittered by, as a fraction of their current value. A
+    /// jitter of `0.1` moves a value by up to ±10%. Integers are rounded to the nearest
+    /// whole step, so small integers may not move at all. Defaults to `0.1`.
+    pub jitter: f64,
+    /// The chance of a `bool` field being flipped. Defaults to `0.5`.
+    pub flip_chance: f64,
+    /// The paths of fields to leave untouched, with nested fields separated by dots, such
+    /// as `"stats.speed"`.
+    pub skip: Vec<String>,
+    /// How many levels of nested structs to recurse into. Fields of structs nested any
+    /// deeper are skipped. Defaults to `4`.
+    pub max_depth: usize,
+}
+
+impl RandomizeOptions {
+    /// Builder function to set the jitter of numeric fields.
+    #[inline]
+    #[must_use]
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Builder function to set the chance of `bool` fields being flipped.
+    #[inline]
+    #[must_use]
+    pub fn with_flip_chance(mut self, flip_chance: f64) -> Self {
+        self.flip_chance = flip_chance;
+        self
+    }
+
+    /// Builder function to skip the field at the given path.
+    #[inline]
+    #[must_use]
+    pub fn with_skipped(mut self, path: impl Into<String>) -> Self {
+        self.skip.push(path.into());
+        self
+    }
+
+    /// Builder function to set how many levels of nested structs to recurse into.
+    #[inline]
+    #[must_use]
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+}
+
+impl Default for RandomizeOptions {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            jitter: 0.1,
+            flip_chance: 0.5,
+            skip: Vec::new(),
+            max_depth: 4,
+        }
+    }
+}
+
+/// The outcome of [`randomize_reflect`], listing the paths of the visited fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RandomizeSummary {
+    /// Fields that were randomized.
+    pub randomized: Vec<String>,
+    /// Fields that were skipped, either by [`RandomizeOptions`], by being marked with
+    /// [`NoRandomize`], or by being nested too deep.
+    pub skipped: Vec<String>,
+    /// Fields of a kind that can't be randomized, such as lists, maps or strings.
+    pub unsupported: Vec<String>,
+}
+
+/// Randomizes the fields of any reflected struct, such as for an editor's "randomize"
+/// button. Numeric fields are jittered by a fraction of their value, `bool` fields are
+/// flipped with a given chance, and fieldless enums are re-rolled among their fieldless
+/// variants. Nested structs and tuple structs are recursed into, up to
+/// [`RandomizeOptions::max_depth`].
+///
+/// Fields are visited in declaration order, so the result is deterministic for a given
+/// RNG state. Fields can be excluded via [`RandomizeOptions::skip`], or by marking them
+/// with [`NoRandomize`]. Fields of any other kind are left untouched and listed as
+/// unsupported in the returned [`RandomizeSummary`].
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Reflect, Default)]
+/// struct Enemy {
+///     speed: f32,
+///     aggressive: bool,
+///     #[reflect(@NoRandomize)]
+///     id: u32,
+///     name: String,
+/// }
+///
+/// let mut enemy = Enemy { speed: 5.0, ..default() };
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let summary = randomize_reflect(&mut enemy, &mut rng, &RandomizeOptions::default());
+///
+/// assert_eq!(summary.randomized, ["speed", "aggressive"]);
+/// assert_eq!(summary.skipped, ["id"]);
+/// assert_eq!(summary.unsupported, ["name"]);
+/// ```
+pub fn randomize_reflect(
+    value: &mut dyn Struct,
+    rng: &mut impl DelegatedRng,
+    options: &RandomizeOptions,
+) -> RandomizeSummary {
+    let mut summary = RandomizeSummary::default();
+
+    randomize_struct(value, "", 0, rng, options, &mut summary);
+
+    summary
+}
+
+fn field_path(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{prefix}.{name}")
+    }
+}
+
+fn randomize_struct(
+    value: &mut dyn Struct,
+    prefix: &str,
+    depth: usize,
+    rng: &mut impl DelegatedRng,
+    options: &RandomizeOptions,
+    summary: &mut RandomizeSummary,
+) {
+    let info = value.get_represented_struct_info();
+
+    for index in 0..value.field_len() {
+        let path = field_path(prefix, value.name_at(index).unwrap_or_default());
+        let marked = info
+            .and_then(|info| info.field_at(index))
+            .is_some_and(|field| field.custom_attributes().contains::<NoRandomize>());
+
+        if let Some(field) = value.field_at_mut(index) {
+            randomize_field_value(field, path, marked, depth, rng, options, summary);
+        }
+    }
+}
+
+fn randomize_tuple_struct(
+    value: &mut dyn TupleStruct,
+    prefix: &str,
+    depth: usize,
+    rng: &mut impl DelegatedRng,
+    options: &RandomizeOptions,
+    summary: &mut RandomizeSummary,
+) {
+    let info = value.get_represented_tuple_struct_info();
+
+    for index in 0..value.field_len() {
+        let path = field_path(prefix, &index.to_string());
+        let marked = info
+            .and_then(|info| info.field_at(index))
+            .is_some_and(|field| field.custom_attributes().contains::<NoRandomize>());
+
+        if let Some(field) = value.field_mut(index) {
+            randomize_field_value(field, path, marked, depth, rng, options, summary);
+        }
+    }
+}
+
+fn randomize_field_value(
+    field: &mut dyn PartialReflect,
+    path: String,
+    marked: bool,
+    depth: usize,
+    rng: &mut impl DelegatedRng,
+    options: &RandomizeOptions,
+    summary: &mut RandomizeSummary,
+) {
+    if marked || options.skip.contains(&path) {
+        summary.skipped.push(path);
+        return;
+    }
+
+    if jitter_numeric(field, options.jitter, rng) {
+        summary.randomized.push(path);
+        return;
+    }
+
+    if let Some(flag) = field.try_downcast_mut::<bool>() {
+        if rng.chance(options.flip_chance) {
+            *flag = !*flag;
+        }
+        summary.randomized.push(path);
+        return;
+    }
+
+    match field.reflect_mut() {
+        ReflectMut::Struct(_) | ReflectMut::TupleStruct(_) if depth >= options.max_depth => {
+            summary.skipped.push(path);
+        }
+        ReflectMut::Struct(nested) => {
+            randomize_struct(nested, &path, depth + 1, rng, options, summary);
+        }
+        ReflectMut::TupleStruct(nested) => {
+            randomize_tuple_struct(nested, &path, depth + 1, rng, options, summary);
+        }
+        ReflectMut::Enum(value) => {
+            if reroll_enum(value, rng) {
+                summary.randomized.push(path);
+            } else {
+                summary.unsupported.push(path);
+            }
+        }
+        _ => summary.unsupported.push(path),
+    }
+}
+
+/// Jitters the value if it is of a numeric type, returning whether it was.
+fn jitter_numeric(
+    field: &mut dyn PartialReflect,
+    jitter: f64,
+    rng: &mut impl DelegatedRng,
+) -> bool {
+    macro_rules! jitter_float {
+        ($($ty:ty),*) => {$(
+            if let Some(value) = field.try_downcast_mut::<$ty>() {
+                *value += (f64::from(*value) * jitter * rng.f64_normalized()) as $ty;
+                return true;
+            }
+        )*};
+    }
+
+    macro_rules! jitter_int {
+        ($($ty:ty),*) => {$(
+            if let Some(value) = field.try_downcast_mut::<$ty>() {
+                let current = *value as i128;
+                let delta = (current.unsigned_abs() as f64 * jitter.abs()).round() as i128;
+                let jittered = current + rng.i128(-delta..=delta);
+
+                *value = jittered.clamp(<$ty>::MIN as i128, <$ty>::MAX as i128) as $ty;
+                return true;
+            }
+        )*};
+    }
+
+    jitter_float!(f32, f64);
+    jitter_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+    false
+}
+
+/// Re-rolls a fieldless enum among its fieldless variants, returning whether it could.
+fn reroll_enum(value: &mut dyn Enum, rng: &mut impl DelegatedRng) -> bool {
+    if value.variant_type() != VariantType::Unit {
+        return false;
+    }
+
+    let Some(info) = value.get_represented_enum_info() else {
+        return false;
+    };
+
+    let variants: Vec<&str> = info
+        .iter()
+        .filter(|variant| variant.variant_type() == VariantType::Unit)
+        .map(|variant| variant.name())
+        .collect();
+
+    let picked = variants[rng.index(..variants.len())];
+
+    value
+        .try_apply(&DynamicEnum::new(picked, DynamicVariant::Unit))
+        .is_ok()
+}
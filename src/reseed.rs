@@ -0,0 +1,127 @@
+use crate::*;
+use bevy_state::prelude::{OnEnter, States};
+use tracing::error;
+
+/// Where [`RngPlugin::reseed_on_enter`] takes the seed for reseeding the [`GlobalRng`] from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy_state")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub enum SeedSource {
+    /// Reseeds with the given seed, the same as [`GlobalRng::with_seed`] would.
+    Fixed(u64),
+    /// Reseeds with the seed of the [`NextWorldSeed`] resource at the time the state is
+    /// entered, such as one chosen on a menu. Reseeding is skipped with an error logged if
+    /// the resource is missing.
+    NextWorldSeed,
+    /// Reseeds with fresh entropy, as [`GlobalRng::reseed_from_entropy`] does.
+    Entropy,
+}
+
+/// The seed to reseed the [`GlobalRng`] with on entering a state, for
+/// [`SeedSource::NextWorldSeed`]. Meant to be inserted by the game once the player has
+/// chosen or been given a world seed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Resource, Reflect)]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy_state")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, PartialEq, Hash, Resource, Serialize, Deserialize)
+)]
+#[cfg_attr(not(feature = "serialize"), reflect(Debug, PartialEq, Hash, Resource))]
+pub struct NextWorldSeed(pub u64);
+
+/// A marker [`Component`] for re-forking an entity's [`RngComponent`] from the [`GlobalRng`]
+/// whenever it is reseeded by [`RngPlugin::reseed_on_enter`], so that gameplay RNGs are
+/// reset along with it. RNGs without the marker, such as those for cosmetic effects on a
+/// menu, are left untouched. Marked components are re-forked in [`Entity`] order, so that
+/// the result is deterministic regardless of query iteration order.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn spawn_enemy(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+///     commands.spawn((RngComponent::from(&mut global), ReseedOnState));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Component, Reflect)]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy_state")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[reflect(Debug, Default, PartialEq, Component)]
+pub struct ReseedOnState;
+
+/// Registers the reseeding of a [`RngPlugin::reseed_on_enter`] call onto an [`App`].
+pub(crate) type StateReseed = Box<dyn Fn(&mut App) + Send + Sync>;
+
+/// Returns the registration of a system reseeding the [`GlobalRng`], or the
+/// [`StrictGlobalRng`] in strict mode, whenever `state` is entered.
+pub(crate) fn reseed_on_enter<S: States>(state: S, source: SeedSource) -> StateReseed {
+    Box::new(move |app: &mut App| {
+        app.add_systems(
+            OnEnter(state.clone()),
+            move |global: Option<ResMut<'_, GlobalRng>>,
+                  strict: Option<ResMut<'_, StrictGlobalRng>>,
+                  next: Option<Res<'_, NextWorldSeed>>,
+                  q_rngs: Query<'_, '_, (Entity, &mut RngComponent), With<ReseedOnState>>| {
+                reseed_global(source, global, strict, next, q_rngs);
+            },
+        );
+    })
+}
+
+fn reseed_global(
+    source: SeedSource,
+    global: Option<ResMut<'_, GlobalRng>>,
+    strict: Option<ResMut<'_, StrictGlobalRng>>,
+    next: Option<Res<'_, NextWorldSeed>>,
+    q_rngs: Query<'_, '_, (Entity, &mut RngComponent), With<ReseedOnState>>,
+) {
+    if global.is_none() && strict.is_none() {
+        error!("Cannot reseed the GlobalRng on entering a state, as it is missing");
+        return;
+    }
+
+    let seed = match source {
+        SeedSource::Fixed(seed) => Some(seed),
+        SeedSource::NextWorldSeed => {
+            let Some(next) = next else {
+                error!("Cannot reseed the GlobalRng on entering a state without a NextWorldSeed");
+                return;
+            };
+
+            Some(next.0)
+        }
+        SeedSource::Entropy => None,
+    };
+
+    if let Some(mut global) = global {
+        match seed {
+            Some(seed) => global.reseed(seed),
+            None => global.reseed_from_entropy(),
+        }
+
+        refork_marked(q_rngs, || RngComponent::from(&mut global));
+    } else if let Some(mut strict) = strict {
+        match seed {
+            Some(seed) => strict.reseed(seed),
+            None => strict.reseed_from_entropy(),
+        }
+
+        refork_marked(q_rngs, || strict.seed_component());
+    }
+}
+
+/// Re-forks the RNG components marked with [`ReseedOnState`] in place, in [`Entity`] order.
+fn refork_marked(
+    mut q_rngs: Query<'_, '_, (Entity, &mut RngComponent), With<ReseedOnState>>,
+    mut from_global: impl FnMut() -> RngComponent,
+) {
+    let mut marked: Vec<_> = q_rngs.iter_mut().collect();
+
+    marked.sort_unstable_by_key(|(entity, _)| *entity);
+
+    for (_, mut rng) in marked {
+        rng.set_source(from_global().into_inner());
+    }
+}
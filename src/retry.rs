@@ -0,0 +1,17 @@
+use std::fmt;
+
+/// The error returned by [`DelegatedRng::retry_sample`](crate::DelegatedRng::retry_sample)
+/// when no attempt succeeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryExhausted {
+    /// The amount of attempts made before giving up.
+    pub attempts: u32,
+}
+
+impl fmt::Display for RetryExhausted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sampling failed after {} attempts", self.attempts)
+    }
+}
+
+impl std::error::Error for RetryExhausted {}
@@ -0,0 +1,614 @@
+use crate::*;
+use std::{
+    any::type_name,
+    borrow::Cow,
+    collections::{HashMap, VecDeque},
+    ops::RangeBounds,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+/// Default amount of entries an [`RngLog`] will keep before discarding the oldest ones.
+pub const DEFAULT_RNG_LOG_CAPACITY: usize = 4096;
+
+/// A single draw recorded by a [`LoggedRng`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "rng-log")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct RngLogEntry {
+    /// The stream of the [`LoggedRng`] the draw was made through, as given by
+    /// [`LoggedRng::stream`].
+    pub stream: u64,
+    /// The frame the draw was made in, counted by [`RngPlugin`] from `0`.
+    pub frame: u64,
+    /// The type name of the RNG wrapped by the [`LoggedRng`] the draw was made through.
+    pub type_name: Cow<'static, str>,
+    /// The name of the delegated method that was called.
+    pub method: Cow<'static, str>,
+    /// The bits of the drawn value, widened to a `u64`. Floats are stored as their bits,
+    /// `char`s as their code point and `bool`s as `0` or `1`, while 128-bit integers only
+    /// keep their lower 64 bits. Methods that don't draw a single number, such as
+    /// [`DelegatedRng::shuffle`] or [`DelegatedRng::fork`], store a fingerprint of the RNG's
+    /// state after the call instead, which differs as soon as anything drawn differs.
+    pub value: u64,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+struct LogBuffer {
+    entries: VecDeque<RngLogEntry>,
+    capacity: usize,
+    dropped: u64,
+    frame: u64,
+    #[cfg_attr(feature = "serialize", serde(default))]
+    next_stream: u64,
+}
+
+impl LogBuffer {
+    fn push(&mut self, entry: RngLogEntry) {
+        if self.capacity == 0 {
+            self.dropped += 1;
+            return;
+        }
+
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+            self.dropped += 1;
+        }
+
+        self.entries.push_back(entry);
+    }
+}
+
+/// A bounded log of the draws made through [`LoggedRng`] wrappers, for finding the first draw
+/// at which a replay diverges from its recording, with [`RngLog::diff`].
+///
+/// The log is a cheap handle to shared storage, so it can be cloned into as many
+/// [`LoggedRng`]s as needed, as well as out of the `App` to be inspected or exported. Each
+/// [`LoggedRng`] records into its own stream, so draws of different RNGs interleaving in a
+/// different order, such as from systems running in parallel, aren't reported as a divergence.
+/// [`RngPlugin`] inserts one automatically, and advances its frame count at the end of every
+/// frame, in [`Last`].
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn spawn_logged(mut commands: Commands, mut global: ResMut<GlobalRng>, log: Res<RngLog>) {
+///     commands.spawn(log.wrap(RngComponent::from(&mut global)));
+/// }
+///
+/// fn roll(mut q_rngs: Query<&mut LoggedRng<RngComponent>>) {
+///     for mut rng in q_rngs.iter_mut() {
+///         let _damage = rng.u32(1..=6);
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins(RngPlugin::new().with_rng_seed(5))
+///     .add_systems(Startup, spawn_logged)
+///     .add_systems(Update, roll);
+/// ```
+#[derive(Debug, Clone, Resource)]
+#[cfg_attr(docsrs, doc(cfg(feature = "rng-log")))]
+pub struct RngLog {
+    inner: Arc<Mutex<LogBuffer>>,
+}
+
+impl RngLog {
+    /// Create a new [`RngLog`] that keeps at most `capacity` entries, discarding the oldest
+    /// ones once full.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self::from_buffer(LogBuffer {
+            entries: VecDeque::with_capacity(capacity.min(DEFAULT_RNG_LOG_CAPACITY)),
+            capacity,
+            dropped: 0,
+            frame: 0,
+            next_stream: 0,
+        })
+    }
+
+    fn from_buffer(buffer: LogBuffer) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(buffer)),
+        }
+    }
+
+    /// Wraps the given RNG into a [`LoggedRng`] recording into this log, on the next unused
+    /// stream of the log. Streams are numbered in the order RNGs are wrapped, starting from
+    /// `0`, so RNGs have to be wrapped in a deterministic order for their streams to match
+    /// between runs, such as when they are seeded.
+    #[must_use]
+    pub fn wrap<T: DelegatedRng + Send + Sync + 'static>(&self, rng: T) -> LoggedRng<T> {
+        let stream = {
+            let mut buffer = self.lock();
+            let stream = buffer.next_stream;
+            buffer.next_stream += 1;
+            stream
+        };
+
+        self.wrap_with_stream(rng, stream)
+    }
+
+    /// Wraps the given RNG into a [`LoggedRng`] recording into the given stream of this log,
+    /// such as a key derived from what the RNG belongs to. Doesn't affect the streams
+    /// assigned by [`RngLog::wrap`], so mixing both can put two RNGs on the same stream.
+    #[inline]
+    #[must_use]
+    pub fn wrap_with_stream<T: DelegatedRng + Send + Sync + 'static>(
+        &self,
+        rng: T,
+        stream: u64,
+    ) -> LoggedRng<T> {
+        LoggedRng {
+            rng,
+            log: self.clone(),
+            stream,
+        }
+    }
+
+    /// Returns a copy of all the currently retained entries, oldest first.
+    #[must_use]
+    pub fn entries(&self) -> Vec<RngLogEntry> {
+        self.lock().entries.iter().cloned().collect()
+    }
+
+    /// Returns the index into [`RngLog::entries`] of the first entry differing from the one
+    /// of `other`, or `None` if both logs retain the same entries. Entries are compared
+    /// stream by stream, so the order in which the draws of different streams interleave
+    /// doesn't matter. If `other` retains more entries for a stream but otherwise matches,
+    /// the index is the length of this log.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let recording = RngLog::default();
+    /// let replay = RngLog::default();
+    ///
+    /// let mut recorded = recording.wrap(RngComponent::with_seed(5));
+    /// let mut replayed = replay.wrap(RngComponent::with_seed(5));
+    ///
+    /// recorded.u32(..);
+    /// replayed.u32(..);
+    ///
+    /// assert_eq!(recording.diff(&replay), None);
+    ///
+    /// recorded.u32(..);
+    /// replayed.f64();
+    ///
+    /// assert_eq!(recording.diff(&replay), Some(1));
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &RngLog) -> Option<usize> {
+        if Arc::ptr_eq(&self.inner, &other.inner) {
+            return None;
+        }
+
+        let (this, other) = (self.lock(), other.lock());
+
+        let mut streams: HashMap<u64, Vec<&RngLogEntry>> = HashMap::new();
+
+        for entry in &other.entries {
+            streams.entry(entry.stream).or_default().push(entry);
+        }
+
+        let mut positions: HashMap<u64, usize> = HashMap::new();
+
+        for (index, entry) in this.entries.iter().enumerate() {
+            let position = positions.entry(entry.stream).or_default();
+
+            if streams
+                .get(&entry.stream)
+                .and_then(|entries| entries.get(*position))
+                != Some(&entry)
+            {
+                return Some(index);
+            }
+
+            *position += 1;
+        }
+
+        streams
+            .iter()
+            .any(|(stream, entries)| positions.get(stream).copied().unwrap_or(0) < entries.len())
+            .then_some(this.entries.len())
+    }
+
+    /// The amount of currently retained entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Returns `true` if no entries are retained.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().entries.is_empty()
+    }
+
+    /// The maximum amount of entries that are retained.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.lock().capacity
+    }
+
+    /// The amount of entries that were discarded due to the log being full.
+    #[must_use]
+    pub fn dropped(&self) -> u64 {
+        self.lock().dropped
+    }
+
+    /// The frame that draws are currently recorded in.
+    #[must_use]
+    pub fn frame(&self) -> u64 {
+        self.lock().frame
+    }
+
+    /// Starts recording draws in the next frame. Called at the end of every frame by
+    /// [`RngPlugin`].
+    pub fn advance_frame(&self) {
+        self.lock().frame += 1;
+    }
+
+    /// Discards all retained entries and resets the dropped count, keeping the frame count.
+    pub fn clear(&self) {
+        let mut buffer = self.lock();
+        buffer.entries.clear();
+        buffer.dropped = 0;
+    }
+
+    fn record<T>(&self, stream: u64, method: &'static str, value: u64) {
+        let mut buffer = self.lock();
+        let frame = buffer.frame;
+
+        buffer.push(RngLogEntry {
+            stream,
+            frame,
+            type_name: Cow::Borrowed(type_name::<T>()),
+            method: Cow::Borrowed(method),
+            value,
+        });
+    }
+
+    fn lock(&self) -> MutexGuard<'_, LogBuffer> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for RngLog {
+    /// Creates a [`RngLog`] with [`DEFAULT_RNG_LOG_CAPACITY`].
+    fn default() -> Self {
+        Self::new(DEFAULT_RNG_LOG_CAPACITY)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl Serialize for RngLog {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.lock().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for RngLog {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        LogBuffer::deserialize(deserializer).map(Self::from_buffer)
+    }
+}
+
+/// A thin wrapper around any [`DelegatedRng`] that forwards every call to it as is, while
+/// recording what is drawn into an [`RngLog`]. The values drawn through the delegated
+/// [`TurboRand`] methods, such as [`DelegatedRng::u32`] or [`DelegatedRng::f64`], as well as
+/// [`DelegatedRng::chance`] and the range methods, are recorded as is. The sampling, shuffling
+/// and forking methods, such as [`DelegatedRng::sample`], [`DelegatedRng::weighted_sample`],
+/// [`DelegatedRng::shuffle`] or [`DelegatedRng::fork`], record a fingerprint of the RNG's
+/// state after the call. Draws made directly on [`DelegatedRng::get_mut`] aren't recorded.
+/// Usable both as a [`Component`] and as a [`Resource`], and created with [`RngLog::wrap`].
+#[derive(Debug, Clone, Component, Resource)]
+#[cfg_attr(docsrs, doc(cfg(feature = "rng-log")))]
+pub struct LoggedRng<T: DelegatedRng + Send + Sync + 'static> {
+    rng: T,
+    log: RngLog,
+    stream: u64,
+}
+
+impl<T: DelegatedRng + Send + Sync + 'static> LoggedRng<T> {
+    /// Returns the [`RngLog`] the draws are recorded into.
+    #[inline]
+    #[must_use]
+    pub const fn log(&self) -> &RngLog {
+        &self.log
+    }
+
+    /// Returns the stream of the [`RngLog`] the draws are recorded into.
+    #[inline]
+    #[must_use]
+    pub const fn stream(&self) -> u64 {
+        self.stream
+    }
+
+    /// Returns a reference to the wrapped RNG.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &T {
+        &self.rng
+    }
+
+    /// Consumes the [`LoggedRng`], returning the wrapped RNG with its state intact.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.rng
+    }
+
+    fn record(&self, method: &'static str, value: u64) {
+        self.log.record::<T>(self.stream, method, value);
+    }
+
+    /// Records a fingerprint of the wrapped RNG's state, drawn from a copy of it.
+    fn record_state(&mut self, method: &'static str) {
+        let fingerprint = self.rng.get_mut().clone().u64(..);
+
+        self.record(method, fingerprint);
+    }
+}
+
+/// Converts a drawn value into the bits recorded by a [`LoggedRng`].
+trait LogBits {
+    fn log_bits(self) -> u64;
+}
+
+macro_rules! impl_log_bits {
+    ($($type:ty => |$value:ident| $bits:expr;)*) => {
+        $(
+            impl LogBits for $type {
+                #[inline]
+                fn log_bits(self) -> u64 {
+                    let $value = self;
+                    $bits
+                }
+            }
+        )*
+    };
+}
+
+impl_log_bits! {
+    u128 => |value| value as u64;
+    u64 => |value| value;
+    u32 => |value| u64::from(value);
+    u16 => |value| u64::from(value);
+    u8 => |value| u64::from(value);
+    i128 => |value| value as u64;
+    i64 => |value| value as u64;
+    i32 => |value| value as u64;
+    i16 => |value| value as u64;
+    i8 => |value| value as u64;
+    usize => |value| value as u64;
+    isize => |value| value as u64;
+    char => |value| u64::from(value);
+    bool => |value| u64::from(value);
+    f64 => |value| value.to_bits();
+    f32 => |value| u64::from(value.to_bits());
+}
+
+/// Generates the [`DelegatedRng`] methods of [`LoggedRng`] for a list given by
+/// [`delegated_methods`], forwarding to the wrapped RNG and recording the drawn value.
+macro_rules! delegate_logged_methods {
+    ($($method:ident($($input:ident: $type:ty = $example:expr)?) -> $output:ty;)*) => {
+        $(
+            #[inline]
+            #[cfg_attr(debug_assertions, track_caller)]
+            fn $method(&mut self $(, $input: $type)?) -> $output {
+                let value = self.rng.$method($($input)?);
+
+                self.record(stringify!($method), value.log_bits());
+
+                value
+            }
+        )*
+    };
+}
+
+impl<T: DelegatedRng + Send + Sync + 'static> DelegatedRng for LoggedRng<T> {
+    type Source = T::Source;
+
+    #[inline]
+    fn get_mut(&mut self) -> &mut Self::Source {
+        self.rng.get_mut()
+    }
+
+    delegated_methods!(delegate_logged_methods);
+
+    #[inline]
+    fn chance(&mut self, rate: f64) -> bool {
+        let outcome = self.rng.chance(rate);
+
+        self.record("chance", outcome.log_bits());
+
+        outcome
+    }
+
+    #[inline]
+    #[cfg_attr(debug_assertions, track_caller)]
+    fn range<U: SampleRange>(&mut self, bounds: impl RangeBounds<U>) -> U {
+        let value = self.rng.range(bounds);
+
+        self.record_state("range");
+
+        value
+    }
+
+    #[inline]
+    #[cfg_attr(debug_assertions, track_caller)]
+    fn f32_range(&mut self, bounds: impl RangeBounds<f32>) -> f32 {
+        let value = self.rng.f32_range(bounds);
+
+        self.record("f32_range", value.log_bits());
+
+        value
+    }
+
+    #[inline]
+    #[cfg_attr(debug_assertions, track_caller)]
+    fn f64_range(&mut self, bounds: impl RangeBounds<f64>) -> f64 {
+        let value = self.rng.f64_range(bounds);
+
+        self.record("f64_range", value.log_bits());
+
+        value
+    }
+
+    #[inline]
+    fn fork(&mut self) -> Self::Source {
+        let fork = self.rng.fork();
+
+        self.record_state("fork");
+
+        fork
+    }
+
+    #[inline]
+    fn fork_many(&mut self, n: usize) -> Vec<Self::Source> {
+        let forks = self.rng.fork_many(n);
+
+        self.record_state("fork_many");
+
+        forks
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, buffer: &mut [u8]) {
+        self.rng.fill_bytes(buffer);
+
+        self.record_state("fill_bytes");
+    }
+
+    #[inline]
+    fn shuffle<U>(&mut self, slice: &mut [U]) {
+        self.rng.shuffle(slice);
+
+        self.record_state("shuffle");
+    }
+
+    #[inline]
+    fn partial_shuffle<'a, U>(
+        &mut self,
+        slice: &'a mut [U],
+        amount: usize,
+    ) -> (&'a mut [U], &'a mut [U]) {
+        let shuffled = self.rng.partial_shuffle(slice, amount);
+
+        self.record_state("partial_shuffle");
+
+        shuffled
+    }
+
+    #[inline]
+    fn shuffle_prefix<'a, U>(
+        &mut self,
+        slice: &'a mut [U],
+        amount: usize,
+    ) -> (&'a mut [U], &'a mut [U]) {
+        let shuffled = self.rng.shuffle_prefix(slice, amount);
+
+        self.record_state("shuffle_prefix");
+
+        shuffled
+    }
+
+    #[inline]
+    fn weighted_shuffle<U, F: Fn(&U) -> f64>(&mut self, slice: &mut [U], weight: F) {
+        self.rng.weighted_shuffle(slice, weight);
+
+        self.record_state("weighted_shuffle");
+    }
+
+    #[inline]
+    fn sample<'a, U>(&mut self, list: &'a [U]) -> Option<&'a U> {
+        let sampled = self.rng.sample(list);
+
+        self.record_state("sample");
+
+        sampled
+    }
+
+    #[inline]
+    fn sample_iter<U: Iterator>(&mut self, list: U) -> Option<U::Item> {
+        let sampled = self.rng.sample_iter(list);
+
+        self.record_state("sample_iter");
+
+        sampled
+    }
+
+    #[inline]
+    fn sample_mut<'a, U>(&mut self, list: &'a mut [U]) -> Option<&'a mut U> {
+        let sampled = self.rng.sample_mut(list);
+
+        self.record_state("sample_mut");
+
+        sampled
+    }
+
+    #[inline]
+    fn sample_multiple<'a, U>(&mut self, list: &'a [U], amount: usize) -> Vec<&'a U> {
+        let sampled = self.rng.sample_multiple(list, amount);
+
+        self.record_state("sample_multiple");
+
+        sampled
+    }
+
+    #[inline]
+    fn sample_multiple_iter<U: Iterator>(&mut self, list: U, amount: usize) -> Vec<U::Item> {
+        let sampled = self.rng.sample_multiple_iter(list, amount);
+
+        self.record_state("sample_multiple_iter");
+
+        sampled
+    }
+
+    #[inline]
+    fn sample_multiple_mut<'a, U>(&mut self, list: &'a mut [U], amount: usize) -> Vec<&'a mut U> {
+        let sampled = self.rng.sample_multiple_mut(list, amount);
+
+        self.record_state("sample_multiple_mut");
+
+        sampled
+    }
+
+    #[inline]
+    fn weighted_sample<'a, U, F>(&mut self, list: &'a [U], weight_sampler: F) -> Option<&'a U>
+    where
+        F: Fn((&U, usize)) -> f64,
+    {
+        let sampled = self.rng.weighted_sample(list, weight_sampler);
+
+        self.record_state("weighted_sample");
+
+        sampled
+    }
+
+    #[inline]
+    fn weighted_sample_mut<'a, U, F>(
+        &mut self,
+        list: &'a mut [U],
+        weight_sampler: F,
+    ) -> Option<&'a mut U>
+    where
+        F: Fn((&U, usize)) -> f64,
+    {
+        let sampled = self.rng.weighted_sample_mut(list, weight_sampler);
+
+        self.record_state("weighted_sample_mut");
+
+        sampled
+    }
+}
+
+/// Advances the frame of the [`RngLog`] resource.
+pub(crate) fn advance_rng_log_frame(log: Res<'_, RngLog>) {
+    log.advance_frame();
+}
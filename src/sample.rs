@@ -0,0 +1,31 @@
+use std::fmt;
+
+/// The error returned by [`DelegatedRng::try_sample_multiple`](crate::DelegatedRng::try_sample_multiple)
+/// and [`DelegatedRng::sample_multiple_distinct`](crate::DelegatedRng::sample_multiple_distinct)
+/// when more items are requested than can be sampled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleError {
+    /// The list holds fewer items than were requested.
+    NotEnoughElements {
+        /// The amount of items requested.
+        requested: usize,
+        /// The amount of items available to sample from.
+        available: usize,
+    },
+}
+
+impl fmt::Display for SampleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotEnoughElements {
+                requested,
+                available,
+            } => write!(
+                f,
+                "requested {requested} items to sample, but only {available} are available"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SampleError {}
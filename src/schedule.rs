@@ -0,0 +1,280 @@
+use crate::{
+    hash::{mix, StableHasher},
+    *,
+};
+use std::{
+    hash::Hash,
+    ops::{Range, RangeInclusive},
+};
+
+/// The number of minutes in a day, the end of the time covered by a [`DaySchedule`].
+pub const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// A slot in a [`ScheduleGenerator`], from which a number of activities are picked
+/// from a weighted pool, each starting within the slot's time window.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct ScheduleSlot<A> {
+    /// The weighted pool of activities to pick from. Weights are relative to each other,
+    /// with activities weighted `0.0` or less never being picked.
+    pub pool: Vec<(A, f64)>,
+    /// How many activities to pick from the pool. The same activity can be picked more
+    /// than once.
+    pub count: RangeInclusive<u32>,
+    /// The window, in minutes since midnight, within which each picked activity must start.
+    pub window: Range<u32>,
+    /// The range of durations of each picked activity, in minutes.
+    pub duration: Range<u32>,
+}
+
+/// An activity placed in a [`DaySchedule`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct ScheduleEntry<A> {
+    /// The scheduled activity.
+    pub activity: A,
+    /// The start of the activity, in minutes since midnight.
+    pub start: u32,
+    /// The end of the activity, in minutes since midnight.
+    pub end: u32,
+}
+
+/// A schedule for a single day, generated by a [`ScheduleGenerator`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct DaySchedule<A> {
+    /// The scheduled activities, in chronological order and never overlapping.
+    pub entries: Vec<ScheduleEntry<A>>,
+    /// The picked activities that had to be dropped, as they could no longer start within
+    /// their slot's window once pushed back by the activities before them.
+    pub dropped: Vec<A>,
+}
+
+/// A generator of daily schedules, such as for NPCs in a life sim. Each schedule is derived
+/// from a master seed, a key identifying who the schedule is for and the day number, so the
+/// same day is always generated the same way, regardless of how many other schedules were
+/// generated or in which order.
+///
+/// # Overlaps
+///
+/// Slots are resolved in the order they were added. The activities picked for a slot are
+/// ordered by their drawn start time, then placed one after the other: an activity that
+/// would start before the previous one has ended is pushed back to start when it ends. If
+/// that pushes its start past the end of its slot's window, it is dropped instead, and
+/// listed in [`DaySchedule::dropped`]. Activities are cut short at [`MINUTES_PER_DAY`].
+/// Thus earlier slots take priority, and slots should usually be added in chronological
+/// order.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Activity {
+///     Wake,
+///     Work,
+///     Shop,
+///     Tavern,
+/// }
+///
+/// let generator = ScheduleGenerator::new()
+///     .with_activity(Activity::Wake, 6 * 60..8 * 60, 30..60)
+///     .with_activity(Activity::Work, 8 * 60..10 * 60, 6 * 60..8 * 60)
+///     .with_pool(
+///         vec![(Activity::Shop, 3.0), (Activity::Tavern, 1.0)],
+///         1..=3,
+///         16 * 60..22 * 60,
+///         30..90,
+///     );
+///
+/// let schedule = generator.generate_with_seed(12345, "blacksmith", 3);
+///
+/// assert_eq!(schedule.entries[0].activity, Activity::Wake);
+/// assert_eq!(schedule, generator.generate_with_seed(12345, "blacksmith", 3));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(transparent))]
+pub struct ScheduleGenerator<A> {
+    slots: Vec<ScheduleSlot<A>>,
+}
+
+impl<A> ScheduleGenerator<A> {
+    /// Creates a new [`ScheduleGenerator`] with no slots.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { slots: Vec::new() }
+    }
+
+    /// Builder function to add a slot with a single, fixed activity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window or duration range is empty, or if the window extends past
+    /// [`MINUTES_PER_DAY`].
+    #[inline]
+    #[must_use]
+    pub fn with_activity(self, activity: A, window: Range<u32>, duration: Range<u32>) -> Self {
+        self.with_pool(vec![(activity, 1.0)], 1..=1, window, duration)
+    }
+
+    /// Builder function to add a slot picking `count` activities from a weighted pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window or duration range is empty, or if the window extends past
+    /// [`MINUTES_PER_DAY`].
+    #[inline]
+    #[must_use]
+    pub fn with_pool(
+        mut self,
+        pool: Vec<(A, f64)>,
+        count: RangeInclusive<u32>,
+        window: Range<u32>,
+        duration: Range<u32>,
+    ) -> Self {
+        self.add_slot(ScheduleSlot {
+            pool,
+            count,
+            window,
+            duration,
+        });
+        self
+    }
+
+    /// Adds a slot to the generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window or duration range is empty, or if the window extends past
+    /// [`MINUTES_PER_DAY`].
+    pub fn add_slot(&mut self, slot: ScheduleSlot<A>) {
+        assert!(
+            !slot.window.is_empty() && slot.window.end <= MINUTES_PER_DAY,
+            "schedule window must not be empty nor extend past the end of the day"
+        );
+        assert!(
+            !slot.duration.is_empty(),
+            "schedule duration must not be empty"
+        );
+
+        self.slots.push(slot);
+    }
+
+    /// Returns the slots of the generator, in the order they are resolved.
+    #[inline]
+    #[must_use]
+    pub fn slots(&self) -> &[ScheduleSlot<A>] {
+        &self.slots
+    }
+
+    /// Generates the schedule of `npc_key` for the given `day`, seeded from the upcoming
+    /// output of `master`. The `master` itself is not advanced, so schedules stay the same
+    /// for as long as nothing else draws from it, such as when it is only ever used for
+    /// deriving schedules. For a schedule that never changes with the master's state, use
+    /// [`ScheduleGenerator::generate_with_seed`] instead.
+    #[inline]
+    #[must_use]
+    pub fn generate(&self, npc_key: impl Hash, day: u32, master: &GlobalRng) -> DaySchedule<A>
+    where
+        A: Clone,
+    {
+        let seed = master.clone().get_mut().gen_u64();
+
+        self.generate_with_seed(seed, npc_key, day)
+    }
+
+    /// Generates the schedule of `npc_key` for the given `day`, derived from `seed`.
+    #[must_use]
+    pub fn generate_with_seed(&self, seed: u64, npc_key: impl Hash, day: u32) -> DaySchedule<A>
+    where
+        A: Clone,
+    {
+        let rng = Rng::with_seed(mix(
+            mix(seed, StableHasher::hash_one(npc_key)),
+            u64::from(day),
+        ));
+
+        let mut schedule = DaySchedule {
+            entries: Vec::new(),
+            dropped: Vec::new(),
+        };
+        let mut free_from = 0;
+
+        for slot in &self.slots {
+            let total: f64 = slot
+                .pool
+                .iter()
+                .map(|&(_, weight)| weight)
+                .filter(|weight| *weight > 0.0 && weight.is_finite())
+                .sum();
+
+            if total <= 0.0 {
+                continue;
+            }
+
+            let count = rng.u32(slot.count.clone());
+
+            let mut picked: Vec<(u32, u32, &A)> = (0..count)
+                .map(|_| {
+                    let activity = pick_weighted(&slot.pool, total, &rng);
+                    let start = rng.u32(slot.window.clone());
+                    let duration = rng.u32(slot.duration.clone());
+
+                    (start, duration, activity)
+                })
+                .collect();
+
+            // Stable, so that activities drawn with the same start keep their pick order.
+            picked.sort_by_key(|&(start, ..)| start);
+
+            for (start, duration, activity) in picked {
+                let start = start.max(free_from);
+
+                if start >= slot.window.end {
+                    schedule.dropped.push(activity.clone());
+                    continue;
+                }
+
+                let end = (start + duration).min(MINUTES_PER_DAY);
+
+                schedule.entries.push(ScheduleEntry {
+                    activity: activity.clone(),
+                    start,
+                    end,
+                });
+                free_from = end;
+            }
+        }
+
+        schedule
+    }
+}
+
+impl<A> Default for ScheduleGenerator<A> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn pick_weighted<'a, A>(pool: &'a [(A, f64)], total: f64, rng: &Rng) -> &'a A {
+    let mut target = rng.f64() * total;
+    let mut picked = None;
+
+    for (activity, weight) in pool {
+        if *weight > 0.0 && weight.is_finite() {
+            picked = Some(activity);
+            target -= weight;
+
+            if target < 0.0 {
+                break;
+            }
+        }
+    }
+
+    // Falls back to the last valid entry should rounding leave the target past the total.
+    picked.expect("pool has at least one valid entry")
+}
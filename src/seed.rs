@@ -0,0 +1,149 @@
+use crate::hash::{mix, StableHasher};
+use std::hash::Hasher;
+
+/// The seed that an empty seed string maps to.
+pub const EMPTY_SEED: u64 = 0;
+
+/// Derives a `u64` seed from a human-friendly seed string, such as `"SPICY-MANGO-42"`, for
+/// seeds shared between players. Strings of `0x` followed by up to 16 hexadecimal digits map
+/// to the number they spell out, so that a seed displayed as `format!("{seed:#x}")` can be
+/// typed back in as is. An empty string maps to [`EMPTY_SEED`]. All other strings are hashed
+/// as their exact UTF-8 bytes, so the mapping is case and whitespace sensitive.
+///
+/// The hash is based on the `wymix` function from [wyhash](https://github.com/wangyi-fudan/wyhash)
+/// with a fixed secret, and is stable across releases and platforms, so a seed string always
+/// maps to the same seed.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::seed_from_str;
+///
+/// let seed = seed_from_str("SPICY-MANGO-42");
+///
+/// assert_eq!(seed_from_str(&format!("{seed:#x}")), seed);
+/// ```
+#[must_use]
+pub fn seed_from_str(seed: &str) -> u64 {
+    if seed.is_empty() {
+        return EMPTY_SEED;
+    }
+
+    if let Some(hex) = seed.strip_prefix("0x") {
+        if (1..=16).contains(&hex.len()) && hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            if let Ok(seed) = u64::from_str_radix(hex, 16) {
+                return seed;
+            }
+        }
+    }
+
+    let mut hasher = StableHasher::default();
+    hasher.write(seed.as_bytes());
+    hasher.finish()
+}
+
+/// Derives a 40 byte seed from a human-friendly seed string, for seeding a `ChaChaRng`. The
+/// string is first mapped to a `u64` with [`seed_from_str`], which is then extended to
+/// 40 bytes, so both seeds derived from the same string are related to each other.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::chacha_seed_from_str;
+///
+/// assert_ne!(chacha_seed_from_str("SPICY-MANGO-42"), [0; 40]);
+/// ```
+#[must_use]
+pub fn chacha_seed_from_str(seed: &str) -> [u8; 40] {
+    extend_seed(seed_from_str(seed))
+}
+
+/// Extends a `u64` seed to 40 bytes, by mixing it with the index of each 8 byte chunk.
+fn extend_seed(seed: u64) -> [u8; 40] {
+    let mut output = [0; 40];
+
+    for (index, chunk) in (0u64..).zip(output.chunks_exact_mut(8)) {
+        chunk.copy_from_slice(&mix(seed, index).to_le_bytes());
+    }
+
+    output
+}
+
+/// A builder for deriving a seed from several inputs, such as a world seed string, a
+/// difficulty and a chunk coordinate, without hashing them together ad hoc.
+///
+/// Starting from the `base` seed, each input is folded into the running seed in turn with the
+/// `wymix` based mixing function of [wyhash](https://github.com/wangyi-fudan/wyhash) that
+/// [`seed_from_str`] also uses. A `u64` input is mixed in directly, while strings and byte
+/// slices are mixed in as little endian words of 8 bytes, zero padded, followed by their
+/// length. A mixer without any inputs finishes with the `base` seed as is.
+///
+/// The order in which inputs are mixed matters, so mixing `a` then `b` yields a different
+/// seed than mixing `b` then `a`. A string mixes the same as its UTF-8 bytes. The outputs are
+/// stable across releases and platforms, so the same inputs always derive the same seed.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+/// use bevy_turborand::SeedMixer;
+///
+/// let chunk = (3i32, -7i32);
+///
+/// let mixer = SeedMixer::new(12345)
+///     .mix_str("SPICY-MANGO-42")
+///     .mix(2)
+///     .mix(chunk.0 as u64)
+///     .mix(chunk.1 as u64);
+///
+/// let mut rng = RngComponent::with_mixed_seed(mixer);
+///
+/// assert_eq!(rng.u64(..), RngComponent::with_seed(mixer.finish()).u64(..));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SeedMixer(u64);
+
+impl SeedMixer {
+    /// Creates a new [`SeedMixer`], starting from the given `base` seed.
+    #[inline]
+    #[must_use]
+    pub const fn new(base: u64) -> Self {
+        Self(base)
+    }
+
+    /// Mixes a `u64` into the seed.
+    #[inline]
+    #[must_use]
+    pub const fn mix(self, value: u64) -> Self {
+        Self(mix(self.0, value))
+    }
+
+    /// Mixes a string into the seed, the same as mixing its UTF-8 bytes with
+    /// [`SeedMixer::mix_bytes`].
+    #[inline]
+    #[must_use]
+    pub fn mix_str(self, value: &str) -> Self {
+        self.mix_bytes(value.as_bytes())
+    }
+
+    /// Mixes a byte slice into the seed.
+    #[inline]
+    #[must_use]
+    pub fn mix_bytes(self, bytes: &[u8]) -> Self {
+        let mut hasher = StableHasher::from_state(self.0);
+        hasher.write(bytes);
+        Self(hasher.finish())
+    }
+
+    /// Returns the mixed seed.
+    #[inline]
+    #[must_use]
+    pub const fn finish(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the mixed seed extended to 40 bytes, for seeding a `ChaChaRng`. The seed is
+    /// extended the same way as by [`chacha_seed_from_str`].
+    #[inline]
+    #[must_use]
+    pub fn finish_chacha(self) -> [u8; 40] {
+        extend_seed(self.0)
+    }
+}
@@ -0,0 +1,122 @@
+use crate::*;
+
+type SpawnStep = Box<dyn FnOnce(&mut World, &mut GlobalRng) -> Vec<Entity>>;
+
+/// A builder for deterministically setting up the starting state of a [`World`] in
+/// tests. Every entity spawned by the builder receives its own [`RngComponent`], forked
+/// from a [`GlobalRng`] with the given seed, which is then inserted into the [`World`].
+///
+/// Spawns are applied in the order they were added to the builder, so the resulting
+/// state only ever depends on the seed and the order of the calls. The bundle closures
+/// are given the [`RngComponent`] of the entity being spawned, so that any randomised
+/// starting values are drawn from the entity's own RNG before it is inserted.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Debug, Component)]
+/// struct Player;
+///
+/// #[derive(Debug, Component)]
+/// struct Enemy;
+///
+/// #[derive(Debug, Component)]
+/// struct HitPoints(u32);
+///
+/// let mut app = App::new();
+///
+/// let spawned = WorldSeeder::new(12345)
+///     .spawn(|_| (Player, HitPoints(100)))
+///     .spawn_batch(2, |rng| (Enemy, HitPoints(rng.u32(15..=25))))
+///     .apply_to_app(&mut app);
+///
+/// let player = spawned[0][0];
+/// let enemies = &spawned[1];
+///
+/// assert!(app.world().get::<RngComponent>(player).is_some());
+/// assert_eq!(enemies.len(), 2);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub struct WorldSeeder {
+    seed: u64,
+    steps: Vec<SpawnStep>,
+}
+
+impl WorldSeeder {
+    /// Creates a new [`WorldSeeder`], with the [`GlobalRng`] initialised from the given seed.
+    #[inline]
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Builder function to spawn a single entity with the bundle returned by `bundle`,
+    /// alongside its own forked [`RngComponent`].
+    #[inline]
+    #[must_use]
+    pub fn spawn<B: Bundle>(self, bundle: impl FnOnce(&mut RngComponent) -> B + 'static) -> Self {
+        let mut bundle = Some(bundle);
+
+        self.spawn_batch(1, move |rng| (bundle.take().unwrap())(rng))
+    }
+
+    /// Builder function to spawn `amount` entities with the bundles returned by `bundle`,
+    /// each alongside its own forked [`RngComponent`].
+    #[inline]
+    #[must_use]
+    pub fn spawn_batch<B: Bundle>(
+        mut self,
+        amount: usize,
+        mut bundle: impl FnMut(&mut RngComponent) -> B + 'static,
+    ) -> Self {
+        self.steps.push(Box::new(move |world, global| {
+            let mut spawned = Vec::with_capacity(amount);
+
+            for _ in 0..amount {
+                let mut rng = RngComponent::from(&mut *global);
+                let bundle = bundle(&mut rng);
+
+                spawned.push(world.spawn((bundle, rng)).id());
+            }
+
+            spawned
+        }));
+        self
+    }
+
+    /// Applies all spawns to the [`World`] in order, then inserts the [`GlobalRng`] they
+    /// were forked from. Returns the spawned entities, grouped by the call that spawned
+    /// them, in the order of the calls.
+    pub fn apply(self, world: &mut World) -> Vec<Vec<Entity>> {
+        let mut global = GlobalRng::with_seed(self.seed);
+
+        let spawned = self
+            .steps
+            .into_iter()
+            .map(|step| step(world, &mut global))
+            .collect();
+
+        world.insert_resource(global);
+
+        spawned
+    }
+
+    /// Same as [`WorldSeeder::apply`], applied to the [`World`] of the given [`App`].
+    pub fn apply_to_app(self, app: &mut App) -> Vec<Vec<Entity>> {
+        self.apply(app.world_mut())
+    }
+}
+
+impl std::fmt::Debug for WorldSeeder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WorldSeeder")
+            .field("seed", &self.seed)
+            .field("steps", &self.steps.len())
+            .finish()
+    }
+}
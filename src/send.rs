@@ -0,0 +1,78 @@
+use crate::*;
+
+/// An owned, `Send + 'static` RNG source forked from a [`DelegatedRng`] with
+/// [`DelegatedRng::fork_send`], for moving into async tasks, such as ones spawned on the
+/// [`AsyncComputeTaskPool`], without the RNG wrapper itself leaving the ECS. Provides all the
+/// [`TurboRand`] methods of the forked source.
+///
+/// As each [`SendRng`] is forked from its parent in turn, forking them in a fixed order, such
+/// as by chunk coordinates, yields the same outputs per task no matter when the tasks run or
+/// complete.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy::tasks::{block_on, poll_once, AsyncComputeTaskPool, Task};
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Component)]
+/// struct ChunkTask(Task<Vec<u32>>);
+///
+/// fn spawn_chunk_tasks(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+///     let pool = AsyncComputeTaskPool::get();
+///
+///     for _chunk in 0..4 {
+///         let rng = global.fork_send();
+///         let task = pool.spawn(async move { (0..16).map(|_| rng.u32(0..100)).collect() });
+///
+///         commands.spawn(ChunkTask(task));
+///     }
+/// }
+///
+/// fn poll_chunk_tasks(mut commands: Commands, mut q_tasks: Query<(Entity, &mut ChunkTask)>) {
+///     for (entity, mut task) in q_tasks.iter_mut() {
+///         if let Some(heights) = block_on(poll_once(&mut task.0)) {
+///             println!("Chunk heights: {heights:?}");
+///             commands.entity(entity).remove::<ChunkTask>();
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`AsyncComputeTaskPool`]: https://docs.rs/bevy_tasks/0.15/bevy_tasks/struct.AsyncComputeTaskPool.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct SendRng<S: Send + 'static>(S);
+
+impl<S: Send + 'static> SendRng<S> {
+    /// Wraps an owned source as is, keeping its state intact.
+    #[inline]
+    #[must_use]
+    pub const fn new(source: S) -> Self {
+        Self(source)
+    }
+
+    /// Consumes the [`SendRng`], returning the wrapped source with its state intact.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<S: TurboCore + Send + 'static> TurboCore for SendRng<S> {
+    #[inline]
+    fn fill_bytes(&self, buffer: &mut [u8]) {
+        self.0.fill_bytes(buffer);
+    }
+}
+
+impl<S: GenCore + Send + 'static> GenCore for SendRng<S> {
+    const GEN_KIND: TurboKind = S::GEN_KIND;
+
+    #[inline]
+    fn gen<const SIZE: usize>(&self) -> [u8; SIZE] {
+        self.0.gen()
+    }
+}
+
+impl<S: SecureCore + Send + 'static> SecureCore for SendRng<S> {}
@@ -0,0 +1,85 @@
+use crate::*;
+use std::collections::HashMap;
+
+/// A uniform grid bucketing accepted points by cell, so that distance checks only
+/// need to look at the points in the neighbouring cells.
+struct SpatialGrid {
+    cell_size: f32,
+    cells: HashMap<IVec3, Vec<Vec3>>,
+}
+
+impl SpatialGrid {
+    fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Returns the cell of `point`. Coordinates too large for an [`IVec3`] saturate, which
+    /// only merges far away cells, as distances are still checked point by point.
+    fn cell(&self, point: Vec3) -> IVec3 {
+        (point / self.cell_size).floor().as_ivec3()
+    }
+
+    fn insert(&mut self, point: Vec3) {
+        self.cells.entry(self.cell(point)).or_default().push(point);
+    }
+
+    /// Returns whether any point within the grid is closer than the cell size to `point`.
+    fn has_neighbour(&self, point: Vec3) -> bool {
+        let cell = self.cell(point);
+        let min_dist_squared = self.cell_size * self.cell_size;
+
+        (-1..=1).any(|x| {
+            (-1..=1).any(|y| {
+                (-1..=1).any(|z| {
+                    self.cells
+                        .get(&cell.saturating_add(IVec3::new(x, y, z)))
+                        .is_some_and(|points| {
+                            points
+                                .iter()
+                                .any(|other| other.distance_squared(point) < min_dist_squared)
+                        })
+                })
+            })
+        })
+    }
+}
+
+/// Greedily picks up to `k` indices of `candidates` in the given visiting `order`, skipping any
+/// candidate within an exclusion zone or closer than `min_dist` to an already picked one.
+pub(crate) fn pick_separated(
+    order: &[usize],
+    candidates: &[Vec3],
+    k: usize,
+    min_dist: f32,
+    exclusions: &[(Vec3, f32)],
+) -> Vec<usize> {
+    let mut picked = Vec::with_capacity(k.min(candidates.len()));
+    let mut grid = (min_dist > 0.0 && min_dist.is_finite()).then(|| SpatialGrid::new(min_dist));
+
+    for &index in order {
+        if picked.len() == k {
+            break;
+        }
+
+        let point = candidates[index];
+
+        let excluded = exclusions
+            .iter()
+            .any(|&(center, radius)| center.distance_squared(point) < radius * radius);
+
+        if excluded || grid.as_ref().is_some_and(|grid| grid.has_neighbour(point)) {
+            continue;
+        }
+
+        if let Some(grid) = grid.as_mut() {
+            grid.insert(point);
+        }
+
+        picked.push(index);
+    }
+
+    picked
+}
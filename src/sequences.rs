@@ -0,0 +1,177 @@
+use crate::*;
+use std::ops::Range;
+
+/// The bases of each dimension, in order.
+const BASES: [u32; 3] = [2, 3, 5];
+
+/// The amount of digits scrambled in each dimension, enough to cover every `u32` index.
+const DEPTHS: [usize; 3] = [32, 21, 14];
+
+/// Where the digit permutations of each dimension start in [`HaltonSequence`].
+const OFFSETS: [usize; 3] = [0, 32 * 2, 32 * 2 + 21 * 3];
+
+/// The total amount of digit permutation entries of a scrambled [`HaltonSequence`].
+const PERMUTATIONS_LEN: usize = 32 * 2 + 21 * 3 + 14 * 5;
+
+/// A low-discrepancy sequence of points, for spreading out positions such as foliage more
+/// evenly than uniformly random ones, which tend to cluster and leave gaps. The points
+/// come from the Halton sequence, using bases `2` and `3` for 2D points and bases `2`,
+/// `3` and `5` for 3D points.
+///
+/// The sequence is deterministic by construction, with randomness only coming in through
+/// the index it starts from, such as one drawn by [`HaltonSequence::from_rng`], and the
+/// optional digit scrambling of [`HaltonSequence::scrambled`], which breaks up the visible
+/// patterns between dimensions while keeping the points evenly spread out. Each call to
+/// [`HaltonSequence::next_vec2`] or [`HaltonSequence::next_vec3`] advances the sequence by
+/// one index, wrapping around after [`u32::MAX`].
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let mut foliage = HaltonSequence::from_rng(&mut rng).scrambled(&mut rng);
+///
+/// let positions: Vec<Vec2> = (0..100)
+///     .map(|_| foliage.next_vec2(-50.0..50.0, -50.0..50.0))
+///     .collect();
+///
+/// assert!(positions
+///     .iter()
+///     .all(|position| position.abs().max_element() <= 50.0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct HaltonSequence {
+    index: u32,
+    /// The digit permutation of each digit position of each dimension, or empty if the
+    /// sequence isn't scrambled.
+    permutations: Vec<u8>,
+}
+
+impl HaltonSequence {
+    /// Creates a new, unscrambled [`HaltonSequence`] starting at `index`. An index of `0`
+    /// yields the origin as its first point, so sequences usually start from `1` or above.
+    #[inline]
+    #[must_use]
+    pub const fn new(index: u32) -> Self {
+        Self {
+            index,
+            permutations: Vec::new(),
+        }
+    }
+
+    /// Creates a new, unscrambled [`HaltonSequence`] starting at an index drawn from `rng`,
+    /// so that different seeds yield different, but still evenly spread out, points.
+    #[inline]
+    #[must_use]
+    pub fn from_rng(rng: &mut impl DelegatedRng) -> Self {
+        Self::new(rng.u32(..))
+    }
+
+    /// Builder function to scramble the digits of the sequence with random permutations
+    /// drawn from `rng`, one for each digit position of each dimension.
+    #[must_use]
+    pub fn scrambled(mut self, rng: &mut impl DelegatedRng) -> Self {
+        self.permutations = Vec::with_capacity(PERMUTATIONS_LEN);
+
+        for (base, depth) in BASES.into_iter().zip(DEPTHS) {
+            for _ in 0..depth {
+                let start = self.permutations.len();
+
+                self.permutations.extend(0..base as u8);
+                rng.shuffle(&mut self.permutations[start..]);
+            }
+        }
+
+        self
+    }
+
+    /// Returns the next 2D point, with each axis mapped into its given range.
+    #[inline]
+    pub fn next_vec2(&mut self, x: Range<f32>, y: Range<f32>) -> Vec2 {
+        let index = self.advance();
+
+        Vec2::new(
+            lerp(x, self.radical_inverse(0, index)),
+            lerp(y, self.radical_inverse(1, index)),
+        )
+    }
+
+    /// Returns the next 3D point, with each axis mapped into its given range.
+    #[inline]
+    pub fn next_vec3(&mut self, x: Range<f32>, y: Range<f32>, z: Range<f32>) -> Vec3 {
+        let index = self.advance();
+
+        Vec3::new(
+            lerp(x, self.radical_inverse(0, index)),
+            lerp(y, self.radical_inverse(1, index)),
+            lerp(z, self.radical_inverse(2, index)),
+        )
+    }
+
+    /// Returns the index of the next point.
+    #[inline]
+    #[must_use]
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns `true` if the digits of the sequence are scrambled.
+    #[inline]
+    #[must_use]
+    pub fn is_scrambled(&self) -> bool {
+        !self.permutations.is_empty()
+    }
+
+    fn advance(&mut self) -> u32 {
+        let index = self.index;
+
+        self.index = index.wrapping_add(1);
+
+        index
+    }
+
+    /// Mirrors the digits of `index` in the base of `dimension` around the radix point,
+    /// yielding a value in the range of `0.0..1.0`.
+    fn radical_inverse(&self, dimension: usize, mut index: u32) -> f64 {
+        let base = BASES[dimension];
+        let inverse = 1.0 / f64::from(base);
+        let permutations = self
+            .permutations
+            .get(OFFSETS[dimension]..OFFSETS[dimension] + DEPTHS[dimension] * base as usize);
+
+        // Unscrambled digits past the last non-zero one are all zero and add nothing.
+        let depth = if permutations.is_some() {
+            DEPTHS[dimension]
+        } else {
+            DEPTHS[dimension].min(32 - index.leading_zeros() as usize)
+        };
+
+        let mut factor = inverse;
+        let mut value = 0.0;
+
+        for position in 0..depth {
+            let digit = index % base;
+
+            index /= base;
+
+            let digit = permutations.map_or(digit, |permutations| {
+                u32::from(permutations[position * base as usize + digit as usize])
+            });
+
+            value += f64::from(digit) * factor;
+            factor *= inverse;
+        }
+
+        value
+    }
+}
+
+/// Maps `t`, in the range of `0.0..1.0`, into `range`.
+#[inline]
+fn lerp(range: Range<f32>, t: f64) -> f32 {
+    range.start + (range.end - range.start) * t as f32
+}
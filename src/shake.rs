@@ -0,0 +1,173 @@
+use crate::{hash::*, *};
+
+#[cfg(feature = "bevy_math")]
+const AXIS_X: usize = 0;
+#[cfg(feature = "bevy_math")]
+const AXIS_Y: usize = 1;
+#[cfg(feature = "bevy_math")]
+const AXIS_Z: usize = 2;
+const AXIS_ROTATION: usize = 3;
+
+/// A deterministic camera shake generator. Produces a continuous, noise-like curve
+/// that is a pure function of the elapsed time, so sampling it at any frame rate
+/// always yields the same underlying shake, making it reproducible in replays.
+///
+/// The curve is value-noise over lattice points, placed `frequency` times per second
+/// and derived from seeds drawn once from a [`DelegatedRng`] at construction, with
+/// smooth interpolation between them. The output is then scaled by an exponentially
+/// decaying envelope, starting at `amplitude` and decaying at the rate of `decay` per
+/// second.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Shake {
+///     sampler: ShakeSampler,
+///     elapsed: f32,
+/// }
+///
+/// fn start_shake(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+///     commands.spawn(Shake {
+///         sampler: ShakeSampler::from_rng(&mut *global)
+///             .with_amplitude(0.5)
+///             .with_decay(3.0),
+///         elapsed: 0.0,
+///     });
+/// }
+///
+/// # #[cfg(feature = "bevy_math")]
+/// fn apply_shake(time: Res<Time>, mut q_shake: Query<(&mut Shake, &mut Transform)>) {
+///     for (mut shake, mut transform) in q_shake.iter_mut() {
+///         shake.elapsed += time.delta_secs();
+///
+///         transform.translation = shake.sampler.offset(shake.elapsed).extend(0.0);
+///         transform.rotation = Quat::from_rotation_z(shake.sampler.rotation(shake.elapsed));
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct ShakeSampler {
+    seeds: [u64; 4],
+    amplitude: f32,
+    frequency: f32,
+    decay: f32,
+    max_rotation: f32,
+}
+
+impl ShakeSampler {
+    /// Creates a new [`ShakeSampler`], drawing its lattice seeds from the given RNG.
+    /// Defaults to an amplitude of `1.0`, a frequency of `15.0`, no decay and a
+    /// maximum rotation of `0.1` radians.
+    #[inline]
+    #[must_use]
+    pub fn from_rng(rng: &mut impl DelegatedRng) -> Self {
+        let source = rng.get_mut();
+
+        Self {
+            seeds: [
+                source.gen_u64(),
+                source.gen_u64(),
+                source.gen_u64(),
+                source.gen_u64(),
+            ],
+            amplitude: 1.0,
+            frequency: 15.0,
+            decay: 0.0,
+            max_rotation: 0.1,
+        }
+    }
+
+    /// Builder function to set the starting amplitude of the offsets.
+    #[inline]
+    #[must_use]
+    pub const fn with_amplitude(mut self, amplitude: f32) -> Self {
+        self.amplitude = amplitude;
+        self
+    }
+
+    /// Builder function to set how many lattice points are placed per second.
+    /// Higher values yield a faster, more jittery shake.
+    #[inline]
+    #[must_use]
+    pub const fn with_frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Builder function to set the exponential decay rate of the envelope per second.
+    /// A value of `0.0` means the shake never decays.
+    #[inline]
+    #[must_use]
+    pub const fn with_decay(mut self, decay: f32) -> Self {
+        self.decay = decay;
+        self
+    }
+
+    /// Builder function to set the maximum rotation in radians, scaled by the
+    /// envelope in the same manner as the amplitude.
+    #[inline]
+    #[must_use]
+    pub const fn with_max_rotation(mut self, max_rotation: f32) -> Self {
+        self.max_rotation = max_rotation;
+        self
+    }
+
+    /// Returns the envelope at time `t`, being a value between `0.0` and `1.0`.
+    /// Negative times are treated as `0.0`.
+    #[inline]
+    #[must_use]
+    pub fn envelope(&self, t: f32) -> f32 {
+        (-self.decay * t.max(0.0)).exp()
+    }
+
+    /// Returns the 2D offset at time `t` in seconds.
+    #[cfg(feature = "bevy_math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
+    #[inline]
+    #[must_use]
+    pub fn offset(&self, t: f32) -> Vec2 {
+        Vec2::new(self.noise(AXIS_X, t), self.noise(AXIS_Y, t)) * self.amplitude * self.envelope(t)
+    }
+
+    /// Returns the 3D offset at time `t` in seconds. The `x` and `y` axes match
+    /// those of [`ShakeSampler::offset`].
+    #[cfg(feature = "bevy_math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
+    #[inline]
+    #[must_use]
+    pub fn offset_3d(&self, t: f32) -> Vec3 {
+        Vec3::new(
+            self.noise(AXIS_X, t),
+            self.noise(AXIS_Y, t),
+            self.noise(AXIS_Z, t),
+        ) * self.amplitude
+            * self.envelope(t)
+    }
+
+    /// Returns the rotation in radians at time `t` in seconds.
+    #[inline]
+    #[must_use]
+    pub fn rotation(&self, t: f32) -> f32 {
+        self.noise(AXIS_ROTATION, t) * self.max_rotation * self.envelope(t)
+    }
+
+    /// Smoothly interpolated value noise in the range of `-1.0..1.0`.
+    fn noise(&self, axis: usize, t: f32) -> f32 {
+        let position = t * self.frequency;
+        let floor = position.floor();
+        let fraction = position - floor;
+        let lattice = floor as i64 as u64;
+
+        let a = to_signed_f32(mix(self.seeds[axis], lattice));
+        let b = to_signed_f32(mix(self.seeds[axis], lattice.wrapping_add(1)));
+
+        // Quintic fade, giving continuous first and second derivatives at lattice points.
+        let fade = fraction * fraction * fraction * (fraction * (fraction * 6.0 - 15.0) + 10.0);
+
+        a + (b - a) * fade
+    }
+}
@@ -0,0 +1,208 @@
+use crate::*;
+use bevy_math::primitives::{Annulus, Circle, Cuboid, Rectangle, Sphere, Triangle2d};
+use std::f32::consts::TAU;
+
+/// A trait for shapes that can be uniformly sampled with a [`DelegatedRng`], either within
+/// their interior or along their boundary. Shapes are centred on the origin, as with
+/// Bevy's own math primitives.
+///
+/// Bevy's prelude includes its own `ShapeSample` trait, with methods of the same names
+/// taking a `rand` RNG instead. Where both traits are in scope, calls can be disambiguated
+/// as `SampleShape::sample_interior(&shape, &mut rng)`.
+///
+/// # Example
+/// ```
+/// use bevy::math::primitives::Circle;
+/// use bevy_turborand::prelude::*;
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let circle = Circle::new(2.0);
+///
+/// let point = circle.sample_interior(&mut rng);
+///
+/// assert!(point.length() <= 2.0);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "shapes")))]
+pub trait SampleShape {
+    /// The type of the sampled points.
+    type Output;
+
+    /// Samples a point uniformly from within the shape, boundary included.
+    fn sample_interior(&self, rng: &mut impl DelegatedRng) -> Self::Output;
+
+    /// Samples a point uniformly from the boundary of the shape. For 3D shapes, this is
+    /// their surface.
+    fn sample_boundary(&self, rng: &mut impl DelegatedRng) -> Self::Output;
+}
+
+/// Returns a unit vector in a uniformly random direction on the plane.
+#[inline]
+fn unit_circle(rng: &mut impl DelegatedRng) -> Vec2 {
+    Vec2::from_angle(rng.f32() * TAU)
+}
+
+/// Returns a unit vector in a uniformly random direction in space.
+#[inline]
+fn unit_sphere(rng: &mut impl DelegatedRng) -> Vec3 {
+    // By Archimedes' hat-box theorem, a uniform height gives a uniform area on the sphere.
+    let z = rng.f32_normalized();
+    let ring = unit_circle(rng) * (1.0 - z * z).max(0.0).sqrt();
+
+    ring.extend(z)
+}
+
+impl SampleShape for Circle {
+    type Output = Vec2;
+
+    #[inline]
+    fn sample_interior(&self, rng: &mut impl DelegatedRng) -> Vec2 {
+        // The square root compensates for the area growing with the radius.
+        unit_circle(rng) * self.radius * rng.f32().sqrt()
+    }
+
+    #[inline]
+    fn sample_boundary(&self, rng: &mut impl DelegatedRng) -> Vec2 {
+        unit_circle(rng) * self.radius
+    }
+}
+
+impl SampleShape for Annulus {
+    type Output = Vec2;
+
+    #[inline]
+    fn sample_interior(&self, rng: &mut impl DelegatedRng) -> Vec2 {
+        let inner = self.inner_circle.radius * self.inner_circle.radius;
+        let outer = self.outer_circle.radius * self.outer_circle.radius;
+
+        // Interpolates the squared radius, so that the rings are weighted by their area.
+        unit_circle(rng) * (inner + rng.f32() * (outer - inner)).sqrt()
+    }
+
+    #[inline]
+    fn sample_boundary(&self, rng: &mut impl DelegatedRng) -> Vec2 {
+        let inner = self.inner_circle.radius;
+        let outer = self.outer_circle.radius;
+
+        // Both circles are weighted by their circumference, and so by their radius.
+        let radius = if rng.f32() * (inner + outer) < inner {
+            inner
+        } else {
+            outer
+        };
+
+        unit_circle(rng) * radius
+    }
+}
+
+impl SampleShape for Rectangle {
+    type Output = Vec2;
+
+    #[inline]
+    fn sample_interior(&self, rng: &mut impl DelegatedRng) -> Vec2 {
+        Vec2::new(rng.f32_normalized(), rng.f32_normalized()) * self.half_size
+    }
+
+    fn sample_boundary(&self, rng: &mut impl DelegatedRng) -> Vec2 {
+        let Vec2 { x, y } = self.half_size;
+
+        // Picks a distance along one vertical and one horizontal side, then a side of each.
+        let along = rng.f32() * (x + y);
+        let side = if rng.bool() { 1.0 } else { -1.0 };
+
+        if along < y {
+            Vec2::new(x * side, rng.f32_normalized() * y)
+        } else {
+            Vec2::new(rng.f32_normalized() * x, y * side)
+        }
+    }
+}
+
+impl SampleShape for Triangle2d {
+    type Output = Vec2;
+
+    fn sample_interior(&self, rng: &mut impl DelegatedRng) -> Vec2 {
+        let [a, b, c] = self.vertices;
+
+        let (mut u, mut v) = (rng.f32(), rng.f32());
+
+        // Folds points from the other half of the parallelogram back into the triangle.
+        if u + v > 1.0 {
+            u = 1.0 - u;
+            v = 1.0 - v;
+        }
+
+        a + (b - a) * u + (c - a) * v
+    }
+
+    fn sample_boundary(&self, rng: &mut impl DelegatedRng) -> Vec2 {
+        let [a, b, c] = self.vertices;
+
+        let edges = [(a, b), (b, c), (c, a)];
+        let lengths = edges.map(|(start, end)| start.distance(end));
+
+        let mut along = rng.f32() * lengths.iter().sum::<f32>();
+
+        for ((start, end), length) in edges.into_iter().zip(lengths) {
+            if along < length {
+                return start.lerp(end, along / length);
+            }
+
+            along -= length;
+        }
+
+        // Only reached if rounding leaves the distance past the perimeter.
+        a
+    }
+}
+
+impl SampleShape for Sphere {
+    type Output = Vec3;
+
+    #[inline]
+    fn sample_interior(&self, rng: &mut impl DelegatedRng) -> Vec3 {
+        // The cube root compensates for the volume growing with the radius.
+        unit_sphere(rng) * self.radius * rng.f32().cbrt()
+    }
+
+    #[inline]
+    fn sample_boundary(&self, rng: &mut impl DelegatedRng) -> Vec3 {
+        unit_sphere(rng) * self.radius
+    }
+}
+
+impl SampleShape for Cuboid {
+    type Output = Vec3;
+
+    #[inline]
+    fn sample_interior(&self, rng: &mut impl DelegatedRng) -> Vec3 {
+        Vec3::new(
+            rng.f32_normalized(),
+            rng.f32_normalized(),
+            rng.f32_normalized(),
+        ) * self.half_size
+    }
+
+    fn sample_boundary(&self, rng: &mut impl DelegatedRng) -> Vec3 {
+        let Vec3 { x, y, z } = self.half_size;
+
+        // Each pair of opposite faces is weighted by its area, facing along x, y and z.
+        let areas = [y * z, x * z, x * y];
+        let target = rng.f32() * areas.iter().sum::<f32>();
+
+        let axis = if target < areas[0] {
+            0
+        } else if target < areas[0] + areas[1] {
+            1
+        } else {
+            2
+        };
+
+        let mut point = SampleShape::sample_interior(self, rng);
+        let side = if rng.bool() { 1.0 } else { -1.0 };
+
+        point[axis] = self.half_size[axis] * side;
+
+        point
+    }
+}
@@ -0,0 +1,198 @@
+use crate::*;
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+#[cfg(target_arch = "wasm32")]
+use instant::Instant;
+
+/// The time budget given to a [`SlicedGenerator`] for the current frame.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub struct Budget {
+    deadline: Instant,
+}
+
+impl Budget {
+    #[inline]
+    fn new(limit: Duration) -> Self {
+        Self {
+            deadline: Instant::now() + limit,
+        }
+    }
+
+    /// Returns the time remaining in this frame's budget.
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.deadline.saturating_duration_since(Instant::now())
+    }
+
+    /// Returns whether this frame's budget has been used up.
+    #[inline]
+    #[must_use]
+    pub fn is_exhausted(&self) -> bool {
+        Instant::now() >= self.deadline
+    }
+}
+
+/// The progress reported by a [`SlicedGenerator`]'s generator after a step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub enum GenProgress {
+    /// There is more work to be done.
+    Pending,
+    /// The generation has finished.
+    Complete,
+}
+
+/// An [`Event`] sent once a [`SlicedGenerator`] has completed its generation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub struct GenerationComplete {
+    /// The entity holding the [`SlicedGenerator`] that completed.
+    pub entity: Entity,
+}
+
+type Generator = Box<dyn FnMut(&mut Rng, Budget) -> GenProgress + Send + Sync>;
+
+/// A [`Component`] for running a long generation task over several frames, within
+/// a per-frame time budget. The generator owns a dedicated [`Rng`], so draws from
+/// other systems running in between frames never affect its output.
+///
+/// The generator is a resumable state machine, called repeatedly with the [`Rng`]
+/// and the current [`Budget`] until either it reports [`GenProgress::Complete`] or the
+/// budget runs out, in which case it is resumed on the next frame. It is always called
+/// at least once per frame, so it should do a unit of work on each call and keep track
+/// of where it left off. Doing so makes the output depend only on the seed and the
+/// generator logic, and never on how the work was split across frames.
+///
+/// Once complete, a [`GenerationComplete`] event is sent and the generator is no
+/// longer called. The generators are driven by [`RngPlugin`] during [`Update`],
+/// within the [`RngSystems::SlicedGeneration`] set.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+/// use std::sync::{Arc, Mutex};
+///
+/// #[derive(Resource)]
+/// struct Heights(Arc<Mutex<Vec<u32>>>);
+///
+/// fn start_generation(mut commands: Commands, mut global: ResMut<GlobalRng>, heights: Res<Heights>) {
+///     let heights = heights.0.clone();
+///
+///     commands.spawn(SlicedGenerator::new(&mut *global, move |rng, _budget| {
+///         let mut heights = heights.lock().unwrap();
+///
+///         heights.push(rng.u32(0..64));
+///
+///         if heights.len() < 4096 {
+///             GenProgress::Pending
+///         } else {
+///             GenProgress::Complete
+///         }
+///     }));
+/// }
+/// ```
+#[derive(Component)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub struct SlicedGenerator {
+    rng: RngComponent,
+    generator: Generator,
+    budget: Duration,
+    finished: bool,
+}
+
+impl SlicedGenerator {
+    /// Creates a new [`SlicedGenerator`], forking its [`Rng`] from the given RNG.
+    /// Defaults to a budget of 4 milliseconds per frame.
+    #[inline]
+    #[must_use]
+    pub fn new(
+        rng: &mut impl DelegatedRng,
+        generator: impl FnMut(&mut Rng, Budget) -> GenProgress + Send + Sync + 'static,
+    ) -> Self {
+        Self::with_seed(rng.get_mut().gen_u64(), generator)
+    }
+
+    /// Creates a new [`SlicedGenerator`] with its [`Rng`] initialised from the given seed.
+    /// Defaults to a budget of 4 milliseconds per frame.
+    #[inline]
+    #[must_use]
+    pub fn with_seed(
+        seed: u64,
+        generator: impl FnMut(&mut Rng, Budget) -> GenProgress + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            rng: RngComponent::with_seed(seed),
+            generator: Box::new(generator),
+            budget: Duration::from_millis(4),
+            finished: false,
+        }
+    }
+
+    /// Builder function to set the time budget given to the generator per frame.
+    #[inline]
+    #[must_use]
+    pub const fn with_budget(mut self, budget: Duration) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// Returns the time budget given to the generator per frame.
+    #[inline]
+    #[must_use]
+    pub const fn budget(&self) -> Duration {
+        self.budget
+    }
+
+    /// Returns whether the generator has completed.
+    #[inline]
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Runs the generator until it completes or the budget for this frame runs out.
+    /// Returns whether the generator completed during this call.
+    fn step(&mut self) -> bool {
+        let budget = Budget::new(self.budget);
+
+        loop {
+            if (self.generator)(self.rng.get_mut(), budget) == GenProgress::Complete {
+                self.finished = true;
+                return true;
+            }
+
+            if budget.is_exhausted() {
+                return false;
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for SlicedGenerator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SlicedGenerator")
+            .field("rng", &self.rng)
+            .field("budget", &self.budget)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Drives all unfinished [`SlicedGenerator`]s for the current frame.
+pub(crate) fn drive_sliced_generators(
+    mut q_generators: Query<'_, '_, (Entity, &mut SlicedGenerator)>,
+    mut completed: EventWriter<'_, GenerationComplete>,
+) {
+    for (entity, mut generator) in q_generators.iter_mut() {
+        // Checked before stepping, so finished generators aren't flagged as changed.
+        if !generator.is_finished() && generator.step() {
+            completed.send(GenerationComplete { entity });
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use crate::*;
+use std::collections::BTreeMap;
+
+/// An item in the pool of a [`SlotPool`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct SlotItem<Id> {
+    /// The identifier of the item, returned when it is rolled.
+    pub id: Id,
+    /// The relative weight of the item. Items with a weight of `0.0` or less are never rolled.
+    pub weight: f64,
+    /// The category of the item, whose cap limits how many items of the category are rolled
+    /// across all slots. Items without a category are never capped.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub category: Option<String>,
+}
+
+/// A slot in a [`SlotRoller`], with its weighted pool of items.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct SlotPool<Id> {
+    /// The name of the slot.
+    pub name: String,
+    /// The items that can be rolled for the slot.
+    pub items: Vec<SlotItem<Id>>,
+}
+
+/// An item rolled for a slot, as referred to by a [`SlotExclusion`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct SlotPick<Id> {
+    /// The name of the slot.
+    pub slot: String,
+    /// The identifier of the item.
+    pub item: Id,
+}
+
+/// A rule in a [`SlotRoller`] forbidding two picks from both being rolled. The rule goes
+/// both ways: whichever of the two slots is resolved first excludes the other pick.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct SlotExclusion<Id> {
+    /// The first of the mutually exclusive picks.
+    pub first: SlotPick<Id>,
+    /// The second of the mutually exclusive picks.
+    pub second: SlotPick<Id>,
+}
+
+impl<Id: PartialEq> SlotExclusion<Id> {
+    /// Returns the pick excluded by the given pick, if the rule applies to it.
+    fn excluded_by(&self, slot: &str, item: &Id) -> Option<&SlotPick<Id>> {
+        if self.first.slot == slot && self.first.item == *item {
+            Some(&self.second)
+        } else if self.second.slot == slot && self.second.item == *item {
+            Some(&self.first)
+        } else {
+            None
+        }
+    }
+}
+
+/// The outcome of [`SlotRoller::roll`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct SlotAssignment<Id> {
+    /// The rolled items, by slot name, in the order the slots were resolved.
+    pub assigned: Vec<(String, Id)>,
+    /// The slots left empty, as every item of their pool was ruled out by exclusions or caps.
+    pub empty: Vec<String>,
+}
+
+impl<Id> SlotAssignment<Id> {
+    /// Returns the item rolled for the given slot, if any.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, slot: &str) -> Option<&Id> {
+        self.assigned
+            .iter()
+            .find(|(name, _)| name == slot)
+            .map(|(_, item)| item)
+    }
+}
+
+/// A weighted roller for filling a set of slots with items, such as the attachment points of
+/// a character generator, under constraints between the slots.
+///
+/// Slots are resolved in the order they were added, each rolling one item from its pool by
+/// weight. Before each roll, items are ruled out if a [`SlotExclusion`] forbids them alongside
+/// an item already rolled, or if their category has reached its cap, with the weights of the
+/// remaining items renormalised. A slot with no items left is reported in
+/// [`SlotAssignment::empty`]. Slots resolved earlier thus take priority over later ones.
+///
+/// With the `serialize` feature, rollers can be authored in RON or any other format. Rolls
+/// are deterministic for a given RNG state and roller.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// let roller = SlotRoller::new()
+///     .with_item("left_shoulder", "pauldron", 2.0, None)
+///     .with_item("left_shoulder", "spikes", 1.0, None)
+///     .with_item("right_shoulder", "pauldron", 2.0, None)
+///     .with_item("right_shoulder", "spikes", 1.0, None)
+///     .with_item("face", "scar", 1.0, Some("face"))
+///     .with_item("face", "tattoo", 1.0, Some("face"))
+///     .with_exclusion("left_shoulder", "spikes", "right_shoulder", "pauldron")
+///     .with_cap("face", 1);
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let assignment = roller.roll(&mut rng);
+///
+/// assert!(assignment.get("left_shoulder").is_some());
+/// assert!(assignment.empty.is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct SlotRoller<Id> {
+    slots: Vec<SlotPool<Id>>,
+    #[cfg_attr(feature = "serialize", serde(default))]
+    exclusions: Vec<SlotExclusion<Id>>,
+    #[cfg_attr(feature = "serialize", serde(default))]
+    caps: BTreeMap<String, u32>,
+}
+
+impl<Id> SlotRoller<Id> {
+    /// Creates a new, empty [`SlotRoller`].
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            exclusions: Vec::new(),
+            caps: BTreeMap::new(),
+        }
+    }
+
+    /// Builder function to add an item to the pool of a slot, adding the slot if it
+    /// doesn't exist yet.
+    #[inline]
+    #[must_use]
+    pub fn with_item(
+        mut self,
+        slot: impl Into<String>,
+        id: Id,
+        weight: f64,
+        category: Option<&str>,
+    ) -> Self {
+        self.add_item(slot, id, weight, category);
+        self
+    }
+
+    /// Adds an item to the pool of a slot, adding the slot if it doesn't exist yet.
+    pub fn add_item(
+        &mut self,
+        slot: impl Into<String>,
+        id: Id,
+        weight: f64,
+        category: Option<&str>,
+    ) {
+        let slot = slot.into();
+        let item = SlotItem {
+            id,
+            weight,
+            category: category.map(str::to_owned),
+        };
+
+        match self.slots.iter_mut().find(|pool| pool.name == slot) {
+            Some(pool) => pool.items.push(item),
+            None => self.slots.push(SlotPool {
+                name: slot,
+                items: vec![item],
+            }),
+        }
+    }
+
+    /// Builder function to forbid `first_item` in `first_slot` from being rolled alongside
+    /// `second_item` in `second_slot`.
+    #[inline]
+    #[must_use]
+    pub fn with_exclusion(
+        mut self,
+        first_slot: impl Into<String>,
+        first_item: Id,
+        second_slot: impl Into<String>,
+        second_item: Id,
+    ) -> Self {
+        self.exclusions.push(SlotExclusion {
+            first: SlotPick {
+                slot: first_slot.into(),
+                item: first_item,
+            },
+            second: SlotPick {
+                slot: second_slot.into(),
+                item: second_item,
+            },
+        });
+        self
+    }
+
+    /// Builder function to cap how many items of a category are rolled across all slots.
+    #[inline]
+    #[must_use]
+    pub fn with_cap(mut self, category: impl Into<String>, max: u32) -> Self {
+        self.caps.insert(category.into(), max);
+        self
+    }
+
+    /// Returns the slots of the roller, in the order they are resolved.
+    #[inline]
+    #[must_use]
+    pub fn slots(&self) -> &[SlotPool<Id>] {
+        &self.slots
+    }
+
+    /// Returns the exclusion rules of the roller.
+    #[inline]
+    #[must_use]
+    pub fn exclusions(&self) -> &[SlotExclusion<Id>] {
+        &self.exclusions
+    }
+
+    /// Returns the cap of a category, if it has one.
+    #[inline]
+    #[must_use]
+    pub fn cap(&self, category: &str) -> Option<u32> {
+        self.caps.get(category).copied()
+    }
+
+    /// Rolls an item for every slot, in the order the slots were added.
+    pub fn roll(&self, rng: &mut impl DelegatedRng) -> SlotAssignment<Id>
+    where
+        Id: Clone + PartialEq,
+    {
+        let mut assignment = SlotAssignment {
+            assigned: Vec::with_capacity(self.slots.len()),
+            empty: Vec::new(),
+        };
+        let mut excluded: Vec<&SlotPick<Id>> = Vec::new();
+        let mut counts: BTreeMap<&str, u32> = BTreeMap::new();
+
+        for pool in &self.slots {
+            let available: Vec<&SlotItem<Id>> = pool
+                .items
+                .iter()
+                .filter(|item| item.weight > 0.0 && item.weight.is_finite())
+                .filter(|item| {
+                    !excluded
+                        .iter()
+                        .any(|pick| pick.slot == pool.name && pick.item == item.id)
+                })
+                .filter(|item| {
+                    item.category.as_deref().is_none_or(|category| {
+                        self.cap(category)
+                            .is_none_or(|cap| counts.get(category).copied().unwrap_or(0) < cap)
+                    })
+                })
+                .collect();
+
+            if available.is_empty() {
+                assignment.empty.push(pool.name.clone());
+                continue;
+            }
+
+            let total: f64 = available.iter().map(|item| item.weight).sum();
+            let mut target = rng.get_mut().f64() * total;
+
+            // Falls back to the last item should rounding leave the target past the total.
+            let picked = available
+                .iter()
+                .copied()
+                .find(|item| {
+                    target -= item.weight;
+                    target < 0.0
+                })
+                .unwrap_or(available[available.len() - 1]);
+
+            excluded.extend(
+                self.exclusions
+                    .iter()
+                    .filter_map(|rule| rule.excluded_by(&pool.name, &picked.id)),
+            );
+
+            if let Some(category) = picked.category.as_deref() {
+                *counts.entry(category).or_default() += 1;
+            }
+
+            assignment
+                .assigned
+                .push((pool.name.clone(), picked.id.clone()));
+        }
+
+        assignment
+    }
+}
+
+impl<Id> Default for SlotRoller<Id> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
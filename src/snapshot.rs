@@ -0,0 +1,383 @@
+use crate::*;
+use bevy_ecs::reflect::AppTypeRegistry;
+use bevy_reflect::{TypeData, TypeRegistry};
+use std::fmt;
+
+/// The RNG components captured from a single entity by an [`RngWorldSnapshot`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+pub struct RngEntitySnapshot {
+    /// The captured [`RngComponent`], if the entity had one.
+    #[cfg(feature = "wyrand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+    pub rng: Option<RngComponent>,
+    /// The captured [`ChaChaRngComponent`], if the entity had one.
+    #[cfg(feature = "chacha")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
+    pub chacha: Option<ChaChaRngComponent>,
+}
+
+/// The error returned by [`RngWorldSnapshot::apply`] and [`RngWorldSnapshot::apply_keyed`]
+/// when entities captured by the snapshot can't be found in the [`World`]. Nothing is
+/// restored when this is returned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+pub enum SnapshotError<K = Entity> {
+    /// No entity matches the given keys in the [`World`].
+    MissingEntities(Vec<K>),
+}
+
+impl<K: fmt::Debug> fmt::Display for SnapshotError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingEntities(missing) => write!(
+                f,
+                "{} captured entities are missing from the world: {missing:?}",
+                missing.len()
+            ),
+        }
+    }
+}
+
+impl<K: fmt::Debug> std::error::Error for SnapshotError<K> {}
+
+/// The error returned by [`insert_reflected_resource`] and [`insert_reflected_component`]
+/// when a reflected value can't be inserted into the [`World`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+pub enum ReflectInsertError {
+    /// The value doesn't represent a type registered in the [`World`]'s [`AppTypeRegistry`],
+    /// such as when the type was never registered or the registry is missing entirely.
+    Unregistered(String),
+    /// The type of the value is registered without [`ReflectResource`] or
+    /// [`ReflectComponent`] data, as whichever was being inserted.
+    MissingTypeData(String),
+    /// The entity to insert the component into doesn't exist.
+    MissingEntity(Entity),
+}
+
+impl fmt::Display for ReflectInsertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unregistered(type_path) => {
+                write!(f, "{type_path} is not registered in the AppTypeRegistry")
+            }
+            Self::MissingTypeData(type_path) => write!(
+                f,
+                "{type_path} is registered without ReflectResource or ReflectComponent data"
+            ),
+            Self::MissingEntity(entity) => write!(f, "{entity} is missing from the world"),
+        }
+    }
+}
+
+impl std::error::Error for ReflectInsertError {}
+
+/// Inserts a reflected [`Resource`] into the [`World`], overwriting any existing one, such as
+/// a [`GlobalRng`] deserialized from a save with a
+/// [`ReflectDeserializer`](bevy_reflect::serde::ReflectDeserializer). The concrete type is
+/// looked up in the [`World`]'s [`AppTypeRegistry`], so it doesn't need to be known up front,
+/// and the value may be a dynamic representation of it, as deserializers produce.
+///
+/// # Errors
+///
+/// Returns [`ReflectInsertError::Unregistered`] if the type isn't registered, and
+/// [`ReflectInsertError::MissingTypeData`] if it is registered without [`ReflectResource`].
+pub fn insert_reflected_resource(
+    world: &mut World,
+    resource: &dyn PartialReflect,
+) -> Result<(), ReflectInsertError> {
+    let registry = registry_of(world, resource)?;
+    let registry = registry.read();
+
+    reflect_data::<ReflectResource>(&registry, resource)?.insert(world, resource, &registry);
+
+    Ok(())
+}
+
+/// Inserts a reflected [`Component`] into `entity`, overwriting any existing one, such as an
+/// [`RngComponent`] deserialized from a save. Looks up the concrete type the same way as
+/// [`insert_reflected_resource`].
+///
+/// # Errors
+///
+/// Returns [`ReflectInsertError::MissingEntity`] if `entity` doesn't exist,
+/// [`ReflectInsertError::Unregistered`] if the type isn't registered, and
+/// [`ReflectInsertError::MissingTypeData`] if it is registered without [`ReflectComponent`].
+pub fn insert_reflected_component(
+    world: &mut World,
+    entity: Entity,
+    component: &dyn PartialReflect,
+) -> Result<(), ReflectInsertError> {
+    let registry = registry_of(world, component)?;
+    let registry = registry.read();
+
+    let reflect_component = reflect_data::<ReflectComponent>(&registry, component)?;
+    let mut entity = world
+        .get_entity_mut(entity)
+        .map_err(|_| ReflectInsertError::MissingEntity(entity))?;
+
+    reflect_component.insert(&mut entity, component, &registry);
+
+    Ok(())
+}
+
+/// Returns a handle to the [`World`]'s [`AppTypeRegistry`], to release the borrow of the
+/// [`World`] before inserting into it.
+fn registry_of(
+    world: &World,
+    value: &dyn PartialReflect,
+) -> Result<AppTypeRegistry, ReflectInsertError> {
+    world
+        .get_resource::<AppTypeRegistry>()
+        .cloned()
+        .ok_or_else(|| ReflectInsertError::Unregistered(value.reflect_type_path().to_string()))
+}
+
+fn reflect_data<'a, D: TypeData>(
+    registry: &'a TypeRegistry,
+    value: &dyn PartialReflect,
+) -> Result<&'a D, ReflectInsertError> {
+    let type_path = value.reflect_type_path();
+
+    let registration = value
+        .get_represented_type_info()
+        .and_then(|info| registry.get(info.type_id()))
+        .ok_or_else(|| ReflectInsertError::Unregistered(type_path.to_string()))?;
+
+    registration
+        .data::<D>()
+        .ok_or_else(|| ReflectInsertError::MissingTypeData(type_path.to_string()))
+}
+
+/// A snapshot of the entire RNG state of a [`World`], meant for replays and save games: the
+/// [`GlobalRng`] and [`GlobalChaChaRng`] resources along with the [`RngComponent`]s and
+/// [`ChaChaRngComponent`]s of every entity, captured and restored in one call.
+///
+/// By default, entities are matched by their [`Entity`] id, which is only stable within the
+/// same [`World`]. For restoring onto a different [`World`], such as one loaded from a save,
+/// entities can be matched by a stable key component of the user's instead, with
+/// [`RngWorldSnapshot::capture_keyed`] and [`RngWorldSnapshot::apply_keyed`].
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// let mut world = World::new();
+///
+/// world.insert_resource(GlobalRng::with_seed(5));
+/// let entity = world.spawn(RngComponent::with_seed(7)).id();
+///
+/// let snapshot = RngWorldSnapshot::capture(&mut world);
+///
+/// let expected = world.get_mut::<RngComponent>(entity).unwrap().u64(..);
+///
+/// snapshot.apply(&mut world).unwrap();
+///
+/// assert_eq!(world.get_mut::<RngComponent>(entity).unwrap().u64(..), expected);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+pub struct RngWorldSnapshot<K = Entity> {
+    #[cfg(feature = "wyrand")]
+    global: Option<GlobalRng>,
+    #[cfg(feature = "chacha")]
+    global_chacha: Option<GlobalChaChaRng>,
+    entities: Vec<(K, RngEntitySnapshot)>,
+}
+
+impl RngWorldSnapshot {
+    /// Captures the RNG state of the [`World`], with entities matched by their [`Entity`] id.
+    #[must_use]
+    pub fn capture(world: &mut World) -> Self {
+        let mut query = world.query::<Entity>();
+        let entities: Vec<Entity> = query.iter(world).collect();
+
+        Self::capture_with(world, entities.into_iter().map(|entity| (entity, entity)))
+    }
+
+    /// Restores the RNG state onto the [`World`], matching entities by their [`Entity`] id.
+    /// Resources are inserted if missing, and components are inserted or overwritten.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::MissingEntities`] with every captured entity that no longer
+    /// exists, in which case nothing is restored.
+    pub fn apply(&self, world: &mut World) -> Result<(), SnapshotError> {
+        let resolved = self
+            .entities
+            .iter()
+            .map(|(entity, _)| world.get_entity(*entity).ok().map(|_| *entity))
+            .collect();
+
+        self.apply_with(world, resolved)
+    }
+}
+
+impl<K: Component + Clone + PartialEq> RngWorldSnapshot<K> {
+    /// Captures the RNG state of the [`World`], with entities matched by their `K` key
+    /// component. Only entities with a key are captured.
+    #[must_use]
+    pub fn capture_keyed(world: &mut World) -> Self {
+        let mut query = world.query::<(Entity, &K)>();
+        let keyed: Vec<(K, Entity)> = query
+            .iter(world)
+            .map(|(entity, key)| (key.clone(), entity))
+            .collect();
+
+        Self::capture_with(world, keyed)
+    }
+
+    /// Restores the RNG state onto the [`World`], matching entities by their `K` key
+    /// component. Resources are inserted if missing, and components are inserted or
+    /// overwritten. Where several entities share a key, the first one found is restored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::MissingEntities`] with every captured key that no entity has,
+    /// in which case nothing is restored.
+    pub fn apply_keyed(&self, world: &mut World) -> Result<(), SnapshotError<K>> {
+        let mut query = world.query::<(Entity, &K)>();
+        let keyed: Vec<(Entity, &K)> = query.iter(world).collect();
+
+        let resolved = self
+            .entities
+            .iter()
+            .map(|(key, _)| {
+                keyed
+                    .iter()
+                    .find(|(_, other)| *other == key)
+                    .map(|&(entity, _)| entity)
+            })
+            .collect();
+
+        self.apply_with(world, resolved)
+    }
+}
+
+impl<K: Clone> RngWorldSnapshot<K> {
+    /// Returns the keys of the captured entities.
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entities.iter().map(|(key, _)| key)
+    }
+
+    /// Returns the RNG components captured for the given key, if any.
+    #[inline]
+    #[must_use]
+    pub fn entity(&self, key: &K) -> Option<&RngEntitySnapshot>
+    where
+        K: PartialEq,
+    {
+        self.entities
+            .iter()
+            .find(|(other, _)| other == key)
+            .map(|(_, snapshot)| snapshot)
+    }
+
+    /// Returns the captured [`GlobalRng`], if the [`World`] had one.
+    #[cfg(feature = "wyrand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+    #[inline]
+    #[must_use]
+    pub fn global(&self) -> Option<&GlobalRng> {
+        self.global.as_ref()
+    }
+
+    /// Returns the captured [`GlobalChaChaRng`], if the [`World`] had one.
+    #[cfg(feature = "chacha")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
+    #[inline]
+    #[must_use]
+    pub fn global_chacha(&self) -> Option<&GlobalChaChaRng> {
+        self.global_chacha.as_ref()
+    }
+
+    fn capture_with(world: &World, keyed: impl IntoIterator<Item = (K, Entity)>) -> Self {
+        let entities = keyed
+            .into_iter()
+            .filter_map(|(key, entity)| {
+                let snapshot = RngEntitySnapshot {
+                    #[cfg(feature = "wyrand")]
+                    rng: world.get::<RngComponent>(entity).cloned(),
+                    #[cfg(feature = "chacha")]
+                    chacha: world.get::<ChaChaRngComponent>(entity).cloned(),
+                };
+
+                snapshot.has_any().then_some((key, snapshot))
+            })
+            .collect();
+
+        Self {
+            #[cfg(feature = "wyrand")]
+            global: world.get_resource::<GlobalRng>().cloned(),
+            #[cfg(feature = "chacha")]
+            global_chacha: world.get_resource::<GlobalChaChaRng>().cloned(),
+            entities,
+        }
+    }
+
+    /// Restores the snapshot, with `resolved` holding the entity matched for each captured
+    /// entry, in order.
+    fn apply_with(
+        &self,
+        world: &mut World,
+        resolved: Vec<Option<Entity>>,
+    ) -> Result<(), SnapshotError<K>> {
+        let missing: Vec<K> = self
+            .entities
+            .iter()
+            .zip(&resolved)
+            .filter(|(_, entity)| entity.is_none())
+            .map(|((key, _), _)| key.clone())
+            .collect();
+
+        if !missing.is_empty() {
+            return Err(SnapshotError::MissingEntities(missing));
+        }
+
+        #[cfg(feature = "wyrand")]
+        if let Some(global) = &self.global {
+            world.insert_resource(global.clone());
+        }
+
+        #[cfg(feature = "chacha")]
+        if let Some(global) = &self.global_chacha {
+            world.insert_resource(global.clone());
+        }
+
+        for ((_, snapshot), entity) in self.entities.iter().zip(resolved.into_iter().flatten()) {
+            let mut entity = world.entity_mut(entity);
+
+            #[cfg(feature = "wyrand")]
+            if let Some(rng) = &snapshot.rng {
+                entity.insert(rng.clone());
+            }
+
+            #[cfg(feature = "chacha")]
+            if let Some(rng) = &snapshot.chacha {
+                entity.insert(rng.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl RngEntitySnapshot {
+    #[inline]
+    fn has_any(&self) -> bool {
+        #[cfg(feature = "wyrand")]
+        if self.rng.is_some() {
+            return true;
+        }
+
+        #[cfg(feature = "chacha")]
+        if self.chacha.is_some() {
+            return true;
+        }
+
+        false
+    }
+}
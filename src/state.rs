@@ -0,0 +1,482 @@
+use crate::*;
+use serde::{
+    de::{self, DeserializeSeed, SeqAccess, Visitor},
+    ser::{self, Impossible, SerializeStruct, SerializeTuple},
+};
+use std::fmt;
+
+/// Errors that can occur when restoring an RNG source from its raw state bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "state-bytes")))]
+pub enum RngStateError {
+    /// The provided bytes are not of the length expected for the RNG source.
+    InvalidLength {
+        /// The amount of bytes the RNG source state is made of.
+        expected: usize,
+        /// The amount of bytes that were provided.
+        found: usize,
+    },
+    /// The provided bytes do not describe a valid state for the RNG source.
+    InvalidState,
+}
+
+impl fmt::Display for RngStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { expected, found } => write!(
+                f,
+                "invalid RNG state length, expected {expected} bytes but found {found}"
+            ),
+            Self::InvalidState => f.write_str("bytes do not describe a valid RNG state"),
+        }
+    }
+}
+
+impl std::error::Error for RngStateError {}
+
+/// A trait for RNG sources whose full, reproducible state can be extracted and
+/// restored as plain bytes, for use in custom save formats. The byte layout is
+/// the little-endian encoding of the source's internal state, and is only stable
+/// for as long as the underlying `turborand` source does not change its internals.
+///
+/// `turborand` has no accessor for the state of its sources other than their `serde`
+/// implementations, so the bytes are written and read by a minimal built-in serializer.
+/// No serialization format is involved, but the `serde` crate is still required.
+#[cfg_attr(docsrs, doc(cfg(feature = "state-bytes")))]
+pub trait StateBytes: Sized {
+    /// The exact amount of bytes making up the state of the source.
+    const STATE_SIZE: usize;
+
+    /// Returns the current state of the source as bytes.
+    fn state_bytes(&self) -> Vec<u8>;
+
+    /// Creates a new source from the given state bytes.
+    fn from_state_bytes(bytes: &[u8]) -> Result<Self, RngStateError>;
+}
+
+#[cfg(feature = "wyrand")]
+impl StateBytes for Rng {
+    const STATE_SIZE: usize = 8;
+
+    #[inline]
+    fn state_bytes(&self) -> Vec<u8> {
+        encode(self, Self::STATE_SIZE)
+    }
+
+    #[inline]
+    fn from_state_bytes(bytes: &[u8]) -> Result<Self, RngStateError> {
+        decode(bytes, Self::STATE_SIZE)
+    }
+}
+
+/// Size of the ChaCha8 internal state words.
+#[cfg(feature = "chacha")]
+const CHACHA_STATE: usize = 16 * 4;
+/// Size of the ChaCha8 entropy buffer, in bytes.
+#[cfg(feature = "chacha")]
+const CHACHA_CACHE: usize = 8 * 8;
+
+#[cfg(feature = "chacha")]
+impl StateBytes for ChaChaRng {
+    /// The ChaCha8 state words, the cached entropy buffer and the buffer cursor.
+    const STATE_SIZE: usize = CHACHA_STATE + CHACHA_CACHE + 8;
+
+    #[inline]
+    fn state_bytes(&self) -> Vec<u8> {
+        encode(self, Self::STATE_SIZE)
+    }
+
+    #[inline]
+    fn from_state_bytes(bytes: &[u8]) -> Result<Self, RngStateError> {
+        // The cursor must never point beyond the end of the entropy buffer.
+        if bytes.len() == Self::STATE_SIZE {
+            let mut cursor = [0u8; 8];
+            cursor.copy_from_slice(&bytes[CHACHA_STATE + CHACHA_CACHE..]);
+
+            if u64::from_le_bytes(cursor) > CHACHA_CACHE as u64 {
+                return Err(RngStateError::InvalidState);
+            }
+        }
+
+        decode(bytes, Self::STATE_SIZE)
+    }
+}
+
+fn encode<T: serde::Serialize>(value: &T, size: usize) -> Vec<u8> {
+    let mut writer = ByteWriter(Vec::with_capacity(size));
+
+    value
+        .serialize(&mut writer)
+        .expect("RNG sources should only be made up of integer values");
+
+    debug_assert_eq!(writer.0.len(), size);
+
+    writer.0
+}
+
+fn decode<T: serde::de::DeserializeOwned>(bytes: &[u8], size: usize) -> Result<T, RngStateError> {
+    if bytes.len() != size {
+        return Err(RngStateError::InvalidLength {
+            expected: size,
+            found: bytes.len(),
+        });
+    }
+
+    let mut reader = ByteReader(bytes);
+
+    T::deserialize(&mut reader).map_err(|_| RngStateError::InvalidState)
+}
+
+#[derive(Debug)]
+struct Error;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unsupported RNG state")
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(_msg: T) -> Self {
+        Self
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(_msg: T) -> Self {
+        Self
+    }
+}
+
+/// A minimal serializer that writes integers as little-endian bytes, ignoring all structure.
+struct ByteWriter(Vec<u8>);
+
+macro_rules! write_int {
+    ($method:ident, $ty:ty) => {
+        fn $method(self, v: $ty) -> Result<(), Error> {
+            self.0.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+    };
+}
+
+macro_rules! unsupported {
+    ($($method:ident($($ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method(self, $(_: $ty),*) -> Result<(), Error> {
+                Err(Error)
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for &mut ByteWriter {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Impossible<(), Error>;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Impossible<(), Error>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    write_int!(serialize_u8, u8);
+    write_int!(serialize_u16, u16);
+    write_int!(serialize_u32, u32);
+    write_int!(serialize_u64, u64);
+
+    unsupported!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(&'static str),
+        serialize_unit_variant(&'static str, u32, &'static str),
+    );
+
+    fn serialize_some<T: ?Sized + serde::Serialize>(self, _value: &T) -> Result<(), Error> {
+        Err(Error)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + serde::Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + serde::Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<(), Error> {
+        Err(Error)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        Err(Error)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Error> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        Err(Error)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Err(Error)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Err(Error)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Err(Error)
+    }
+}
+
+impl SerializeTuple for &mut ByteWriter {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + serde::Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl SerializeStruct for &mut ByteWriter {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + serde::Serialize>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Restores an RNG source from the integers making up its state, in the order they are
+/// serialized and regardless of the width they were read as. The result is passed back through
+/// [`StateBytes::from_state_bytes`], so that it is validated the same way as raw state bytes.
+#[cfg(feature = "serialize-compact")]
+pub(crate) fn from_state_integers<T>(integers: &[u64]) -> Result<T, RngStateError>
+where
+    T: StateBytes + serde::Serialize + serde::de::DeserializeOwned,
+{
+    let mut reader = IntReader(integers.iter());
+
+    let value = T::deserialize(&mut reader).map_err(|_| RngStateError::InvalidState)?;
+
+    if reader.0.len() > 0 {
+        return Err(RngStateError::InvalidState);
+    }
+
+    T::from_state_bytes(&encode(&value, T::STATE_SIZE))
+}
+
+/// The counterpart to [`ByteWriter`], reading integers back in the order they are requested.
+struct ByteReader<'a>(&'a [u8]);
+
+impl ByteReader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        if self.0.len() < N {
+            return Err(Error);
+        }
+
+        let (bytes, rest) = self.0.split_at(N);
+        self.0 = rest;
+
+        let mut output = [0u8; N];
+        output.copy_from_slice(bytes);
+
+        Ok(output)
+    }
+}
+
+macro_rules! read_int {
+    ($method:ident, $visit:ident, $ty:ty) => {
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            visitor.$visit(<$ty>::from_le_bytes(self.take()?))
+        }
+    };
+}
+
+impl<'de> de::Deserializer<'de> for &mut ByteReader<'_> {
+    type Error = Error;
+
+    read_int!(deserialize_u8, visit_u8, u8);
+    read_int!(deserialize_u16, visit_u16, u16);
+    read_int!(deserialize_u32, visit_u32, u32);
+    read_int!(deserialize_u64, visit_u64, u64);
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Error> {
+        Err(Error)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_seq(Elements {
+            reader: self,
+            remaining: len,
+        })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_tuple(fields.len(), visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u128 f32 f64 char str string bytes byte_buf option unit
+        unit_struct seq tuple_struct map enum identifier ignored_any
+    }
+}
+
+struct Elements<'r, R> {
+    reader: &'r mut R,
+    remaining: usize,
+}
+
+impl<'de, R> SeqAccess<'de> for Elements<'_, R>
+where
+    for<'r> &'r mut R: de::Deserializer<'de, Error = Error>,
+{
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+
+        seed.deserialize(&mut *self.reader).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+/// A variant of [`ByteReader`] reading from already parsed integers instead of raw bytes.
+#[cfg(feature = "serialize-compact")]
+struct IntReader<'a>(std::slice::Iter<'a, u64>);
+
+#[cfg(feature = "serialize-compact")]
+macro_rules! read_parsed_int {
+    ($method:ident, $visit:ident, $ty:ty) => {
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            let value = self.0.next().ok_or(Error)?;
+
+            visitor.$visit(<$ty>::try_from(*value).map_err(|_| Error)?)
+        }
+    };
+}
+
+#[cfg(feature = "serialize-compact")]
+impl<'de> de::Deserializer<'de> for &mut IntReader<'_> {
+    type Error = Error;
+
+    read_parsed_int!(deserialize_u8, visit_u8, u8);
+    read_parsed_int!(deserialize_u16, visit_u16, u16);
+    read_parsed_int!(deserialize_u32, visit_u32, u32);
+    read_parsed_int!(deserialize_u64, visit_u64, u64);
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Error> {
+        Err(Error)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_seq(Elements {
+            reader: self,
+            remaining: len,
+        })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_tuple(fields.len(), visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u128 f32 f64 char str string bytes byte_buf option unit
+        unit_struct seq tuple_struct map enum identifier ignored_any
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{hash::mix, *};
+
+/// A stateless source of random-looking values, each a pure function of a seed and a key,
+/// built on the `wymix` function of [wyhash](https://github.com/wangyi-fudan/wyhash). As
+/// there is no state advancing between calls, there is nothing to save, restore or desync:
+/// the same seed and key always yield the same value, in any order, on any thread, and
+/// across releases. Suited to values such as a per-entity flicker phase, keyed by the
+/// entity and salted with the current tick.
+///
+/// This is **not** a sequential RNG. Hashing the same key twice yields the same value
+/// twice, so any variation must come from the keys themselves, such as an incrementing
+/// counter. Inserted by [`RngPlugin`], seeded with the same seed as the global RNG.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Flicker {
+///     intensity: f32,
+/// }
+///
+/// fn flicker(
+///     stateless: Res<StatelessRng>,
+///     time: Res<Time>,
+///     mut q_flicker: Query<(Entity, &mut Flicker)>,
+/// ) {
+///     let tick = (time.elapsed_secs() * 10.0) as u64;
+///
+///     for (entity, mut flicker) in q_flicker.iter_mut() {
+///         let phase = stateless.hash_f32(stateless.hash_entity(entity, tick));
+///
+///         flicker.intensity = 0.8 + 0.2 * phase;
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Resource, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, PartialEq, Resource, Serialize, Deserialize)
+)]
+#[cfg_attr(not(feature = "serialize"), reflect(Debug, PartialEq, Resource))]
+pub struct StatelessRng {
+    seed: u64,
+}
+
+impl StatelessRng {
+    /// Creates a new [`StatelessRng`] with the given seed.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Returns the seed of the [`StatelessRng`].
+    #[inline]
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns a well distributed `u64` for `key`. Adjacent keys yield uncorrelated values.
+    #[inline]
+    #[must_use]
+    pub const fn hash_u64(&self, key: u64) -> u64 {
+        mix(self.seed, key)
+    }
+
+    /// Returns a `f32` in the range of `0.0..1.0` for `key`, from the top 24 bits of
+    /// [`StatelessRng::hash_u64`].
+    #[inline]
+    #[must_use]
+    pub fn hash_f32(&self, key: u64) -> f32 {
+        (self.hash_u64(key) >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Returns a well distributed `u64` for `entity`, further keyed by `salt`, such as the
+    /// current tick or a per-use constant.
+    ///
+    /// The whole [`Entity`] is hashed, generation included, on purpose: once an entity is
+    /// despawned and its index reused, the new entity yields different values rather than
+    /// taking over those of the old one, the same as any other unrelated entity.
+    #[inline]
+    #[must_use]
+    pub const fn hash_entity(&self, entity: Entity, salt: u64) -> u64 {
+        mix(self.hash_u64(entity.to_bits()), salt)
+    }
+}
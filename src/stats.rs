@@ -0,0 +1,164 @@
+use crate::*;
+use std::fmt::Debug;
+
+/// The seed used by [`expect_frequency`] for its trials.
+pub const STATS_SEED: u64 = 0x5EED_57A7;
+
+/// Upper tail quantile of the standard normal distribution for a significance of `0.001`.
+const Z_0_001: f64 = 3.090_232;
+
+/// Runs `trials` seeded trials of `rng_fn`, and asserts that each outcome listed in
+/// `expected` occurs at the given frequency, give or take `tolerance`. Both the
+/// frequencies and the tolerance are absolute, so `("epic", 0.02)` with a tolerance
+/// of `0.005` accepts the epic outcome occurring between 1.5% and 2.5% of the time.
+///
+/// The trials are run against an [`RngComponent`] seeded with [`STATS_SEED`], so the
+/// outcome is always the same for the same closure.
+///
+/// # Panics
+///
+/// Panics naming the offending outcome if any observed frequency falls outside of
+/// the tolerance, or if `rng_fn` yields an outcome not listed in `expected`.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::{prelude::*, stats::expect_frequency};
+///
+/// expect_frequency(
+///     |rng| if rng.chance(0.02) { "epic" } else { "common" },
+///     100_000,
+///     &[("epic", 0.02), ("common", 0.98)],
+///     0.005,
+/// );
+/// ```
+#[track_caller]
+pub fn expect_frequency<L, F>(rng_fn: F, trials: usize, expected: &[(L, f64)], tolerance: f64)
+where
+    L: PartialEq + Debug,
+    F: FnMut(&mut RngComponent) -> L,
+{
+    expect_frequency_with(
+        &mut RngComponent::with_seed(STATS_SEED),
+        rng_fn,
+        trials,
+        expected,
+        tolerance,
+    );
+}
+
+/// Same as [`expect_frequency`], but running the trials against the given RNG instead,
+/// for testing with other seeds or other [`DelegatedRng`] sources.
+///
+/// # Panics
+///
+/// Panics naming the offending outcome if any observed frequency falls outside of
+/// the tolerance, or if `rng_fn` yields an outcome not listed in `expected`.
+#[track_caller]
+pub fn expect_frequency_with<R, L, F>(
+    rng: &mut R,
+    mut rng_fn: F,
+    trials: usize,
+    expected: &[(L, f64)],
+    tolerance: f64,
+) where
+    R: DelegatedRng,
+    L: PartialEq + Debug,
+    F: FnMut(&mut R) -> L,
+{
+    assert!(trials > 0, "at least one trial is required");
+
+    let mut counts = vec![0usize; expected.len()];
+
+    for _ in 0..trials {
+        let outcome = rng_fn(rng);
+
+        match expected.iter().position(|(label, _)| *label == outcome) {
+            Some(index) => counts[index] += 1,
+            None => panic!("outcome {outcome:?} is not one of the expected outcomes"),
+        }
+    }
+
+    for ((label, frequency), count) in expected.iter().zip(counts) {
+        let observed = count as f64 / trials as f64;
+
+        assert!(
+            (observed - frequency).abs() <= tolerance,
+            "outcome {label:?} occurred at a frequency of {observed:.5} ({count} of {trials} trials), expected {frequency:.5} ± {tolerance:.5}",
+        );
+    }
+}
+
+/// Runs a chi-squared goodness of fit test of `samples` against a uniform distribution
+/// over `buckets` buckets, at a significance of `0.001`, and returns the chi-squared
+/// statistic. Each sample is the index of the bucket it falls in.
+///
+/// # Panics
+///
+/// Panics if the samples are not uniformly distributed, naming the bucket deviating
+/// the most from its expected count. Also panics if there are fewer than two buckets,
+/// no samples, or a sample is not a valid bucket index.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::{prelude::*, stats::chi_squared_uniform};
+///
+/// let mut rng = RngComponent::with_seed(1);
+///
+/// chi_squared_uniform((0..10_000).map(|_| rng.usize(..6)), 6);
+/// ```
+#[track_caller]
+pub fn chi_squared_uniform(samples: impl IntoIterator<Item = usize>, buckets: usize) -> f64 {
+    assert!(buckets > 1, "at least two buckets are required");
+
+    let mut counts = vec![0usize; buckets];
+    let mut total = 0usize;
+
+    for sample in samples {
+        assert!(
+            sample < buckets,
+            "sample {sample} is out of range for {buckets} buckets"
+        );
+        counts[sample] += 1;
+        total += 1;
+    }
+
+    assert!(total > 0, "at least one sample is required");
+
+    let expected = total as f64 / buckets as f64;
+
+    let contributions: Vec<f64> = counts
+        .iter()
+        .map(|&count| {
+            let delta = count as f64 - expected;
+            delta * delta / expected
+        })
+        .collect();
+
+    let statistic: f64 = contributions.iter().sum();
+
+    // Wilson-Hilferty approximation of the chi-squared critical value.
+    let df = (buckets - 1) as f64;
+    let term = 2.0 / (9.0 * df);
+    let critical = df * (1.0 - term + Z_0_001 * term.sqrt()).powi(3);
+
+    if statistic > critical {
+        let (worst, _) =
+            contributions
+                .iter()
+                .enumerate()
+                .fold((0, f64::MIN), |best, (index, &contribution)| {
+                    if contribution > best.1 {
+                        (index, contribution)
+                    } else {
+                        best
+                    }
+                });
+
+        panic!(
+            "samples are not uniformly distributed (chi-squared {statistic:.3} > {critical:.3}), bucket {worst} has {} samples, expected {expected:.1}",
+            counts[worst],
+        );
+    }
+
+    statistic
+}
@@ -0,0 +1,130 @@
+use crate::sync::SyncRng;
+use crate::*;
+
+/// A component holding several independent, labelled [`Rng`] streams, for entities with
+/// more than one unrelated use for randomness, such as movement jitter and loot drops.
+/// Drawing from one stream never affects the others, so adding a random call to one system
+/// doesn't change what the other systems roll.
+///
+/// Streams are forked from a source in the order of their labels, so appending a new label
+/// later leaves the streams of the existing labels unchanged for the same source state.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn spawn_goblin(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+///     commands.spawn(RngStreams::from_rng(&mut *global, ["movement", "loot"]));
+/// }
+///
+/// fn drop_loot(mut q_streams: Query<&mut RngStreams>) {
+///     for mut streams in q_streams.iter_mut() {
+///         let gold = streams.stream("loot").u32(1..=10);
+///
+///         println!("Dropped {gold} gold");
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Component, PartialEq, Reflect)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(opaque, Debug, PartialEq, Default, Component, Serialize, Deserialize)
+)]
+#[cfg_attr(
+    not(feature = "serialize"),
+    reflect(opaque, Debug, PartialEq, Default, Component)
+)]
+pub struct RngStreams {
+    streams: Vec<(String, SyncRng<Rng>)>,
+}
+
+impl RngStreams {
+    /// Creates a new [`RngStreams`], forking one stream from `rng` per label, in the order
+    /// of the labels. Repeated labels are only forked once.
+    #[must_use]
+    pub fn from_rng<R, L>(rng: &mut R, labels: impl IntoIterator<Item = L>) -> Self
+    where
+        R: DelegatedRng,
+        L: Into<String>,
+    {
+        let mut streams = Self::default();
+
+        for label in labels {
+            streams.add_stream(rng, label);
+        }
+
+        streams
+    }
+
+    /// Forks a new stream from `rng` under the given label, unless a stream with that label
+    /// already exists. Returns the stream for the label.
+    pub fn add_stream(
+        &mut self,
+        rng: &mut impl DelegatedRng,
+        label: impl Into<String>,
+    ) -> &mut Rng {
+        let label = label.into();
+
+        let index = match self.position(&label) {
+            Some(index) => index,
+            None => {
+                self.streams
+                    .push((label, SyncRng::new(Rng::with_seed(rng.get_mut().gen_u64()))));
+                self.streams.len() - 1
+            }
+        };
+
+        self.streams[index].1.get_mut()
+    }
+
+    /// Returns the stream with the given label.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no stream with the given label.
+    #[inline]
+    pub fn stream(&mut self, label: &str) -> &mut Rng {
+        match self.position(label) {
+            Some(index) => self.streams[index].1.get_mut(),
+            None => panic!(
+                "no RNG stream labelled `{label}`, expected one of {:?}",
+                self.labels().collect::<Vec<_>>()
+            ),
+        }
+    }
+
+    /// Returns the stream with the given label, or `None` if there is no such stream.
+    #[inline]
+    pub fn try_stream(&mut self, label: &str) -> Option<&mut Rng> {
+        self.position(label)
+            .map(|index| self.streams[index].1.get_mut())
+    }
+
+    /// Returns an iterator over the labels of the streams, in the order they were forked.
+    #[inline]
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.streams.iter().map(|(label, _)| label.as_str())
+    }
+
+    /// Returns the number of streams.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.streams.len()
+    }
+
+    /// Returns `true` if there are no streams.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.streams.is_empty()
+    }
+
+    #[inline]
+    fn position(&self, label: &str) -> Option<usize> {
+        self.streams.iter().position(|(name, _)| name == label)
+    }
+}
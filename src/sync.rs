@@ -0,0 +1,124 @@
+use crate::*;
+use std::fmt;
+
+/// Marks an RNG source whose `&self` methods are only safe to share between threads as
+/// long as none of them mutate. The sources implementing it keep their state in a [`Cell`]
+/// or an [`UnsafeCell`], and advance it from `&self` when drawing values, which is why they
+/// are not [`Sync`] on their own.
+///
+/// # Safety
+///
+/// Implementors must guarantee that their [`Clone`], [`PartialEq`], [`fmt::Debug`] and,
+/// with the `serialize` or `state-bytes` features, [`Serialize`] and
+/// [`StateBytes::state_bytes`] impls only ever read their state, and never advance, refill
+/// or otherwise write to it.
+///
+/// [`Cell`]: std::cell::Cell
+/// [`UnsafeCell`]: std::cell::UnsafeCell
+pub(crate) unsafe trait ReadOnlyShared: Send {}
+
+// SAFETY: `WyRand` keeps its state in a `Cell`, which its `Clone`, `PartialEq` and
+// `Serialize` impls only read with `Cell::get`, while its `Debug` impl doesn't touch it. Its
+// `StateBytes` impl only serializes it.
+#[cfg(feature = "wyrand")]
+unsafe impl ReadOnlyShared for Rng {}
+
+// SAFETY: `ChaCha8` keeps its state and its buffered output in `UnsafeCell`s, which its
+// `Clone`, `PartialEq` and `Serialize` impls only read from, never refilling the buffer nor
+// moving its cursor, while its `Debug` impl doesn't touch them. Its `StateBytes` impl only
+// serializes them.
+#[cfg(feature = "chacha")]
+unsafe impl ReadOnlyShared for ChaChaRng {}
+
+// SAFETY: `LegacyRng` keeps its state in a `Cell`, which its derived `Clone`, `PartialEq`
+// and `Serialize` impls, as well as its `StateBytes` impl, only read with `Cell::get`, while
+// its `Debug` impl doesn't touch it.
+#[cfg(feature = "compat")]
+unsafe impl ReadOnlyShared for LegacyRng {}
+
+/// The one wrapper making the RNG sources held by the components and resources of this
+/// crate [`Sync`], as the ECS requires. It never hands out a shared reference to the source,
+/// only an exclusive one with [`SyncRng::get_mut`], so no value can be drawn through a
+/// `&SyncRng`, nor through a shared reference to anything wrapping one.
+///
+/// The only `&self` access happens in the [`Clone`], [`PartialEq`], [`fmt::Debug`] and
+/// [`Serialize`] impls below, and in [`SyncRng::state_bytes`], which [`ReadOnlyShared`]
+/// guarantees to be reads. Any number of threads may then interleave those reads in any
+/// order without a data race, as a write needs a `&mut SyncRng`, which the borrow checker
+/// won't hand out while a shared reference is alive on any thread.
+#[repr(transparent)]
+#[derive(Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(transparent))]
+pub(crate) struct SyncRng<T>(T);
+
+// SAFETY: See the docs of `SyncRng` and of `ReadOnlyShared`. Sharing a `&SyncRng<T>` only
+// ever allows reading the source, and moving it to another thread requires `T: Send`.
+unsafe impl<T: ReadOnlyShared> Sync for SyncRng<T> {}
+
+impl<T> SyncRng<T> {
+    #[inline]
+    pub(crate) const fn new(source: T) -> Self {
+        Self(source)
+    }
+
+    #[inline]
+    pub(crate) fn get_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+
+    #[inline]
+    pub(crate) fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+#[cfg(feature = "bevy_rand-compat")]
+impl<T: StateBytes> SyncRng<T> {
+    #[inline]
+    pub(crate) fn state_bytes(&self) -> Vec<u8> {
+        self.0.state_bytes()
+    }
+}
+
+impl<T: Clone> Clone for SyncRng<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: PartialEq> PartialEq for SyncRng<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SyncRng<T> {
+    /// Formats the wrapped source as is, so wrapping it doesn't change the output.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+// Checks that the wrappers stay `Send + Sync` without any `unsafe impl` of their own.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[cfg(feature = "wyrand")]
+    {
+        assert_send_sync::<GlobalRng>();
+        assert_send_sync::<RngComponent>();
+        assert_send_sync::<RngStreams>();
+        assert_send_sync::<StrictGlobalRng>();
+        assert_send_sync::<GlobalEntropy>();
+    }
+    #[cfg(feature = "chacha")]
+    {
+        assert_send_sync::<GlobalChaChaRng>();
+        assert_send_sync::<ChaChaRngComponent>();
+    }
+    #[cfg(feature = "compat")]
+    assert_send_sync::<LegacyRngComponent>();
+};
@@ -0,0 +1,343 @@
+use crate::*;
+use std::fmt;
+
+/// The error returned when building a [`Cdf`] from invalid weights.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CdfError {
+    /// No items were given.
+    Empty,
+    /// The weight of the item at `index` is negative, infinite or NaN.
+    InvalidWeight {
+        /// The index of the item with the invalid weight.
+        index: usize,
+        /// The invalid weight.
+        weight: f64,
+    },
+    /// Every weight is zero, or the weights add up to more than an `f64` can represent. For a
+    /// [`TieredOutcome`], the probabilities add up to more than `1.0`.
+    InvalidTotal,
+}
+
+impl fmt::Display for CdfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("cannot build a distribution without any items"),
+            Self::InvalidWeight { index, weight } => write!(
+                f,
+                "item {index} has an invalid weight of {weight}, expected a finite, non-negative weight"
+            ),
+            Self::InvalidTotal => f.write_str(
+                "the weights must add up to a finite total greater than zero",
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CdfError {}
+
+/// A cumulative distribution over weighted items, such as the spawn table of an enemy wave,
+/// which can be queried with a roll made beforehand. This allows recording the roll instead
+/// of the outcome, such as for replays, and resolving it against the table again later.
+///
+/// The weights are normalized on construction, splitting the range of `0.0..1.0` into one
+/// bin per item, sized by its share of the total weight and in the order the items were
+/// given. Each bin includes its lower edge and excludes its upper edge, so a roll landing
+/// exactly on the edge between two bins picks the later one, and items with a weight of zero
+/// have empty bins and are never picked. Lookups are a binary search over the bin edges.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// let wave = Cdf::new([("goblin", 6.0), ("orc", 3.0), ("troll", 1.0)]).unwrap();
+///
+/// // Rolls recorded by a replay resolve to the same spawns every time.
+/// assert_eq!(wave.locate(0.0), &"goblin");
+/// assert_eq!(wave.locate(0.6), &"orc");
+/// assert_eq!(wave.locate(0.95), &"troll");
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let spawns: Vec<_> = (0..10).map(|_| *wave.sample(&mut rng)).collect();
+///
+/// assert_eq!(spawns.len(), 10);
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(
+        try_from = "Vec<(T, f64)>",
+        into = "Vec<(T, f64)>",
+        bound(
+            serialize = "T: Serialize + Clone",
+            deserialize = "T: Deserialize<'de>"
+        )
+    )
+)]
+pub struct Cdf<T> {
+    items: Vec<T>,
+    /// The upper edge of the bin of each item, with the last one being exactly `1.0`.
+    edges: Vec<f64>,
+    /// The index of the last item with a weight greater than zero.
+    last: usize,
+}
+
+impl<T> Cdf<T> {
+    /// Builds a [`Cdf`] from items and their weights, normalizing the weights.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CdfError::Empty`] if no items are given, [`CdfError::InvalidWeight`] for
+    /// the first weight that is negative, infinite or NaN, and [`CdfError::InvalidTotal`] if
+    /// the weights don't add up to a finite total greater than zero.
+    pub fn new(weighted: impl IntoIterator<Item = (T, f64)>) -> Result<Self, CdfError> {
+        let (items, weights): (Vec<T>, Vec<f64>) = weighted.into_iter().unzip();
+
+        if items.is_empty() {
+            return Err(CdfError::Empty);
+        }
+
+        if let Some((index, &weight)) = weights
+            .iter()
+            .enumerate()
+            .find(|(_, weight)| !weight.is_finite() || **weight < 0.0)
+        {
+            return Err(CdfError::InvalidWeight { index, weight });
+        }
+
+        let total: f64 = weights.iter().sum();
+
+        if !total.is_finite() || total <= 0.0 {
+            return Err(CdfError::InvalidTotal);
+        }
+
+        let last = weights
+            .iter()
+            .rposition(|&weight| weight > 0.0)
+            .unwrap_or_default();
+
+        let mut cumulative = 0.0;
+        let mut edges: Vec<f64> = weights
+            .iter()
+            .map(|weight| {
+                cumulative += weight;
+                (cumulative / total).min(1.0)
+            })
+            .collect();
+
+        // Guard against rounding, so that only rolls of 1.0 or more fall past the last bin.
+        edges[last..].fill(1.0);
+
+        Ok(Self { items, edges, last })
+    }
+
+    /// Returns the item whose bin contains `roll`, a value in the range of `0.0..1.0`, such
+    /// as one returned by [`DelegatedRng::f64`]. Rolls below `0.0` or NaN are treated as
+    /// `0.0`, and rolls of `1.0` or above pick the last item with a weight greater than zero.
+    #[must_use]
+    pub fn locate(&self, roll: f64) -> &T {
+        let roll = roll.max(0.0);
+        let index = self.edges.partition_point(|&edge| edge <= roll);
+
+        &self.items[index.min(self.last)]
+    }
+
+    /// Samples an item, the same as calling [`Cdf::locate`] with a roll of
+    /// [`DelegatedRng::f64`].
+    #[inline]
+    pub fn sample(&self, rng: &mut impl DelegatedRng) -> &T {
+        self.locate(rng.f64())
+    }
+
+    /// Returns the normalized probability of the item at `index` being picked, or `None` if
+    /// there is no such item.
+    #[inline]
+    #[must_use]
+    pub fn probability(&self, index: usize) -> Option<f64> {
+        let lower = index
+            .checked_sub(1)
+            .map_or(0.0, |previous| self.edges[previous]);
+
+        self.edges.get(index).map(|&upper| upper - lower)
+    }
+
+    /// Returns the items, in the order their bins appear in.
+    #[inline]
+    #[must_use]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns the number of items.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if there are no items, which is never the case for a valid [`Cdf`].
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<T> TryFrom<Vec<(T, f64)>> for Cdf<T> {
+    type Error = CdfError;
+
+    #[inline]
+    fn try_from(weighted: Vec<(T, f64)>) -> Result<Self, Self::Error> {
+        Self::new(weighted)
+    }
+}
+
+impl<T> From<Cdf<T>> for Vec<(T, f64)> {
+    /// Returns the items along with their normalized weights.
+    fn from(cdf: Cdf<T>) -> Self {
+        let probabilities: Vec<f64> = (0..cdf.len())
+            .filter_map(|index| cdf.probability(index))
+            .collect();
+
+        cdf.items.into_iter().zip(probabilities).collect()
+    }
+}
+
+/// A table of tiered outcomes resolved with a single roll, such as whether an attack misses,
+/// hits, crits or supercrits. Each tier is given with its probability, and any probability
+/// left over when they add up to less than `1.0` goes to the first tier, so that the most
+/// common outcome doesn't need to be worked out by hand. In a table of
+/// `[(Miss, 0.0), (Hit, 0.7), (Crit, 0.2), (Supercrit, 0.05)]`, `Miss` thus gets the
+/// remaining `0.05`.
+///
+/// Tiers split the range of `0.0..1.0` in the order they were given, the same way the bins
+/// of a [`Cdf`] do, with each tier including its lower threshold and excluding its upper one.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Attack {
+///     Miss,
+///     Hit,
+///     Crit,
+/// }
+///
+/// let attack = TieredOutcome::new([(Attack::Miss, 0.0), (Attack::Hit, 0.75), (Attack::Crit, 0.05)])
+///     .unwrap();
+///
+/// // The remaining 0.2 goes to the first tier.
+/// assert_eq!(attack.roll_with(0.1), &Attack::Miss);
+/// assert_eq!(attack.roll_with(0.5), &Attack::Hit);
+/// assert_eq!(attack.roll_with(0.99), &Attack::Crit);
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let outcome = attack.roll(&mut rng);
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(
+        try_from = "Vec<(T, f64)>",
+        into = "Vec<(T, f64)>",
+        bound(
+            serialize = "T: Serialize + Clone",
+            deserialize = "T: Deserialize<'de>"
+        )
+    )
+)]
+pub struct TieredOutcome<T> {
+    tiers: Cdf<T>,
+}
+
+impl<T> TieredOutcome<T> {
+    /// How far the probabilities may add up to more than `1.0`, to allow for rounding in
+    /// authored values such as `0.7 + 0.2 + 0.1`.
+    const TOLERANCE: f64 = 1e-9;
+
+    /// Builds a [`TieredOutcome`] from tiers and their probabilities, giving any probability
+    /// left over to the first tier.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CdfError::Empty`] if no tiers are given, [`CdfError::InvalidWeight`] for
+    /// the first probability that is not within `0.0..=1.0`, and [`CdfError::InvalidTotal`]
+    /// if the probabilities add up to more than `1.0`.
+    pub fn new(tiers: impl IntoIterator<Item = (T, f64)>) -> Result<Self, CdfError> {
+        let mut tiers: Vec<(T, f64)> = tiers.into_iter().collect();
+
+        if tiers.is_empty() {
+            return Err(CdfError::Empty);
+        }
+
+        if let Some((index, &(_, weight))) = tiers
+            .iter()
+            .enumerate()
+            .find(|(_, (_, probability))| !(0.0..=1.0).contains(probability))
+        {
+            return Err(CdfError::InvalidWeight { index, weight });
+        }
+
+        let total: f64 = tiers.iter().map(|(_, probability)| probability).sum();
+
+        if total > 1.0 + Self::TOLERANCE {
+            return Err(CdfError::InvalidTotal);
+        }
+
+        tiers[0].1 += (1.0 - total).max(0.0);
+
+        Cdf::new(tiers).map(|tiers| Self { tiers })
+    }
+
+    /// Rolls for an outcome with a single [`DelegatedRng::f64`] draw.
+    #[inline]
+    pub fn roll(&self, rng: &mut impl DelegatedRng) -> &T {
+        self.roll_with(rng.f64())
+    }
+
+    /// Returns the outcome for a roll made beforehand, a value in the range of `0.0..1.0`,
+    /// such as one recorded by a replay. Rolls outside of the range resolve the same as with
+    /// [`Cdf::locate`].
+    #[inline]
+    #[must_use]
+    pub fn roll_with(&self, value: f64) -> &T {
+        self.tiers.locate(value)
+    }
+
+    /// Returns the probability of the tier at `index`, including any leftover probability for
+    /// the first tier, or `None` if there is no such tier.
+    #[inline]
+    #[must_use]
+    pub fn probability(&self, index: usize) -> Option<f64> {
+        self.tiers.probability(index)
+    }
+
+    /// Returns the tiers, in the order they were given.
+    #[inline]
+    #[must_use]
+    pub fn tiers(&self) -> &[T] {
+        self.tiers.items()
+    }
+}
+
+impl<T> TryFrom<Vec<(T, f64)>> for TieredOutcome<T> {
+    type Error = CdfError;
+
+    #[inline]
+    fn try_from(tiers: Vec<(T, f64)>) -> Result<Self, Self::Error> {
+        Self::new(tiers)
+    }
+}
+
+impl<T> From<TieredOutcome<T>> for Vec<(T, f64)> {
+    /// Returns the tiers along with their probabilities, including any leftover probability
+    /// for the first tier.
+    #[inline]
+    fn from(outcome: TieredOutcome<T>) -> Self {
+        outcome.tiers.into()
+    }
+}
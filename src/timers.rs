@@ -0,0 +1,444 @@
+use crate::*;
+#[cfg(feature = "wyrand")]
+use bevy_time::Time;
+use bevy_time::TimerMode;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::time::Duration;
+
+/// A timer with a random duration, drawn from a range of seconds. When a repeating timer
+/// finishes, the duration for the next cycle is drawn anew, making it useful for spawners
+/// and other recurring events that shouldn't happen at a fixed interval.
+///
+/// The first duration is drawn on the first call to [`JitteredTimer::tick`], from the RNG
+/// given to it, so that constructing the timer doesn't require access to an RNG. Until then,
+/// the timer has a zero duration.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Spawner(JitteredTimer);
+///
+/// fn setup_spawner(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+///     commands.spawn((
+///         Spawner(JitteredTimer::new(1.5..3.0, TimerMode::Repeating)),
+///         RngComponent::from(&mut global),
+///     ));
+/// }
+///
+/// fn tick_spawners(time: Res<Time>, mut q_spawners: Query<(&mut Spawner, &mut RngComponent)>) {
+///     for (mut spawner, mut rng) in q_spawners.iter_mut() {
+///         if spawner.0.tick(time.delta(), &mut *rng).just_finished() {
+///             // Spawn something
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct JitteredTimer {
+    range: Range<f32>,
+    repeating: bool,
+    duration: Duration,
+    elapsed: Duration,
+    primed: bool,
+    times_finished_this_tick: u32,
+}
+
+impl JitteredTimer {
+    /// The most times a repeating timer can finish within a single [`JitteredTimer::tick`].
+    /// Should a `delta` span more cycles than this, such as a long frame with a tiny range of
+    /// durations, the excess time is dropped, so that a tick never spins on redrawing.
+    pub const MAX_CYCLES_PER_TICK: u32 = 1024;
+
+    /// Creates a new [`JitteredTimer`], with durations drawn from the given range of seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty or its start is negative, or if its end is not finite or
+    /// too large for a [`Duration`].
+    #[inline]
+    #[must_use]
+    pub fn new(range: Range<f32>, mode: TimerMode) -> Self {
+        assert!(
+            range.start >= 0.0 && range.start < range.end,
+            "timer range must not be empty nor negative"
+        );
+        assert!(
+            Duration::try_from_secs_f32(range.end).is_ok(),
+            "timer range must be finite and fit in a Duration"
+        );
+
+        Self {
+            range,
+            repeating: mode == TimerMode::Repeating,
+            duration: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            primed: false,
+            times_finished_this_tick: 0,
+        }
+    }
+
+    /// Advances the timer by `delta`. Each time a repeating timer finishes, the next
+    /// duration is drawn from `rng`, with any excess time carried over into the next cycle,
+    /// up to [`JitteredTimer::MAX_CYCLES_PER_TICK`] cycles.
+    pub fn tick(&mut self, delta: Duration, rng: &mut impl DelegatedRng) -> &Self {
+        if !self.primed {
+            self.duration = self.draw(rng);
+            self.primed = true;
+        }
+
+        self.times_finished_this_tick = 0;
+
+        if !self.repeating {
+            if self.elapsed < self.duration {
+                self.elapsed = (self.elapsed + delta).min(self.duration);
+
+                if self.elapsed == self.duration {
+                    self.times_finished_this_tick = 1;
+                }
+            }
+
+            return self;
+        }
+
+        self.elapsed += delta;
+
+        while self.elapsed >= self.duration {
+            self.elapsed -= self.duration;
+            self.times_finished_this_tick += 1;
+            self.duration = self.draw(rng);
+
+            if self.times_finished_this_tick == Self::MAX_CYCLES_PER_TICK {
+                self.elapsed = Duration::ZERO;
+                break;
+            }
+        }
+
+        self
+    }
+
+    /// Returns whether the timer has finished. A repeating timer is only finished on the
+    /// tick it wraps around, while a non-repeating timer stays finished once it has.
+    #[inline]
+    #[must_use]
+    pub fn finished(&self) -> bool {
+        if self.repeating {
+            self.just_finished()
+        } else {
+            self.primed && self.elapsed >= self.duration
+        }
+    }
+
+    /// Returns whether the timer finished during the last tick.
+    #[inline]
+    #[must_use]
+    pub fn just_finished(&self) -> bool {
+        self.times_finished_this_tick > 0
+    }
+
+    /// Returns how many times the timer finished during the last tick.
+    #[inline]
+    #[must_use]
+    pub fn times_finished_this_tick(&self) -> u32 {
+        self.times_finished_this_tick
+    }
+
+    /// Returns the time remaining until the timer next finishes.
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.duration.saturating_sub(self.elapsed)
+    }
+
+    /// Returns the time elapsed in the current cycle.
+    #[inline]
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the duration of the current cycle.
+    #[inline]
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the range of seconds durations are drawn from.
+    #[inline]
+    #[must_use]
+    pub fn range(&self) -> Range<f32> {
+        self.range.clone()
+    }
+
+    /// Returns the [`TimerMode`] of the timer.
+    #[inline]
+    #[must_use]
+    pub fn mode(&self) -> TimerMode {
+        if self.repeating {
+            TimerMode::Repeating
+        } else {
+            TimerMode::Once
+        }
+    }
+
+    fn draw(&self, rng: &mut impl DelegatedRng) -> Duration {
+        let Range { start, end } = self.range;
+
+        // Never yield a zero duration, so repeating timers can always make progress.
+        Duration::from_secs_f32(start + (end - start) * rng.f32()).max(Duration::from_nanos(1))
+    }
+}
+
+/// A recurring interval of `base ± jitter`, firing when the interval elapses and drawing the
+/// next one anew, such as for rate limiting spawners without them falling into a fixed
+/// rhythm. The `Tag` type parameter distinguishes intervals from one another, so that each
+/// can be its own [`Resource`], as registered by [`RngPlugin::with_interval_resource`] and
+/// checked with the [`interval_elapsed`](crate::conditions::interval_elapsed) run condition.
+///
+/// Unlike a repeating [`JitteredTimer`], the interval never catches up: it fires at most once
+/// per [`RandomInterval::tick`], and when a single `delta` spans several intervals, the excess
+/// time is dropped and the next interval starts afresh. A long frame thus never causes a burst
+/// of spawns.
+///
+/// As with [`JitteredTimer`], the first interval is drawn on the first tick.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+/// use std::time::Duration;
+///
+/// #[derive(Component)]
+/// struct Spawner(RandomInterval);
+///
+/// fn tick_spawners(time: Res<Time>, mut q_spawners: Query<(&mut Spawner, &mut RngComponent)>) {
+///     for (mut spawner, mut rng) in q_spawners.iter_mut() {
+///         if spawner.0.tick(time.delta(), &mut *rng) {
+///             // Spawn something, every 2 seconds give or take half a second
+///         }
+///     }
+/// }
+///
+/// let spawner = Spawner(RandomInterval::new(
+///     Duration::from_secs(2),
+///     Duration::from_millis(500),
+/// ));
+/// ```
+#[derive(Resource, Component)]
+pub struct RandomInterval<Tag: Send + Sync + 'static = ()> {
+    base: Duration,
+    jitter: Duration,
+    interval: Duration,
+    elapsed: Duration,
+    primed: bool,
+    just_elapsed: bool,
+    _tag: PhantomData<fn() -> Tag>,
+}
+
+impl<Tag: Send + Sync + 'static> RandomInterval<Tag> {
+    /// Creates a new [`RandomInterval`], with intervals drawn uniformly from
+    /// `base - jitter..=base + jitter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` is zero or `jitter` is greater than `base`.
+    #[inline]
+    #[must_use]
+    pub fn new(base: Duration, jitter: Duration) -> Self {
+        assert!(
+            !base.is_zero() && jitter <= base,
+            "interval base must not be zero nor less than its jitter"
+        );
+
+        Self {
+            base,
+            jitter,
+            interval: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            primed: false,
+            just_elapsed: false,
+            _tag: PhantomData,
+        }
+    }
+
+    /// Advances the interval by `delta`, returning whether it elapsed. When it does, the next
+    /// interval is drawn from `rng`, and any excess time is dropped rather than carried over.
+    pub fn tick(&mut self, delta: Duration, rng: &mut impl DelegatedRng) -> bool {
+        if !self.primed {
+            self.interval = self.draw(rng);
+            self.primed = true;
+        }
+
+        self.elapsed += delta;
+        self.just_elapsed = self.elapsed >= self.interval;
+
+        if self.just_elapsed {
+            self.elapsed = Duration::ZERO;
+            self.interval = self.draw(rng);
+        }
+
+        self.just_elapsed
+    }
+
+    /// Returns whether the interval elapsed during the last tick.
+    #[inline]
+    #[must_use]
+    pub fn just_elapsed(&self) -> bool {
+        self.just_elapsed
+    }
+
+    /// Returns the time elapsed in the current interval.
+    #[inline]
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the length of the current interval, which is zero until the first tick.
+    #[inline]
+    #[must_use]
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Returns the time remaining until the interval next elapses.
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.interval.saturating_sub(self.elapsed)
+    }
+
+    /// Returns the base length of the intervals.
+    #[inline]
+    #[must_use]
+    pub fn base(&self) -> Duration {
+        self.base
+    }
+
+    /// Returns how far the intervals may deviate from their base length.
+    #[inline]
+    #[must_use]
+    pub fn jitter(&self) -> Duration {
+        self.jitter
+    }
+
+    fn draw(&self, rng: &mut impl DelegatedRng) -> Duration {
+        let nanos = |duration: Duration| u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+
+        let min = nanos(self.base - self.jitter);
+        let max = nanos(self.base.saturating_add(self.jitter));
+
+        // Drawn as whole nanoseconds, so intervals don't depend on float rounding, but never
+        // zero, so the interval can't fire on every tick.
+        Duration::from_nanos(rng.u64(min..=max)).max(Duration::from_nanos(1))
+    }
+}
+
+impl<Tag: Send + Sync + 'static> Clone for RandomInterval<Tag> {
+    fn clone(&self) -> Self {
+        Self {
+            _tag: PhantomData,
+            ..*self
+        }
+    }
+}
+
+impl<Tag: Send + Sync + 'static> PartialEq for RandomInterval<Tag> {
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base
+            && self.jitter == other.jitter
+            && self.interval == other.interval
+            && self.elapsed == other.elapsed
+            && self.primed == other.primed
+            && self.just_elapsed == other.just_elapsed
+    }
+}
+
+impl<Tag: Send + Sync + 'static> std::fmt::Debug for RandomInterval<Tag> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RandomInterval")
+            .field("base", &self.base)
+            .field("jitter", &self.jitter)
+            .field("interval", &self.interval)
+            .field("elapsed", &self.elapsed)
+            .field("just_elapsed", &self.just_elapsed)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Ticks the [`RandomInterval`] resource of `Tag` by the [`Time`] delta, drawing its
+/// intervals from its own fork of the [`GlobalRng`].
+#[cfg(feature = "wyrand")]
+pub(crate) fn tick_random_interval<Tag: Send + Sync + 'static>(
+    time: Res<'_, Time>,
+    mut rng: Local<'_, ForkedRng<GlobalRng>>,
+    mut interval: ResMut<'_, RandomInterval<Tag>>,
+) {
+    interval.tick(time.delta(), &mut *rng);
+}
+
+/// An [`Rng`] forked from the [`StrictGlobalRng`] on creation, the same as a [`ForkedRng`]
+/// would from a [`GlobalRng`] in the same state.
+#[cfg(feature = "wyrand")]
+pub(crate) struct StrictForkedRng(Rng);
+
+#[cfg(feature = "wyrand")]
+impl FromWorld for StrictForkedRng {
+    fn from_world(world: &mut World) -> Self {
+        Self(world.resource_mut::<StrictGlobalRng>().fork())
+    }
+}
+
+#[cfg(feature = "wyrand")]
+impl DelegatedRng for StrictForkedRng {
+    type Source = Rng;
+
+    #[inline]
+    fn get_mut(&mut self) -> &mut Self::Source {
+        &mut self.0
+    }
+}
+
+/// Ticks the [`RandomInterval`] resource of `Tag` by the [`Time`] delta, drawing its
+/// intervals from its own fork of the [`StrictGlobalRng`], in strict mode.
+#[cfg(feature = "wyrand")]
+pub(crate) fn tick_random_interval_strict<Tag: Send + Sync + 'static>(
+    time: Res<'_, Time>,
+    mut rng: Local<'_, StrictForkedRng>,
+    mut interval: ResMut<'_, RandomInterval<Tag>>,
+) {
+    interval.tick(time.delta(), &mut *rng);
+}
+
+/// Registers a [`RngPlugin::with_interval_resource`] call onto an [`App`], inserting its
+/// resource only if asked to, and forking from the [`StrictGlobalRng`] in strict mode.
+#[cfg(feature = "wyrand")]
+pub(crate) type IntervalRegistration = Box<dyn Fn(&mut App, bool, bool) + Send + Sync>;
+
+/// Returns the registration of a [`RandomInterval`] resource of `Tag`, and of the system
+/// ticking it.
+#[cfg(feature = "wyrand")]
+pub(crate) fn interval_resource<Tag: Send + Sync + 'static>(
+    base: Duration,
+    jitter: Duration,
+) -> IntervalRegistration {
+    // Validated eagerly, so that a bad configuration panics at the builder call.
+    let interval = RandomInterval::<Tag>::new(base, jitter);
+
+    Box::new(move |app: &mut App, insert_resource: bool, strict: bool| {
+        if insert_resource && !app.world().contains_resource::<RandomInterval<Tag>>() {
+            app.insert_resource(interval.clone());
+        }
+
+        if strict {
+            app.add_systems(PreUpdate, tick_random_interval_strict::<Tag>);
+        } else {
+            app.add_systems(PreUpdate, tick_random_interval::<Tag>);
+        }
+    })
+}
@@ -1,8 +1,19 @@
 use crate::*;
-use std::{fmt::Debug, ops::RangeBounds};
+use std::{
+    fmt::Debug,
+    ops::{Range, RangeBounds},
+};
+use tracing::debug;
+
+#[cfg(feature = "bevy_color")]
+use bevy_color::{Color, Mix};
+#[cfg(feature = "bevy_color")]
+use std::ops::RangeInclusive;
 
 #[cfg(feature = "rand")]
 use turborand::prelude::RandBorrowed;
+#[cfg(feature = "uuid")]
+use uuid::Uuid;
 
 /// A trait for applying to [`Component`]s and Resources that wrap a [`TurboCore`] RNG source.
 ///
@@ -18,6 +29,16 @@ use turborand::prelude::RandBorrowed;
 /// not always the most ergonomic way to use the RNG, so getting to the inner [`TurboCore`] source via
 /// [`DelegatedRng::get_mut`] is the other manner to use the RNG within a system, giving access to
 /// `&self` methods of [`TurboRand`].
+///
+/// In debug builds, the delegated methods taking a range check it before delegating, so that an
+/// empty range panics with a message naming the wrapper and the method, such as
+/// `RngComponent::u32 called with empty range 5..5`. Release builds skip the check.
+///
+/// Every delegated method goes through [`DelegatedRng::get_mut`] on its own, which for wrappers
+/// like the global RNGs with the `diagnostics` feature enabled means extra bookkeeping per draw.
+/// For hot loops drawing many values at once, [`DelegatedRng::with_source`] borrows the source
+/// once for the whole loop instead, as the bulk-fill methods such as
+/// [`DelegatedRng::fill_u32_range`] do.
 pub trait DelegatedRng
 where
     Self::Source: Default
@@ -59,6 +80,92 @@ where
     /// ```
     fn get_mut(&mut self) -> &mut Self::Source;
 
+    /// Returns the [`RngAuditLog`] that the probability-bearing calls made through this RNG
+    /// are recorded into, if it was handed one. Only the wrappers provided by this crate can
+    /// be handed a log, see [`RngAuditLog`] for when that happens.
+    #[cfg(feature = "audit")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "audit")))]
+    #[inline]
+    fn audit_log(&self) -> Option<&RngAuditLog> {
+        None
+    }
+
+    /// Calls `f` with the internal [`TurboRand`] source, borrowing it once for however many
+    /// values `f` draws, and returns its output. Draws made through the source produce the
+    /// exact same stream as the same draws made through the delegated methods, so hot loops
+    /// can switch to this without changing their results.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    /// let mut delegated = rng.clone();
+    ///
+    /// let sum: u64 = rng.with_source(|source| (0..1000).map(|_| u64::from(source.u32(..))).sum());
+    /// let expected: u64 = (0..1000).map(|_| u64::from(delegated.u32(..))).sum();
+    ///
+    /// assert_eq!(sum, expected);
+    /// ```
+    #[inline]
+    fn with_source<R>(&mut self, f: impl FnOnce(&mut Self::Source) -> R) -> R {
+        f(self.get_mut())
+    }
+
+    /// Returns the internal [`TurboRand`] source of an RNG accessed through change detection,
+    /// such as a [`Mut`] from a query or a [`ResMut`], flagging the RNG as changed. This is
+    /// what calling [`DelegatedRng::get_mut`] or any delegated method through a [`Mut`] does
+    /// implicitly, spelled out for when it sits next to [`DelegatedRng::get_untracked`].
+    #[inline]
+    fn get_tracked<'a>(this: &'a mut impl DetectChangesMut<Inner = Self>) -> &'a mut Self::Source
+    where
+        Self: Sized + 'a,
+    {
+        this.set_changed();
+        this.bypass_change_detection().get_mut()
+    }
+
+    /// Returns the internal [`TurboRand`] source of an RNG accessed through change detection,
+    /// such as a [`Mut`] from a query or a [`ResMut`], **without** flagging the RNG as
+    /// changed. Useful for systems that only draw from an RNG some of the time, so that the
+    /// RNG is only flagged as changed, such as for replication, when a draw actually happens,
+    /// by calling [`DetectChangesMut::set_changed`].
+    ///
+    /// Drawing from the returned source still advances its state, so skipping
+    /// [`DetectChangesMut::set_changed`] after a draw hides a real change from anything relying
+    /// on change detection.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Stunned(bool);
+    ///
+    /// fn recover(mut q_stunned: Query<(&mut RngComponent, &mut Stunned)>) {
+    ///     for (mut rng, mut stunned) in q_stunned.iter_mut() {
+    ///         if !stunned.0 {
+    ///             // Not drawing, so the RNG is left unchanged.
+    ///             continue;
+    ///         }
+    ///
+    ///         let source = RngComponent::get_untracked(&mut rng);
+    ///
+    ///         stunned.0 = !source.chance(0.25);
+    ///         rng.set_changed();
+    ///     }
+    /// }
+    /// # App::new().add_systems(Update, recover);
+    /// ```
+    #[inline]
+    fn get_untracked<'a>(this: &'a mut impl DetectChangesMut<Inner = Self>) -> &'a mut Self::Source
+    where
+        Self: Sized + 'a,
+    {
+        this.bypass_change_detection().get_mut()
+    }
+
     /// Forks the [`DelegatedRng`] source with a random seed/state, based on the original
     /// source's seed. Delegated version of [`ForkableCore::fork`].
     #[inline]
@@ -67,6 +174,58 @@ where
         self.get_mut().fork()
     }
 
+    /// Forks the [`DelegatedRng`] source into an owned [`SendRng`], for moving into an async
+    /// task while the [`DelegatedRng`] itself stays in the ECS. Yields the same source as
+    /// [`DelegatedRng::fork`] would.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut global = GlobalRng::with_seed(5);
+    /// let mut expected = GlobalRng::with_seed(5);
+    ///
+    /// let rng = global.fork_send();
+    ///
+    /// let roll = std::thread::spawn(move || rng.u32(1..=6)).join().unwrap();
+    ///
+    /// assert_eq!(roll, expected.fork().u32(1..=6));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn fork_send(&mut self) -> SendRng<Self::Source>
+    where
+        Self::Source: Send + 'static,
+    {
+        SendRng::new(self.fork())
+    }
+
+    /// Forks `n` new sources in one go, yielding the exact same sources as calling
+    /// [`DelegatedRng::fork`] `n` times. Useful for spawning many entities, as the sources
+    /// can be forked up front and then handed out without needing access to `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut global = GlobalRng::with_seed(5);
+    ///
+    /// let components: Vec<RngComponent> = global
+    ///     .fork_many(3)
+    ///     .into_iter()
+    ///     .map(RngComponent::from)
+    ///     .collect();
+    ///
+    /// assert_eq!(components.len(), 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn fork_many(&mut self, n: usize) -> Vec<Self::Source> {
+        let rng = self.get_mut();
+
+        (0..n).map(|_| rng.fork()).collect()
+    }
+
     /// Reseeds the [`DelegatedRng`] with a new seed/state, resolving to the
     /// seed type of the underlying [`SeededCore`] instance.
     #[inline]
@@ -74,6 +233,92 @@ where
         self.get_mut().reseed(seed);
     }
 
+    /// Returns the full state of the underlying source as plain bytes, for use in
+    /// custom save formats that don't make use of `serde`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let saved = rng.state_bytes();
+    /// let expected = rng.u64(..);
+    ///
+    /// rng.restore_from_bytes(&saved).unwrap();
+    ///
+    /// assert_eq!(rng.u64(..), expected);
+    /// ```
+    #[cfg(all(feature = "state-bytes", any(feature = "wyrand", feature = "chacha")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "state-bytes")))]
+    #[inline]
+    fn state_bytes(&mut self) -> Vec<u8>
+    where
+        Self::Source: StateBytes,
+    {
+        self.get_mut().state_bytes()
+    }
+
+    /// Restores the underlying source to the state described by the given bytes,
+    /// as obtained from [`DelegatedRng::state_bytes`]. If the bytes are not valid,
+    /// the current state is left untouched.
+    #[cfg(all(feature = "state-bytes", any(feature = "wyrand", feature = "chacha")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "state-bytes")))]
+    #[inline]
+    fn restore_from_bytes(&mut self, bytes: &[u8]) -> Result<(), RngStateError>
+    where
+        Self::Source: StateBytes,
+    {
+        *self.get_mut() = Self::Source::from_state_bytes(bytes)?;
+        Ok(())
+    }
+
+    /// Returns a [`RngStateDiff`] describing how the underlying source changed since it was
+    /// in the state of `baseline`. When both are on the same stream, the diff only records
+    /// how far the source advanced, instead of its full state.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    /// let baseline = rng.clone();
+    ///
+    /// rng.u64(..);
+    ///
+    /// assert_eq!(rng.diff(&baseline).change, RngStateChange::Advanced { draws: 1 });
+    /// ```
+    #[cfg(all(feature = "state-bytes", any(feature = "wyrand", feature = "chacha")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "state-bytes")))]
+    #[inline]
+    #[must_use]
+    fn diff(&mut self, baseline: &Self) -> RngStateDiff
+    where
+        Self: Clone,
+        Self::Source: StateDiff + PartialEq,
+    {
+        RngStateDiff::between(baseline.clone().get_mut(), self.get_mut())
+    }
+
+    /// Applies a [`RngStateDiff`] obtained from [`DelegatedRng::diff`], bringing the
+    /// underlying source from the diff's baseline state to the state it describes. If the
+    /// diff can't be applied, the current state is left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RngDiffError::BaselineMismatch`] if the source is not in the baseline state
+    /// the diff was made against, or [`RngDiffError::InvalidState`] if the diff does not
+    /// describe a valid state.
+    #[cfg(all(feature = "state-bytes", any(feature = "wyrand", feature = "chacha")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "state-bytes")))]
+    #[inline]
+    fn apply_diff(&mut self, diff: &RngStateDiff) -> Result<(), RngDiffError>
+    where
+        Self::Source: StateDiff,
+    {
+        diff.apply_to(self.get_mut())
+    }
+
     /// Return a compatibility shim for working with crates from the `rand`
     /// ecosystem.
     #[cfg(feature = "rand")]
@@ -83,225 +328,1414 @@ where
         RandBorrowed::from(self.get_mut())
     }
 
-    delegate_rng_trait!(
-        u128,
-        u128,
-        impl RangeBounds<u128>,
-        "Delegated [`TurboRand::u128`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        u64,
-        u64,
-        impl RangeBounds<u64>,
-        "Delegated [`TurboRand::u64`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        u32,
-        u32,
-        impl RangeBounds<u32>,
-        "Delegated [`TurboRand::u32`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        u16,
-        u16,
-        impl RangeBounds<u16>,
-        "Delegated [`TurboRand::u16`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        u8,
-        u8,
-        impl RangeBounds<u8>,
-        "Delegated [`TurboRand::u8`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        i128,
-        i128,
-        impl RangeBounds<i128>,
-        "Delegated [`TurboRand::i128`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        i64,
-        i64,
-        impl RangeBounds<i64>,
-        "Delegated [`TurboRand::i64`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        i32,
-        i32,
-        impl RangeBounds<i32>,
-        "Delegated [`TurboRand::i32`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        i16,
-        i16,
-        impl RangeBounds<i16>,
-        "Delegated [`TurboRand::i16`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        i8,
-        i8,
-        impl RangeBounds<i8>,
-        "Delegated [`TurboRand::i8`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        usize,
-        usize,
-        impl RangeBounds<usize>,
-        "Delegated [`TurboRand::usize`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        isize,
-        isize,
-        impl RangeBounds<isize>,
-        "Delegated [`TurboRand::isize`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        index,
-        usize,
-        impl RangeBounds<usize>,
-        "Delegated [`TurboRand::index`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        chance,
-        bool,
-        f64,
-        "Delegated [`TurboRand::chance`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        char,
-        char,
-        impl RangeBounds<char>,
-        "Delegated [`TurboRand::char`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        digit,
-        char,
-        u8,
-        "Delegated [`TurboRand::digit`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        alphabetic,
-        char,
-        "Delegated [`TurboRand::alphabetic`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        alphanumeric,
-        char,
-        "Delegated [`TurboRand::alphanumeric`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        lowercase,
-        char,
-        "Delegated [`TurboRand::lowercase`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        uppercase,
-        char,
-        "Delegated [`TurboRand::uppercase`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        bool,
-        bool,
-        "Delegated [`TurboRand::bool`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        f64,
-        f64,
-        "Delegated [`TurboRand::f64`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        f32,
-        f32,
-        "Delegated [`TurboRand::f32`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        f64_normalized,
-        f64,
-        "Delegated [`TurboRand::f64_normalized`] method from [`TurboRand`]."
-    );
-    delegate_rng_trait!(
-        f32_normalized,
-        f32,
-        "Delegated [`TurboRand::f32_normalized`] method from [`TurboRand`]."
-    );
+    /// Forks the [`DelegatedRng`] source into an owned compatibility shim for working with
+    /// crates from the `rand` ecosystem. Unlike [`DelegatedRng::as_rand`], the returned
+    /// [`RandOwned`] does not borrow `self`, so it can be stored or passed by value.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    /// let mut owned = rng.fork_as_rand();
+    ///
+    /// let mut deck: Vec<u32> = (0..10).collect();
+    ///
+    /// deck.shuffle(&mut owned);
+    ///
+    /// assert_ne!(deck, (0..10).collect::<Vec<u32>>());
+    /// ```
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    #[inline]
+    #[must_use]
+    fn fork_as_rand(&mut self) -> RandOwned<Self::Source> {
+        RandOwned::from(self.fork())
+    }
+
+    delegated_methods!(delegate_rng_trait);
 
-    /// Delegated [`TurboCore::fill_bytes`] method from [`TurboCore`].
+    /// Returns a random value within `bounds`, for any type implementing [`SampleRange`],
+    /// dispatching to the typed method of that type, such as [`DelegatedRng::u32`]. Allows
+    /// writing code that is generic over the sampled type.
+    ///
+    /// Float ranges must be bounded on both ends, and respect whether each bound is included,
+    /// see [`DelegatedRng::f32_range`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, or if a float range is unbounded.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// fn roll_stat<T: SampleRange + PartialOrd + Copy>(
+    ///     rng: &mut impl DelegatedRng,
+    ///     min: T,
+    ///     max: T,
+    /// ) -> T {
+    ///     rng.range(min..=max)
+    /// }
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let strength = roll_stat(&mut rng, 3u8, 18);
+    /// let speed = roll_stat(&mut rng, 0.5f32, 1.5);
+    ///
+    /// assert!((3..=18).contains(&strength));
+    /// assert!((0.5..=1.5).contains(&speed));
+    /// assert!((0..10).contains(&rng.range(0..10)));
+    /// ```
     #[inline]
-    fn fill_bytes(&mut self, buffer: &mut [u8]) {
-        self.get_mut().fill_bytes(buffer);
+    #[cfg_attr(debug_assertions, track_caller)]
+    fn range<T: SampleRange>(&mut self, bounds: impl RangeBounds<T>) -> T {
+        #[cfg(debug_assertions)]
+        crate::range::debug_check_range::<Self, _>("range", &bounds);
+
+        T::sample_range(self.get_mut(), bounds)
     }
 
-    /// Delegated [`TurboRand::shuffle`] method from [`TurboRand`].
+    /// Returns a random `f32` within `bounds`, respecting whether each bound is included.
+    /// An excluded bound is never returned, so `0.0..1.0` is safe to feed into functions
+    /// such as `acos` or `sqrt(1.0 - x)`, while an included bound can be returned exactly.
+    /// The same as [`DelegatedRng::range`] for `f32`.
+    ///
+    /// The full range `..` is rejected rather than defined as `0.0..1.0`, as there is no
+    /// meaningful uniform distribution over all floats, see [`DelegatedRng::f32`] for the
+    /// unit range instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, such as `1.0..1.0`, if either bound is NaN, or if
+    /// either end is unbounded.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let cosine = rng.f32_range(-1.0..1.0);
+    ///
+    /// assert!(!cosine.acos().is_nan());
+    /// assert!((0.0..=1.0).contains(&rng.f32_range(0.0..=1.0)));
+    /// ```
     #[inline]
-    fn shuffle<T>(&mut self, slice: &mut [T]) {
-        self.get_mut().shuffle(slice);
+    #[cfg_attr(debug_assertions, track_caller)]
+    fn f32_range(&mut self, bounds: impl RangeBounds<f32>) -> f32 {
+        #[cfg(debug_assertions)]
+        crate::range::debug_check_range::<Self, _>("f32_range", &bounds);
+
+        f32::sample_range(self.get_mut(), bounds)
     }
 
-    /// Delegated [`TurboRand::partial_shuffle`] method from [`TurboRand`].
+    /// Returns a random `f64` within `bounds`, respecting whether each bound is included,
+    /// the same way as [`DelegatedRng::f32_range`] does for `f32`. The same as
+    /// [`DelegatedRng::range`] for `f64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, such as `1.0..1.0`, if either bound is NaN, or if
+    /// either end is unbounded.
     #[inline]
-    fn partial_shuffle<'a, T>(
-        &mut self,
-        slice: &'a mut [T],
-        amount: usize,
-    ) -> (&'a mut [T], &'a mut [T]) {
-        self.get_mut().partial_shuffle(slice, amount)
+    #[cfg_attr(debug_assertions, track_caller)]
+    fn f64_range(&mut self, bounds: impl RangeBounds<f64>) -> f64 {
+        #[cfg(debug_assertions)]
+        crate::range::debug_check_range::<Self, _>("f64_range", &bounds);
+
+        f64::sample_range(self.get_mut(), bounds)
     }
 
-    /// Delegated [`TurboRand::sample`] method from [`TurboRand`].
+    /// Returns a random `f32` interpolated between `a` and `b`, in the range of `a..b`. Works
+    /// just the same if `a` is greater than `b`, and always returns exactly `a` if both are
+    /// equal. If either input is NaN, NaN is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let value = rng.lerp_f32(10.0, -10.0);
+    ///
+    /// assert!(value > -10.0 && value <= 10.0);
+    /// ```
     #[inline]
-    fn sample<'a, T>(&mut self, list: &'a [T]) -> Option<&'a T> {
-        self.get_mut().sample(list)
+    fn lerp_f32(&mut self, a: f32, b: f32) -> f32 {
+        a + (b - a) * self.get_mut().f32()
     }
 
-    /// Delegated [`TurboRand::sample_iter`] method from [`TurboRand`].
+    /// Returns a random `f64` interpolated between `a` and `b`, in the range of `a..b`. Works
+    /// just the same if `a` is greater than `b`, and always returns exactly `a` if both are
+    /// equal. If either input is NaN, NaN is returned.
     #[inline]
-    fn sample_iter<T: Iterator>(&mut self, list: T) -> Option<T::Item> {
-        self.get_mut().sample_iter(list)
+    fn lerp_f64(&mut self, a: f64, b: f64) -> f64 {
+        a + (b - a) * self.get_mut().f64()
     }
 
-    /// Delegated [`TurboRand::sample_mut`] method from [`TurboRand`].
+    /// Fills `out` with random `f32`s in the range of `range.start..range.end`, each the same
+    /// as [`DelegatedRng::lerp_f32`] would return for the range, but accessing the source
+    /// only once for the whole slice. Produces the exact same stream as calling
+    /// [`DelegatedRng::lerp_f32`] for each value in turn, for the same RNG state.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    /// let mut particles = [0.0; 1024];
+    ///
+    /// rng.fill_f32_range(&mut particles, -1.0..1.0);
+    ///
+    /// assert!(particles.iter().all(|value| (-1.0..=1.0).contains(value)));
+    /// ```
     #[inline]
-    fn sample_mut<'a, T>(&mut self, list: &'a mut [T]) -> Option<&'a mut T> {
-        self.get_mut().sample_mut(list)
+    fn fill_f32_range(&mut self, out: &mut [f32], range: Range<f32>) {
+        let span = range.end - range.start;
+
+        self.with_source(|source| {
+            for value in out {
+                *value = range.start + span * source.f32();
+            }
+        });
     }
 
-    /// Delegated [`TurboRand::sample_multiple`] method from [`TurboRand`].
+    /// Fills `out` with random `u32`s within `range`, accessing the source only once for the
+    /// whole slice. Produces the exact same stream as calling [`DelegatedRng::u32`] for each
+    /// value in turn, for the same RNG state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty and `out` isn't.
     #[inline]
-    fn sample_multiple<'a, T>(&mut self, list: &'a [T], amount: usize) -> Vec<&'a T> {
-        self.get_mut().sample_multiple(list, amount)
+    fn fill_u32_range(&mut self, out: &mut [u32], range: impl RangeBounds<u32>) {
+        let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+
+        self.with_source(|source| {
+            for value in out {
+                *value = source.u32(bounds);
+            }
+        });
     }
 
-    /// Delegated [`TurboRand::sample_multiple_iter`] method from [`TurboRand`].
+    /// Fills `out` with random [`Vec2`]s, with their `x` and `y` components in the ranges of
+    /// `x_range` and `y_range` respectively, accessing the source only once for the whole
+    /// slice. Each vector draws its `x` component before its `y` component, producing the
+    /// exact same stream as calling [`DelegatedRng::lerp_f32`] for each component in turn,
+    /// for the same RNG state.
+    #[cfg(feature = "bevy_math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
     #[inline]
-    fn sample_multiple_iter<T: Iterator>(&mut self, list: T, amount: usize) -> Vec<T::Item> {
-        self.get_mut().sample_multiple_iter(list, amount)
+    fn fill_vec2(&mut self, out: &mut [Vec2], x_range: Range<f32>, y_range: Range<f32>) {
+        let x_span = x_range.end - x_range.start;
+        let y_span = y_range.end - y_range.start;
+
+        self.with_source(|source| {
+            for value in out {
+                let x = x_range.start + x_span * source.f32();
+                let y = y_range.start + y_span * source.f32();
+
+                *value = Vec2::new(x, y);
+            }
+        });
     }
 
-    /// Delegated [`TurboRand::sample_multiple_mut`] method from [`TurboRand`].
+    /// Returns a random `f32` in the range of `-magnitude..=magnitude`. A zero magnitude
+    /// always returns exactly `0.0`, and a NaN magnitude returns NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let wobble = rng.f32_symmetric(0.5);
+    ///
+    /// assert!((-0.5..=0.5).contains(&wobble));
+    /// ```
     #[inline]
-    fn sample_multiple_mut<'a, T>(&mut self, list: &'a mut [T], amount: usize) -> Vec<&'a mut T> {
-        self.get_mut().sample_multiple_mut(list, amount)
+    fn f32_symmetric(&mut self, magnitude: f32) -> f32 {
+        // Adding a positive zero turns a negative zero result into a positive one.
+        self.get_mut().f32_normalized() * magnitude + 0.0
     }
 
-    /// Delegated [`TurboRand::weighted_sample`] method from [`TurboRand`].
+    /// Returns a random `f64` in the range of `-magnitude..=magnitude`. A zero magnitude
+    /// always returns exactly `0.0`, and a NaN magnitude returns NaN.
     #[inline]
-    fn weighted_sample<'a, T, F>(&mut self, list: &'a [T], weight_sampler: F) -> Option<&'a T>
-    where
-        F: Fn((&T, usize)) -> f64,
-    {
-        self.get_mut().weighted_sample(list, weight_sampler)
+    fn f64_symmetric(&mut self, magnitude: f64) -> f64 {
+        // Adding a positive zero turns a negative zero result into a positive one.
+        self.get_mut().f64_normalized() * magnitude + 0.0
     }
 
-    /// Delegated [`TurboRand::weighted_sample_mut`] method from [`TurboRand`].
+    /// Delegated [`TurboRand::chance`] method from [`TurboRand`].
     #[inline]
-    fn weighted_sample_mut<'a, T, F>(
-        &mut self,
-        list: &'a mut [T],
-        weight_sampler: F,
-    ) -> Option<&'a mut T>
-    where
-        F: Fn((&T, usize)) -> f64,
-    {
-        self.get_mut().weighted_sample_mut(list, weight_sampler)
+    fn chance(&mut self, rate: f64) -> bool {
+        let outcome = self.get_mut().chance(rate);
+
+        #[cfg(feature = "audit")]
+        if let Some(log) = self.audit_log() {
+            log.record("chance", rate, AuditOutcome::Bool(outcome));
+        }
+
+        outcome
+    }
+
+    /// Returns `true` with a probability of exactly `numerator / denominator`, such as
+    /// `ratio(1, 8)` for a "1 in 8" chance. Unlike [`DelegatedRng::chance`], the check is
+    /// performed with integer comparison, so there is no floating point drift. A `numerator`
+    /// of `0` always returns `false` and a `numerator` greater or equal to the `denominator`
+    /// always returns `true`, neither of which will advance the RNG state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// assert!(rng.ratio(8, 8));
+    /// assert!(!rng.ratio(0, 8));
+    ///
+    /// let _critical_hit = rng.ratio(1, 8);
+    /// ```
+    #[inline]
+    fn ratio(&mut self, numerator: u32, denominator: u32) -> bool {
+        assert!(denominator != 0, "ratio denominator must not be zero");
+
+        let outcome = match numerator {
+            0 => false,
+            numerator if numerator >= denominator => true,
+            numerator => self.get_mut().u32(..denominator) < numerator,
+        };
+
+        #[cfg(feature = "audit")]
+        if let Some(log) = self.audit_log() {
+            log.record(
+                "ratio",
+                f64::from(numerator.min(denominator)) / f64::from(denominator),
+                AuditOutcome::Bool(outcome),
+            );
+        }
+
+        outcome
+    }
+
+    /// Delegated [`TurboCore::fill_bytes`] method from [`TurboCore`].
+    #[inline]
+    fn fill_bytes(&mut self, buffer: &mut [u8]) {
+        self.get_mut().fill_bytes(buffer);
     }
+
+    /// Borrows the source as a [`RandomByteStream`], generating bytes in chunks of `chunk`
+    /// bytes at a time, for consuming many bytes a few at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk` is zero.
+    #[inline]
+    #[must_use]
+    fn byte_stream(&mut self, chunk: usize) -> RandomByteStream<'_, Self::Source> {
+        RandomByteStream::new(self.get_mut(), chunk)
+    }
+
+    /// Returns a random `u64` identifier, for tagging procedurally generated content in a way
+    /// that is reproducible from the RNG's seed, such as for save files or network sync.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    /// let mut replayed = RngComponent::with_seed(5);
+    ///
+    /// assert_eq!(rng.gen_id64(), replayed.gen_id64());
+    /// ```
+    #[inline]
+    fn gen_id64(&mut self) -> u64 {
+        self.get_mut().gen_u64()
+    }
+
+    /// Returns a random `u128` identifier. Same as [`DelegatedRng::gen_id64`], but with a
+    /// much lower chance of collisions when identifiers are drawn from many sources.
+    #[inline]
+    fn gen_id128(&mut self) -> u128 {
+        self.get_mut().gen_u128()
+    }
+
+    /// Returns a random version 4 [`Uuid`], built from 16 random bytes with the version and
+    /// variant bits set accordingly.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let id = rng.gen_uuid_v4();
+    ///
+    /// assert_eq!(id.get_version_num(), 4);
+    /// assert_eq!(id.get_variant(), uuid::Variant::RFC4122);
+    /// ```
+    #[cfg(feature = "uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+    #[inline]
+    fn gen_uuid_v4(&mut self) -> Uuid {
+        let mut bytes = [0u8; 16];
+
+        self.get_mut().fill_bytes(&mut bytes);
+
+        uuid::Builder::from_random_bytes(bytes).into_uuid()
+    }
+
+    /// Returns `N` random bytes for use as a cryptographic token, such as a session token.
+    /// Only available for RNGs backed by a [`SecureCore`] source, such as
+    /// [`GlobalChaChaRng`] or [`ChaChaRngComponent`], so that tokens can't be minted from a
+    /// predictable source by accident:
+    ///
+    /// ```compile_fail
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::new();
+    ///
+    /// let token: [u8; 32] = rng.token_bytes();
+    /// ```
+    ///
+    /// A token is only as unpredictable as the seed of its source, so sources minting tokens
+    /// should be seeded from entropy rather than a fixed seed.
+    ///
+    /// [`GlobalChaChaRng`]: crate::GlobalChaChaRng
+    /// [`ChaChaRngComponent`]: crate::ChaChaRngComponent
+    #[inline]
+    fn token_bytes<const N: usize>(&mut self) -> [u8; N]
+    where
+        Self::Source: SecureCore,
+    {
+        let mut bytes = [0; N];
+
+        self.get_mut().fill_bytes(&mut bytes);
+
+        bytes
+    }
+
+    /// Returns a token of `len` random bytes, encoded as lowercase hexadecimal, so twice as
+    /// many characters long. Only available for RNGs backed by a [`SecureCore`] source, the
+    /// same as [`DelegatedRng::token_bytes`].
+    fn token_hex(&mut self, len: usize) -> String
+    where
+        Self::Source: SecureCore,
+    {
+        let mut bytes = vec![0; len];
+
+        self.get_mut().fill_bytes(&mut bytes);
+
+        crate::encoding::hex(&bytes)
+    }
+
+    /// Returns a token of `len` random bytes, encoded as URL-safe base64 without padding, so
+    /// `ceil(len * 4 / 3)` characters long. Only available for RNGs backed by a
+    /// [`SecureCore`] source, the same as [`DelegatedRng::token_bytes`].
+    fn token_base64url(&mut self, len: usize) -> String
+    where
+        Self::Source: SecureCore,
+    {
+        let mut bytes = vec![0; len];
+
+        self.get_mut().fill_bytes(&mut bytes);
+
+        crate::encoding::base64url(&bytes)
+    }
+
+    /// Delegated [`TurboRand::shuffle`] method from [`TurboRand`].
+    #[inline]
+    fn shuffle<T>(&mut self, slice: &mut [T]) {
+        self.get_mut().shuffle(slice);
+    }
+
+    /// Delegated [`TurboRand::partial_shuffle`] method from [`TurboRand`].
+    #[inline]
+    fn partial_shuffle<'a, T>(
+        &mut self,
+        slice: &'a mut [T],
+        amount: usize,
+    ) -> (&'a mut [T], &'a mut [T]) {
+        self.get_mut().partial_shuffle(slice, amount)
+    }
+
+    /// Shuffles only the first `amount` elements of `slice` into place, such as for picking
+    /// `amount` items in a random order without shuffling a whole list. Returns the shuffled
+    /// prefix and the remainder, in that order. An `amount` greater than the length of `slice`
+    /// shuffles the entire slice.
+    ///
+    /// Implemented as a Fisher-Yates shuffle over the first `amount` positions, each swapped
+    /// with a position drawn with [`TurboRand::index`], so that the output is the same on
+    /// 32-bit and 64-bit platforms. Draws exactly once per shuffled position. Unlike
+    /// [`DelegatedRng::partial_shuffle`], which shuffles the tail of the slice with
+    /// `turborand`'s algorithm, this never panics on slices with fewer than two elements.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    /// let mut loot: Vec<u32> = (0..10_000).collect();
+    ///
+    /// let (picked, rest) = rng.shuffle_prefix(&mut loot, 3);
+    ///
+    /// assert_eq!(picked.len(), 3);
+    /// assert_eq!(rest.len(), 9_997);
+    /// ```
+    #[inline]
+    fn shuffle_prefix<'a, T>(
+        &mut self,
+        slice: &'a mut [T],
+        amount: usize,
+    ) -> (&'a mut [T], &'a mut [T]) {
+        let len = slice.len();
+        let amount = amount.min(len);
+
+        self.with_source(|source| {
+            for index in 0..amount {
+                slice.swap(index, source.index(index..len));
+            }
+        });
+
+        slice.split_at_mut(amount)
+    }
+
+    /// Sorts the slice stably by `key`, then shuffles each run of items with equal keys, so
+    /// that ties are broken randomly, such as for leaderboards or target priorities. Items
+    /// with unequal keys keep their sorted relative order.
+    ///
+    /// Only the tied groups draw from the RNG, with a group of `n` items making `n - 1`
+    /// draws, so the amount of draws only ever depends on the number and sizes of the tied
+    /// groups.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let mut scores = [("ann", 3), ("bob", 5), ("cat", 3), ("dan", 1)];
+    ///
+    /// rng.tiebreak_sort_by_key(&mut scores, |&(_, score)| std::cmp::Reverse(score));
+    ///
+    /// assert_eq!(scores[0], ("bob", 5));
+    /// assert_eq!(scores[3], ("dan", 1));
+    /// ```
+    #[inline]
+    fn tiebreak_sort_by_key<T, K: Ord>(&mut self, items: &mut [T], key: impl Fn(&T) -> K) {
+        items.sort_by_key(&key);
+
+        for group in items.chunk_by_mut(|a, b| key(a) == key(b)) {
+            if group.len() > 1 {
+                self.shuffle(group);
+            }
+        }
+    }
+
+    /// Same as [`DelegatedRng::tiebreak_sort_by_key`], but leaves the slice untouched and
+    /// returns the indices of its items in their sorted order instead.
+    #[inline]
+    fn tiebreak_order_by_key<T, K: Ord>(
+        &mut self,
+        items: &[T],
+        key: impl Fn(&T) -> K,
+    ) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..items.len()).collect();
+
+        self.tiebreak_sort_by_key(&mut order, |&index| key(&items[index]));
+
+        order
+    }
+
+    /// Shuffles the slice so that items with higher weights tend to come earlier, as if
+    /// repeatedly drawing an item by weight without replacement, such as for ordering
+    /// matchmaking candidates or loot. Items with a zero, negative or NaN weight are never
+    /// drawn, and are instead moved to the end, keeping their relative order.
+    ///
+    /// Implemented as the Efraimidis-Spirakis algorithm, sorting the items by a key of
+    /// `u^(1 / weight)` for a `u` drawn with [`TurboRand::f64`], so that this is
+    /// `O(n log n)`. Draws exactly once per item, including for unweighted ones.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let mut players = [("ann", 1.0), ("bob", 100.0), ("cat", 0.0)];
+    ///
+    /// rng.weighted_shuffle(&mut players, |&(_, rating)| rating);
+    ///
+    /// assert_eq!(players[2], ("cat", 0.0));
+    /// ```
+    fn weighted_shuffle<T, F: Fn(&T) -> f64>(&mut self, slice: &mut [T], weight: F) {
+        let rng = self.get_mut();
+
+        // Comparing `ln(u) / weight` orders the same as `u^(1 / weight)`, without underflowing
+        // to zero for small weights. `u` is taken from `(0, 1]`, so that its logarithm is
+        // always finite, leaving negative infinity to mark the unweighted items.
+        let mut keys: Vec<(f64, usize)> = slice
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let u = 1.0 - rng.f64();
+                let weight = weight(item);
+
+                let key = if weight > 0.0 {
+                    u.ln() / weight
+                } else {
+                    f64::NEG_INFINITY
+                };
+
+                (key, index)
+            })
+            .collect();
+
+        // A stable sort keeps the unweighted items in their original order.
+        keys.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+
+        // Moves each item into place by following the cycles of the permutation.
+        let mut placed = vec![false; keys.len()];
+
+        for start in 0..keys.len() {
+            let mut current = start;
+
+            while !placed[current] {
+                placed[current] = true;
+
+                let next = keys[current].1;
+
+                if next == start {
+                    break;
+                }
+
+                slice.swap(current, next);
+                current = next;
+            }
+        }
+    }
+
+    /// Delegated [`TurboRand::sample`] method from [`TurboRand`].
+    #[inline]
+    fn sample<'a, T>(&mut self, list: &'a [T]) -> Option<&'a T> {
+        self.get_mut().sample(list)
+    }
+
+    /// Samples a random element of `options`, returning a clone of it, or `None` if
+    /// `options` is empty. Picks the same element as [`DelegatedRng::sample`], but without
+    /// borrowing from `options`, such as for when the options are a temporary.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let greeting = rng.pick(&["Hello".to_string(), "Howdy".to_string()]);
+    ///
+    /// assert!(greeting.is_some());
+    /// assert_eq!(rng.pick::<u8>(&[]), None);
+    /// ```
+    #[inline]
+    fn pick<T: Clone>(&mut self, options: &[T]) -> Option<T> {
+        self.sample(options).cloned()
+    }
+
+    /// Delegated [`TurboRand::sample_iter`] method from [`TurboRand`].
+    #[inline]
+    fn sample_iter<T: Iterator>(&mut self, list: T) -> Option<T::Item> {
+        self.get_mut().sample_iter(list)
+    }
+
+    /// Delegated [`TurboRand::sample_mut`] method from [`TurboRand`].
+    #[inline]
+    fn sample_mut<'a, T>(&mut self, list: &'a mut [T]) -> Option<&'a mut T> {
+        self.get_mut().sample_mut(list)
+    }
+
+    /// Delegated [`TurboRand::sample_multiple`] method from [`TurboRand`].
+    ///
+    /// Never samples the same position of the list twice, but does return equal values more
+    /// than once if the list contains duplicates, for which see
+    /// [`DelegatedRng::sample_multiple_distinct`]. If `amount` is greater than the length of
+    /// the list, silently returns every item instead, for which see
+    /// [`DelegatedRng::try_sample_multiple`].
+    #[inline]
+    fn sample_multiple<'a, T>(&mut self, list: &'a [T], amount: usize) -> Vec<&'a T> {
+        self.get_mut().sample_multiple(list, amount)
+    }
+
+    /// Same as [`DelegatedRng::sample_multiple`], but guarantees that exactly `amount` items
+    /// are returned. Returns [`SampleError::NotEnoughElements`] without drawing from the RNG
+    /// if the list holds fewer than `amount` items.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let hand = [1, 2, 3];
+    ///
+    /// assert_eq!(rng.try_sample_multiple(&hand, 2).unwrap().len(), 2);
+    /// assert_eq!(
+    ///     rng.try_sample_multiple(&hand, 4),
+    ///     Err(SampleError::NotEnoughElements {
+    ///         requested: 4,
+    ///         available: 3
+    ///     })
+    /// );
+    /// ```
+    #[inline]
+    fn try_sample_multiple<'a, T>(
+        &mut self,
+        list: &'a [T],
+        amount: usize,
+    ) -> Result<Vec<&'a T>, SampleError> {
+        if amount > list.len() {
+            return Err(SampleError::NotEnoughElements {
+                requested: amount,
+                available: list.len(),
+            });
+        }
+
+        Ok(self.sample_multiple(list, amount))
+    }
+
+    /// Samples exactly `amount` items from a list, none of which are equal to each other.
+    /// Where the list contains equal values, only the first of them can be sampled, so that
+    /// each distinct value is as likely to be picked as any other. Returns
+    /// [`SampleError::NotEnoughElements`] without drawing from the RNG if the list holds fewer
+    /// than `amount` distinct values. Finding the distinct values is `O(n^2)`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let drops = ["gold", "gold", "gold", "sword"];
+    ///
+    /// let mut sampled = rng.sample_multiple_distinct(&drops, 2).unwrap();
+    /// sampled.sort();
+    ///
+    /// assert_eq!(sampled, [&"gold", &"sword"]);
+    /// ```
+    fn sample_multiple_distinct<'a, T: PartialEq>(
+        &mut self,
+        list: &'a [T],
+        amount: usize,
+    ) -> Result<Vec<&'a T>, SampleError> {
+        let distinct: Vec<&T> = list
+            .iter()
+            .enumerate()
+            .filter(|&(index, item)| !list[..index].contains(item))
+            .map(|(_, item)| item)
+            .collect();
+
+        if amount > distinct.len() {
+            return Err(SampleError::NotEnoughElements {
+                requested: amount,
+                available: distinct.len(),
+            });
+        }
+
+        Ok(self.sample_multiple_iter(distinct.into_iter(), amount))
+    }
+
+    /// Samples `N` unique items from a list into an array, without allocating. Like
+    /// [`DelegatedRng::sample_multiple`], never samples the same position of the list twice.
+    /// Returns `None` without drawing from the RNG if the list holds fewer than `N` items.
+    ///
+    /// Runs a partial Fisher–Yates shuffle over the indices of the list, recording the few
+    /// swapped positions on the stack instead of copying the indices, which makes this
+    /// `O(N^2)` regardless of the length of the list. Best suited to small values of `N`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let colours = ["red", "green", "blue", "yellow"];
+    ///
+    /// let [first, second] = rng.sample_array(&colours).unwrap();
+    ///
+    /// assert_ne!(first, second);
+    /// assert_eq!(rng.sample_array::<_, 5>(&colours), None);
+    /// ```
+    fn sample_array<'a, T, const N: usize>(&mut self, list: &'a [T]) -> Option<[&'a T; N]> {
+        /// Returns the index currently held by a position of the virtually shuffled indices.
+        fn lookup(swapped: &[(usize, usize)], position: usize) -> usize {
+            swapped
+                .iter()
+                .rev()
+                .find(|&&(swapped, _)| swapped == position)
+                .map_or(position, |&(_, index)| index)
+        }
+
+        if list.len() < N {
+            return None;
+        }
+
+        let rng = self.get_mut();
+        let mut swapped = [(0, 0); N];
+        let mut indices = [0; N];
+
+        for (position, index) in indices.iter_mut().enumerate() {
+            let target = rng.index(position..list.len());
+
+            *index = lookup(&swapped[..position], target);
+
+            // The current position is never looked up again, so only the target needs to
+            // remember the index swapped into it.
+            swapped[position] = (target, lookup(&swapped[..position], position));
+        }
+
+        Some(indices.map(|index| &list[index]))
+    }
+
+    /// Shuffles an array in place, without allocating. Produces the same order as
+    /// [`DelegatedRng::shuffle`] for the same RNG state.
+    #[inline]
+    fn shuffle_array<T, const N: usize>(&mut self, array: &mut [T; N]) {
+        self.get_mut().shuffle(array);
+    }
+
+    /// Delegated [`TurboRand::sample_multiple_iter`] method from [`TurboRand`].
+    #[inline]
+    fn sample_multiple_iter<T: Iterator>(&mut self, list: T, amount: usize) -> Vec<T::Item> {
+        self.get_mut().sample_multiple_iter(list, amount)
+    }
+
+    /// Delegated [`TurboRand::sample_multiple_mut`] method from [`TurboRand`].
+    #[inline]
+    fn sample_multiple_mut<'a, T>(&mut self, list: &'a mut [T], amount: usize) -> Vec<&'a mut T> {
+        self.get_mut().sample_multiple_mut(list, amount)
+    }
+
+    /// Samples a random entry from a map, such as a [`HashMap`](std::collections::HashMap),
+    /// in a manner that is deterministic regardless of the map's iteration order. The entries
+    /// are collected and sorted by key before sampling, which makes this `O(n log n)`. For maps
+    /// that already iterate in a sorted order, use [`DelegatedRng::sample_btree_map`] instead,
+    /// and for when determinism isn't needed, [`DelegatedRng::sample_iter`]. Returns `None`
+    /// if the map is empty, without advancing the RNG.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let loot = HashMap::from([("sword", 1), ("shield", 2), ("potion", 5)]);
+    ///
+    /// let (item, amount) = rng.sample_map(&loot).unwrap();
+    /// ```
+    #[inline]
+    fn sample_map<'a, K, V>(
+        &mut self,
+        map: impl IntoIterator<Item = (&'a K, &'a V)>,
+    ) -> Option<(&'a K, &'a V)>
+    where
+        K: Ord + 'a,
+        V: 'a,
+    {
+        let mut entries: Vec<_> = map.into_iter().collect();
+
+        if entries.is_empty() {
+            return None;
+        }
+
+        entries.sort_unstable_by_key(|&(key, _)| key);
+
+        Some(entries[self.index(..entries.len())])
+    }
+
+    /// Samples a random value from a set, such as a [`HashSet`](std::collections::HashSet),
+    /// in a manner that is deterministic regardless of the set's iteration order. The values
+    /// are collected and sorted before sampling, which makes this `O(n log n)`. For sets that
+    /// already iterate in a sorted order, use [`DelegatedRng::sample_btree_set`] instead, and
+    /// for when determinism isn't needed, [`DelegatedRng::sample_iter`]. Returns `None` if the
+    /// set is empty, without advancing the RNG.
+    #[inline]
+    fn sample_set<'a, T>(&mut self, set: impl IntoIterator<Item = &'a T>) -> Option<&'a T>
+    where
+        T: Ord + 'a,
+    {
+        let mut values: Vec<_> = set.into_iter().collect();
+
+        if values.is_empty() {
+            return None;
+        }
+
+        values.sort_unstable();
+
+        Some(values[self.index(..values.len())])
+    }
+
+    /// Samples a random entry from a [`BTreeMap`](std::collections::BTreeMap). As it already
+    /// iterates in key order, no sorting is needed, making this `O(n)` and yielding the same
+    /// results as [`DelegatedRng::sample_map`]. Returns `None` if the map is empty, without
+    /// advancing the RNG.
+    #[inline]
+    fn sample_btree_map<'a, K, V>(
+        &mut self,
+        map: &'a std::collections::BTreeMap<K, V>,
+    ) -> Option<(&'a K, &'a V)> {
+        if map.is_empty() {
+            return None;
+        }
+
+        map.iter().nth(self.index(..map.len()))
+    }
+
+    /// Samples a random value from a [`BTreeSet`](std::collections::BTreeSet). As it already
+    /// iterates in sorted order, no sorting is needed, making this `O(n)` and yielding the same
+    /// results as [`DelegatedRng::sample_set`]. Returns `None` if the set is empty, without
+    /// advancing the RNG.
+    #[inline]
+    fn sample_btree_set<'a, T>(&mut self, set: &'a std::collections::BTreeSet<T>) -> Option<&'a T> {
+        if set.is_empty() {
+            return None;
+        }
+
+        set.iter().nth(self.index(..set.len()))
+    }
+
+    /// Delegated [`TurboRand::weighted_sample`] method from [`TurboRand`].
+    #[inline]
+    fn weighted_sample<'a, T, F>(&mut self, list: &'a [T], weight_sampler: F) -> Option<&'a T>
+    where
+        F: Fn((&T, usize)) -> f64,
+    {
+        #[cfg(feature = "audit")]
+        if self.audit_log().is_some() {
+            let weights = crate::audit::weights(list, weight_sampler);
+            let index = crate::audit::weighted_index(self.get_mut(), &weights);
+
+            if let Some(log) = self.audit_log() {
+                log.record_weighted("weighted_sample", &weights, index);
+            }
+
+            return index.map(|index| &list[index]);
+        }
+
+        self.get_mut().weighted_sample(list, weight_sampler)
+    }
+
+    /// Delegated [`TurboRand::weighted_sample_mut`] method from [`TurboRand`].
+    #[inline]
+    fn weighted_sample_mut<'a, T, F>(
+        &mut self,
+        list: &'a mut [T],
+        weight_sampler: F,
+    ) -> Option<&'a mut T>
+    where
+        F: Fn((&T, usize)) -> f64,
+    {
+        #[cfg(feature = "audit")]
+        if self.audit_log().is_some() {
+            let weights = crate::audit::weights(list, weight_sampler);
+            let index = crate::audit::weighted_index(self.get_mut(), &weights);
+
+            if let Some(log) = self.audit_log() {
+                log.record_weighted("weighted_sample_mut", &weights, index);
+            }
+
+            return index.map(|index| &mut list[index]);
+        }
+
+        self.get_mut().weighted_sample_mut(list, weight_sampler)
+    }
+
+    /// Runs `f` against the inner source up to `max_attempts` times, until it returns `Some`,
+    /// such as for rejection sampling. If no attempt succeeds, returns [`RetryExhausted`] with
+    /// the amount of attempts made, instead of looping forever.
+    ///
+    /// **Note**: The amount of values drawn from the RNG depends on how many attempts it takes
+    /// to succeed. This is still deterministic for a given RNG state, but any change to `f` or
+    /// its constraints can shift all subsequent draws. For determinism-sensitive uses, consider
+    /// running the retries on a forked RNG, so that the parent only ever advances by one draw.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// // Rejection sample an odd number.
+    /// let odd = rng.retry_sample(16, |rng| Some(rng.u32(..100)).filter(|n| n % 2 == 1));
+    ///
+    /// assert!(odd.is_ok_and(|n| n % 2 == 1));
+    /// ```
+    #[inline]
+    fn retry_sample<T>(
+        &mut self,
+        max_attempts: u32,
+        mut f: impl FnMut(&mut Self::Source) -> Option<T>,
+    ) -> Result<T, RetryExhausted> {
+        let rng = self.get_mut();
+
+        (0..max_attempts)
+            .find_map(|_| f(rng))
+            .ok_or(RetryExhausted {
+                attempts: max_attempts,
+            })
+    }
+
+    /// Same as [`DelegatedRng::retry_sample`], but returns `default` if no attempt succeeds.
+    #[inline]
+    fn retry_sample_or<T>(
+        &mut self,
+        max_attempts: u32,
+        default: T,
+        f: impl FnMut(&mut Self::Source) -> Option<T>,
+    ) -> T {
+        self.retry_sample(max_attempts, f).unwrap_or(default)
+    }
+
+    /// Same as [`DelegatedRng::chance`], but with the `rate` modified by the modifier for
+    /// `tag` in the given [`ProbabilityModifiers`], if any. Without modifiers or a modifier
+    /// for the tag, this is the same as calling [`DelegatedRng::chance`].
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    /// let modifiers = ProbabilityModifiers::new()
+    ///     .with_modifier("loot", ProbabilityModifier::offset(1.0));
+    ///
+    /// assert!(rng.chance_tagged("loot", 0.1, Some(&modifiers)));
+    /// ```
+    #[inline]
+    fn chance_tagged(
+        &mut self,
+        tag: &str,
+        rate: f64,
+        modifiers: Option<&ProbabilityModifiers>,
+    ) -> bool {
+        let rate = modifiers.map_or(rate, |modifiers| modifiers.apply(tag, rate));
+
+        self.chance(rate)
+    }
+
+    /// Same as [`DelegatedRng::chance`], but with `modifier` added to the `base` rate, such as
+    /// from a luck stat. The modified rate is clamped to the range of `0.0..=1.0`, so it never
+    /// panics for rates going out of range. A NaN rate is treated as `0.0` and logged at the
+    /// `debug` level.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// assert!(rng.chance_modified(0.9, 0.2));
+    /// assert!(!rng.chance_modified(1.5, -2.0));
+    /// ```
+    #[inline]
+    fn chance_modified(&mut self, base: f64, modifier: f64) -> bool {
+        self.chance(clamp_rate(base + modifier))
+    }
+
+    /// Same as [`DelegatedRng::chance`], but with the `base` rate multiplied by `factor`. The
+    /// multiplied rate is clamped the same way as by [`DelegatedRng::chance_modified`].
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// assert!(rng.chance_multiplied(0.5, 3.0));
+    /// ```
+    #[inline]
+    fn chance_multiplied(&mut self, base: f64, factor: f64) -> bool {
+        self.chance(clamp_rate(base * factor))
+    }
+
+    /// Same as [`DelegatedRng::chance`], but taking the `rate` as a `f32`, yielding the same
+    /// outcome as the `rate` converted to a `f64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is not in the range of `0.0..=1.0`.
+    #[inline]
+    fn chance_f32(&mut self, rate: f32) -> bool {
+        self.chance(f64::from(rate))
+    }
+
+    /// Returns the number of failed trials before the first success, for trials succeeding
+    /// with a probability of `p` each, such as the amount of misses before a hit. Draws a
+    /// single `f64`, by inverting the distribution, so the cost is constant regardless of
+    /// `p`. A `p` of `1.0` always returns `0` without advancing the RNG state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not in the range of `0.0..=1.0`, or is `0.0`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let misses = rng.geometric(0.25);
+    ///
+    /// assert_eq!(rng.geometric(1.0), 0);
+    /// ```
+    #[inline]
+    fn geometric(&mut self, p: f64) -> u64 {
+        assert!(
+            p > 0.0 && p <= 1.0,
+            "geometric probability must be in the range of 0.0..=1.0 and not 0.0"
+        );
+
+        if p == 1.0 {
+            return 0;
+        }
+
+        geometric_inverse(self.get_mut().f64(), p)
+    }
+
+    /// Same as [`DelegatedRng::geometric`], but taking `p` as a `f32`, yielding the same
+    /// outcome as `p` converted to a `f64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not in the range of `0.0..=1.0`, or is `0.0`.
+    #[inline]
+    fn geometric_f32(&mut self, p: f32) -> u64 {
+        self.geometric(f64::from(p))
+    }
+
+    /// Returns the number of successes in `n` trials, succeeding with a probability of `p`
+    /// each, such as the amount of hits in a volley of arrows. A `p` of `0.0` or `1.0`
+    /// always returns `0` or `n` respectively, without advancing the RNG state.
+    ///
+    /// The method used depends on `n` and `p`, with a `p` above `0.5` counting failures
+    /// instead, as `n` minus the successes for `1.0 - p`:
+    ///
+    /// * Up to 64 trials, each trial is drawn directly, which is exact.
+    /// * Above 64 trials with fewer than 10 expected successes, the gaps between successes
+    ///   are drawn with [`DelegatedRng::geometric`], which is exact as well.
+    /// * Otherwise, the result is drawn from a normal approximation of the distribution,
+    ///   rounded and clamped to `0..=n`, which costs the same regardless of `n`, but isn't
+    ///   exact. The approximation and where it kicks in may be refined in future versions, so
+    ///   results in this range aren't guaranteed to stay the same across versions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not in the range of `0.0..=1.0`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let hits = rng.binomial(20, 0.3);
+    ///
+    /// assert!(hits <= 20);
+    /// assert_eq!(rng.binomial(20, 1.0), 20);
+    /// ```
+    fn binomial(&mut self, n: u64, p: f64) -> u64 {
+        assert!(
+            (0.0..=1.0).contains(&p),
+            "binomial probability must be in the range of 0.0..=1.0"
+        );
+
+        if p > 0.5 {
+            return n - self.binomial(n, 1.0 - p);
+        }
+
+        if p == 0.0 || n == 0 {
+            return 0;
+        }
+
+        let rng = self.get_mut();
+
+        if n <= BINOMIAL_DIRECT_TRIALS {
+            return (0..n).map(|_| u64::from(rng.f64() < p)).sum();
+        }
+
+        let mean = n as f64 * p;
+
+        if mean < BINOMIAL_WAITING_MEAN {
+            let mut successes = 0;
+            let mut trials = 0u64;
+
+            loop {
+                trials = trials.saturating_add(geometric_inverse(rng.f64(), p));
+
+                if trials >= n {
+                    return successes;
+                }
+
+                successes += 1;
+                trials += 1;
+            }
+        }
+
+        // Box-Muller, only using the first of the pair so each call draws the same amount.
+        let radius = (-2.0 * (1.0 - rng.f64()).ln()).sqrt();
+        let normal = radius * (std::f64::consts::TAU * rng.f64()).cos();
+        let sample = (mean + normal * (mean * (1.0 - p)).sqrt()).round();
+
+        (sample.clamp(0.0, n as f64) as u64).min(n)
+    }
+
+    /// Same as [`DelegatedRng::binomial`], but taking `p` as a `f32`, yielding the same
+    /// outcome as `p` converted to a `f64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not in the range of `0.0..=1.0`.
+    #[inline]
+    fn binomial_f32(&mut self, n: u64, p: f32) -> u64 {
+        self.binomial(n, f64::from(p))
+    }
+
+    /// Same as [`DelegatedRng::weighted_sample`], but with each weight modified by the
+    /// modifier for `tag` in the given [`ProbabilityModifiers`], if any. Without modifiers
+    /// or a modifier for the tag, this is the same as calling [`DelegatedRng::weighted_sample`].
+    #[inline]
+    fn weighted_sample_tagged<'a, T, F>(
+        &mut self,
+        tag: &str,
+        list: &'a [T],
+        weight_sampler: F,
+        modifiers: Option<&ProbabilityModifiers>,
+    ) -> Option<&'a T>
+    where
+        F: Fn((&T, usize)) -> f64,
+    {
+        match modifiers {
+            Some(modifiers) => {
+                self.weighted_sample(list, |item| modifiers.apply(tag, weight_sampler(item)))
+            }
+            None => self.weighted_sample(list, weight_sampler),
+        }
+    }
+
+    /// Returns a random, fully opaque sRGB [`Color`], with each channel sampled
+    /// uniformly from `0.0..1.0`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let faction_color = rng.color_rgb();
+    /// ```
+    #[cfg(feature = "bevy_color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_color")))]
+    #[inline]
+    fn color_rgb(&mut self) -> Color {
+        let source = self.get_mut();
+
+        Color::srgb(source.f32(), source.f32(), source.f32())
+    }
+
+    /// Returns a random, fully opaque HSL [`Color`], with a hue sampled uniformly
+    /// around the whole color wheel, and the saturation and lightness sampled from
+    /// within the given ranges. Constraining the saturation and lightness while keeping
+    /// the hue unconstrained yields colors that are distinct from each other, yet
+    /// consistent in tone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is not within `0.0..=1.0`, or if its start is greater than its end.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let pastel = rng.color_hsl(0.4..=0.6, 0.75..=0.85);
+    /// ```
+    #[cfg(feature = "bevy_color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_color")))]
+    #[inline]
+    fn color_hsl(
+        &mut self,
+        saturation_range: RangeInclusive<f32>,
+        lightness_range: RangeInclusive<f32>,
+    ) -> Color {
+        fn sample_unit(rng: &impl TurboRand, range: &RangeInclusive<f32>) -> f32 {
+            let (start, end) = (*range.start(), *range.end());
+
+            assert!(
+                (0.0..=1.0).contains(&start) && (0.0..=1.0).contains(&end) && start <= end,
+                "color range must be within 0.0..=1.0"
+            );
+
+            start + (end - start) * rng.f32()
+        }
+
+        let source = self.get_mut();
+
+        let hue = source.f32() * 360.0;
+        let saturation = sample_unit(source, &saturation_range);
+        let lightness = sample_unit(source, &lightness_range);
+
+        Color::hsl(hue, saturation, lightness)
+    }
+
+    /// Returns a random [`Color`] along the gradient between `a` and `b`, mixed
+    /// in the color space of `a`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let autumn_leaf = rng.color_in_gradient(Color::srgb(0.9, 0.7, 0.1), Color::srgb(0.6, 0.1, 0.0));
+    /// ```
+    #[cfg(feature = "bevy_color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_color")))]
+    #[inline]
+    fn color_in_gradient(&mut self, a: Color, b: Color) -> Color {
+        a.mix(&b, self.get_mut().f32())
+    }
+
+    /// Picks up to `k` of the `candidates` points, each at least `min_dist` apart from each
+    /// other and outside of all `exclusions`, given as a center and radius. Returns the indices of
+    /// the picked candidates, in the order they were picked.
+    ///
+    /// Candidates are visited in a random order, greedily picking those that satisfy the
+    /// constraints. If the constraints can't be met for all `k` points, fewer indices are
+    /// returned, so the length of the output reports how many could be picked. The result is
+    /// deterministic for a given RNG state and candidate order.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let markers: Vec<Vec3> = (0..10).map(|x| Vec3::new(x as f32 * 2.0, 0.0, 0.0)).collect();
+    /// let player = Vec3::ZERO;
+    ///
+    /// let spawns = rng.pick_separated(&markers, 3, 5.0, &[(player, 4.0)]);
+    ///
+    /// assert!(spawns.len() <= 3);
+    /// ```
+    #[cfg(feature = "bevy_math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
+    #[inline]
+    fn pick_separated(
+        &mut self,
+        candidates: &[Vec3],
+        k: usize,
+        min_dist: f32,
+        exclusions: &[(Vec3, f32)],
+    ) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..candidates.len()).collect();
+
+        self.shuffle(&mut order);
+
+        crate::separated::pick_separated(&order, candidates, k, min_dist, exclusions)
+    }
+
+    /// Scatters `total` points across the `regions`, so that each region gets an equal share,
+    /// give or take one. The total is split evenly, with the remainder going to randomly
+    /// chosen regions, one extra point each. Points are then sampled uniformly within their
+    /// region. Returns each point tagged with the index of its region, grouped by region in
+    /// the order of `regions`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `regions` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(5);
+    ///
+    /// let quadrants = [
+    ///     Rect::new(-100.0, -100.0, 0.0, 0.0),
+    ///     Rect::new(0.0, -100.0, 100.0, 0.0),
+    ///     Rect::new(-100.0, 0.0, 0.0, 100.0),
+    ///     Rect::new(0.0, 0.0, 100.0, 100.0),
+    /// ];
+    ///
+    /// let items = rng.scatter_balanced(&quadrants, 10);
+    ///
+    /// assert_eq!(items.len(), 10);
+    /// assert!(items.iter().all(|&(region, pos)| quadrants[region].contains(pos)));
+    /// ```
+    #[cfg(feature = "bevy_math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
+    fn scatter_balanced(&mut self, regions: &[Rect], total: usize) -> Vec<(usize, Vec2)> {
+        assert!(!regions.is_empty(), "at least one region is required");
+
+        let share = total / regions.len();
+        let remainder = total % regions.len();
+
+        // All remainders of an equal split are the same, so ties are broken randomly.
+        let mut extra: Vec<usize> = (0..regions.len()).collect();
+        self.shuffle(&mut extra);
+        extra.truncate(remainder);
+
+        let rng = self.get_mut();
+        let mut points = Vec::with_capacity(total);
+
+        for (index, region) in regions.iter().enumerate() {
+            let count = share + usize::from(extra.contains(&index));
+            let size = region.size();
+
+            points.extend((0..count).map(|_| {
+                let offset = Vec2::new(rng.f32(), rng.f32()) * size;
+
+                (index, region.min + offset)
+            }));
+        }
+
+        points
+    }
+}
+
+/// Clamps a modified rate to the range of `0.0..=1.0`, treating NaN as `0.0`.
+#[inline]
+fn clamp_rate(rate: f64) -> f64 {
+    if rate.is_nan() {
+        debug!("A modified chance rate was NaN, so treating it as 0.0");
+        return 0.0;
+    }
+
+    rate.clamp(0.0, 1.0)
+}
+
+/// The most trials [`DelegatedRng::binomial`] draws one by one.
+const BINOMIAL_DIRECT_TRIALS: u64 = 64;
+
+/// The expected amount of successes below which [`DelegatedRng::binomial`] draws the gaps
+/// between successes, rather than approximating the distribution.
+const BINOMIAL_WAITING_MEAN: f64 = 10.0;
+
+/// Maps a uniform `f64` in `0.0..1.0` to the number of failures before the first success,
+/// for a `p` in the range of `0.0..1.0`, exclusive of `0.0`.
+#[inline]
+fn geometric_inverse(uniform: f64, p: f64) -> u64 {
+    // `1.0 - uniform` is never zero, so the logarithm is always finite.
+    ((1.0 - uniform).ln() / (-p).ln_1p()).floor() as u64
 }
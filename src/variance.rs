@@ -0,0 +1,213 @@
+use crate::{
+    hash::{mix, StableHasher},
+    *,
+};
+use std::collections::BTreeMap;
+
+/// A distribution for a parameter of a [`VarianceProfile`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub enum VarianceDistribution {
+    /// Always resolves to the same value.
+    Constant(f32),
+    /// A uniform distribution in the range of `min..max`.
+    Uniform {
+        /// The lower bound of the range.
+        min: f32,
+        /// The upper bound of the range.
+        max: f32,
+    },
+    /// A normal distribution, optionally clamped to keep outliers within sensible limits.
+    Normal {
+        /// The mean of the distribution.
+        mean: f32,
+        /// The standard deviation of the distribution.
+        std_dev: f32,
+        /// The range the value is clamped to, if any.
+        #[cfg_attr(feature = "serialize", serde(default))]
+        clamp: Option<(f32, f32)>,
+    },
+}
+
+impl VarianceDistribution {
+    /// Samples a value from the distribution.
+    fn sample(&self, rng: &Rng) -> f32 {
+        match *self {
+            Self::Constant(value) => value,
+            Self::Uniform { min, max } => min + (max - min) * rng.f32(),
+            Self::Normal {
+                mean,
+                std_dev,
+                clamp,
+            } => {
+                // Box-Muller transform, keeping the first sample. `1.0 - f64()` is never zero.
+                let radius = (-2.0 * (1.0 - rng.f64()).ln()).sqrt();
+                let angle = std::f64::consts::TAU * rng.f64();
+
+                let value = mean + std_dev * (radius * angle.cos()) as f32;
+
+                clamp.map_or(value, |(min, max)| value.clamp(min, max))
+            }
+        }
+    }
+}
+
+/// The values resolved by [`VarianceProfile::apply`], keyed by parameter name.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(transparent))]
+pub struct ResolvedVariance {
+    values: BTreeMap<String, f32>,
+}
+
+impl ResolvedVariance {
+    /// Returns the resolved value of a parameter, if the profile has it.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<f32> {
+        self.values.get(name).copied()
+    }
+
+    /// Returns the resolved value of a parameter, or `default` if the profile doesn't have it.
+    #[inline]
+    #[must_use]
+    pub fn get_or(&self, name: &str, default: f32) -> f32 {
+        self.get(name).unwrap_or(default)
+    }
+
+    /// Returns an iterator over all parameters and their resolved values, ordered by name.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f32)> {
+        self.values
+            .iter()
+            .map(|(name, &value)| (name.as_str(), value))
+    }
+}
+
+/// A profile of named parameters varying per instance, such as the break thresholds of
+/// joints or the friction of materials, so that instances don't all feel the same. Each
+/// parameter has its own [`VarianceDistribution`], and profiles can be authored in RON or
+/// any other format with the `serialize` feature.
+///
+/// Resolving a profile draws a single value from the given RNG, from which every parameter
+/// derives its own stream keyed by its name. Adding, removing or reordering parameters
+/// thus never changes the values resolved for the others.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Joint {
+///     break_force: f32,
+///     friction: f32,
+/// }
+///
+/// let profile = VarianceProfile::new()
+///     .with_parameter("break_force", VarianceDistribution::Normal {
+///         mean: 500.0,
+///         std_dev: 25.0,
+///         clamp: Some((400.0, 600.0)),
+///     })
+///     .with_parameter("friction", VarianceDistribution::Uniform { min: 0.4, max: 0.6 });
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let mut joints = Vec::new();
+///
+/// profile.stamp(&mut rng, 0..10, |_, variance| {
+///     joints.push(Joint {
+///         break_force: variance.get_or("break_force", 500.0),
+///         friction: variance.get_or("friction", 0.5),
+///     });
+/// });
+///
+/// assert!(joints.iter().all(|joint| (400.0..=600.0).contains(&joint.break_force)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(transparent))]
+pub struct VarianceProfile {
+    parameters: BTreeMap<String, VarianceDistribution>,
+}
+
+impl VarianceProfile {
+    /// Creates a new [`VarianceProfile`] without any parameters.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            parameters: BTreeMap::new(),
+        }
+    }
+
+    /// Builder function to set the distribution of a parameter.
+    #[inline]
+    #[must_use]
+    pub fn with_parameter(
+        mut self,
+        name: impl Into<String>,
+        distribution: VarianceDistribution,
+    ) -> Self {
+        self.set(name, distribution);
+        self
+    }
+
+    /// Sets the distribution of a parameter, replacing any previous one.
+    #[inline]
+    pub fn set(&mut self, name: impl Into<String>, distribution: VarianceDistribution) {
+        self.parameters.insert(name.into(), distribution);
+    }
+
+    /// Removes a parameter, returning its distribution if there was one.
+    #[inline]
+    pub fn remove(&mut self, name: &str) -> Option<VarianceDistribution> {
+        self.parameters.remove(name)
+    }
+
+    /// Returns an iterator over all parameters and their distributions, ordered by name.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &VarianceDistribution)> {
+        self.parameters
+            .iter()
+            .map(|(name, distribution)| (name.as_str(), distribution))
+    }
+
+    /// Resolves a value for every parameter, drawing a single value from `rng`.
+    #[must_use]
+    pub fn apply(&self, rng: &mut impl DelegatedRng) -> ResolvedVariance {
+        self.resolve(rng.get_mut().gen_u64())
+    }
+
+    /// Resolves a value for every parameter, derived from the given seed.
+    #[must_use]
+    pub fn resolve(&self, seed: u64) -> ResolvedVariance {
+        let values = self
+            .parameters
+            .iter()
+            .map(|(name, distribution)| {
+                let rng = Rng::with_seed(mix(seed, StableHasher::hash_one(name.as_str())));
+
+                (name.clone(), distribution.sample(&rng))
+            })
+            .collect();
+
+        ResolvedVariance { values }
+    }
+
+    /// Resolves the profile once for each of the `targets`, such as spawned entities,
+    /// handing each target to `stamp` along with its resolved values, in order.
+    #[inline]
+    pub fn stamp<T>(
+        &self,
+        rng: &mut impl DelegatedRng,
+        targets: impl IntoIterator<Item = T>,
+        mut stamp: impl FnMut(T, &ResolvedVariance),
+    ) {
+        for target in targets {
+            stamp(target, &self.apply(rng));
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::*;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Options<T> {
+    Uniform(Vec<T>),
+    Weighted(Cdf<T>),
+}
+
+/// Samples owned values out of a fixed set of options, such as the variants of an enum,
+/// either uniformly or by weight. Sampling clones the picked option, so the sampler can be
+/// built once, such as in a [`Resource`] or a `static`, and then sampled without borrowing
+/// it alongside the RNG.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Weather {
+///     Clear,
+///     Rain,
+///     Storm,
+/// }
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let any = VariantSampler::uniform(&[Weather::Clear, Weather::Rain, Weather::Storm]).unwrap();
+/// let mostly_clear = VariantSampler::weighted(&[
+///     (Weather::Clear, 8.0),
+///     (Weather::Rain, 2.0),
+///     (Weather::Storm, 0.5),
+/// ])
+/// .unwrap();
+///
+/// let today = any.sample(&mut rng);
+/// let tomorrow = mostly_clear.sample(&mut rng);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantSampler<T> {
+    options: Options<T>,
+}
+
+impl<T: Clone> VariantSampler<T> {
+    /// Creates a [`VariantSampler`] picking each of `options` with the same probability.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CdfError::Empty`] if no options are given.
+    pub fn uniform(options: &[T]) -> Result<Self, CdfError> {
+        if options.is_empty() {
+            return Err(CdfError::Empty);
+        }
+
+        Ok(Self {
+            options: Options::Uniform(options.to_vec()),
+        })
+    }
+
+    /// Creates a [`VariantSampler`] picking each option with a probability proportional to
+    /// its weight. Options with a weight of zero are never picked.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Cdf::new`], for no options, negative, infinite or NaN
+    /// weights, and weights not adding up to a finite total greater than zero.
+    pub fn weighted(options: &[(T, f64)]) -> Result<Self, CdfError> {
+        Cdf::new(options.iter().cloned()).map(|cdf| Self {
+            options: Options::Weighted(cdf),
+        })
+    }
+
+    /// Samples one of the options, returning a clone of it. Uniform samplers pick the same
+    /// option as [`DelegatedRng::pick`] would from the same options, while weighted ones
+    /// pick the same as [`Cdf::sample`].
+    #[inline]
+    pub fn sample(&self, rng: &mut impl DelegatedRng) -> T {
+        match &self.options {
+            Options::Uniform(options) => options[rng.index(..options.len())].clone(),
+            Options::Weighted(cdf) => cdf.sample(rng).clone(),
+        }
+    }
+
+    /// Returns the options, in the order they were given.
+    #[inline]
+    #[must_use]
+    pub fn options(&self) -> &[T] {
+        match &self.options {
+            Options::Uniform(options) => options,
+            Options::Weighted(cdf) => cdf.items(),
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use crate::*;
+
+/// How a [`RandomWalk`] moves on each step.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub enum WalkMode {
+    /// Moves by a random delta only, so the walk tends to drift ever further from where it
+    /// started.
+    Free,
+    /// An Ornstein–Uhlenbeck style walk, which on each step also moves a fraction `pull` of
+    /// the way back towards `mean`, keeping it wandering around the mean instead of drifting
+    /// away.
+    MeanReverting {
+        /// The value the walk is pulled back towards, on every axis.
+        mean: f32,
+        /// The fraction of the distance to the mean covered on each step, in the range of
+        /// `0.0..=1.0`.
+        pull: f32,
+    },
+}
+
+/// A temporally coherent random value, for effects such as wind or camera sway where white
+/// noise would look jittery. Each step moves the value by a random delta of up to
+/// `max_step` from where it was, optionally clamped to bounds and pulled back towards a mean.
+///
+/// A single walk can be used in one, two or three dimensions, with [`RandomWalk::step`],
+/// [`RandomWalk::step_vec2`] and [`RandomWalk::step_vec3`] respectively. Each axis walks
+/// independently, and only the axes used by a step are advanced by it. A step draws one
+/// value from the RNG per axis used.
+///
+/// It is a plain value type, meant to be kept inside user components or resources.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// let mut wind = RandomWalk::new(0.1)
+///     .with_bounds(-1.0, 1.0)
+///     .with_mean_reversion(0.0, 0.05);
+///
+/// for _ in 0..100 {
+///     let strength = wind.step(&mut rng);
+///
+///     assert!((-1.0..=1.0).contains(&strength));
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct RandomWalk {
+    value: Vec3,
+    max_step: f32,
+    bounds: Option<(f32, f32)>,
+    mode: WalkMode,
+}
+
+impl RandomWalk {
+    /// Creates a new, unbounded [`RandomWalk`] starting at zero, moving by up to `max_step`
+    /// per step.
+    #[inline]
+    #[must_use]
+    pub const fn new(max_step: f32) -> Self {
+        Self {
+            value: Vec3::ZERO,
+            max_step,
+            bounds: None,
+            mode: WalkMode::Free,
+        }
+    }
+
+    /// Builder function to set the starting value of a one dimensional walk.
+    #[inline]
+    #[must_use]
+    pub fn with_value(mut self, value: f32) -> Self {
+        self.value.x = value;
+        self
+    }
+
+    /// Builder function to set the starting value of a two dimensional walk.
+    #[inline]
+    #[must_use]
+    pub fn with_value_vec2(mut self, value: Vec2) -> Self {
+        self.value = value.extend(self.value.z);
+        self
+    }
+
+    /// Builder function to set the starting value of a three dimensional walk.
+    #[inline]
+    #[must_use]
+    pub const fn with_value_vec3(mut self, value: Vec3) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Builder function to clamp the walk to the range of `min..=max` on every axis. The
+    /// bounds are swapped if given in reverse. The current value is clamped on the next step.
+    #[inline]
+    #[must_use]
+    pub fn with_bounds(mut self, min: f32, max: f32) -> Self {
+        self.bounds = Some((min.min(max), min.max(max)));
+        self
+    }
+
+    /// Builder function to make the walk mean-reverting, pulling it a fraction `pull` of the
+    /// way back towards `mean` on every step. `pull` is clamped to the range of `0.0..=1.0`.
+    #[inline]
+    #[must_use]
+    pub fn with_mean_reversion(mut self, mean: f32, pull: f32) -> Self {
+        self.mode = WalkMode::MeanReverting {
+            mean,
+            pull: pull.clamp(0.0, 1.0),
+        };
+        self
+    }
+
+    /// Returns the current value of a one dimensional walk.
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> f32 {
+        self.value.x
+    }
+
+    /// Returns the current value of a two dimensional walk.
+    #[inline]
+    #[must_use]
+    pub fn value_vec2(&self) -> Vec2 {
+        self.value.truncate()
+    }
+
+    /// Returns the current value of a three dimensional walk.
+    #[inline]
+    #[must_use]
+    pub const fn value_vec3(&self) -> Vec3 {
+        self.value
+    }
+
+    /// Returns the maximum distance moved by a random delta on each step.
+    #[inline]
+    #[must_use]
+    pub const fn max_step(&self) -> f32 {
+        self.max_step
+    }
+
+    /// Returns the mode of the walk.
+    #[inline]
+    #[must_use]
+    pub const fn mode(&self) -> WalkMode {
+        self.mode
+    }
+
+    /// Advances a one dimensional walk by one step, returning the new value.
+    #[inline]
+    pub fn step(&mut self, rng: &mut impl DelegatedRng) -> f32 {
+        self.value.x = self.advance(self.value.x, rng);
+        self.value.x
+    }
+
+    /// Advances a two dimensional walk by one step, returning the new value.
+    #[inline]
+    pub fn step_vec2(&mut self, rng: &mut impl DelegatedRng) -> Vec2 {
+        self.value.x = self.advance(self.value.x, rng);
+        self.value.y = self.advance(self.value.y, rng);
+        self.value.truncate()
+    }
+
+    /// Advances a three dimensional walk by one step, returning the new value.
+    #[inline]
+    pub fn step_vec3(&mut self, rng: &mut impl DelegatedRng) -> Vec3 {
+        self.value.x = self.advance(self.value.x, rng);
+        self.value.y = self.advance(self.value.y, rng);
+        self.value.z = self.advance(self.value.z, rng);
+        self.value
+    }
+
+    /// Returns the next value of a single axis.
+    fn advance(&self, value: f32, rng: &mut impl DelegatedRng) -> f32 {
+        let reverted = match self.mode {
+            WalkMode::Free => value,
+            WalkMode::MeanReverting { mean, pull } => value + (mean - value) * pull,
+        };
+
+        let next = reverted + rng.f32_symmetric(self.max_step);
+
+        match self.bounds {
+            Some((min, max)) => next.clamp(min, max),
+            None => next,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::*;
+use web_sys::Storage;
+
+/// Returns the `localStorage` of the page, if it is available. It may not be, such as when
+/// browsing privately or when storage is disabled.
+fn local_storage() -> Option<Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+/// Returns the seed persisted in the page's `localStorage` under the given key, such as for
+/// displaying it in a UI. Returns `None` if no valid seed is stored, or if storage is
+/// unavailable.
+#[cfg_attr(docsrs, doc(cfg(all(target_arch = "wasm32", feature = "web-persist"))))]
+#[must_use]
+pub fn persisted_seed(key: &str) -> Option<u64> {
+    local_storage()?.get_item(key).ok()??.parse().ok()
+}
+
+/// Removes the seed persisted in the page's `localStorage` under the given key, so that the
+/// next [`RngPlugin::with_persisted_seed`] generates a new one, such as when starting a new
+/// game. Returns `false` if storage is unavailable.
+#[cfg_attr(docsrs, doc(cfg(all(target_arch = "wasm32", feature = "web-persist"))))]
+pub fn clear_persisted_seed(key: &str) -> bool {
+    local_storage().is_some_and(|storage| storage.remove_item(key).is_ok())
+}
+
+/// Returns the seed persisted under the given key, or generates a new one and tries to
+/// persist it. Falls back to a random seed that isn't persisted should storage be
+/// unavailable, never panicking.
+pub(crate) fn load_or_persist_seed(key: &str) -> u64 {
+    if let Some(seed) = persisted_seed(key) {
+        return seed;
+    }
+
+    let seed = Rng::new().gen_u64();
+
+    if let Some(storage) = local_storage() {
+        // Storage may be full or read-only, in which case the seed just isn't persisted.
+        let _ = storage.set_item(key, &seed.to_string());
+    }
+
+    seed
+}
@@ -0,0 +1,116 @@
+use crate::*;
+use std::fmt;
+
+#[cfg(feature = "chacha")]
+use turborand::prelude::ChaChaRng;
+
+/// The error returned by [`WorldRngExt`] when a global RNG resource is missing from the
+/// [`World`], which usually means [`RngPlugin`] wasn't added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingGlobalRng {
+    /// The name of the missing resource.
+    pub resource: &'static str,
+}
+
+impl fmt::Display for MissingGlobalRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the {} resource is missing from the world, was RngPlugin added?",
+            self.resource
+        )
+    }
+}
+
+impl std::error::Error for MissingGlobalRng {}
+
+/// Extension methods for accessing RNGs directly from a [`World`], such as in exclusive
+/// systems or in custom commands, without chaining resource and component lookups by hand.
+/// Forking from the world advances the global RNG exactly like forking from a
+/// [`ResMut`] does, so both paths produce the same sources for the same seed.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn spawn_boss(world: &mut World) {
+///     let rng = world.fork_rng().expect("RngPlugin was added");
+///
+///     world.spawn(RngComponent::from(rng));
+/// }
+///
+/// App::new()
+///     .add_plugins(RngPlugin::new().with_rng_seed(12345))
+///     .add_systems(Update, spawn_boss);
+/// ```
+pub trait WorldRngExt {
+    /// Returns the [`GlobalRng`] resource.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`GlobalRng`] resource is missing from the world.
+    #[cfg(feature = "wyrand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+    fn rng(&mut self) -> Mut<'_, GlobalRng>;
+
+    /// Forks a new [`Rng`] from the [`GlobalRng`] resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MissingGlobalRng`] if the [`GlobalRng`] resource is missing from the world,
+    /// instead of inserting a randomly seeded one that would silently break determinism.
+    #[cfg(feature = "wyrand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+    fn fork_rng(&mut self) -> Result<Rng, MissingGlobalRng>;
+
+    /// Forks a new [`ChaChaRng`] from the [`GlobalChaChaRng`] resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MissingGlobalRng`] if the [`GlobalChaChaRng`] resource is missing from the
+    /// world.
+    #[cfg(feature = "chacha")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
+    fn fork_chacha(&mut self) -> Result<ChaChaRng, MissingGlobalRng>;
+
+    /// Returns the [`RngComponent`] of `entity`, or `None` if the entity doesn't exist or
+    /// has no [`RngComponent`].
+    #[cfg(feature = "wyrand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+    fn rng_component_for(&mut self, entity: Entity) -> Option<Mut<'_, RngComponent>>;
+}
+
+impl WorldRngExt for World {
+    #[cfg(feature = "wyrand")]
+    #[inline]
+    fn rng(&mut self) -> Mut<'_, GlobalRng> {
+        self.resource_mut::<GlobalRng>()
+    }
+
+    #[cfg(feature = "wyrand")]
+    #[inline]
+    fn fork_rng(&mut self) -> Result<Rng, MissingGlobalRng> {
+        self.get_resource_mut::<GlobalRng>()
+            .map(|mut global| global.fork())
+            .ok_or(MissingGlobalRng {
+                resource: "GlobalRng",
+            })
+    }
+
+    #[cfg(feature = "chacha")]
+    #[inline]
+    fn fork_chacha(&mut self) -> Result<ChaChaRng, MissingGlobalRng> {
+        self.get_resource_mut::<GlobalChaChaRng>()
+            .map(|mut global| global.fork())
+            .ok_or(MissingGlobalRng {
+                resource: "GlobalChaChaRng",
+            })
+    }
+
+    #[cfg(feature = "wyrand")]
+    #[inline]
+    fn rng_component_for(&mut self, entity: Entity) -> Option<Mut<'_, RngComponent>> {
+        self.get_mut::<RngComponent>(entity)
+    }
+}
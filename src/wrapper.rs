@@ -0,0 +1,208 @@
+use crate::*;
+use std::fmt::Debug;
+
+/// The bounds an RNG source needs to satisfy to be wrapped by a [`GlobalRngWrapper`] or an
+/// [`RngComponentWrapper`]: those of [`DelegatedRng::Source`], along with being [`Send`] and
+/// [`Sync`] so that it can live in the ECS. Implemented automatically for every type that
+/// satisfies them, so third-party generators only need to implement the `turborand` traits.
+///
+/// [`Rng`](turborand::prelude::Rng) and [`ChaChaRng`](turborand::prelude::ChaChaRng) aren't
+/// [`Sync`], which is why they keep their own dedicated wrappers instead.
+pub trait RngSource:
+    Default
+    + Debug
+    + Clone
+    + PartialEq
+    + TurboCore
+    + GenCore
+    + TurboRand
+    + SeededCore
+    + ForkableCore
+    + Send
+    + Sync
+    + 'static
+{
+}
+
+impl<T> RngSource for T where
+    T: Default
+        + Debug
+        + Clone
+        + PartialEq
+        + TurboCore
+        + GenCore
+        + TurboRand
+        + SeededCore
+        + ForkableCore
+        + Send
+        + Sync
+        + 'static
+{
+}
+
+/// A global RNG [`Resource`] wrapping any [`RngSource`], such as a generator from another
+/// crate, for when neither [`GlobalRng`] nor [`GlobalChaChaRng`] fit. Gets all its methods
+/// from [`DelegatedRng`], the same as the built-in globals, and can be inserted with
+/// [`RngSourceAppExt::add_rng_source`].
+///
+/// Unlike the built-in globals, the wrapper isn't registered for reflection, as that would
+/// require the wrapped source to implement [`Reflect`].
+#[derive(Debug, Clone, Default, PartialEq, Resource)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct GlobalRngWrapper<T: RngSource>(T);
+
+impl<T: RngSource> GlobalRngWrapper<T> {
+    /// Create a new [`GlobalRngWrapper`] instance with a default source, which is usually
+    /// randomly seeded.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self(T::default())
+    }
+
+    /// Create a new [`GlobalRngWrapper`] instance with a given seed.
+    #[inline]
+    #[must_use]
+    pub fn with_seed(seed: T::Seed) -> Self {
+        Self(T::with_seed(seed))
+    }
+
+    /// Wraps an existing source as is, taking ownership of it without reseeding or forking.
+    #[inline]
+    #[must_use]
+    pub const fn from_source(rng: T) -> Self {
+        Self(rng)
+    }
+
+    /// Consumes the [`GlobalRngWrapper`], returning the wrapped source with its state intact.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: RngSource> DelegatedRng for GlobalRngWrapper<T> {
+    type Source = T;
+
+    #[inline]
+    fn get_mut(&mut self) -> &mut Self::Source {
+        &mut self.0
+    }
+}
+
+/// An RNG [`Component`] wrapping any [`RngSource`], such as a generator from another crate,
+/// for when neither [`RngComponent`] nor [`ChaChaRngComponent`] fit. Gets all its methods
+/// from [`DelegatedRng`], the same as the built-in components, and can be forked from any
+/// [`DelegatedRng`] with the same source, such as a [`GlobalRngWrapper`].
+///
+/// Unlike the built-in components, the wrapper isn't registered for reflection, as that
+/// would require the wrapped source to implement [`Reflect`].
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn spawn_enemy<T: RngSource>(
+///     mut commands: Commands,
+///     mut global: ResMut<GlobalRngWrapper<T>>,
+/// ) {
+///     commands.spawn(RngComponentWrapper::from(&mut global));
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Component)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct RngComponentWrapper<T: RngSource>(T);
+
+impl<T: RngSource> RngComponentWrapper<T> {
+    /// Create a new [`RngComponentWrapper`] instance with a default source, which is usually
+    /// randomly seeded.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self(T::default())
+    }
+
+    /// Create a new [`RngComponentWrapper`] instance with a given seed.
+    #[inline]
+    #[must_use]
+    pub fn with_seed(seed: T::Seed) -> Self {
+        Self(T::with_seed(seed))
+    }
+
+    /// Wraps an existing source as is, taking ownership of it without reseeding or forking.
+    #[inline]
+    #[must_use]
+    pub const fn from_source(rng: T) -> Self {
+        Self(rng)
+    }
+
+    /// Consumes the [`RngComponentWrapper`], returning the wrapped source with its state
+    /// intact.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: RngSource> DelegatedRng for RngComponentWrapper<T> {
+    type Source = T;
+
+    #[inline]
+    fn get_mut(&mut self) -> &mut Self::Source {
+        &mut self.0
+    }
+}
+
+impl<T: RngSource, R: DelegatedRng<Source = T>> From<&mut R> for RngComponentWrapper<T> {
+    #[inline]
+    fn from(rng: &mut R) -> Self {
+        Self(rng.fork())
+    }
+}
+
+impl<T: RngSource, R: DelegatedRng<Source = T>> From<&mut Mut<'_, R>> for RngComponentWrapper<T> {
+    #[inline]
+    fn from(rng: &mut Mut<'_, R>) -> Self {
+        Self(rng.fork())
+    }
+}
+
+impl<T, R> From<&mut ResMut<'_, R>> for RngComponentWrapper<T>
+where
+    T: RngSource,
+    R: DelegatedRng<Source = T> + Resource,
+{
+    #[inline]
+    fn from(rng: &mut ResMut<'_, R>) -> Self {
+        Self(rng.fork())
+    }
+}
+
+/// Extension methods for inserting a [`GlobalRngWrapper`] into an [`App`], the same way
+/// [`RngPlugin`] inserts the built-in globals.
+pub trait RngSourceAppExt {
+    /// Inserts a [`GlobalRngWrapper`] with a default source, which is usually randomly
+    /// seeded, unless one is already present.
+    fn add_rng_source<T: RngSource>(&mut self) -> &mut Self;
+
+    /// Inserts a [`GlobalRngWrapper`] seeded with the given seed, unless one is already
+    /// present, in which case its state is kept.
+    fn add_seeded_rng_source<T: RngSource>(&mut self, seed: T::Seed) -> &mut Self;
+}
+
+impl RngSourceAppExt for App {
+    fn add_rng_source<T: RngSource>(&mut self) -> &mut Self {
+        self.init_resource::<GlobalRngWrapper<T>>()
+    }
+
+    fn add_seeded_rng_source<T: RngSource>(&mut self, seed: T::Seed) -> &mut Self {
+        if !self.world().contains_resource::<GlobalRngWrapper<T>>() {
+            self.insert_resource(GlobalRngWrapper::<T>::with_seed(seed));
+        }
+
+        self
+    }
+}
@@ -0,0 +1,105 @@
+use crate::*;
+use rand_core::{Error, RngCore, SeedableRng};
+
+/// A `rand_core` adapter over an [`Rng`], seeded the same way as the `WyRand` source of the
+/// `wyrand` crate, as used by `bevy_rand`: the seed bytes are taken as its exact state.
+/// Both implement the same algorithm, so a [`WyRandCompat`] and a `WyRand` created from the
+/// same seed bytes yield the exact same outputs, which allows migrating to `bevy_rand`
+/// gradually, with both crates continuing each other's streams.
+///
+/// Note that the seeds themselves are not interchangeable: [`RngComponent::with_seed`]
+/// derives its state from the seed, unlike `WyRand::from_seed`. Only the state bytes, as
+/// given by [`RngComponent::to_wyrand_entropy_bytes`], carry over exactly.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+/// use rand_core::{RngCore, SeedableRng};
+///
+/// let mut rng = RngComponent::with_seed(5);
+///
+/// // The same as seeding `bevy_rand`'s `EntropyComponent::<WyRand>::from_seed`.
+/// let mut adapter = WyRandCompat::from_seed(rng.to_wyrand_entropy_bytes());
+///
+/// assert_eq!(adapter.next_u64(), rng.u64(..));
+///
+/// // And back, continuing where the adapter left off.
+/// let mut restored = RngComponent::from(adapter.clone());
+///
+/// assert_eq!(restored.u64(..), adapter.next_u64());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy_rand-compat")))]
+pub struct WyRandCompat(Rng);
+
+impl WyRandCompat {
+    /// Returns the exact state of the wrapped [`Rng`] as little-endian bytes, which seed a
+    /// `WyRand` source to continue the same stream.
+    #[inline]
+    #[must_use]
+    pub fn state_bytes(&self) -> [u8; 8] {
+        let mut bytes = [0; 8];
+
+        bytes.copy_from_slice(&StateBytes::state_bytes(&self.0));
+
+        bytes
+    }
+
+    /// Consumes the [`WyRandCompat`], returning the wrapped [`Rng`] with its state intact.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Rng {
+        self.0
+    }
+}
+
+impl RngCore for WyRandCompat {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.gen_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.gen_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.0.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for WyRandCompat {
+    /// The exact state of the [`Rng`], as little-endian bytes.
+    type Seed = [u8; 8];
+
+    /// Creates a new [`WyRandCompat`] with the `seed` bytes as the exact state, matching
+    /// `WyRand::from_seed` of the `wyrand` crate.
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self(Rng::from_state_bytes(&seed).expect("any 8 bytes should be a valid Rng state"))
+    }
+}
+
+impl From<RngComponent> for WyRandCompat {
+    /// Wraps the [`Rng`] of the [`RngComponent`] as is, keeping its state intact.
+    #[inline]
+    fn from(rng: RngComponent) -> Self {
+        Self(rng.into_inner())
+    }
+}
+
+impl From<WyRandCompat> for RngComponent {
+    /// Wraps the [`Rng`] of the [`WyRandCompat`] as is, keeping its state intact.
+    #[inline]
+    fn from(rng: WyRandCompat) -> Self {
+        Self::from(rng.into_inner())
+    }
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+use std::collections::HashSet;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// A pool of 12 affixes, split into 4 groups of 3.
+fn pool() -> AffixRoller<u32> {
+    (0..12)
+        .map(|id| AffixEntry {
+            id,
+            weight: f64::from(id % 3 + 1) * 2.5,
+            group: id / 3,
+        })
+        .collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rolls_are_deterministic() {
+    let roller = pool();
+
+    let rolled = roller.roll(3, &mut RngComponent::with_seed(9));
+
+    assert_eq!(rolled, roller.roll(3, &mut RngComponent::with_seed(9)));
+    assert!(rolled.complete);
+    assert_eq!(rolled.affixes, vec![8, 5, 2]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rolls_are_unique_and_group_exclusive() {
+    let roller = pool();
+    let mut rng = RngComponent::with_seed(9);
+
+    for _ in 0..500 {
+        let rolled = roller.roll(4, &mut rng);
+
+        assert!(rolled.complete);
+
+        let ids: HashSet<u32> = rolled.affixes.iter().copied().collect();
+        let groups: HashSet<u32> = rolled.affixes.iter().map(|id| id / 3).collect();
+
+        assert_eq!(ids.len(), 4);
+        assert_eq!(groups.len(), 4);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn impossible_rolls_return_as_many_as_possible() {
+    let roller = pool().with_affix(100, 0.0, 10).with_affix(101, -1.0, 11);
+    let mut rng = RngComponent::with_seed(9);
+
+    let rolled = roller.roll(6, &mut rng);
+
+    // The zero and negative weight groups can never be rolled.
+    assert!(!rolled.complete);
+    assert_eq!(rolled.affixes.len(), 4);
+
+    let empty = AffixRoller::<u32>::new().roll(1, &mut rng);
+
+    assert!(!empty.complete);
+    assert!(empty.affixes.is_empty());
+    assert!(AffixRoller::<u32>::new().roll(0, &mut rng).complete);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rolls_follow_relative_weights() {
+    let roller = AffixRoller::new()
+        .with_affix("common", 9.0, 0)
+        .with_affix("rare", 1.0, 0);
+    let mut rng = RngComponent::with_seed(9);
+
+    let rares = (0..10_000)
+        .filter(|_| roller.roll(1, &mut rng).affixes == ["rare"])
+        .count();
+
+    assert!((900..1100).contains(&rares), "{rares} rares rolled");
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pools_load_from_data() {
+    let payload = r#"[
+        (id: "sharp", weight: 10.0, group: 0),
+        (id: "keen", weight: 5.0, group: 0),
+        (id: "fiery", weight: 3.0, group: 1),
+    ]"#;
+
+    let roller: AffixRoller<String> = ron::from_str(payload).unwrap();
+
+    assert_eq!(roller.entries().len(), 3);
+    assert_eq!(roller.entries()[1].id, "keen");
+
+    let serialized = ron::to_string(&roller).unwrap();
+    let deserialized: AffixRoller<String> = ron::from_str(&serialized).unwrap();
+
+    assert_eq!(roller, deserialized);
+}
@@ -0,0 +1,323 @@
+#![cfg(feature = "audit")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Component, Default)]
+struct Chest;
+
+const LOOT: [u32; 3] = [1, 2, 3];
+
+fn open_chests(mut q_chests: Query<&mut RngComponent, With<Chest>>) {
+    let _label = audit_label("open_chests");
+
+    let mut rng = q_chests.single_mut();
+
+    if rng.chance(0.5) {
+        rng.weighted_sample(&LOOT, |(&item, _)| f64::from(item) / 4.0);
+    }
+}
+
+fn unlabelled_roll(mut global: ResMut<GlobalRng>) {
+    global.chance(0.25);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn audit_log_records_seeded_outcomes() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(12345).with_audit_capacity(8))
+        .add_systems(Update, (open_chests, unlabelled_roll).chain());
+
+    app.world_mut().spawn((Chest, RngComponent::with_seed(7)));
+
+    let log = app.world().resource::<RngAuditLog>().clone();
+
+    assert!(log.is_recording());
+    assert!(log.is_empty());
+
+    app.update();
+    app.update();
+
+    // Replay the same scenario directly against the sources to obtain the expected outcomes.
+    let mut chest = RngComponent::with_seed(7);
+    let mut global = GlobalRng::with_seed(12345);
+    let mut expected = Vec::new();
+
+    for _ in 0..2 {
+        let opened = chest.get_mut().chance(0.5);
+        expected.push((
+            Some("open_chests"),
+            "chance",
+            0.5,
+            AuditOutcome::Bool(opened),
+        ));
+
+        if opened {
+            let item = chest
+                .get_mut()
+                .weighted_sample(&LOOT, |(&item, _)| f64::from(item) / 4.0)
+                .copied();
+            let index = item.map(|item| item as usize - 1);
+            expected.push((
+                Some("open_chests"),
+                "weighted_sample",
+                item.map_or(0.0, |item| f64::from(item) / 6.0),
+                AuditOutcome::Index(index),
+            ));
+        }
+
+        let rolled = global.get_mut().chance(0.25);
+        expected.push((None, "chance", 0.25, AuditOutcome::Bool(rolled)));
+    }
+
+    let entries: Vec<_> = log
+        .entries()
+        .into_iter()
+        .map(|entry| {
+            (
+                entry.label.map(|label| label.into_owned()),
+                entry.method,
+                entry.input,
+                entry.outcome,
+            )
+        })
+        .collect();
+    let expected: Vec<_> = expected
+        .into_iter()
+        .map(|(label, method, input, outcome)| (label.map(String::from), method, input, outcome))
+        .collect();
+
+    assert_eq!(entries, expected);
+
+    let mut csv = Vec::new();
+    log.write_csv(&mut csv).unwrap();
+
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "label,method,input,outcome\n\
+        open_chests,chance,0.5,true\n\
+        open_chests,weighted_sample,0.3333333333333333,1\n\
+        ,chance,0.25,true\n\
+        open_chests,chance,0.5,true\n\
+        open_chests,weighted_sample,0.5,2\n\
+        ,chance,0.25,false\n"
+    );
+
+    // The log is bounded, discarding the oldest entries first.
+    for _ in 0..4 {
+        app.update();
+    }
+
+    assert_eq!(log.len(), 8);
+    assert!(log.dropped() > 0);
+
+    // Paused logs stop recording.
+    log.set_recording(false);
+    log.clear();
+    app.update();
+
+    assert!(!log.is_recording());
+    assert!(log.is_empty());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn apps_record_into_their_own_logs() {
+    let mut first = App::new();
+    let mut second = App::new();
+
+    first
+        .add_plugins(RngPlugin::new().with_rng_seed(1))
+        .add_systems(Update, unlabelled_roll);
+    second
+        .add_plugins(RngPlugin::new().with_rng_seed(2))
+        .add_systems(Update, (open_chests, unlabelled_roll).chain());
+
+    second
+        .world_mut()
+        .spawn((Chest, RngComponent::with_seed(7)));
+
+    first.update();
+    second.update();
+    second.update();
+
+    let first_log = first.world().resource::<RngAuditLog>();
+    let second_log = second.world().resource::<RngAuditLog>();
+
+    assert_eq!(first_log.len(), 1);
+    assert!(second_log.len() >= 4);
+    assert!(second_log
+        .entries()
+        .iter()
+        .any(|entry| entry.label.as_deref() == Some("open_chests")));
+
+    // Wrappers outside of any world aren't handed a log.
+    let mut detached = RngComponent::with_seed(7);
+
+    detached.chance(0.5);
+
+    assert!(detached.audit_log().is_none());
+    assert_eq!(first_log.len(), 1);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_samples_record_the_picked_index() {
+    #[derive(Clone, Copy)]
+    struct Marker;
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5));
+
+    let entity = app.world_mut().spawn(RngComponent::with_seed(9)).id();
+    let mut expected = RngComponent::with_seed(9);
+    let weights = [0.1, 0.2, 0.7];
+    let calls = std::cell::Cell::new(0);
+
+    let mut rng = app.world_mut().get_mut::<RngComponent>(entity).unwrap();
+
+    // Zero-sized elements share the same address, so the index can't be recovered from it.
+    let markers = [Marker; 3];
+    let picked: Vec<_> = (0..50)
+        .map(|_| {
+            rng.weighted_sample(&markers, |(_, index)| {
+                calls.set(calls.get() + 1);
+                weights[index]
+            })
+            .map(|_| ())
+        })
+        .collect();
+
+    assert!(picked.iter().all(Option::is_some));
+    assert_eq!(calls.get(), 50 * markers.len());
+
+    let log = app.world().resource::<RngAuditLog>();
+    let expected_indices: Vec<_> = (0..50)
+        .map(|_| {
+            let indices = [0, 1, 2];
+
+            expected
+                .get_mut()
+                .weighted_sample(&indices, |(_, index)| weights[index])
+                .copied()
+        })
+        .collect();
+    let indices: Vec<_> = log
+        .entries()
+        .into_iter()
+        .map(|entry| match entry.outcome {
+            AuditOutcome::Index(index) => index,
+            AuditOutcome::Bool(_) => unreachable!(),
+        })
+        .collect();
+
+    assert_eq!(indices, expected_indices);
+    assert!(indices.iter().any(|&index| index != Some(0)));
+}
+
+/// Clears the log and runs a single frame, returning only what that frame recorded.
+fn audited_frame(app: &mut App) -> Vec<AuditEntry> {
+    let log = app.world().resource::<RngAuditLog>().clone();
+
+    log.clear();
+    app.update();
+
+    log.entries()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn forked_on_spawn_components_keep_recording() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(12345))
+        .add_systems(Update, open_chests);
+
+    app.world_mut()
+        .spawn((Chest, RngComponent::with_seed(7), ForkOnSpawn));
+
+    app.update();
+    app.update();
+
+    let entries = audited_frame(&mut app);
+
+    assert!(!entries.is_empty());
+    assert!(entries
+        .iter()
+        .all(|entry| entry.label.as_deref() == Some("open_chests")));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reseeded_globals_keep_recording() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(12345))
+        .add_systems(Update, unlabelled_roll);
+
+    app.update();
+    app.update();
+
+    app.world_mut().resource_mut::<GlobalRng>().reseed(5);
+
+    assert_eq!(audited_frame(&mut app).len(), 1);
+
+    app.world_mut()
+        .resource_mut::<GlobalRng>()
+        .reseed_from_entropy();
+
+    assert_eq!(audited_frame(&mut app).len(), 1);
+    assert_eq!(audited_frame(&mut app).len(), 1);
+}
+
+#[cfg(feature = "bevy_state")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reseeding_on_entering_a_state_keeps_recording() {
+    use bevy::state::app::StatesPlugin;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, States)]
+    enum GameState {
+        #[default]
+        MainMenu,
+        InGame,
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins((
+        StatesPlugin,
+        RngPlugin::new()
+            .with_rng_seed(12345)
+            .reseed_on_enter(GameState::InGame, SeedSource::Fixed(5)),
+    ))
+    .init_state::<GameState>()
+    .add_systems(Update, (open_chests, unlabelled_roll).chain());
+
+    app.world_mut()
+        .spawn((Chest, RngComponent::with_seed(7), ReseedOnState));
+
+    app.update();
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::InGame);
+    app.update();
+
+    let entries = audited_frame(&mut app);
+
+    assert!(entries
+        .iter()
+        .any(|entry| entry.label.as_deref() == Some("open_chests")));
+    assert!(entries.iter().any(|entry| entry.label.is_none()));
+}
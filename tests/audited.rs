@@ -0,0 +1,121 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::{prelude::*, time::TimeUpdateStrategy, utils::Duration};
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn audited_rng_forwards_identical_values() {
+    let mut audited = AuditedRng::new(RngComponent::with_seed(5));
+    let mut plain = RngComponent::with_seed(5);
+
+    assert_eq!(audited.u64(..), plain.u64(..));
+    assert_eq!(audited.f32(), plain.f32());
+    assert_eq!(audited.chance(0.5), plain.chance(0.5));
+    assert_eq!(audited.sample(&[1, 2, 3]), plain.sample(&[1, 2, 3]));
+
+    audited.assert_draw_count(4);
+    assert_eq!(audited.into_inner(), plain);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "expected 2 RNG calls this tick, but 1 were made")]
+fn assert_draw_count_panics_on_mismatch() {
+    let mut audited = AuditedRng::new(GlobalRng::with_seed(5));
+
+    audited.bool();
+
+    audited.assert_draw_count(2);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn tick_history_is_bounded() {
+    let mut audited = AuditedRng::with_history(RngComponent::with_seed(5), 3);
+
+    for draws in 0..5 {
+        for _ in 0..draws {
+            audited.u8(..);
+        }
+
+        audited.end_tick();
+    }
+
+    assert_eq!(audited.tick_history().collect::<Vec<_>>(), [2, 3, 4]);
+}
+
+#[derive(Component)]
+struct PerTick;
+
+#[derive(Component)]
+struct PerFrame;
+
+fn fixed_draw(mut q_rngs: Query<&mut AuditedRng<RngComponent>, With<PerTick>>) {
+    for mut rng in q_rngs.iter_mut() {
+        rng.u32(..);
+    }
+}
+
+fn variable_draw(
+    mut q_rngs: Query<&mut AuditedRng<RngComponent>, With<PerFrame>>,
+    mut global: ResMut<AuditedRng<GlobalRng>>,
+) {
+    for mut rng in q_rngs.iter_mut() {
+        rng.u32(..);
+    }
+
+    global.u32(..);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn counts_are_recorded_per_fixed_tick() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AuditedRngPlugin::<RngComponent>::new(),
+        AuditedRngPlugin::<GlobalRng>::new(),
+    ))
+    .insert_resource(Time::<Fixed>::from_hz(4.0))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        125,
+    )))
+    .insert_resource(AuditedRng::new(GlobalRng::with_seed(5)))
+    .add_systems(FixedUpdate, fixed_draw)
+    .add_systems(Update, variable_draw);
+
+    let fixed = app
+        .world_mut()
+        .spawn((AuditedRng::new(RngComponent::with_seed(1)), PerTick))
+        .id();
+    let variable = app
+        .world_mut()
+        .spawn((AuditedRng::new(RngComponent::with_seed(2)), PerFrame))
+        .id();
+
+    // At 8 frames per second, the 4Hz fixed schedule runs on every other frame.
+    for _ in 0..9 {
+        app.update();
+    }
+
+    let fixed = app.world().get::<AuditedRng<RngComponent>>(fixed).unwrap();
+    let variable = app
+        .world()
+        .get::<AuditedRng<RngComponent>>(variable)
+        .unwrap();
+    let global = app.world().resource::<AuditedRng<GlobalRng>>();
+
+    assert_eq!(fixed.tick_history().collect::<Vec<_>>(), [1; 4]);
+    // Systems in `Update` run twice per fixed tick, giving away that they aren't fixed.
+    assert_eq!(variable.tick_history().collect::<Vec<_>>(), [2; 4]);
+    assert_eq!(global.tick_history().collect::<Vec<_>>(), [2; 4]);
+    fixed.assert_draw_count(0);
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "bevy_rand-compat")]
+
+use bevy_turborand::prelude::*;
+use rand_core::{impls, Error, RngCore, SeedableRng};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// A stand-in for the `WyRand` source of the `wyrand` crate, as used by `bevy_rand`,
+/// following the same algorithm and seeding.
+struct WyRand {
+    state: u64,
+}
+
+impl WyRand {
+    fn state(&self) -> u64 {
+        self.state
+    }
+}
+
+impl SeedableRng for WyRand {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self {
+            state: u64::from_le_bytes(seed),
+        }
+    }
+}
+
+impl RngCore for WyRand {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0xa076_1d64_78bd_642f);
+        let t = u128::from(self.state).wrapping_mul(u128::from(self.state ^ 0xe703_7ed1_a0b4_28db));
+        (t.wrapping_shr(64) ^ t) as u64
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn component_state_transfers_to_wyrand() {
+    let mut rng = RngComponent::with_seed(5);
+
+    // Transfers mid-stream, not just from the initial seed.
+    rng.u64(..);
+
+    let bytes = rng.to_wyrand_entropy_bytes();
+
+    assert_eq!(rng.to_wyrand_entropy_bytes(), bytes);
+
+    let mut wyrand = WyRand::from_seed(bytes);
+    let mut compat = WyRandCompat::from_seed(bytes);
+
+    for _ in 0..100 {
+        let expected = rng.u64(..);
+
+        assert_eq!(wyrand.next_u64(), expected);
+        assert_eq!(compat.next_u64(), expected);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn wyrand_state_transfers_to_component() {
+    let mut wyrand = WyRand::seed_from_u64(9);
+
+    wyrand.next_u64();
+
+    let mut rng = RngComponent::from_entropy_bytes(wyrand.state().to_le_bytes());
+
+    for _ in 0..100 {
+        assert_eq!(rng.u64(..), wyrand.next_u64());
+    }
+
+    assert_eq!(
+        RngComponent::from_entropy_bytes(rng.to_wyrand_entropy_bytes()),
+        rng
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn entropy_bytes_read_through_shared_references() {
+    let rng = RngComponent::with_seed(5);
+    let shared = &rng;
+
+    // Seeding derives the state from the seed, so only the state bytes carry over.
+    assert_eq!(shared.to_wyrand_entropy_bytes(), 11u64.to_le_bytes());
+    assert_eq!(RngComponent::from_entropy_bytes(11u64.to_le_bytes()), rng);
+    assert_ne!(RngComponent::from_entropy_bytes(5u64.to_le_bytes()), rng);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn compat_matches_wyrand_from_the_same_seed() {
+    let mut wyrand = WyRand::from_seed(9u64.to_le_bytes());
+    let mut compat = WyRandCompat::from_seed(9u64.to_le_bytes());
+
+    assert_eq!(compat.state_bytes(), 9u64.to_le_bytes());
+
+    for _ in 0..100 {
+        assert_eq!(compat.next_u64(), wyrand.next_u64());
+    }
+
+    assert_eq!(compat.state_bytes(), wyrand.state().to_le_bytes());
+
+    let mut rng = RngComponent::from(compat.clone());
+
+    assert_eq!(rng.u64(..), wyrand.next_u64());
+    assert_eq!(WyRandCompat::from(rng), {
+        compat.next_u64();
+        compat
+    });
+}
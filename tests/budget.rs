@@ -0,0 +1,138 @@
+#![cfg(all(feature = "diagnostics", feature = "wyrand"))]
+
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+use bevy_turborand::prelude::*;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// The call counts are shared by the whole process, so tests touching them must not overlap.
+static COUNTS: Mutex<()> = Mutex::new(());
+
+fn lock_counts() -> MutexGuard<'static, ()> {
+    COUNTS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn hot_loop(mut global: ResMut<GlobalRng>) {
+    for _ in 0..20 {
+        global.u32(..);
+    }
+}
+
+fn light_use(mut global: ResMut<GlobalRng>) {
+    global.u32(..);
+}
+
+fn budget_app(budget: RngBudget) -> App {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(1))
+        .insert_resource(budget);
+
+    app
+}
+
+fn drain_violations(app: &mut App) -> Vec<RngBudgetExceeded> {
+    app.world_mut()
+        .resource_mut::<Events<RngBudgetExceeded>>()
+        .drain()
+        .collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reports_violation_once_per_frame() {
+    let _lock = lock_counts();
+
+    let mut app = budget_app(RngBudget::new().with_limit(GlobalRng::CALLS_DIAGNOSTIC, 10));
+
+    app.add_systems(Update, hot_loop)
+        .add_systems(PostUpdate, hot_loop);
+
+    for _ in 0..3 {
+        app.update();
+
+        let violations = drain_violations(&mut app);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].wrapper, GlobalRng::CALLS_DIAGNOSTIC);
+        assert_eq!(violations[0].schedule, Update.intern());
+        assert_eq!(violations[0].calls, 20);
+        assert_eq!(violations[0].max_calls, 10);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn names_the_schedule_going_over_budget() {
+    let _lock = lock_counts();
+
+    let mut app = budget_app(RngBudget::new().with_limit(GlobalRng::CALLS_DIAGNOSTIC, 25));
+
+    app.add_systems(Update, hot_loop)
+        .add_systems(PostUpdate, hot_loop);
+
+    app.update();
+
+    let violations = drain_violations(&mut app);
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].schedule, PostUpdate.intern());
+    assert_eq!(violations[0].calls, 40);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn stays_quiet_within_budget() {
+    let _lock = lock_counts();
+
+    let mut app = budget_app(
+        RngBudget::new()
+            .with_limit(GlobalRng::CALLS_DIAGNOSTIC, 10)
+            .with_limit(RngComponent::CALLS_DIAGNOSTIC, 0),
+    );
+
+    app.add_systems(Update, light_use);
+
+    for _ in 0..20 {
+        app.update();
+
+        assert!(drain_violations(&mut app).is_empty());
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn does_nothing_without_budget() {
+    let _lock = lock_counts();
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(1))
+        .add_systems(Update, hot_loop);
+
+    app.update();
+
+    assert!(drain_violations(&mut app).is_empty());
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "over its budget of 10 per frame")]
+fn panics_in_debug_when_configured() {
+    let _lock = lock_counts();
+
+    let mut app = budget_app(
+        RngBudget::new()
+            .with_limit(GlobalRng::CALLS_DIAGNOSTIC, 10)
+            .with_action(RngBudgetAction::PanicInDebug),
+    );
+
+    app.add_systems(Update, hot_loop);
+
+    app.update();
+}
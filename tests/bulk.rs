@@ -0,0 +1,83 @@
+#![cfg(feature = "wyrand")]
+
+#[cfg(feature = "bevy_math")]
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn fill_f32_range_matches_single_calls() {
+    let mut bulk = RngComponent::with_seed(5);
+    let mut single = RngComponent::with_seed(5);
+
+    let mut out = [0.0; 64];
+    bulk.fill_f32_range(&mut out, -2.5..7.5);
+
+    for value in out {
+        assert_eq!(value, single.lerp_f32(-2.5, 7.5));
+        assert!((-2.5..=7.5).contains(&value));
+    }
+
+    assert_eq!(bulk, single);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn fill_u32_range_matches_single_calls() {
+    let mut bulk = GlobalRng::with_seed(5);
+    let mut single = GlobalRng::with_seed(5);
+
+    let mut out = [0; 64];
+    bulk.fill_u32_range(&mut out, 10..=20);
+
+    for value in out {
+        assert_eq!(value, single.u32(10..=20));
+    }
+
+    assert_eq!(bulk, single);
+
+    bulk.fill_u32_range(&mut out, ..);
+
+    for value in out {
+        assert_eq!(value, single.u32(..));
+    }
+}
+
+#[cfg(feature = "bevy_math")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn fill_vec2_matches_single_calls() {
+    let mut bulk = RngComponent::with_seed(5);
+    let mut single = RngComponent::with_seed(5);
+
+    let mut out = [Vec2::ZERO; 64];
+    bulk.fill_vec2(&mut out, 0.0..1920.0, -1080.0..0.0);
+
+    for value in out {
+        let x = single.lerp_f32(0.0, 1920.0);
+        let y = single.lerp_f32(-1080.0, 0.0);
+
+        assert_eq!(value, Vec2::new(x, y));
+    }
+
+    assert_eq!(bulk, single);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn empty_fills_draw_nothing() {
+    let mut rng = RngComponent::with_seed(5);
+
+    rng.fill_f32_range(&mut [], 0.0..1.0);
+    rng.fill_u32_range(&mut [], 5..5);
+    #[cfg(feature = "bevy_math")]
+    rng.fill_vec2(&mut [], 0.0..1.0, 0.0..1.0);
+
+    assert_eq!(rng, RngComponent::with_seed(5));
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+use std::io::Read;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn streams_match_one_big_fill() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut expected = rng.clone();
+
+    let mut stream = rng.byte_stream(64);
+    let mut output = Vec::new();
+
+    for size in [1, 3, 0, 70, 5, 200, 2] {
+        if size == 1 {
+            output.push(stream.next_u8());
+        } else {
+            output.extend_from_slice(stream.take_bytes(size));
+        }
+    }
+
+    output.extend((0..31).map(|_| Iterator::next(&mut stream).unwrap()));
+
+    let mut reference = vec![0; output.len()];
+    expected.fill_bytes(&mut reference);
+
+    assert_eq!(output, reference);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chunks_are_only_generated_when_needed() {
+    let mut rng = RngComponent::with_seed(5);
+    let untouched = rng.clone();
+
+    let mut stream = rng.byte_stream(16);
+
+    assert_eq!(stream.remaining(), 0);
+    assert_eq!(stream.chunk(), 16);
+
+    stream.next_u8();
+
+    assert_eq!(stream.remaining(), 15);
+
+    stream.take_bytes(40);
+
+    // The 15 buffered bytes and two more chunks, less the 40 taken.
+    assert_eq!(stream.remaining(), 7);
+
+    stream.refill();
+
+    assert_eq!(stream.remaining(), 16);
+    assert_ne!(rng, untouched);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reads_serve_the_same_bytes() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut expected = rng.clone();
+
+    let mut buffer = [0; 100];
+    rng.byte_stream(24).read_exact(&mut buffer).unwrap();
+
+    let mut reference = [0; 100];
+    expected.fill_bytes(&mut reference);
+
+    assert_eq!(buffer, reference);
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_streams_match_for_any_chunk_size() {
+    let mut rng = ChaChaRngComponent::with_seed([3; 40]);
+    let mut expected = rng.clone();
+
+    let mut stream = rng.byte_stream(7);
+    let output: Vec<u8> = (0..10)
+        .flat_map(|n| stream.take_bytes(n).to_vec())
+        .collect();
+
+    let mut reference = vec![0; output.len()];
+    expected.fill_bytes(&mut reference);
+
+    assert_eq!(output, reference);
+}
+
+#[test]
+#[should_panic(expected = "byte stream chunk size must not be zero")]
+fn zero_sized_chunks_are_rejected() {
+    let mut rng = RngComponent::with_seed(5);
+
+    let _ = rng.byte_stream(0);
+}
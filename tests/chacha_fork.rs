@@ -0,0 +1,87 @@
+#![cfg(feature = "chacha")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn batch_forks_match_sequential_forks() {
+    let mut batched = ChaChaRngComponent::with_seed([3; 40]);
+    let mut sequential = batched.clone();
+
+    let forks = batched.fork_components(3);
+
+    assert_eq!(
+        forks,
+        [
+            sequential.fork_component(),
+            sequential.fork_component(),
+            sequential.fork_component()
+        ]
+    );
+    assert_eq!(batched, sequential);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn global_batch_forks_match_sequential_forks() {
+    let mut batched = GlobalChaChaRng::with_seed([3; 40]);
+    let mut sequential = batched.clone();
+    let mut sources = batched.clone();
+
+    let forks = batched.fork_components(3);
+
+    assert_eq!(
+        forks,
+        [
+            sequential.fork_component(),
+            sequential.fork_component(),
+            sequential.fork_component()
+        ]
+    );
+    assert_eq!(
+        forks,
+        sources
+            .fork_many(3)
+            .into_iter()
+            .map(ChaChaRngComponent::from)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(batched, sequential);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn forked_children_diverge() {
+    let mut parent = ChaChaRngComponent::with_seed([3; 40]);
+
+    let mut children = parent.fork_components(4);
+    let outputs: Vec<u128> = children.iter_mut().map(|child| child.u128(..)).collect();
+
+    for (index, output) in outputs.iter().enumerate() {
+        assert!(!outputs[index + 1..].contains(output));
+    }
+
+    // A child is not a copy of its parent either.
+    assert_ne!(parent.clone().u128(..), outputs[0]);
+    assert_ne!(parent.fork_component(), parent);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn forked_components_wrap_forked_sources() {
+    let mut parent = ChaChaRngComponent::with_seed([3; 40]);
+    let mut expected = parent.clone();
+
+    assert_eq!(
+        parent.fork_component(),
+        ChaChaRngComponent::from(expected.fork())
+    );
+    assert!(parent.fork_components(0).is_empty());
+    assert_eq!(parent, expected);
+}
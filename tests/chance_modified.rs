@@ -0,0 +1,104 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn modified_chances_match_chance_with_the_sum() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut expected = RngComponent::with_seed(5);
+
+    for (base, modifier) in [(0.1, 0.2), (0.5, -0.25), (0.3, 0.3), (0.75, 0.0)] {
+        assert_eq!(
+            rng.chance_modified(base, modifier),
+            expected.chance(base + modifier)
+        );
+    }
+
+    assert_eq!(rng, expected);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn multiplied_chances_match_chance_with_the_product() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut expected = RngComponent::with_seed(5);
+
+    for (base, factor) in [(0.1, 2.0), (0.5, 0.5), (0.3, 1.5), (0.75, 1.0)] {
+        assert_eq!(
+            rng.chance_multiplied(base, factor),
+            expected.chance(base * factor)
+        );
+    }
+
+    assert_eq!(rng, expected);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn modified_chances_are_clamped() {
+    let mut rng = RngComponent::with_seed(5);
+
+    for _ in 0..100 {
+        assert!(!rng.chance_modified(1.5, -2.0));
+        assert!(rng.chance_modified(-0.5, 2.0));
+        assert!(rng.chance_modified(0.9, 0.2));
+        assert!(!rng.chance_modified(0.1, -0.2));
+        assert!(rng.chance_multiplied(0.5, 3.0));
+        assert!(!rng.chance_multiplied(0.5, -1.0));
+        assert!(rng.chance_modified(0.0, f64::INFINITY));
+    }
+
+    // Fully clamped rates never advance the RNG.
+    assert_eq!(rng, RngComponent::with_seed(5));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn nan_rates_never_succeed() {
+    let mut rng = RngComponent::with_seed(5);
+
+    for _ in 0..100 {
+        assert!(!rng.chance_modified(f64::NAN, 0.5));
+        assert!(!rng.chance_modified(0.5, f64::NAN));
+        assert!(!rng.chance_modified(f64::INFINITY, f64::NEG_INFINITY));
+        assert!(!rng.chance_multiplied(f64::NAN, 2.0));
+        assert!(!rng.chance_multiplied(f64::INFINITY, 0.0));
+    }
+
+    assert_eq!(rng, RngComponent::with_seed(5));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn modified_chances_are_deterministic() {
+    let mut rng = RngComponent::with_seed(5);
+
+    let outcomes: Vec<bool> = (0..8)
+        .map(|step| rng.chance_modified(0.25, f64::from(step) * 0.05))
+        .collect();
+
+    assert_eq!(
+        outcomes,
+        [false, false, false, false, true, false, true, false]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn f32_chances_match_chance() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut expected = RngComponent::with_seed(5);
+
+    for rate in [0.0f32, 0.1, 0.5, 0.9, 1.0] {
+        assert_eq!(rng.chance_f32(rate), expected.chance(f64::from(rate)));
+    }
+
+    assert_eq!(rng, expected);
+}
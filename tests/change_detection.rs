@@ -0,0 +1,167 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Component)]
+struct Parent;
+
+/// Runs `system` once, returning whether the [`RngComponent`] of the `Parent` entity and the
+/// [`GlobalRng`] were flagged as changed by it.
+fn changes_after<M>(system: impl IntoSystem<(), (), M>) -> (bool, bool) {
+    let mut world = World::new();
+
+    world.insert_resource(GlobalRng::with_seed(5));
+    world.spawn((Parent, RngComponent::with_seed(1)));
+    world.clear_trackers();
+
+    world.run_system_once(system).unwrap();
+
+    let component = world
+        .query_filtered::<Ref<RngComponent>, With<Parent>>()
+        .single(&world)
+        .is_changed();
+    let global = world.resource_ref::<GlobalRng>().is_changed();
+
+    (component, global)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn querying_without_drawing_is_untracked() {
+    let changes = changes_after(
+        |mut q_rng: Query<&mut RngComponent>| {
+            for _rng in q_rng.iter_mut() {}
+        },
+    );
+
+    assert_eq!(changes, (false, false));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn delegated_draws_are_tracked() {
+    let changes = changes_after(|mut q_rng: Query<&mut RngComponent>| {
+        q_rng.single_mut().u32(..);
+    });
+
+    assert_eq!(changes, (true, false));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn tracked_access_is_flagged() {
+    let changes = changes_after(
+        |mut q_rng: Query<&mut RngComponent>, mut global: ResMut<GlobalRng>| {
+            RngComponent::get_tracked(&mut q_rng.single_mut());
+            GlobalRng::get_tracked(&mut global);
+        },
+    );
+
+    assert_eq!(changes, (true, true));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn untracked_draws_are_not_flagged() {
+    let changes = changes_after(
+        |mut q_rng: Query<&mut RngComponent>, mut global: ResMut<GlobalRng>| {
+            RngComponent::get_untracked(&mut q_rng.single_mut()).u32(..);
+            GlobalRng::get_untracked(&mut global).u32(..);
+        },
+    );
+
+    assert_eq!(changes, (false, false));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn untracked_draws_still_advance_the_state() {
+    let mut world = World::new();
+    let entity = world.spawn(RngComponent::with_seed(1)).id();
+
+    let mut rng = world.get_mut::<RngComponent>(entity).unwrap();
+    let drawn = RngComponent::get_untracked(&mut rng).u32(..);
+
+    let mut expected = RngComponent::with_seed(1);
+
+    assert_eq!(drawn, expected.u32(..));
+    assert_eq!(world.get::<RngComponent>(entity), Some(&expected));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seeding_children_is_tracked_by_default() {
+    let changes = changes_after(
+        |mut commands: Commands, mut q_rng: Query<&mut RngComponent, With<Parent>>| {
+            let mut parent = q_rng.single_mut();
+
+            commands.spawn(RngComponent::from(&mut parent));
+        },
+    );
+
+    assert_eq!(changes, (true, false));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seeding_children_untracked_leaves_the_parent_unflagged() {
+    let changes = changes_after(
+        |mut commands: Commands,
+         mut q_rng: Query<&mut RngComponent, With<Parent>>,
+         mut global: ResMut<GlobalRng>| {
+            let mut parent = q_rng.single_mut();
+
+            commands.spawn(RngComponent::from_parent_untracked(&mut parent));
+            commands.spawn(RngComponent::from_parent_untracked(&mut global));
+        },
+    );
+
+    assert_eq!(changes, (false, false));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn untracked_seeding_matches_tracked_seeding() {
+    let mut world = World::new();
+    let tracked = world.spawn(RngComponent::with_seed(3)).id();
+    let untracked = world.spawn(RngComponent::with_seed(3)).id();
+
+    let child = RngComponent::from(&mut world.get_mut::<RngComponent>(tracked).unwrap());
+    let untracked_child =
+        RngComponent::from_parent_untracked(&mut world.get_mut::<RngComponent>(untracked).unwrap());
+
+    assert_eq!(child, untracked_child);
+    assert_eq!(
+        world.get::<RngComponent>(tracked),
+        world.get::<RngComponent>(untracked)
+    );
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_children_can_be_seeded_untracked() {
+    let mut world = World::new();
+
+    world.insert_resource(GlobalChaChaRng::with_seed([2; 40]));
+    world.clear_trackers();
+
+    let child = world
+        .run_system_once(|mut global: ResMut<GlobalChaChaRng>| {
+            ChaChaRngComponent::from_parent_untracked(&mut global)
+        })
+        .unwrap();
+
+    assert!(!world.resource_ref::<GlobalChaChaRng>().is_changed());
+    assert_eq!(
+        child,
+        ChaChaRngComponent::from(&mut GlobalChaChaRng::with_seed([2; 40]))
+    );
+}
@@ -0,0 +1,129 @@
+#![cfg(all(feature = "wyrand", feature = "bevy_math"))]
+
+use bevy::math::{IVec2, IVec3};
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn stream(rng: &Rng) -> Vec<u64> {
+    (0..8).map(|_| rng.gen_u64()).collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chunk_streams_are_independent_of_request_order() {
+    let provider = ChunkRngProvider::new(12345);
+
+    let coords: Vec<IVec2> = (-3..3)
+        .flat_map(|x| (-3..3).map(move |y| IVec2::new(x, y)))
+        .collect();
+
+    let forwards: Vec<Vec<u64>> = coords
+        .iter()
+        .map(|&coord| stream(&provider.rng_for(coord)))
+        .collect();
+
+    let mut backwards: Vec<Vec<u64>> = coords
+        .iter()
+        .rev()
+        .map(|&coord| stream(&provider.rng_for(coord)))
+        .collect();
+
+    backwards.reverse();
+
+    assert_eq!(forwards, backwards);
+
+    let volume = ChunkRngProvider::new(12345);
+    let coord = IVec3::new(4, -2, 9);
+
+    let first = stream(&volume.rng_for_3d(coord));
+    volume.rng_for_3d(IVec3::new(4, -2, 8)).gen_u64();
+
+    assert_eq!(stream(&volume.rng_for_3d(coord)), first);
+    assert_ne!(
+        volume.seed_for_3d(IVec3::new(4, -2, 0)),
+        volume.seed_for(IVec2::new(4, -2))
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn adjacent_chunks_avalanche() {
+    let provider = ChunkRngProvider::new(0);
+
+    let mut total = 0;
+    let mut count = 0;
+
+    for x in -16..16 {
+        for y in -16..16 {
+            let coord = IVec2::new(x, y);
+            let seed = provider.seed_for(coord);
+
+            for neighbour in [coord + IVec2::X, coord + IVec2::Y] {
+                let flipped = (seed ^ provider.seed_for(neighbour)).count_ones();
+
+                // No pair of neighbours should come anywhere near colliding.
+                assert!(flipped >= 12, "{coord} -> {neighbour}: {flipped}");
+
+                total += flipped;
+                count += 1;
+            }
+
+            let above = provider.seed_for_3d(coord.extend(1));
+            let flipped = (provider.seed_for_3d(coord.extend(0)) ^ above).count_ones();
+
+            assert!(flipped >= 12, "{coord}: {flipped}");
+        }
+    }
+
+    // On average, half of the bits flip between neighbours.
+    let average = f64::from(total) / f64::from(count);
+
+    assert!((31.0..33.0).contains(&average), "{average}");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cached_progress_is_resumed() {
+    let mut provider = ChunkRngProvider::new(7).with_cache(2);
+
+    let a = IVec2::new(0, 0);
+    let b = IVec2::new(1, 0);
+    let c = IVec2::new(2, 0);
+
+    let rng = provider.resume(a);
+    rng.gen_u64();
+    let expected = rng.clone().gen_u64();
+    provider.store(a, rng);
+
+    provider.store(b, provider.rng_for(b));
+
+    assert!(provider.is_stored(a));
+    assert_eq!(provider.resume(a).gen_u64(), expected);
+
+    // Taken out of the cache, so resuming again starts over.
+    assert!(!provider.is_stored(a));
+    assert_eq!(provider.resume(a).gen_u64(), provider.rng_for(a).gen_u64());
+
+    // The least recently stored chunk is evicted first.
+    provider.store(a, provider.rng_for(a));
+    provider.store(c, provider.rng_for(c));
+
+    assert!(!provider.is_stored(b));
+    assert!(provider.is_stored(a));
+    assert!(provider.is_stored(c));
+
+    // 2D and 3D chunks are cached separately.
+    assert!(!provider.is_stored_3d(IVec3::ZERO));
+
+    // Without a cache, nothing is stored.
+    let mut uncached = ChunkRngProvider::new(7);
+
+    uncached.store(a, uncached.rng_for(a));
+
+    assert!(!uncached.is_stored(a));
+}
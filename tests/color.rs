@@ -0,0 +1,80 @@
+#![cfg(all(feature = "bevy_color", feature = "wyrand"))]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn channels(color: Color) -> [f32; 4] {
+    color.to_srgba().to_f32_array()
+}
+
+fn assert_valid(color: Color) {
+    for channel in channels(color) {
+        assert!(
+            (0.0..=1.0).contains(&channel),
+            "{color:?} has a channel outside 0.0..=1.0"
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_colors() {
+    let mut rng = RngComponent::with_seed(42);
+
+    assert_eq!(
+        channels(rng.color_rgb()),
+        [0.017374516, 0.55479366, 0.7779464, 1.0]
+    );
+    assert_eq!(
+        channels(rng.color_hsl(0.5..=0.7, 0.4..=0.6)),
+        [0.21194638, 0.6594292, 0.28096628, 1.0]
+    );
+    assert_eq!(
+        channels(rng.color_in_gradient(Color::srgb(1.0, 0.0, 0.0), Color::srgb(0.0, 0.0, 1.0))),
+        [0.41206402, 0.0, 0.587936, 1.0]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn colors_are_within_valid_ranges() {
+    let mut rng = RngComponent::with_seed(7);
+
+    for _ in 0..1000 {
+        assert_valid(rng.color_rgb());
+        assert_valid(rng.color_hsl(0.0..=1.0, 0.0..=1.0));
+        assert_valid(
+            rng.color_in_gradient(Color::srgb(1.0, 1.0, 0.0), Color::hsl(240.0, 1.0, 0.5)),
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn hsl_colors_respect_their_ranges() {
+    let mut rng = RngComponent::with_seed(7);
+
+    for _ in 0..1000 {
+        let hsla = rng.color_hsl(0.4..=0.6, 0.7..=0.8).to_srgba();
+        let hsla = Hsla::from(hsla);
+
+        assert!((0.0..360.0).contains(&hsla.hue));
+        assert!((0.399..=0.601).contains(&hsla.saturation), "{hsla:?}");
+        assert!((0.699..=0.801).contains(&hsla.lightness), "{hsla:?}");
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "color range must be within 0.0..=1.0")]
+fn hsl_ranges_must_be_normalized() {
+    let mut rng = RngComponent::with_seed(7);
+
+    rng.color_hsl(0.5..=1.5, 0.5..=0.5);
+}
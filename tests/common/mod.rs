@@ -0,0 +1,51 @@
+use bevy::utils::tracing::{
+    field::{Field, Visit},
+    span, subscriber, Event, Level, Metadata, Subscriber,
+};
+use std::{
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+
+/// Captures the messages of all warnings logged while it is the default subscriber.
+#[derive(Clone, Default)]
+struct WarningCapture(Arc<Mutex<Vec<String>>>);
+
+impl Visit for WarningCapture {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.0.lock().unwrap().push(format!("{value:?}"));
+        }
+    }
+}
+
+impl Subscriber for WarningCapture {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        *metadata.level() == Level::WARN
+    }
+
+    fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+        span::Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        event.record(&mut self.clone());
+    }
+
+    fn enter(&self, _span: &span::Id) {}
+
+    fn exit(&self, _span: &span::Id) {}
+}
+
+/// Runs `f`, returning its output along with the warnings logged meanwhile.
+pub fn with_warnings<T>(f: impl FnOnce() -> T) -> (T, Vec<String>) {
+    let capture = WarningCapture::default();
+    let output = subscriber::with_default(capture.clone(), f);
+    let warnings = capture.0.lock().unwrap().clone();
+
+    (output, warnings)
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "serialize-compact")]
+
+use bevy_turborand::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[cfg(feature = "wyrand")]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Save {
+    #[serde(with = "bevy_turborand::compact")]
+    rng: RngComponent,
+}
+
+#[cfg(feature = "chacha")]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ChaChaSave {
+    #[serde(with = "bevy_turborand::compact")]
+    rng: ChaChaRngComponent,
+}
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn compact_rng_round_trip() {
+    let mut rng = RngComponent::with_seed(5);
+
+    rng.u64(..);
+
+    let save = Save { rng };
+    let serialized = ron::to_string(&save).unwrap();
+
+    assert_eq!(serialized, "(rng:[1,58,100,189,120,100,29,118,160])");
+
+    let mut deserialized: Save = ron::from_str(&serialized).unwrap();
+
+    assert_eq!(deserialized, save);
+    assert_eq!(deserialized.rng.u64(..), save.rng.clone().u64(..));
+}
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn default_rng_round_trip() {
+    let mut rng = RngComponent::with_seed(5);
+
+    rng.u64(..);
+
+    let serialized = ron::to_string(&rng).unwrap();
+    let deserialized: RngComponent = ron::from_str(&serialized).unwrap();
+
+    assert_eq!(deserialized, rng);
+
+    // Data saved in the default representation still loads with the compact one.
+    let save: Save = ron::from_str(&format!("(rng:{serialized})")).unwrap();
+
+    assert_eq!(save.rng, rng);
+}
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn legacy_rng_payload_loads() {
+    let save: Save = ron::from_str(include_str!("fixtures/legacy_rng.ron")).unwrap();
+
+    assert_eq!(save.rng, RngComponent::with_seed(5));
+}
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn compact_rng_rejects_invalid_payloads() {
+    let unknown_version = ron::from_str::<Save>("(rng:[2,11,0,0,0,0,0,0,0])").unwrap_err();
+
+    assert!(unknown_version
+        .to_string()
+        .contains("unsupported compact RNG state version 2"));
+
+    assert!(ron::from_str::<Save>("(rng:[])").is_err());
+    assert!(ron::from_str::<Save>("(rng:[1,11,0,0])").is_err());
+    assert!(ron::from_str::<Save>("(rng:(((state:(11,12)))))").is_err());
+    assert!(ron::from_str::<Save>("(rng:(((state:(-11)))))").is_err());
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn compact_chacha_rng_round_trip() {
+    let mut rng = ChaChaRngComponent::with_seed([7; 40]);
+
+    rng.u64(..);
+
+    let save = ChaChaSave { rng };
+    let serialized = ron::to_string(&save).unwrap();
+
+    assert!(serialized.starts_with("(rng:[1,"));
+
+    let mut deserialized: ChaChaSave = ron::from_str(&serialized).unwrap();
+
+    assert_eq!(deserialized, save);
+    assert_eq!(deserialized.rng.u64(..), save.rng.clone().u64(..));
+
+    // Data saved in the default representation still loads with the compact one.
+    let legacy = ron::to_string(&save.rng).unwrap();
+    let loaded: ChaChaSave = ron::from_str(&format!("(rng:{legacy})")).unwrap();
+
+    assert_eq!(loaded, save);
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn legacy_chacha_rng_payload_loads() {
+    let save: ChaChaSave = ron::from_str(include_str!("fixtures/legacy_chacha_rng.ron")).unwrap();
+
+    assert_eq!(save.rng, ChaChaRngComponent::with_seed([7; 40]));
+}
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn compact_global_rng_round_trip() {
+    #[derive(Serialize, Deserialize)]
+    struct GlobalSave {
+        #[serde(with = "bevy_turborand::compact")]
+        rng: GlobalRng,
+    }
+
+    let save = GlobalSave {
+        rng: GlobalRng::with_seed(5),
+    };
+    let serialized = ron::to_string(&save).unwrap();
+
+    assert_eq!(serialized, "(rng:[1,11,0,0,0,0,0,0,0])");
+
+    let deserialized: GlobalSave = ron::from_str(&serialized).unwrap();
+
+    assert_eq!(deserialized.rng, save.rng);
+}
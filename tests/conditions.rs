@@ -0,0 +1,115 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy_turborand::{conditions::*, prelude::*};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Resource, Default)]
+struct Runs(u32);
+
+fn count_runs(mut runs: ResMut<'_, Runs>) {
+    runs.0 += 1;
+}
+
+fn runs_after(updates: usize, configure: impl FnOnce(&mut App)) -> u32 {
+    let mut app = App::new();
+
+    let plugin = RngPlugin::new().with_rng_seed(12345);
+
+    #[cfg(feature = "chacha")]
+    let plugin = plugin.with_chacha_seed([7; 40]);
+
+    app.add_plugins(plugin).init_resource::<Runs>();
+
+    configure(&mut app);
+
+    for _ in 0..updates {
+        app.update();
+    }
+
+    app.world().resource::<Runs>().0
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chance_global_runs_deterministically() {
+    let runs = runs_after(1000, |app| {
+        app.add_systems(Update, count_runs.run_if(chance_global(0.1)));
+    });
+
+    assert_eq!(runs, 99);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ratio_global_runs_deterministically() {
+    let runs = runs_after(1000, |app| {
+        app.add_systems(Update, count_runs.run_if(ratio_global(1, 4)));
+    });
+
+    assert_eq!(runs, 238);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn conditions_do_not_advance_the_global() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(12345))
+        .init_resource::<Runs>()
+        .add_systems(Update, count_runs.run_if(chance_global(0.5)));
+
+    app.update();
+
+    let mut expected = GlobalRng::with_seed(12345);
+    let _ = expected.fork();
+
+    for _ in 0..10 {
+        app.update();
+    }
+
+    assert_eq!(app.world().resource::<GlobalRng>(), &expected);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn conditions_certainties_always_hold() {
+    assert_eq!(
+        runs_after(50, |app| {
+            app.add_systems(Update, count_runs.run_if(chance_global(1.0)));
+        }),
+        50
+    );
+    assert_eq!(
+        runs_after(50, |app| {
+            app.add_systems(Update, count_runs.run_if(ratio_global(0, 3)));
+        }),
+        0
+    );
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chance_from_other_resources() {
+    let runs = runs_after(1000, |app| {
+        app.add_systems(
+            Update,
+            count_runs.run_if(chance_from::<GlobalChaChaRng>(0.25)),
+        );
+    });
+
+    assert_eq!(runs, 234);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "ratio denominator must not be zero")]
+fn ratio_global_rejects_zero_denominators() {
+    let _ = ratio_global(1, 0);
+}
@@ -0,0 +1,91 @@
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// Checks that every delegated method in the list given by `delegated_methods!` yields the
+/// same output as calling it on the source directly, both through [`DelegatedRng`] and
+/// through the inherent method of the wrapper.
+macro_rules! assert_parity {
+    ($($method:ident($($input:ident: $type:ty = $example:expr)?) -> $output:ty;)*) => {
+        fn assert_all_parity<R: DelegatedRng + Clone>(rng: R)
+        where
+            R::Source: std::fmt::Debug,
+        {
+            let mut wrapper = rng;
+            let source = wrapper.get_mut().clone();
+
+            $(
+                assert_eq!(
+                    DelegatedRng::$method(&mut wrapper $(, $example)?),
+                    source.$method($($example)?),
+                    concat!("`", stringify!($method), "` is not delegated as is"),
+                );
+            )*
+
+            assert_eq!(
+                DelegatedRng::chance(&mut wrapper, 0.5),
+                source.chance(0.5),
+                "`chance` is not delegated as is",
+            );
+
+            let mut wrapped = [0u8; 16];
+            let mut direct = [0u8; 16];
+            wrapper.fill_bytes(&mut wrapped);
+            source.fill_bytes(&mut direct);
+            assert_eq!(wrapped, direct, "`fill_bytes` is not delegated as is");
+
+            assert_eq!(wrapper.get_mut(), &source);
+        }
+    };
+}
+
+bevy_turborand::delegated_methods!(assert_parity);
+
+/// Checks that the inherent methods of a wrapper yield the same outputs as the
+/// [`DelegatedRng`] methods they stand in for.
+macro_rules! assert_inherent_parity {
+    ($($method:ident($($input:ident: $type:ty = $example:expr)?) -> $output:ty;)*) => {
+        macro_rules! assert_inherent_parity_of {
+            ($wrapper:expr) => {{
+                let mut inherent = $wrapper;
+                let mut delegated = inherent.clone();
+
+                $(
+                    assert_eq!(
+                        inherent.$method($($example)?),
+                        DelegatedRng::$method(&mut delegated $(, $example)?),
+                        concat!("inherent `", stringify!($method), "` differs"),
+                    );
+                )*
+
+                assert_eq!(inherent, delegated);
+            }};
+        }
+    };
+}
+
+bevy_turborand::delegated_methods!(assert_inherent_parity);
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn wyrand_wrappers_delegate_all_methods() {
+    assert_all_parity(RngComponent::with_seed(3));
+    assert_all_parity(GlobalRng::with_seed(3));
+    assert_inherent_parity_of!(RngComponent::with_seed(3));
+    assert_inherent_parity_of!(GlobalRng::with_seed(3));
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_wrappers_delegate_all_methods() {
+    assert_all_parity(ChaChaRngComponent::with_seed([3; 40]));
+    assert_all_parity(GlobalChaChaRng::with_seed([3; 40]));
+    assert_inherent_parity_of!(ChaChaRngComponent::with_seed([3; 40]));
+    assert_inherent_parity_of!(GlobalChaChaRng::with_seed([3; 40]));
+}
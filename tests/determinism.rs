@@ -191,6 +191,83 @@ fn deterministic_play_through() {
     assert_eq!(app.world().get::<HitPoints>(enemy_2).unwrap().total, 0);
 }
 
+#[cfg(feature = "testing")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_seeded_play_through() {
+    // Set up the game App and World
+    let mut app = App::new();
+
+    // Spawn the player, and some enemies for the player to fight with,
+    // each with their own RngComponent forked from a seeded GlobalRng
+    let spawned = WorldSeeder::new(12345)
+        .spawn(|_| {
+            (
+                Player,
+                HitPoints {
+                    total: 100,
+                    max: 100,
+                },
+                Attack {
+                    min: 5,
+                    max: 10,
+                    hit: 0.6,
+                },
+                Buff {
+                    min: 2,
+                    max: 6,
+                    chance: 0.10,
+                },
+            )
+        })
+        .spawn_batch(2, |_| {
+            (
+                Enemy,
+                HitPoints { total: 20, max: 20 },
+                Attack {
+                    min: 3,
+                    max: 6,
+                    hit: 0.5,
+                },
+            )
+        })
+        .apply_to_app(&mut app);
+
+    let player = spawned[0][0];
+    let (enemy_1, enemy_2) = (spawned[1][0], spawned[1][1]);
+
+    // Add the systems to our App. Order the necessary systems in order
+    // to ensure deterministic behaviour.
+    app.add_systems(
+        Update,
+        ((attack_random_enemy, buff_player).chain(), attack_player),
+    );
+
+    // Run the game once!
+    app.update();
+
+    // Check to see the health of our combatants
+    assert_eq!(app.world().get::<HitPoints>(player).unwrap().total, 100);
+    assert_eq!(app.world().get::<HitPoints>(enemy_1).unwrap().total, 20);
+    assert_eq!(app.world().get::<HitPoints>(enemy_2).unwrap().total, 11);
+
+    // Again!
+    app.update();
+
+    // Player OP. Enemy 2 is in trouble
+    assert_eq!(app.world().get::<HitPoints>(player).unwrap().total, 90);
+    assert_eq!(app.world().get::<HitPoints>(enemy_1).unwrap().total, 20);
+    assert_eq!(app.world().get::<HitPoints>(enemy_2).unwrap().total, 3);
+
+    // And again!
+    app.update();
+
+    // Enemy 2 is now deceased
+    assert_eq!(app.world().get::<HitPoints>(player).unwrap().total, 88);
+    assert_eq!(app.world().get::<HitPoints>(enemy_1).unwrap().total, 20);
+    assert_eq!(app.world().get::<HitPoints>(enemy_2).unwrap().total, 0);
+}
+
 #[test]
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
 fn deterministic_setup() {
@@ -223,6 +300,62 @@ fn deterministic_setup() {
     assert_eq!(enemy_2.u32(..=10), 7);
 }
 
+#[cfg(feature = "testing")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_seeded_setup() {
+    let mut app = App::new();
+
+    let spawned = WorldSeeder::new(23456)
+        .spawn(|_| Player)
+        .spawn_batch(2, |_| Enemy)
+        .apply_to_app(&mut app);
+
+    let mut rolls = spawned
+        .iter()
+        .flatten()
+        .map(|&entity| {
+            app.world_mut()
+                .get_mut::<RngComponent>(entity)
+                .unwrap()
+                .u32(..=10)
+        })
+        .collect::<Vec<_>>()
+        .into_iter();
+
+    assert_eq!(rolls.next(), Some(10));
+    assert_eq!(rolls.next(), Some(1));
+    assert_eq!(rolls.next(), Some(7));
+}
+
+#[cfg(feature = "testing")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_seeded_bundles() {
+    let seed_world = || {
+        let mut world = World::new();
+
+        let spawned = WorldSeeder::new(34567)
+            .spawn_batch(3, |rng| HitPoints {
+                total: rng.u32(10..=20),
+                max: 20,
+            })
+            .apply(&mut world);
+
+        let totals: Vec<u32> = spawned[0]
+            .iter()
+            .map(|&entity| world.get::<HitPoints>(entity).unwrap().total)
+            .collect();
+
+        (totals, world.resource_mut::<GlobalRng>().u64(..))
+    };
+
+    let (totals, next) = seed_world();
+
+    assert_eq!(totals, vec![10, 18, 11]);
+    assert_eq!((totals, next), seed_world());
+}
+
 #[cfg(feature = "chacha")]
 #[test]
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
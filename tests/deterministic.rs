@@ -0,0 +1,135 @@
+#![cfg(feature = "wyrand")]
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+#[cfg(feature = "chacha")]
+use bevy_turborand::SeedMixer;
+use common::with_warnings;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// Updates `app` `frames` times, returning the warnings logged meanwhile.
+fn warnings_after_updates(app: &mut App, frames: usize) -> Vec<String> {
+    let ((), warnings) = with_warnings(|| {
+        for _ in 0..frames {
+            app.update();
+        }
+    });
+
+    warnings
+}
+
+fn roll_a(mut global: ResMut<GlobalRng>) {
+    global.u32(..);
+}
+
+fn roll_b(mut global: ResMut<GlobalRng>) {
+    global.u32(..);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_mode_seeds_every_global() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::deterministic(12345));
+
+    let world = app.world();
+
+    assert_eq!(world.resource::<GlobalRng>(), &GlobalRng::with_seed(12345));
+    assert_eq!(
+        world.resource::<DeterminismSettings>(),
+        &DeterminismSettings::new(12345)
+    );
+    assert_eq!(
+        world.resource::<RngAuditConfig>(),
+        &RngAuditConfig::default()
+    );
+
+    #[cfg(feature = "chacha")]
+    assert_eq!(
+        world.resource::<GlobalChaChaRng>(),
+        &GlobalChaChaRng::with_seed(SeedMixer::new(12345).finish_chacha())
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn unordered_global_access_is_reported_once() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::deterministic(5))
+        .add_systems(Update, (roll_a, roll_b));
+
+    let warnings = warnings_after_updates(&mut app, 3);
+
+    assert_eq!(warnings.len(), 1, "{warnings:?}");
+    assert!(warnings[0].contains("roll_a"), "{warnings:?}");
+    assert!(warnings[0].contains("roll_b"), "{warnings:?}");
+    assert!(warnings[0].contains("Update"), "{warnings:?}");
+    assert!(
+        warnings[0].contains("both access bevy_turborand::global::rng::GlobalRng"),
+        "{warnings:?}"
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ordered_global_access_is_not_reported() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::deterministic(5))
+        .add_systems(Update, (roll_a, roll_b).chain())
+        .add_systems(FixedUpdate, (roll_a, roll_b.ambiguous_with(roll_a)));
+
+    assert_eq!(warnings_after_updates(&mut app, 2), Vec::<String>::new());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn checks_can_be_disabled() {
+    let mut app = App::new();
+
+    app.insert_resource(DeterminismSettings {
+        seed: 5,
+        warn_on_ambiguous_rng: false,
+    })
+    .add_plugins(RngPlugin::deterministic(5))
+    .add_systems(Update, (roll_a, roll_b));
+
+    assert_eq!(warnings_after_updates(&mut app, 2), Vec::<String>::new());
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5))
+        .add_systems(Update, (roll_a, roll_b));
+
+    assert_eq!(warnings_after_updates(&mut app, 2), Vec::<String>::new());
+}
+
+#[cfg(any(debug_assertions, feature = "audit"))]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn components_seeded_from_entropy_are_reported() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::deterministic(5));
+
+    app.world_mut().spawn(RngComponent::with_seed(1));
+    let entity = app.world_mut().spawn(RngComponent::default()).id();
+
+    let warnings = warnings_after_updates(&mut app, 1);
+
+    assert_eq!(
+        warnings,
+        [format!(
+            "RngComponents seeded from entropy were added to entities, breaking determinism: [{entity:?}]"
+        )]
+    );
+}
@@ -0,0 +1,108 @@
+#![cfg(all(feature = "diagnostics", feature = "wyrand"))]
+
+use bevy::{diagnostic::DiagnosticsStore, prelude::*};
+use bevy_turborand::prelude::*;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// The call counts are shared by the whole process, so tests touching them must not overlap.
+static COUNTS: Mutex<()> = Mutex::new(());
+
+fn lock_counts() -> MutexGuard<'static, ()> {
+    COUNTS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn roll_global(mut global: ResMut<GlobalRng>) {
+    global.u32(..);
+    global.chance(0.5);
+    global.bool();
+}
+
+fn roll_components(mut q_rng: Query<&mut RngComponent>) {
+    for mut rng in q_rng.iter_mut() {
+        rng.f32();
+        rng.index(..5);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn counts_calls_made_by_system() {
+    let _lock = lock_counts();
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(1))
+        .add_systems(Update, (reset_rng_call_counts, roll_global).chain());
+
+    app.update();
+
+    assert_eq!(GlobalRng::call_count(), 3);
+    assert_eq!(RngComponent::call_count(), 0);
+
+    app.update();
+
+    assert_eq!(GlobalRng::call_count(), 3);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn counts_component_calls_across_instances() {
+    let _lock = lock_counts();
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(1))
+        .add_systems(Update, roll_components);
+
+    app.world_mut().spawn(RngComponent::with_seed(1));
+    app.world_mut().spawn(RngComponent::with_seed(2));
+
+    RngComponent::reset_call_count();
+
+    app.update();
+
+    assert_eq!(RngComponent::call_count(), 4);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn measures_calls_per_frame() {
+    let _lock = lock_counts();
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(1))
+        .add_systems(Update, roll_global);
+
+    reset_rng_call_counts();
+
+    for _ in 0..3 {
+        app.update();
+
+        let store = app.world().resource::<DiagnosticsStore>();
+        let calls = store
+            .get(&GlobalRng::CALLS_DIAGNOSTIC)
+            .and_then(|diagnostic| diagnostic.value());
+
+        assert_eq!(calls, Some(3.0));
+    }
+
+    // Resetting the counts mid-way must not skew the measurement.
+    reset_rng_call_counts();
+    app.update();
+
+    let store = app.world().resource::<DiagnosticsStore>();
+
+    assert_eq!(
+        store
+            .get(&GlobalRng::CALLS_DIAGNOSTIC)
+            .and_then(|diagnostic| diagnostic.value()),
+        Some(3.0)
+    );
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// Returns the mean of `samples` draws of `sample`.
+fn mean(samples: u32, mut sample: impl FnMut() -> u64) -> f64 {
+    (0..samples).map(|_| sample() as f64).sum::<f64>() / f64::from(samples)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn geometric_matches_its_expected_mean() {
+    let mut rng = RngComponent::with_seed(1);
+
+    for p in [0.05, 0.25, 0.8] {
+        let expected = (1.0 - p) / p;
+        let actual = mean(100_000, || rng.geometric(p));
+
+        assert!(
+            (actual - expected).abs() < expected * 0.03 + 0.01,
+            "{p}: {actual}"
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn geometric_is_deterministic() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut f32_rng = RngComponent::with_seed(5);
+
+    let rolls: Vec<u64> = (0..8).map(|_| rng.geometric(0.25)).collect();
+    let f32_rolls: Vec<u64> = (0..8).map(|_| f32_rng.geometric_f32(0.25)).collect();
+
+    assert_eq!(rolls, [1, 4, 11, 3, 1, 4, 1, 8]);
+    assert_eq!(f32_rolls, rolls);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn certain_outcomes_do_not_advance_the_state() {
+    let mut rng = RngComponent::with_seed(5);
+    let untouched = rng.clone();
+
+    assert_eq!(rng.geometric(1.0), 0);
+    assert_eq!(rng.binomial(100, 0.0), 0);
+    assert_eq!(rng.binomial(100, 1.0), 100);
+    assert_eq!(rng.binomial(0, 0.5), 0);
+    assert_eq!(rng, untouched);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "geometric probability must be in the range of 0.0..=1.0 and not 0.0")]
+fn geometric_rejects_zero() {
+    RngComponent::with_seed(5).geometric(0.0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "geometric probability must be in the range of 0.0..=1.0 and not 0.0")]
+fn geometric_rejects_nan() {
+    RngComponent::with_seed(5).geometric_f32(f32::NAN);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "binomial probability must be in the range of 0.0..=1.0")]
+fn binomial_rejects_invalid_probabilities() {
+    RngComponent::with_seed(5).binomial(10, 1.5);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn binomial_matches_its_expected_mean_in_every_branch() {
+    let mut rng = RngComponent::with_seed(2);
+
+    // Direct, gaps between successes, normal approximation, and the flipped ones.
+    for (n, p) in [
+        (20, 0.3),
+        (1_000, 0.005),
+        (10_000, 0.3),
+        (20, 0.7),
+        (1_000, 0.995),
+        (10_000, 0.7),
+    ] {
+        let expected = n as f64 * p;
+        let actual = mean(20_000, || rng.binomial(n, p));
+
+        assert!(
+            (actual - expected).abs() < expected * 0.01 + 0.05,
+            "{n}, {p}: {actual}"
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn binomial_stays_within_its_trials() {
+    let mut rng = RngComponent::with_seed(3);
+
+    for (n, p) in [(1, 0.5), (65, 0.5), (100, 0.01), (u64::MAX, 0.5)] {
+        for _ in 0..1_000 {
+            assert!(rng.binomial(n, p) <= n);
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn binomial_branches_are_deterministic() {
+    let sequence = |n, p| {
+        let mut rng = RngComponent::with_seed(5);
+
+        (0..6).map(|_| rng.binomial(n, p)).collect::<Vec<_>>()
+    };
+
+    assert_eq!(sequence(20, 0.3), [4, 5, 9, 6, 7, 3]);
+    assert_eq!(sequence(1_000, 0.005), [3, 4, 4, 4, 6, 3]);
+    assert_eq!(sequence(10_000, 0.3), [2985, 2906, 2992, 3040, 3055, 2989]);
+    assert_eq!(sequence(10_000, 0.7), [7015, 7094, 7008, 6960, 6945, 7011]);
+
+    let mut rng = RngComponent::with_seed(5);
+    let mut f32_rng = RngComponent::with_seed(5);
+
+    assert_eq!(
+        rng.binomial(10_000, 0.25),
+        f32_rng.binomial_f32(10_000, 0.25)
+    );
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "wyrand")]
+
+//! Exercises the crate the way a headless server would, with only `bevy_app` & `bevy_ecs`
+//! and without the `bevy` facade, so that `cargo test --no-default-features --features wyrand
+//! --test ecs_only` checks that no more than the ECS crates are needed.
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Resource, Default)]
+struct Rolls(Vec<u32>);
+
+fn spawn_players(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+    for _ in 0..3 {
+        commands.spawn((Player, RngComponent::from(&mut global)));
+    }
+}
+
+fn roll(mut q_players: Query<(Entity, &mut RngComponent), With<Player>>, mut rolls: ResMut<Rolls>) {
+    let mut players: Vec<_> = q_players.iter_mut().collect();
+
+    players.sort_unstable_by_key(|(entity, _)| *entity);
+
+    rolls
+        .0
+        .extend(players.into_iter().map(|(_, mut rng)| rng.u32(1..=20)));
+}
+
+fn server(seed: u64) -> App {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(seed))
+        .init_resource::<Rolls>()
+        .add_systems(Startup, spawn_players)
+        .add_systems(Update, roll);
+
+    app
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn headless_apps_roll_deterministically() {
+    let mut app = server(5);
+    let mut replay = server(5);
+
+    for _ in 0..3 {
+        app.update();
+        replay.update();
+    }
+
+    let rolls = &app.world().resource::<Rolls>().0;
+
+    assert_eq!(rolls.len(), 9);
+    assert!(rolls.iter().all(|roll| (1..=20).contains(roll)));
+    assert_eq!(rolls, &replay.world().resource::<Rolls>().0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn plain_worlds_need_no_app() {
+    let mut world = World::new();
+
+    world.insert_resource(GlobalRng::with_seed(5));
+
+    let mut rng = RngComponent::try_from_world(&mut world).unwrap();
+    let mut expected = RngComponent::from(&mut GlobalRng::with_seed(5));
+
+    assert_eq!(rng.u64(..), expected.u64(..));
+}
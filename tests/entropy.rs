@@ -0,0 +1,89 @@
+#![cfg(any(feature = "wyrand", feature = "chacha"))]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn global_rng_reseeds_from_entropy() {
+    let mut global = GlobalRng::with_seed(5);
+    let seeded = global.clone();
+
+    global.reseed_from_entropy();
+
+    let first = global.clone();
+
+    assert_ne!(first, seeded);
+
+    global.reseed_from_entropy();
+
+    assert_ne!(global, first);
+    assert_ne!(global.u64(..), GlobalRng::with_seed(5).u64(..));
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn global_chacha_rng_reseeds_from_entropy() {
+    let mut global = GlobalChaChaRng::with_seed([5; 40]);
+    let seeded = global.clone();
+
+    global.reseed_from_entropy();
+
+    let first = global.clone();
+
+    assert_ne!(first, seeded);
+
+    global.reseed_from_entropy();
+
+    assert_ne!(global, first);
+    assert_ne!(global.u64(..), GlobalChaChaRng::with_seed([5; 40]).u64(..));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn command_reseeds_present_globals() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new())
+        .add_systems(Update, |mut commands: Commands| {
+            commands.queue(ReseedFromEntropy);
+        });
+
+    #[cfg(feature = "wyrand")]
+    app.insert_resource(GlobalRng::with_seed(5));
+    #[cfg(feature = "chacha")]
+    app.insert_resource(GlobalChaChaRng::with_seed([5; 40]));
+
+    app.update();
+
+    #[cfg(feature = "wyrand")]
+    assert_ne!(
+        app.world().resource::<GlobalRng>(),
+        &GlobalRng::with_seed(5)
+    );
+    #[cfg(feature = "chacha")]
+    assert_ne!(
+        app.world().resource::<GlobalChaChaRng>(),
+        &GlobalChaChaRng::with_seed([5; 40])
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn command_skips_missing_globals() {
+    let mut world = World::new();
+
+    world.commands().queue(ReseedFromEntropy);
+    world.flush();
+
+    #[cfg(feature = "wyrand")]
+    assert!(!world.contains_resource::<GlobalRng>());
+}
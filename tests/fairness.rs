@@ -0,0 +1,208 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+#[cfg(feature = "testing")]
+use bevy_turborand::stats::expect_frequency;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// Returns the longest run of `value` in `rolls`.
+fn longest_streak(rolls: &[bool], value: bool) -> usize {
+    rolls
+        .split(|&roll| roll != value)
+        .map(<[bool]>::len)
+        .max()
+        .unwrap_or_default()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pity_constants_match_known_values() {
+    let known = [(0.05, 0.003_801), (0.25, 0.084_744), (0.5, 0.302_103)];
+
+    for (probability, constant) in known {
+        let chance = PityChance::new(probability);
+
+        assert!(
+            (chance.constant() - constant).abs() < 1e-5,
+            "{probability}: {}",
+            chance.constant()
+        );
+    }
+
+    assert_eq!(PityChance::new(0.0).constant(), 0.0);
+    assert_eq!(PityChance::new(1.0).constant(), 1.0);
+    assert_eq!(PityChance::new(f64::NAN).probability(), 0.0);
+    assert_eq!(PityChance::new(2.0).probability(), 1.0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn tiny_pity_chances_build_quickly() {
+    #[cfg(not(target_arch = "wasm32"))]
+    let start = std::time::Instant::now();
+
+    let chance = PityChance::new(1e-6);
+
+    #[cfg(not(target_arch = "wasm32"))]
+    assert!(start.elapsed() < std::time::Duration::from_secs(1));
+
+    // The constant approaches `π * p² / 2` for small probabilities.
+    let approximate = std::f64::consts::PI * 1e-12 / 2.0;
+
+    assert!((chance.constant() / approximate - 1.0).abs() < 1e-5);
+
+    // Switching to the approximation doesn't shift the constant.
+    let below = PityChance::new(0.000_999_999).constant();
+    let above = PityChance::new(0.001).constant();
+
+    assert!((below / above - 1.0).abs() < 1e-5, "{below} {above}");
+}
+
+#[cfg(feature = "testing")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pity_hits_at_the_target_rate() {
+    for probability in [0.1, 0.25, 0.6] {
+        let mut chance = PityChance::new(probability);
+
+        expect_frequency(
+            |rng| chance.roll(rng),
+            100_000,
+            &[(true, probability), (false, 1.0 - probability)],
+            0.01,
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pity_miss_streaks_are_bounded() {
+    let mut rng = RngComponent::with_seed(3);
+    let mut chance = PityChance::new(0.25);
+
+    // A chance of 0.084744 grows to 1.0 by the twelfth roll.
+    let rolls: Vec<bool> = (0..100_000).map(|_| chance.roll(&mut rng)).collect();
+
+    assert!(longest_streak(&rolls, false) <= 11);
+    assert!(rolls.iter().any(|&hit| hit));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pity_grows_on_misses_and_resets_on_hits() {
+    let mut rng = RngComponent::with_seed(8);
+    let mut chance = PityChance::new(0.25);
+
+    for _ in 0..100 {
+        let before = chance.current_chance();
+
+        if chance.roll(&mut rng) {
+            assert_eq!(chance.misses(), 0);
+            assert_eq!(chance.current_chance(), chance.constant());
+        } else {
+            assert!(chance.current_chance() > before);
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pity_sequences_are_deterministic() {
+    let sequence = |seed| {
+        let mut rng = RngComponent::with_seed(seed);
+        let mut chance = PityChance::new(0.3);
+
+        (0..12).map(|_| chance.roll(&mut rng)).collect::<Vec<_>>()
+    };
+
+    assert_eq!(sequence(5), sequence(5));
+    assert_eq!(
+        sequence(5),
+        [false, false, false, false, true, false, false, false, false, false, true, false]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn bags_hit_exactly_k_times_in_n_draws() {
+    let mut rng = RngComponent::with_seed(4);
+    let mut bag = BagRandom::new(3, 10);
+
+    for _ in 0..100 {
+        let hits = (0..10).filter(|_| bag.roll(&mut rng)).count();
+
+        assert_eq!(hits, 3);
+        assert_eq!(bag.remaining(), 0);
+    }
+
+    let mut never = BagRandom::new(0, 4);
+    let mut always = BagRandom::new(4, 4);
+
+    assert!((0..20).all(|_| !never.roll(&mut rng)));
+    assert!((0..20).all(|_| always.roll(&mut rng)));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn bag_streaks_are_bounded() {
+    let mut rng = RngComponent::with_seed(6);
+    let mut bag = BagRandom::new(1, 5);
+
+    let rolls: Vec<bool> = (0..10_000).map(|_| bag.roll(&mut rng)).collect();
+
+    // At worst, the hit comes first in one bag and last in the next.
+    assert!(longest_streak(&rolls, false) <= 8);
+    assert!(longest_streak(&rolls, true) <= 2);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn bag_sequences_are_deterministic() {
+    let sequence = |seed| {
+        let mut rng = RngComponent::with_seed(seed);
+        let mut bag = BagRandom::new(2, 5);
+
+        (0..10).map(|_| bag.roll(&mut rng)).collect::<Vec<_>>()
+    };
+
+    assert_eq!(sequence(5), sequence(5));
+    assert_eq!(
+        sequence(5),
+        [true, false, false, false, true, true, false, false, false, true]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "bag must have at least one draw and no more successes than draws")]
+fn bags_reject_more_successes_than_draws() {
+    let _ = BagRandom::new(4, 3);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn saved_state_resumes_the_same_sequence() {
+    let mut rng = RngComponent::with_seed(2);
+    let mut chance = PityChance::new(0.2);
+    let mut bag = BagRandom::new(2, 7);
+
+    for _ in 0..4 {
+        chance.roll(&mut rng);
+        bag.roll(&mut rng);
+    }
+
+    let saved = ron::to_string(&(&chance, &bag, &rng)).unwrap();
+    let (mut loaded_chance, mut loaded_bag, mut loaded_rng): (PityChance, BagRandom, RngComponent) =
+        ron::from_str(&saved).unwrap();
+
+    for _ in 0..20 {
+        assert_eq!(loaded_chance.roll(&mut loaded_rng), chance.roll(&mut rng));
+        assert_eq!(loaded_bag.roll(&mut loaded_rng), bag.roll(&mut rng));
+    }
+}
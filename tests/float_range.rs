@@ -0,0 +1,158 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+use std::ops::{Bound, RangeBounds};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+const SAMPLES: usize = 1_000_000;
+
+fn sample_f32(bounds: impl RangeBounds<f32> + Clone) -> Vec<f32> {
+    let mut rng = RngComponent::with_seed(5);
+
+    (0..SAMPLES)
+        .map(|_| rng.f32_range(bounds.clone()))
+        .collect()
+}
+
+fn sample_f64(bounds: impl RangeBounds<f64> + Clone) -> Vec<f64> {
+    let mut rng = RngComponent::with_seed(5);
+
+    (0..SAMPLES)
+        .map(|_| rng.f64_range(bounds.clone()))
+        .collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn excluded_upper_bounds_are_never_returned() {
+    assert!(sample_f32(0.0..1.0)
+        .iter()
+        .all(|value| (0.0..1.0).contains(value)));
+    assert!(sample_f64(0.0..1.0)
+        .iter()
+        .all(|value| (0.0..1.0).contains(value)));
+    assert!(sample_f32(-1.0..1.0)
+        .iter()
+        .all(|value| !value.acos().is_nan()));
+    assert!(sample_f64(-1.0..1.0)
+        .iter()
+        .all(|value| !value.acos().is_nan()));
+
+    // A range a single step wide only holds its lower bound.
+    assert!(sample_f32(1.0..1.0f32.next_up())
+        .iter()
+        .all(|&value| value == 1.0));
+    assert!(sample_f64(1.0..1.0f64.next_up())
+        .iter()
+        .all(|&value| value == 1.0));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn included_upper_bounds_can_be_returned() {
+    let upper = 1.0f32.next_up();
+    let values = sample_f32(1.0..=upper);
+
+    assert!(values.contains(&1.0));
+    assert!(values.contains(&upper));
+    assert!(values.iter().all(|value| (1.0..=upper).contains(value)));
+
+    let upper = 1.0f64.next_up();
+    let values = sample_f64(1.0..=upper);
+
+    assert!(values.contains(&1.0));
+    assert!(values.contains(&upper));
+
+    assert!(sample_f64(-2.5..=-2.5).iter().all(|&value| value == -2.5));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn excluded_lower_bounds_are_never_returned() {
+    let bounds = (Bound::Excluded(1.0f32), Bound::Included(1.0f32.next_up()));
+
+    assert!(sample_f32(bounds)
+        .iter()
+        .all(|&value| value == 1.0f32.next_up()));
+
+    let upper = 1.0f64.next_up().next_up();
+    let bounds = (Bound::Excluded(1.0), Bound::Excluded(upper));
+
+    assert!(sample_f64(bounds)
+        .iter()
+        .all(|&value| value == 1.0f64.next_up()));
+
+    let bounds = (Bound::Excluded(0.0), Bound::Excluded(1.0));
+
+    assert!(sample_f64(bounds)
+        .iter()
+        .all(|&value| value > 0.0 && value < 1.0));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn wide_ranges_stay_within_their_bounds() {
+    assert!(sample_f32(f32::MIN..f32::MAX)
+        .iter()
+        .all(|value| (f32::MIN..f32::MAX).contains(value)));
+    assert!(sample_f64(f64::MIN..=f64::MAX)
+        .iter()
+        .all(|value| (f64::MIN..=f64::MAX).contains(value)));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn float_ranges_are_deterministic() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut generic = RngComponent::with_seed(5);
+
+    let values: Vec<f64> = (0..4).map(|_| rng.f64_range(-1.0..1.0)).collect();
+    let expected: Vec<f64> = (0..4).map(|_| generic.range(-1.0..1.0)).collect();
+
+    assert_eq!(values, expected);
+    assert_eq!(
+        values,
+        [
+            -0.40748098481900574,
+            0.3719972763041701,
+            0.9207610728361497,
+            0.20111813953997926
+        ]
+    );
+
+    let values: Vec<f32> = (0..4).map(|_| rng.f32_range(0.0..=10.0)).collect();
+
+    assert_eq!(values, [2.9488707, 9.609228, 2.126358, 8.370239]);
+}
+
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    should_panic(expected = "RngComponent::f64_range called with empty range 1.0..1.0")
+)]
+#[cfg_attr(
+    not(debug_assertions),
+    should_panic(expected = "Range should not be zero sized or invalid")
+)]
+fn empty_float_ranges_panic() {
+    RngComponent::with_seed(5).f64_range(1.0..1.0);
+}
+
+#[test]
+#[should_panic(expected = "Range should not be zero sized or invalid")]
+fn ranges_excluding_both_neighbouring_bounds_panic() {
+    let bounds = (Bound::Excluded(1.0f32), Bound::Excluded(1.0f32.next_up()));
+
+    RngComponent::with_seed(5).f32_range(bounds);
+}
+
+#[test]
+#[should_panic(expected = "float ranges must have a lower bound")]
+fn full_float_ranges_panic() {
+    RngComponent::with_seed(5).f32_range(..);
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Component)]
+struct Enemy(usize);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn fork_many_matches_sequential_forks() {
+    let mut batch = GlobalRng::with_seed(5);
+    let mut sequential = GlobalRng::with_seed(5);
+
+    let forked = batch.fork_many(3);
+    let expected = [sequential.fork(), sequential.fork(), sequential.fork()];
+
+    assert_eq!(forked, expected);
+    assert_eq!(batch, sequential);
+    assert!(batch.fork_many(0).is_empty());
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_fork_many_matches_sequential_forks() {
+    let mut batch = GlobalChaChaRng::with_seed([5; 40]);
+    let mut sequential = GlobalChaChaRng::with_seed([5; 40]);
+
+    let forked = batch.fork_many(3);
+    let expected = [sequential.fork(), sequential.fork(), sequential.fork()];
+
+    assert_eq!(forked, expected);
+    assert_eq!(batch, sequential);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn spawn_batch_with_rng_matches_forking_one_by_one() {
+    fn spawn_batched(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+        RngComponent::spawn_batch_with_rng(&mut commands, &mut *global, (0..5).map(Enemy));
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5))
+        .add_systems(Startup, spawn_batched);
+
+    app.update();
+
+    let mut expected = GlobalRng::with_seed(5);
+    let mut q_enemies = app.world_mut().query::<(&Enemy, &RngComponent)>();
+    let mut enemies: Vec<_> = q_enemies.iter(app.world()).collect();
+
+    enemies.sort_by_key(|(enemy, _)| enemy.0);
+
+    assert_eq!(enemies.len(), 5);
+
+    for (_, rng) in enemies {
+        assert_eq!(rng, &RngComponent::from(expected.fork()));
+    }
+
+    assert_eq!(app.world().resource::<GlobalRng>(), &expected);
+}
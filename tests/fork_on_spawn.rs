@@ -0,0 +1,185 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Component, Default)]
+struct Goblin;
+
+/// Spawns the same serialized state several times over, as a scene would.
+fn spawn_scene(world: &mut World) -> Vec<Entity> {
+    let template = RngComponent::with_seed(999);
+
+    (0..3)
+        .map(|_| world.spawn((Goblin, template.clone(), ForkOnSpawn)).id())
+        .collect()
+}
+
+fn app_with_seed(seed: u64) -> (App, Vec<Entity>) {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(seed));
+
+    let goblins = spawn_scene(app.world_mut());
+
+    app.update();
+
+    (app, goblins)
+}
+
+fn rolls(app: &mut App, entities: &[Entity]) -> Vec<u32> {
+    entities
+        .iter()
+        .map(|&entity| {
+            app.world_mut()
+                .get_mut::<RngComponent>(entity)
+                .unwrap()
+                .u32(..100)
+        })
+        .collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn identical_seeds_fork_identically() {
+    let (mut app_a, goblins_a) = app_with_seed(12345);
+    let (mut app_b, goblins_b) = app_with_seed(12345);
+
+    let rolls_a = rolls(&mut app_a, &goblins_a);
+    let rolls_b = rolls(&mut app_b, &goblins_b);
+
+    assert_eq!(rolls_a, rolls_b);
+    assert_eq!(rolls_a, vec![43, 60, 21]);
+
+    // Every instance is distinct from the others and from the template
+    let template = RngComponent::with_seed(999);
+    let components: Vec<&RngComponent> = goblins_a
+        .iter()
+        .map(|&entity| app_a.world().get::<RngComponent>(entity).unwrap())
+        .collect();
+
+    assert_ne!(components[0], components[1]);
+    assert_ne!(components[1], components[2]);
+    assert!(components.iter().all(|&component| component != &template));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn different_seeds_diverge() {
+    let (mut app_a, goblins_a) = app_with_seed(12345);
+    let (mut app_b, goblins_b) = app_with_seed(54321);
+
+    assert_ne!(rolls(&mut app_a, &goblins_a), rolls(&mut app_b, &goblins_b));
+}
+
+#[cfg(feature = "bevy_hierarchy")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn forks_from_parent_rng() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(1));
+
+    let parent = app.world_mut().spawn(RngComponent::with_seed(5)).id();
+    let child = app
+        .world_mut()
+        .spawn((RngComponent::with_seed(999), ForkOnSpawn))
+        .set_parent(parent)
+        .id();
+
+    let global_before = app.world().resource::<GlobalRng>().clone();
+
+    app.update();
+
+    let mut expected_parent = RngComponent::with_seed(5);
+    let expected_child = RngComponent::from(&mut expected_parent);
+
+    assert_eq!(
+        app.world().get::<RngComponent>(child),
+        Some(&expected_child)
+    );
+    assert_eq!(
+        app.world().get::<RngComponent>(parent),
+        Some(&expected_parent)
+    );
+    assert_eq!(app.world().resource::<GlobalRng>(), &global_before);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn unmarked_components_are_untouched() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(1));
+
+    let entity = app.world_mut().spawn(RngComponent::with_seed(999)).id();
+
+    app.update();
+
+    assert_eq!(
+        app.world().get::<RngComponent>(entity),
+        Some(&RngComponent::with_seed(999))
+    );
+}
+
+#[cfg(feature = "bevy_hierarchy")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn parents_fork_before_children_with_lower_ids() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(1));
+
+    // Spawns the children first, as recycled ids or a scene's spawn order can, so that
+    // each child's id sorts before its parent's.
+    let instances: Vec<(Entity, Entity)> = (0..2)
+        .map(|_| {
+            let child = app
+                .world_mut()
+                .spawn((RngComponent::with_seed(999), ForkOnSpawn))
+                .id();
+            let parent = app
+                .world_mut()
+                .spawn((RngComponent::with_seed(5), ForkOnSpawn))
+                .add_child(child)
+                .id();
+
+            assert!(child < parent);
+
+            (parent, child)
+        })
+        .collect();
+
+    app.update();
+
+    let mut global = GlobalRng::with_seed(1);
+
+    for &(parent, child) in &instances {
+        let mut expected_parent = RngComponent::from(&mut global);
+        let expected_child = RngComponent::from(&mut expected_parent);
+
+        assert_eq!(
+            app.world().get::<RngComponent>(parent),
+            Some(&expected_parent)
+        );
+        assert_eq!(
+            app.world().get::<RngComponent>(child),
+            Some(&expected_child)
+        );
+    }
+
+    let [(_, first), (_, second)] = instances[..] else {
+        unreachable!();
+    };
+
+    assert_ne!(
+        app.world().get::<RngComponent>(first),
+        app.world().get::<RngComponent>(second)
+    );
+}
@@ -0,0 +1,130 @@
+#![cfg(all(feature = "wyrand", feature = "gen-utils"))]
+
+use bevy::math::UVec2;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// Counts the cells reachable from the origin by walking through passages.
+fn reachable(maze: &GridMaze) -> usize {
+    let mut seen = vec![UVec2::ZERO];
+    let mut queue = vec![UVec2::ZERO];
+
+    while let Some(cell) = queue.pop() {
+        for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+            let Some(next) = cell
+                .x
+                .checked_add_signed(dx)
+                .zip(cell.y.checked_add_signed(dy))
+                .map(|(x, y)| UVec2::new(x, y))
+            else {
+                continue;
+            };
+
+            if maze.passable(cell, next) && !seen.contains(&next) {
+                seen.push(next);
+                queue.push(next);
+            }
+        }
+    }
+
+    seen.len()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn small_mazes_are_deterministic() {
+    let maze = GridMaze::generate(5, 5, &mut RngComponent::with_seed(5));
+
+    assert_eq!(maze, GridMaze::generate(5, 5, &mut GlobalRng::with_seed(5)));
+
+    let walls: Vec<_> = maze.walls().map(|(a, b)| (a.x, a.y, b.x, b.y)).collect();
+
+    assert_eq!(
+        walls,
+        [
+            (1, 0, 1, 1),
+            (2, 0, 2, 1),
+            (3, 0, 3, 1),
+            (0, 1, 1, 1),
+            (1, 1, 2, 1),
+            (3, 1, 4, 1),
+            (1, 2, 2, 2),
+            (1, 2, 1, 3),
+            (2, 2, 3, 2),
+            (3, 2, 4, 2),
+            (0, 3, 1, 3),
+            (2, 3, 3, 3),
+            (2, 3, 2, 4),
+            (3, 3, 4, 3),
+            (0, 4, 1, 4),
+            (1, 4, 2, 4)
+        ]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mazes_are_fully_connected() {
+    let mut seeds = RngComponent::with_seed(1);
+
+    for _ in 0..50 {
+        let mut rng = RngComponent::with_seed(seeds.u64(..));
+        let (width, height) = (rng.u32(1..=12), rng.u32(1..=12));
+
+        let maze = GridMaze::generate(width, height, &mut rng);
+
+        let cells = (width * height) as usize;
+        let edges = (width * (height - 1) + height * (width - 1)) as usize;
+
+        assert_eq!(reachable(&maze), cells);
+        // Connected with exactly one passage fewer than there are cells, so without loops.
+        assert_eq!(maze.walls().count(), edges - (cells - 1));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn passages_are_symmetric_and_local() {
+    let maze = GridMaze::generate(6, 4, &mut RngComponent::with_seed(3));
+
+    for y in 0..4 {
+        for x in 0..6 {
+            let cell = UVec2::new(x, y);
+
+            for other in [cell + UVec2::X, cell + UVec2::Y] {
+                assert_eq!(maze.passable(cell, other), maze.passable(other, cell));
+                assert_eq!(
+                    maze.passable(cell, other),
+                    !maze.walls().any(|wall| wall == (cell, other)) && other.x < 6 && other.y < 4
+                );
+            }
+
+            assert!(!maze.passable(cell, cell));
+            assert!(!maze.passable(cell, cell + UVec2::ONE));
+        }
+    }
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_rngs_generate_mazes_too() {
+    let mut rng = ChaChaRngComponent::with_seed([3; 40]);
+
+    let maze = GridMaze::generate(7, 7, &mut rng);
+    let cell = maze.random_cell(&mut rng);
+
+    assert_eq!(reachable(&maze), 49);
+    assert!(cell.x < 7 && cell.y < 7);
+}
+
+#[test]
+#[should_panic(expected = "maze width and height must not be zero")]
+fn empty_mazes_are_rejected() {
+    let _ = GridMaze::generate(0, 3, &mut RngComponent::with_seed(5));
+}
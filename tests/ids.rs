@@ -0,0 +1,66 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+use std::collections::HashSet;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ids_are_deterministic() {
+    let mut rng = RngComponent::with_seed(5);
+
+    assert_eq!(rng.gen_id64(), 5465023315925113795);
+    assert_eq!(rng.gen_id128(), 326800562077143063931381965225083790540);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn forked_ids_do_not_collide() {
+    let mut global = GlobalRng::with_seed(5);
+
+    let ids: HashSet<u64> = (0..1000)
+        .flat_map(|_| {
+            let mut rng = RngComponent::from(&mut global);
+
+            (0..100).map(move |_| rng.gen_id64())
+        })
+        .collect();
+
+    assert_eq!(ids.len(), 100_000);
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn uuids_are_deterministic() {
+    let mut rng = RngComponent::with_seed(5);
+
+    let ids: Vec<String> = (0..2).map(|_| rng.gen_uuid_v4().to_string()).collect();
+
+    assert_eq!(
+        ids,
+        [
+            "c39bf13a-c0a9-474b-8ce4-b7f6539b9daf",
+            "21f26ca1-b37f-4bf5-a611-71fa3b3dbe99"
+        ]
+    );
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn uuids_have_version_and_variant_bits_set() {
+    let mut rng = RngComponent::with_seed(5);
+
+    for _ in 0..1000 {
+        let id = rng.gen_uuid_v4();
+
+        assert_eq!(id.get_version(), Some(uuid::Version::Random));
+        assert_eq!(id.get_variant(), uuid::Variant::RFC4122);
+    }
+}
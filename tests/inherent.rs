@@ -0,0 +1,115 @@
+#![cfg(all(feature = "wyrand", feature = "chacha"))]
+
+// Deliberately no prelude nor trait imports, so that this only compiles as long as the
+// wrappers provide their common methods inherently.
+use bevy_turborand::{ChaChaRngComponent, GlobalChaChaRng, GlobalRng, RngComponent};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! draw_all {
+    ($rng:expr) => {{
+        let rng = &mut $rng;
+        let mut list = [1, 2, 3, 4];
+
+        rng.shuffle(&mut list);
+
+        let mut prefixed = [1, 2, 3, 4];
+        let mut partial = [1, 2, 3, 4];
+        let mut weighted = [1, 2, 3, 4];
+
+        rng.shuffle_prefix(&mut prefixed, 2);
+        rng.partial_shuffle(&mut partial, 2);
+        rng.weighted_shuffle(&mut weighted, |item| f64::from(*item));
+
+        (
+            rng.u64(..),
+            rng.i32(-5..5),
+            rng.index(..10),
+            rng.f64().to_bits(),
+            rng.bool(),
+            rng.alphanumeric(),
+            rng.chance(0.5),
+            rng.sample(&list).copied(),
+            rng.pick(&list),
+            list,
+            (
+                rng.sample_multiple(&list, 2)
+                    .into_iter()
+                    .copied()
+                    .collect::<Vec<_>>(),
+                rng.weighted_sample(&list, |(item, _)| f64::from(*item) / 4.0)
+                    .copied(),
+                prefixed,
+                partial,
+                weighted,
+            ),
+        )
+    }};
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn wyrand_wrappers_have_inherent_methods() {
+    let mut global = GlobalRng::with_seed(5);
+    let mut component = RngComponent::with_seed(5);
+
+    assert_eq!(draw_all!(global), draw_all!(component));
+    assert_eq!(global.fork(), component.fork());
+
+    component.reseed(9);
+    global.reseed(9);
+
+    assert_eq!(global.initial_seed(), Some(9));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn wrappers_have_inherent_mutable_sampling() {
+    let mut global = GlobalRng::with_seed(5);
+    let mut component = ChaChaRngComponent::with_seed([3; 40]);
+    let mut list = [0_u32; 4];
+
+    *global.sample_mut(&mut list).unwrap() += 1;
+    *component.sample_mut(&mut list).unwrap() += 1;
+
+    for item in global.sample_multiple_mut(&mut list, 2) {
+        *item += 1;
+    }
+
+    for item in component.sample_multiple_mut(&mut list, 2) {
+        *item += 1;
+    }
+
+    *global.weighted_sample_mut(&mut list, |_| 0.5).unwrap() += 1;
+    *component.weighted_sample_mut(&mut list, |_| 0.5).unwrap() += 1;
+
+    assert_eq!(list.iter().sum::<u32>(), 8);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_wrappers_have_inherent_methods() {
+    let mut global = GlobalChaChaRng::with_seed([3; 40]);
+    let mut component = ChaChaRngComponent::with_seed([3; 40]);
+
+    assert_eq!(draw_all!(global), draw_all!(component));
+
+    let forked_global = global.fork();
+    let forked_component = component.fork();
+
+    assert_eq!(forked_global, forked_component);
+
+    global.reseed([7; 40]);
+    component.reseed([7; 40]);
+
+    assert_eq!(global.u128(..), component.u128(..));
+    assert_eq!(global.initial_seed(), Some([7; 40]));
+    assert_eq!(
+        ChaChaRngComponent::from(global.fork()),
+        ChaChaRngComponent::from(component.fork())
+    );
+}
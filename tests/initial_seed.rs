@@ -0,0 +1,168 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seeded_globals_record_their_seed() {
+    let mut global = GlobalRng::with_seed(5);
+
+    global.u64(..);
+
+    assert_eq!(global.initial_seed(), Some(5));
+    assert_eq!(GlobalRng::new().initial_seed(), None);
+    assert_eq!(GlobalRng::from(Rng::with_seed(5)).initial_seed(), None);
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(12345));
+
+    assert_eq!(
+        app.world().resource::<GlobalRng>().initial_seed(),
+        Some(12345)
+    );
+
+    #[cfg(feature = "chacha")]
+    assert_eq!(
+        app.world().resource::<GlobalChaChaRng>().initial_seed(),
+        None
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn recorded_entropy_seed_reproduces_the_stream() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_recorded_entropy_seed());
+
+    let mut global = app.world_mut().resource_mut::<GlobalRng>();
+    let seed = global.initial_seed().unwrap();
+
+    let mut replay = GlobalRng::with_seed(seed);
+
+    assert_eq!(global.u64(..), replay.u64(..));
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new());
+
+    assert_eq!(app.world().resource::<GlobalRng>().initial_seed(), None);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reseeding_updates_the_recorded_seed() {
+    let mut global = GlobalRng::with_seed(5);
+
+    global.reseed(7);
+
+    assert_eq!(global.initial_seed(), Some(7));
+    assert_eq!(global, GlobalRng::with_seed(7));
+
+    global.reseed_from_entropy();
+
+    let seed = global.initial_seed().unwrap();
+
+    assert_eq!(global, GlobalRng::with_seed(seed));
+
+    let mut unrecorded = GlobalRng::new();
+
+    unrecorded.reseed_from_entropy();
+
+    assert_eq!(unrecorded.initial_seed(), None);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn delegated_reseeding_records_the_seed() {
+    fn reseed_delegated<R: DelegatedRng>(rng: &mut R, seed: <R::Source as SeededCore>::Seed) {
+        rng.reseed(seed);
+    }
+
+    let mut global = GlobalRng::new();
+
+    reseed_delegated(&mut global, 7);
+
+    assert_eq!(global.initial_seed(), Some(7));
+    assert_eq!(global, GlobalRng::with_seed(7));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn recorded_seed_is_ignored_when_comparing() {
+    let mut seeded = GlobalRng::with_seed(5);
+    let wrapped = GlobalRng::from(Rng::with_seed(5));
+
+    assert_eq!(seeded, wrapped);
+
+    seeded.u64(..);
+
+    assert_ne!(seeded, wrapped);
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_globals_record_their_seed() {
+    let mut global = GlobalChaChaRng::with_seed([3; 40]);
+
+    global.u64(..);
+
+    assert_eq!(global.initial_seed(), Some([3; 40]));
+
+    global.reseed([4; 40]);
+
+    assert_eq!(global.initial_seed(), Some([4; 40]));
+    assert_eq!(global, GlobalChaChaRng::with_seed([4; 40]));
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_recorded_entropy_seed());
+
+    let mut global = app.world_mut().resource_mut::<GlobalChaChaRng>();
+    let mut replay = GlobalChaChaRng::with_seed(global.initial_seed().unwrap());
+
+    assert_eq!(global.u64(..), replay.u64(..));
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn recorded_seed_is_serialized() {
+    let mut global = GlobalRng::with_seed(5);
+
+    global.u64(..);
+
+    let saved = ron::to_string(&global).unwrap();
+    let loaded: GlobalRng = ron::from_str(&saved).unwrap();
+
+    assert_eq!(loaded, global);
+    assert_eq!(loaded.initial_seed(), Some(5));
+
+    let unseeded = ron::to_string(&GlobalRng::from(Rng::with_seed(5))).unwrap();
+    let without_seed = unseeded.replace(",None)", ")");
+    let loaded: GlobalRng = ron::from_str(&without_seed).unwrap();
+
+    assert_ne!(unseeded, without_seed);
+    assert_eq!(loaded, GlobalRng::with_seed(5));
+    assert_eq!(loaded.initial_seed(), None);
+
+    #[cfg(feature = "chacha")]
+    {
+        let global = GlobalChaChaRng::with_seed([3; 40]);
+
+        let saved = ron::to_string(&global).unwrap();
+        let loaded: GlobalChaChaRng = ron::from_str(&saved).unwrap();
+
+        assert_eq!(loaded, global);
+        assert_eq!(loaded.initial_seed(), Some([3; 40]));
+    }
+}
@@ -0,0 +1,64 @@
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn roll(rng: &mut impl AsMut<Rng>) -> u64 {
+    rng.as_mut().u64(..)
+}
+
+#[cfg(feature = "chacha")]
+fn roll_secure(rng: &mut impl AsMut<ChaChaRng>) -> u64 {
+    rng.as_mut().u64(..)
+}
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rng_ownership_transfer_preserves_state() {
+    let source = Rng::with_seed(23);
+    source.u64(..);
+
+    let expected = source.clone();
+    let mut component = RngComponent::from(source);
+
+    assert_eq!(roll(&mut component), expected.u64(..));
+
+    let inner = component.into_inner();
+
+    assert_eq!(inner, expected);
+    assert_eq!(inner.u64(..), expected.u64(..));
+
+    let mut global = GlobalRng::with_seed(23);
+    let expected = Rng::with_seed(23);
+
+    assert_eq!(roll(&mut global), expected.u64(..));
+    assert_eq!(global.into_inner(), expected);
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_ownership_transfer_preserves_state() {
+    let source = ChaChaRng::with_seed([5; 40]);
+    source.u64(..);
+
+    let expected = source.clone();
+    let mut component = ChaChaRngComponent::from(source);
+
+    assert_eq!(roll_secure(&mut component), expected.u64(..));
+
+    let inner = component.into_inner();
+
+    assert_eq!(inner, expected);
+    assert_eq!(inner.u64(..), expected.u64(..));
+
+    let mut global = GlobalChaChaRng::with_seed([5; 40]);
+    let expected = ChaChaRng::with_seed([5; 40]);
+
+    assert_eq!(roll_secure(&mut global), expected.u64(..));
+    assert_eq!(global.into_inner(), expected);
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "compat")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+// Golden values for the legacy algorithm. These must never change.
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn legacy_step_is_pinned() {
+    let rng = LegacyRng::with_seed(0);
+    rng.reseed(1);
+
+    assert_eq!(rng.gen::<8>(), [44, 237, 248, 225, 149, 22, 239, 205]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn legacy_stream_is_pinned() {
+    let rng = LegacyRng::with_seed(5);
+
+    let values: Vec<u64> = (0..4).map(|_| rng.gen_u64()).collect();
+
+    assert_eq!(
+        values,
+        [
+            5465023315925113795,
+            12654441312904799436,
+            17715893968676123169,
+            11078359461192077670
+        ]
+    );
+
+    let mut bytes = [0u8; 11];
+    rng.fill_bytes(&mut bytes);
+
+    assert_eq!(bytes, [232, 183, 125, 75, 171, 169, 174, 99, 3, 10, 255]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn legacy_forks_are_pinned() {
+    let rng = LegacyRng::with_seed(5);
+    let fork = rng.fork();
+
+    assert_eq!(fork.gen_u64(), 16609780926639888209);
+    assert_eq!(rng.gen_u64(), 12654441312904799436);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn legacy_matches_current_algorithm() {
+    for seed in 0..100 {
+        let legacy = LegacyRngComponent::with_seed(seed);
+        let current = RngComponent::with_seed(seed);
+
+        let mut legacy = legacy;
+        let mut current = current;
+
+        for _ in 0..10 {
+            assert_eq!(legacy.u64(..), current.u64(..));
+            assert_eq!(legacy.f32(), current.f32());
+            assert_eq!(legacy.u32(..10), current.u32(..10));
+        }
+
+        assert_eq!(legacy.state_bytes(), current.state_bytes());
+
+        let mut legacy_fork = LegacyRngComponent::from(legacy.fork());
+        let mut current_fork = RngComponent::from(current.fork());
+
+        assert_eq!(legacy_fork.u64(..), current_fork.u64(..));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn restores_from_legacy_state() {
+    let mut stored = RngComponent::with_seed(5);
+    stored.u64(..);
+
+    let bytes = stored.state_bytes();
+
+    let mut legacy = LegacyRngComponent::from_legacy_state(&bytes).unwrap();
+    let mut current = RngComponent::from_legacy_state(&bytes).unwrap();
+
+    assert_eq!(current, stored);
+    assert_eq!(current.u64(..), stored.clone().u64(..));
+    assert_eq!(legacy.u64(..), stored.u64(..));
+    assert_eq!(legacy.state_bytes(), stored.state_bytes());
+
+    assert_eq!(
+        LegacyRngComponent::from_legacy_state(&[0; 4]),
+        Err(RngStateError::InvalidLength {
+            expected: 8,
+            found: 4
+        })
+    );
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn legacy_components_load_from_saves() {
+    use bevy::reflect::serde::ReflectDeserializer;
+    use serde::de::DeserializeSeed;
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_legacy_components());
+
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+
+    let serialized = ron::to_string(&LegacyRngComponent::with_seed(5)).unwrap();
+    let saved = format!("{{\"bevy_turborand::legacy::LegacyRngComponent\":{serialized}}}");
+
+    let mut deserializer = ron::Deserializer::from_str(&saved).unwrap();
+    let value = ReflectDeserializer::new(&registry)
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+    assert_eq!(
+        LegacyRngComponent::take_from_reflect(value).unwrap(),
+        LegacyRngComponent::with_seed(5)
+    );
+}
@@ -0,0 +1,117 @@
+#![cfg(all(feature = "wyrand", feature = "serialize"))]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+const CURRENT: &str = "(((state:(24691))))";
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn current_payloads_are_still_emitted() {
+    assert_eq!(
+        ron::to_string(&RngComponent::with_seed(12345)).unwrap(),
+        CURRENT
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn legacy_payloads_restore_the_same_state() {
+    let current: RngComponent = ron::from_str(CURRENT).unwrap();
+
+    for payload in [
+        include_str!("fixtures/rng_component_nested.ron"),
+        include_str!("fixtures/rng_component_flat.ron"),
+    ] {
+        let mut rng: RngComponent = ron::from_str(payload).unwrap();
+
+        assert_eq!(rng, current);
+        assert_eq!(rng, RngComponent::with_seed(12345));
+        assert_eq!(rng.u32(..10), 4);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn legacy_payloads_load_within_saves() {
+    #[derive(Debug, serde::Deserialize)]
+    struct Save {
+        rng: RngComponent,
+    }
+
+    for payload in ["(rng:((state:(11))))", "(rng:(state:11))"] {
+        let save: Save = ron::from_str(payload).unwrap();
+        let expected: Save = ron::from_str("(rng:(((state:(11)))))").unwrap();
+
+        assert_eq!(save.rng, expected.rng);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn legacy_payloads_load_through_reflection() {
+    use bevy::reflect::{serde::ReflectDeserializer, FromReflect, TypeRegistry};
+    use serde::de::DeserializeSeed;
+
+    let mut registry = TypeRegistry::default();
+    registry.register::<RngComponent>();
+
+    let payload = r#"{"bevy_turborand::component::rng::RngComponent":((state:(15)))}"#;
+    let mut deserializer = ron::Deserializer::from_str(payload).unwrap();
+
+    let value = ReflectDeserializer::new(&registry)
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+    assert_eq!(
+        RngComponent::from_reflect(value.as_partial_reflect()),
+        Some(RngComponent::with_seed(7))
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn malformed_payloads_are_rejected() {
+    for payload in [
+        "()",
+        "(((state:(24691, 1))))",
+        "(((state:(-1))))",
+        "(((state:(\"24691\"))))",
+        "(((state:(1.5))))",
+    ] {
+        assert!(
+            ron::from_str::<RngComponent>(payload).is_err(),
+            "{payload} should be rejected"
+        );
+    }
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn legacy_chacha_payloads_restore_the_same_state() {
+    let current = ChaChaRngComponent::with_seed([7; 40]);
+
+    assert_eq!(
+        ron::to_string(&current).unwrap(),
+        format!(
+            "(({}))",
+            include_str!("fixtures/chacha_rng_component_flat.ron").trim()
+        )
+    );
+
+    for payload in [
+        include_str!("fixtures/chacha_rng_component_nested.ron"),
+        include_str!("fixtures/chacha_rng_component_flat.ron"),
+    ] {
+        let mut rng: ChaChaRngComponent = ron::from_str(payload).unwrap();
+
+        assert_eq!(rng, current);
+        assert_eq!(rng.u32(..10), 0);
+    }
+}
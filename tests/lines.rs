@@ -0,0 +1,157 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn barks() -> LineSelector {
+    LineSelector::new()
+        .with_line(LineEntry::new("bark.a", 3.0))
+        .with_line(LineEntry::new("bark.b", 2.0))
+        .with_line(LineEntry::new("bark.c", 1.0).with_cooldown(4))
+        .with_line(LineEntry::new("bark.rain", 5.0).with_predicate("raining"))
+}
+
+fn select_many(
+    selector: &mut LineSelector,
+    rng: &mut RngComponent,
+    context: &dyn Fn(&str) -> bool,
+    count: usize,
+) -> Vec<String> {
+    (0..count)
+        .map(|_| selector.select(rng, context).unwrap().to_owned())
+        .collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seeded_selection_sequence_is_pinned() {
+    let mut selector = barks();
+    let mut rng = RngComponent::with_seed(5);
+
+    let selected = select_many(&mut selector, &mut rng, &|_| true, 8);
+
+    assert_eq!(
+        selected,
+        [
+            "bark.b",
+            "bark.rain",
+            "bark.c",
+            "bark.rain",
+            "bark.a",
+            "bark.rain",
+            "bark.a",
+            "bark.rain"
+        ]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn lines_are_never_repeated_immediately() {
+    let mut selector = barks();
+    let mut rng = RngComponent::with_seed(7);
+
+    let selected = select_many(&mut selector, &mut rng, &|_| true, 200);
+
+    assert!(selected.windows(2).all(|pair| pair[0] != pair[1]));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cooldowns_suppress_lines() {
+    let mut selector = barks();
+    let mut rng = RngComponent::with_seed(7);
+
+    let selected = select_many(&mut selector, &mut rng, &|_| true, 500);
+
+    let picks: Vec<usize> = selected
+        .iter()
+        .enumerate()
+        .filter(|(_, key)| *key == "bark.c")
+        .map(|(index, _)| index)
+        .collect();
+
+    assert!(picks.len() > 1);
+    assert!(picks.windows(2).all(|pair| pair[1] - pair[0] > 4));
+
+    // With a cooldown of zero, a lone line can be selected every time.
+    let mut repeating = LineSelector::new().with_line(LineEntry::new("only", 1.0).with_cooldown(0));
+
+    assert_eq!(
+        select_many(&mut repeating, &mut rng, &|_| true, 3),
+        ["only", "only", "only"]
+    );
+
+    // With the default cooldown, a lone line can only be selected every other time.
+    let mut alone = LineSelector::new().with_line(LineEntry::new("only", 1.0));
+
+    assert_eq!(alone.select(&mut rng, &|_| true), Some("only"));
+    assert_eq!(alone.select(&mut rng, &|_| true), None);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn predicates_filter_lines() {
+    let mut selector = barks();
+    let mut rng = RngComponent::with_seed(7);
+
+    let dry = select_many(&mut selector, &mut rng, &|_| false, 100);
+
+    assert!(dry.iter().all(|key| key != "bark.rain"));
+
+    let wet = select_many(&mut selector, &mut rng, &|id| id == "raining", 100);
+
+    assert!(wet.iter().any(|key| key == "bark.rain"));
+
+    let mut gated = LineSelector::new().with_line(LineEntry::new("gated", 1.0).with_predicate("x"));
+
+    assert_eq!(gated.select(&mut rng, &|_| false), None);
+    assert_eq!(gated.history().selections, 0);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn history_survives_saving() {
+    let mut selector = barks();
+    let mut rng = RngComponent::with_seed(9);
+
+    select_many(&mut selector, &mut rng, &|_| true, 5);
+
+    let saved = ron::to_string(selector.history()).unwrap();
+
+    let mut continued = selector.clone();
+    let mut continued_rng = rng.clone();
+
+    let mut restored = barks();
+    restored.set_history(ron::from_str(&saved).unwrap());
+
+    assert_eq!(
+        select_many(&mut restored, &mut rng, &|_| true, 20),
+        select_many(&mut continued, &mut continued_rng, &|_| true, 20)
+    );
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn selectors_can_be_authored_in_ron() {
+    let authored: LineSelector = ron::from_str(
+        r#"(
+            lines: [
+                (key: "bark.a", weight: 3.0),
+                (key: "bark.b", weight: 2.0),
+                (key: "bark.c", weight: 1.0, cooldown: Some(4)),
+                (key: "bark.rain", weight: 5.0, predicate: Some("raining")),
+            ],
+        )"#,
+    )
+    .unwrap();
+
+    assert_eq!(authored, barks());
+}
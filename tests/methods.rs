@@ -0,0 +1,131 @@
+use bevy_turborand::prelude::*;
+#[cfg(feature = "testing")]
+use bevy_turborand::stats::expect_frequency;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ratio_is_deterministic() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let rolls: Vec<bool> = (0..8).map(|_| rng.ratio(1, 2)).collect();
+
+    assert_eq!(
+        &rolls,
+        &[true, false, false, false, true, false, true, true]
+    );
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_ratio_is_deterministic() {
+    let mut rng = ChaChaRngComponent::with_seed([1; 40]);
+
+    let rolls: Vec<bool> = (0..8).map(|_| rng.ratio(3, 4)).collect();
+
+    assert_eq!(&rolls, &[true, true, true, true, true, true, false, true]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ratio_certain_outcomes_do_not_draw() {
+    let mut rng = RngComponent::with_seed(7);
+    let reference = rng.clone();
+
+    assert!(rng.ratio(8, 8));
+    assert!(rng.ratio(9, 8));
+    assert!(!rng.ratio(0, 8));
+    assert_eq!(rng, reference);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "ratio denominator must not be zero")]
+fn ratio_zero_denominator_panics() {
+    RngComponent::with_seed(1).ratio(1, 0);
+}
+
+#[cfg(feature = "testing")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ratio_frequency() {
+    expect_frequency(
+        |rng| rng.ratio(1, 8),
+        80_000,
+        &[(true, 0.125), (false, 0.875)],
+        0.005,
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn lerp_is_deterministic() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let values: Vec<f32> = (0..4).map(|_| rng.lerp_f32(10.0, -10.0)).collect();
+
+    assert_eq!(&values, &[1.8667269, -3.9263105, -5.9894047, -4.983124]);
+
+    for _ in 0..100 {
+        let value = rng.lerp_f64(-2.5, 4.0);
+
+        assert!((-2.5..4.0).contains(&value));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn lerp_between_equal_values_is_exact() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    for value in [0.1f32, -7.3, 1.0e30, f32::MIN_POSITIVE, 123_456.79] {
+        for _ in 0..20 {
+            assert_eq!(rng.lerp_f32(value, value), value);
+            assert_eq!(rng.lerp_f64(value.into(), value.into()), f64::from(value));
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn lerp_propagates_nan() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    assert!(rng.lerp_f32(f32::NAN, 1.0).is_nan());
+    assert!(rng.lerp_f64(1.0, f64::NAN).is_nan());
+    assert!(rng.f32_symmetric(f32::NAN).is_nan());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn symmetric_is_deterministic_and_bounded() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let values: Vec<f32> = (0..4).map(|_| rng.f32_symmetric(2.0)).collect();
+
+    assert_eq!(&values, &[1.6266546, -1.2147379, -0.802119, -1.0033753]);
+
+    for _ in 0..100 {
+        assert!((-0.5..=0.5).contains(&rng.f32_symmetric(0.5)));
+        assert!((-3.0..=3.0).contains(&rng.f64_symmetric(3.0)));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn symmetric_zero_magnitude_is_positive_zero() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    for _ in 0..100 {
+        let value = rng.f32_symmetric(0.0);
+
+        assert_eq!(value.to_bits(), 0.0f32.to_bits());
+        assert_eq!(rng.f64_symmetric(0.0).to_bits(), 0.0f64.to_bits());
+    }
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn hits(rng: &mut RngComponent, tag: &str, modifiers: &ProbabilityModifiers) -> usize {
+    (0..20_000)
+        .filter(|_| rng.chance_tagged(tag, 0.25, Some(modifiers)))
+        .count()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn tagged_calls_shift_while_untagged_do_not() {
+    let mut modifiers = ProbabilityModifiers::new();
+    let mut rng = RngComponent::with_seed(12345);
+
+    let loot_before = hits(&mut rng, "loot", &modifiers);
+    let combat_before = hits(&mut rng, "combat", &modifiers);
+
+    modifiers.set("loot", ProbabilityModifier::multiply(2.0));
+
+    let loot_after = hits(&mut rng, "loot", &modifiers);
+    let combat_after = hits(&mut rng, "combat", &modifiers);
+
+    // Expected 5_000 hits at 0.25 and 10_000 hits at 0.5, with a deviation of ~70.
+    assert!((4_700..=5_300).contains(&loot_before), "{loot_before}");
+    assert!((4_700..=5_300).contains(&combat_before), "{combat_before}");
+    assert!((9_700..=10_300).contains(&loot_after), "{loot_after}");
+    assert!((4_700..=5_300).contains(&combat_after), "{combat_after}");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn missing_modifiers_are_identity() {
+    let modifiers =
+        ProbabilityModifiers::new().with_modifier("loot", ProbabilityModifier::offset(0.5));
+
+    let mut tagged = RngComponent::with_seed(5);
+    let mut plain = RngComponent::with_seed(5);
+
+    for _ in 0..100 {
+        assert_eq!(
+            tagged.chance_tagged("combat", 0.3, Some(&modifiers)),
+            plain.chance(0.3)
+        );
+        assert_eq!(tagged.chance_tagged("loot", 0.3, None), plain.chance(0.3));
+    }
+
+    let list = [1u32, 2, 3, 4];
+
+    for _ in 0..100 {
+        assert_eq!(
+            tagged.weighted_sample_tagged("loot", &list, |(&n, _)| f64::from(n) / 10.0, None),
+            plain.weighted_sample(&list, |(&n, _)| f64::from(n) / 10.0)
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_sample_tagged_applies_modifier() {
+    // Zeroing out the weights of everything but the first item leaves it as the only pick.
+    let modifiers =
+        ProbabilityModifiers::new().with_modifier("loot", ProbabilityModifier::offset(-0.5));
+    let list = [0.9, 0.4, 0.3, 0.2];
+    let mut rng = RngComponent::with_seed(5);
+
+    for _ in 0..100 {
+        assert_eq!(
+            rng.weighted_sample_tagged("loot", &list, |(&w, _)| w, Some(&modifiers)),
+            Some(&0.9)
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn modifiers_clamp_probabilities() {
+    let modifier = ProbabilityModifier {
+        multiplier: 3.0,
+        offset: -0.5,
+    };
+
+    assert_eq!(modifier.apply(0.1), 0.0);
+    assert_eq!(modifier.apply(0.5), 1.0);
+    assert_eq!(ProbabilityModifier::default().apply(0.42), 0.42);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn modifiers_can_be_recorded() {
+    let modifiers = ProbabilityModifiers::new()
+        .with_modifier("loot", ProbabilityModifier::multiply(1.2))
+        .with_modifier("crit", ProbabilityModifier::offset(0.05));
+
+    let recorded = ron::to_string(&modifiers).unwrap();
+
+    assert_eq!(
+        recorded,
+        "{\"crit\":(multiplier:1.0,offset:0.05),\"loot\":(multiplier:1.2,offset:0.0)}"
+    );
+
+    let replayed: ProbabilityModifiers = ron::from_str(&recorded).unwrap();
+
+    assert_eq!(replayed, modifiers);
+}
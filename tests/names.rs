@@ -0,0 +1,116 @@
+#![cfg(all(feature = "wyrand", feature = "gen-utils"))]
+
+use bevy_turborand::{names::InvalidNamePattern, prelude::*};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn names_are_pinned_for_a_seed() {
+    let mut rng = RngComponent::with_seed(5);
+    let names = NameGenerator::default();
+
+    assert_eq!(
+        (0..4).map(|_| names.generate(&mut rng)).collect::<Vec<_>>(),
+        ["Raya", "Gorrenka", "Renvadafa", "Zangarengar"]
+    );
+    assert_eq!(
+        names.generate_with_pattern(&mut rng, "CvcVc").unwrap(),
+        "ThaefIp"
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn names_compose_two_to_four_syllables() {
+    let mut rng = RngComponent::with_seed(5);
+    let names = NameGenerator::default().with_syllables(["xo"]);
+
+    let lengths: Vec<usize> = (0..1_000).map(|_| names.generate(&mut rng).len()).collect();
+
+    assert!(lengths.iter().all(|length| [4, 6, 8].contains(length)));
+    assert!([4, 6, 8].iter().all(|length| lengths.contains(length)));
+    assert!(names.generate(&mut rng).starts_with("Xo"));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn patterns_pick_consonants_and_vowels() {
+    let mut rng = RngComponent::with_seed(5);
+    let names = NameGenerator::default()
+        .with_consonants(["k"])
+        .with_vowels(["a", "o"]);
+
+    for _ in 0..100 {
+        let name = names.generate_with_pattern(&mut rng, "CvcVc").unwrap();
+        let chars: Vec<char> = name.chars().collect();
+
+        assert_eq!(chars.len(), 5);
+        assert_eq!([chars[0], chars[2], chars[4]], ['K', 'k', 'k']);
+        assert!(['a', 'o'].contains(&chars[1]));
+        assert!(['A', 'O'].contains(&chars[3]));
+    }
+
+    assert_eq!(names.generate_with_pattern(&mut rng, ""), Ok(String::new()));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn invalid_patterns_error_without_drawing() {
+    let mut rng = RngComponent::with_seed(5);
+    let names = NameGenerator::default();
+
+    assert_eq!(
+        names.generate_with_pattern(&mut rng, "Cv-cv"),
+        Err(InvalidNamePattern {
+            character: '-',
+            index: 2
+        })
+    );
+    assert_eq!(
+        names.generate_with_pattern(&mut rng, "Cvéx"),
+        Err(InvalidNamePattern {
+            character: 'é',
+            index: 2
+        })
+    );
+    assert_eq!(rng, RngComponent::with_seed(5));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn same_seeds_give_same_names() {
+    let names = NameGenerator::default();
+    let mut global = GlobalRng::with_seed(5);
+
+    let mut first = RngComponent::from(&mut global);
+    let mut second = RngComponent::from(&mut global);
+
+    let first_name = names.generate(&mut first);
+
+    assert_ne!(first_name, names.generate(&mut second));
+    assert_eq!(
+        first_name,
+        names.generate(&mut RngComponent::from(&mut GlobalRng::with_seed(5)))
+    );
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn custom_sets_deserialize_with_defaults() {
+    let names: NameGenerator = ron::from_str(r#"(syllables: ["ka", "ri"])"#).unwrap();
+
+    let defaults = NameGenerator::default();
+
+    assert_eq!(names, defaults.clone().with_syllables(["ka", "ri"]));
+    assert_ne!(names, defaults);
+
+    let round_trip: NameGenerator = ron::from_str(&ron::to_string(&names).unwrap()).unwrap();
+
+    assert_eq!(round_trip, names);
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn full_penalty_never_repeats_back_to_back() {
+    let mut rng = RngComponent::with_seed(7);
+    let mut sampler = NoRepeatSampler::new(vec![1, 2, 3]);
+
+    let mut previous = *sampler.sample(&mut rng);
+
+    for _ in 0..1000 {
+        let next = *sampler.sample(&mut rng);
+
+        assert_ne!(next, previous);
+
+        previous = next;
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sequences_are_deterministic() {
+    let beats = ["greet", "complain", "gossip", "threaten"];
+
+    let sequence = |seed| {
+        let mut rng = RngComponent::with_seed(seed);
+        let mut sampler = NoRepeatSampler::from(&beats[..]).with_memory(2);
+
+        (0..6)
+            .map(|_| *sampler.sample(&mut rng))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(sequence(5), sequence(5));
+    assert_eq!(
+        sequence(5),
+        ["complain", "gossip", "threaten", "complain", "gossip", "threaten"]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn single_item_lists_always_return_it() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut sampler = NoRepeatSampler::new(vec!["only"]).with_memory(3);
+
+    for _ in 0..10 {
+        assert_eq!(sampler.sample(&mut rng), &"only");
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn memory_covering_the_list_cycles_through_it() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut sampler = NoRepeatSampler::new(vec![1, 2, 3]).with_memory(5);
+
+    let first: Vec<i32> = (0..3).map(|_| *sampler.sample(&mut rng)).collect();
+
+    // Once every item was returned, the least recently returned one is always picked.
+    for expected in first.iter().cycle().take(9) {
+        assert_eq!(sampler.sample(&mut rng), expected);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn partial_penalty_reduces_repeats() {
+    let mut rng = RngComponent::with_seed(7);
+    let mut sampler = NoRepeatSampler::new(vec![1, 2]).with_penalty(0.5);
+
+    let mut previous = *sampler.sample(&mut rng);
+    let mut repeats = 0;
+
+    for _ in 0..10_000 {
+        let next = *sampler.sample(&mut rng);
+
+        if next == previous {
+            repeats += 1;
+        }
+
+        previous = next;
+    }
+
+    // A repeat has a weight of 0.5 against 1.0, so happens a third of the time.
+    assert!((3000..3700).contains(&repeats), "{repeats}");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "cannot sample from a NoRepeatSampler without items")]
+fn empty_samplers_panic() {
+    NoRepeatSampler::<u32>::new(Vec::new()).sample(&mut RngComponent::with_seed(5));
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn samplers_roundtrip_through_serde() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut sampler = NoRepeatSampler::new(vec![1, 2, 3]).with_memory(2);
+
+    sampler.sample(&mut rng);
+
+    let serialized = ron::to_string(&sampler).unwrap();
+    let mut restored: NoRepeatSampler<i32> = ron::from_str(&serialized).unwrap();
+
+    assert_eq!(restored, sampler);
+    assert_eq!(restored.sample(&mut rng.clone()), sampler.sample(&mut rng));
+}
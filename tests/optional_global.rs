@@ -0,0 +1,130 @@
+#![cfg(feature = "wyrand")]
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+use common::with_warnings;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn components_seed_from_the_world_global() {
+    let mut world = World::new();
+
+    world.insert_resource(GlobalRng::with_seed(5));
+
+    let mut expected = GlobalRng::with_seed(5);
+
+    assert_eq!(
+        RngComponent::try_from_world(&mut world),
+        Ok(RngComponent::from(&mut expected))
+    );
+    assert_eq!(world.resource::<GlobalRng>(), &expected);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn missing_world_global_is_an_error() {
+    let mut world = World::new();
+
+    let error = RngComponent::try_from_world(&mut world).unwrap_err();
+
+    assert_eq!(
+        error,
+        MissingGlobalRng {
+            resource: "GlobalRng"
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "the GlobalRng resource is missing from the world, was RngPlugin added?"
+    );
+
+    let error: Box<dyn std::error::Error> = Box::new(error);
+
+    assert!(error.source().is_none());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn optional_globals_seed_without_warnings() {
+    let mut global = GlobalRng::with_seed(5);
+    let mut expected = GlobalRng::with_seed(5);
+
+    let (rng, warnings) = with_warnings(|| RngComponent::from_optional_global(Some(&mut global)));
+
+    assert_eq!(rng, RngComponent::from(&mut expected));
+    assert_eq!(global, expected);
+    assert_eq!(warnings, Vec::<String>::new());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn missing_optional_global_falls_back_to_entropy() {
+    let (_, warnings) = with_warnings(|| RngComponent::from_optional_global(None));
+
+    assert_eq!(
+        warnings,
+        ["No GlobalRng to seed a RngComponent from, so seeding it from entropy instead"]
+    );
+
+    let mut app = App::new();
+
+    app.add_systems(
+        Update,
+        |mut commands: Commands, mut global: Option<ResMut<GlobalRng>>| {
+            commands.spawn(RngComponent::from_optional_global(global.as_deref_mut()));
+        },
+    );
+
+    let (_, warnings) = with_warnings(|| app.update());
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        app.world_mut()
+            .query::<&RngComponent>()
+            .iter(app.world())
+            .count(),
+        1
+    );
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_components_seed_from_the_world_global() {
+    let mut world = World::new();
+
+    assert_eq!(
+        ChaChaRngComponent::try_from_world(&mut world),
+        Err(MissingGlobalRng {
+            resource: "GlobalChaChaRng"
+        })
+    );
+
+    world.insert_resource(GlobalChaChaRng::with_seed([3; 40]));
+
+    let mut expected = GlobalChaChaRng::with_seed([3; 40]);
+
+    assert_eq!(
+        ChaChaRngComponent::try_from_world(&mut world),
+        Ok(ChaChaRngComponent::from(&mut expected))
+    );
+
+    let mut global = GlobalChaChaRng::with_seed([3; 40]);
+    let (_, warnings) = with_warnings(|| {
+        let _ = ChaChaRngComponent::from_optional_global(Some(&mut global));
+        ChaChaRngComponent::from_optional_global(None)
+    });
+
+    assert_eq!(
+        warnings,
+        ["No GlobalChaChaRng to seed a ChaChaRngComponent from, so seeding it from entropy instead"]
+    );
+}
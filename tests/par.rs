@@ -0,0 +1,109 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy::tasks::{ComputeTaskPool, TaskPool};
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn batch_sources_are_assigned_by_index() {
+    let mut global = GlobalRng::with_seed(5);
+    let mut expected = GlobalRng::with_seed(5);
+
+    let mut batch = RngBatch::from_global(&mut global, 4);
+
+    assert_eq!(batch.len(), 4);
+    assert!(!batch.is_empty());
+    assert!(batch.get_mut(4).is_none());
+
+    let forks = expected.fork_many(4);
+
+    for (index, fork) in forks.into_iter().enumerate() {
+        assert_eq!(batch.get_mut(index), Some(&mut RngComponent::from(fork)));
+    }
+
+    assert_eq!(global, expected);
+
+    let firsts: Vec<u32> = batch.into_iter().map(|mut rng| rng.u32(..)).collect();
+
+    assert_eq!(firsts, [426628945, 1499961543, 3085048041, 3126746767]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn batch_results_do_not_depend_on_task_order() {
+    let generate = |rng: &mut RngComponent| (0..16).map(|_| rng.u8(..)).collect::<Vec<u8>>();
+
+    let expected: Vec<Vec<u8>> = RngBatch::from_global(&mut GlobalRng::with_seed(5), 8)
+        .into_iter()
+        .map(|mut rng| generate(&mut rng))
+        .collect();
+
+    let pool = ComputeTaskPool::get_or_init(TaskPool::default);
+
+    let chunks: Vec<Vec<u8>> = pool.scope(|scope| {
+        for mut rng in RngBatch::from_global(&mut GlobalRng::with_seed(5), 8) {
+            scope.spawn(async move { generate(&mut rng) });
+        }
+    });
+
+    assert_eq!(chunks, expected);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn empty_batches() {
+    let batch = RngBatch::from_rng(&mut RngComponent::with_seed(5), 0);
+
+    assert!(batch.is_empty());
+    assert_eq!(batch.into_iter().count(), 0);
+}
+
+#[derive(Debug, Component)]
+struct Particle(u32);
+
+fn jitter(mut q_particles: Query<(&mut RngComponent, &mut Particle)>) {
+    par_for_each_rng(&mut q_particles, 3, |mut rng, mut particle| {
+        particle.0 = rng.u32(..);
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn par_for_each_rng_draws_from_each_entity() {
+    ComputeTaskPool::get_or_init(TaskPool::default);
+
+    let mut app = App::new();
+
+    app.add_systems(Update, jitter);
+
+    let particles: Vec<Entity> = (0..10)
+        .map(|seed| {
+            app.world_mut()
+                .spawn((RngComponent::with_seed(seed), Particle(0)))
+                .id()
+        })
+        .collect();
+
+    app.update();
+
+    for (seed, entity) in (0..10).zip(particles) {
+        let world = app.world();
+
+        assert_eq!(
+            world.get::<Particle>(entity).unwrap().0,
+            RngComponent::with_seed(seed).u32(..)
+        );
+
+        let mut expected = RngComponent::with_seed(seed);
+        expected.u32(..);
+
+        assert_eq!(world.get::<RngComponent>(entity), Some(&expected));
+    }
+}
@@ -0,0 +1,98 @@
+#![cfg(all(feature = "wyrand", feature = "bevy_math"))]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// A 10x10 grid of spawn markers, one unit apart.
+fn markers() -> Vec<Vec3> {
+    (0..100)
+        .map(|i| Vec3::new((i % 10) as f32, 0.0, (i / 10) as f32))
+        .collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn picks_are_reproducible() {
+    let markers = markers();
+
+    let picks_a = RngComponent::with_seed(7).pick_separated(&markers, 5, 3.0, &[]);
+    let picks_b = RngComponent::with_seed(7).pick_separated(&markers, 5, 3.0, &[]);
+
+    assert_eq!(picks_a, picks_b);
+    assert_eq!(picks_a, vec![93, 56, 98, 4, 21]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn picks_respect_separation_and_exclusions() {
+    let markers = markers();
+    let player = (Vec3::new(4.5, 0.0, 4.5), 3.0);
+
+    for seed in 0..50 {
+        let picks = RngComponent::with_seed(seed).pick_separated(&markers, 6, 2.5, &[player]);
+
+        assert!(picks.len() <= 6);
+
+        for (i, &a) in picks.iter().enumerate() {
+            assert!(markers[a].distance(player.0) >= player.1);
+
+            for &b in &picks[i + 1..] {
+                assert_ne!(a, b);
+                assert!(markers[a].distance(markers[b]) >= 2.5);
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn unsatisfiable_constraints_return_fewer_picks() {
+    let markers = markers();
+    let mut rng = RngComponent::with_seed(7);
+
+    // Only one marker can ever be picked when they all must be further apart than the grid.
+    assert_eq!(rng.pick_separated(&markers, 4, 20.0, &[]).len(), 1);
+
+    // All markers are excluded.
+    let everything = (Vec3::new(4.5, 0.0, 4.5), 10.0);
+    assert!(rng
+        .pick_separated(&markers, 4, 1.0, &[everything])
+        .is_empty());
+
+    // Asking for more than there are candidates.
+    assert_eq!(rng.pick_separated(&markers[..3], 10, 0.0, &[]).len(), 3);
+    assert!(rng.pick_separated(&[], 3, 1.0, &[]).is_empty());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn huge_coordinates_never_panic() {
+    let far = [Vec3::splat(3e9), Vec3::splat(3e9 + 10.0)];
+
+    assert_eq!(
+        RngComponent::with_seed(7)
+            .pick_separated(&far, 2, 1.0, &[])
+            .len(),
+        1
+    );
+
+    let extremes = [
+        Vec3::splat(f32::MAX),
+        Vec3::splat(f32::MIN),
+        Vec3::new(f32::MAX, f32::MIN, 0.0),
+        Vec3::ZERO,
+    ];
+
+    assert_eq!(
+        RngComponent::with_seed(7)
+            .pick_separated(&extremes, 4, 1e-3, &[])
+            .len(),
+        4
+    );
+}
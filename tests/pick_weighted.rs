@@ -0,0 +1,131 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use bevy_turborand::prelude::*;
+#[cfg(feature = "testing")]
+use bevy_turborand::stats::expect_frequency;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Component)]
+struct Aggro(f64);
+
+#[derive(Component)]
+struct Flying;
+
+/// Picks `n` times from the enemies of `world`, with a fresh RNG seeded with 5.
+fn picks(world: &mut World, n: usize) -> Vec<Option<Entity>> {
+    world
+        .run_system_once(move |q_enemies: Query<(Entity, &Aggro)>| {
+            let mut rng = RngComponent::with_seed(5);
+
+            (0..n)
+                .map(|_| pick_weighted_entity(&q_enemies, &mut rng, |aggro| aggro.0))
+                .collect::<Vec<_>>()
+        })
+        .unwrap()
+}
+
+/// Spawns the same enemies, then splits them into archetypes in an order depending on
+/// `reversed`, so that the query iterates them in a different order.
+fn world_with_enemies(reversed: bool) -> World {
+    let mut world = World::new();
+
+    let enemies: Vec<Entity> = (1..=6)
+        .map(|aggro| world.spawn(Aggro(f64::from(aggro))).id())
+        .collect();
+
+    let mut order = enemies.clone();
+
+    if reversed {
+        order.reverse();
+    }
+
+    for entity in order.into_iter().step_by(2) {
+        world.entity_mut(entity).insert(Flying);
+    }
+
+    world
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn picks_are_independent_of_the_iteration_order() {
+    let mut world = world_with_enemies(false);
+    let mut reversed = world_with_enemies(true);
+
+    let iterated = |world: &mut World| {
+        world
+            .query::<(Entity, &Aggro)>()
+            .iter(world)
+            .map(|(entity, _)| entity)
+            .collect::<Vec<_>>()
+    };
+
+    assert_ne!(iterated(&mut world), iterated(&mut reversed));
+    assert_eq!(picks(&mut world, 20), picks(&mut reversed, 20));
+}
+
+#[cfg(feature = "testing")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn picks_follow_the_weights() {
+    let mut world = World::new();
+
+    let low = world.spawn(Aggro(1.0)).id();
+    let high = world.spawn(Aggro(3.0)).id();
+
+    // Never picked, as picking them would be an unexpected outcome.
+    world.spawn(Aggro(0.0));
+    world.spawn(Aggro(-2.0));
+    world.spawn(Aggro(f64::NAN));
+
+    world
+        .run_system_once(move |q_enemies: Query<(Entity, &Aggro)>| {
+            expect_frequency(
+                |rng| pick_weighted_entity(&q_enemies, rng, |aggro| aggro.0),
+                40_000,
+                &[(Some(high), 0.75), (Some(low), 0.25)],
+                0.01,
+            );
+        })
+        .unwrap();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn no_weight_picks_nothing() {
+    let mut world = World::new();
+
+    assert_eq!(picks(&mut world, 1), [None]);
+
+    world.spawn(Aggro(0.0));
+    world.spawn(Aggro(-1.0));
+
+    world
+        .run_system_once(|q_enemies: Query<(Entity, &Aggro)>| {
+            let mut rng = RngComponent::with_seed(5);
+
+            assert_eq!(
+                pick_weighted_entity(&q_enemies, &mut rng, |aggro| aggro.0),
+                None
+            );
+            assert_eq!(rng, RngComponent::with_seed(5));
+        })
+        .unwrap();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn single_weighted_entities_are_always_picked() {
+    let mut world = World::new();
+
+    world.spawn(Aggro(0.0));
+    let only = world.spawn(Aggro(0.5)).id();
+
+    assert_eq!(picks(&mut world, 10), vec![Some(only); 10]);
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn plugin_preserves_manually_inserted_global() {
+    let mut app = App::new();
+
+    app.insert_resource(GlobalRng::with_seed(5))
+        .add_plugins(RngPlugin::new().with_rng_seed(12345));
+
+    let mut expected = GlobalRng::with_seed(5);
+    let mut global = app.world_mut().resource_mut::<GlobalRng>();
+
+    assert_eq!(global.u64(..), expected.u64(..));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn plugin_without_resources_inserts_none() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(12345).without_resources());
+
+    assert!(!app.world().contains_resource::<GlobalRng>());
+
+    #[cfg(feature = "chacha")]
+    assert!(!app.world().contains_resource::<GlobalChaChaRng>());
+
+    #[cfg(feature = "serialize")]
+    {
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+
+        assert!(registry.get(std::any::TypeId::of::<GlobalRng>()).is_some());
+        assert!(registry
+            .get(std::any::TypeId::of::<RngComponent>())
+            .is_some());
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn plugin_without_resources_runs_with_manual_global() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().without_resources())
+        .insert_resource(GlobalRng::with_seed(5));
+
+    #[cfg(feature = "chacha")]
+    app.insert_resource(GlobalChaChaRng::with_seed([5; 40]));
+
+    let entity = app
+        .world_mut()
+        .spawn((RngComponent::with_seed(1), ForkOnSpawn))
+        .id();
+
+    app.update();
+
+    let mut expected = RngComponent::from(&mut GlobalRng::with_seed(5));
+    let mut forked = app.world_mut().get_mut::<RngComponent>(entity).unwrap();
+
+    assert_eq!(forked.u64(..), expected.u64(..));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn plugin_registers_reflection_data() {
+    use bevy::reflect::{ReflectDeserialize, ReflectSerialize};
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5));
+
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+
+    assert!(registry
+        .get_type_data::<ReflectResource>(std::any::TypeId::of::<GlobalRng>())
+        .is_some());
+    assert!(registry
+        .get_type_data::<ReflectComponent>(std::any::TypeId::of::<RngComponent>())
+        .is_some());
+    assert!(registry
+        .get_type_data::<ReflectComponent>(std::any::TypeId::of::<RngStreams>())
+        .is_some());
+    assert!(registry
+        .get_type_data::<ReflectComponent>(std::any::TypeId::of::<ForkOnSpawn>())
+        .is_some());
+    #[cfg(feature = "bevy_math")]
+    assert!(registry.get(std::any::TypeId::of::<RandomWalk>()).is_some());
+
+    #[cfg(feature = "chacha")]
+    {
+        assert!(registry
+            .get_type_data::<ReflectResource>(std::any::TypeId::of::<GlobalChaChaRng>())
+            .is_some());
+        assert!(registry
+            .get_type_data::<ReflectComponent>(std::any::TypeId::of::<ChaChaRngComponent>())
+            .is_some());
+    }
+
+    let serializable = registry
+        .get_type_data::<ReflectSerialize>(std::any::TypeId::of::<GlobalRng>())
+        .is_some();
+    let deserializable = registry
+        .get_type_data::<ReflectDeserialize>(std::any::TypeId::of::<RngComponent>())
+        .is_some();
+
+    assert_eq!(serializable, cfg!(feature = "serialize"));
+    assert_eq!(deserializable, cfg!(feature = "serialize"));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn global_is_reachable_through_reflection() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5));
+
+    let registry = app.world().resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    let reflect_resource = registry
+        .get_type_data::<ReflectResource>(std::any::TypeId::of::<GlobalRng>())
+        .unwrap();
+
+    let reflected = reflect_resource.reflect(app.world()).unwrap();
+
+    assert_eq!(
+        reflected.downcast_ref::<GlobalRng>(),
+        Some(&GlobalRng::with_seed(5))
+    );
+}
@@ -0,0 +1,151 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+const STARTING_BIOME: SubsystemKey = SubsystemKey::new("starting_biome");
+const FIRST_BOSS: SubsystemKey = SubsystemKey::new("first_boss");
+const WEATHER: SubsystemKey = SubsystemKey::new("weather");
+
+const BIOMES: [&str; 4] = ["forest", "desert", "tundra", "swamp"];
+const BOSSES: [&str; 3] = ["dragon", "lich", "hydra"];
+
+fn starting_biome(rng: &mut Rng) -> String {
+    BIOMES[rng.usize(..BIOMES.len())].to_owned()
+}
+
+fn first_boss(rng: &mut Rng) -> String {
+    // The game rolls the boss's level before picking it.
+    rng.u32(1..=10);
+
+    BOSSES[rng.usize(..BOSSES.len())].to_owned()
+}
+
+fn weather(rng: &mut Rng) -> String {
+    if rng.bool() { "rain" } else { "sun" }.to_owned()
+}
+
+fn menu_preview() -> SeedPreview<String> {
+    SeedPreview::new()
+        .with_extractor(STARTING_BIOME, starting_biome)
+        .with_extractor(FIRST_BOSS, first_boss)
+}
+
+#[derive(Resource)]
+struct MasterSeed(u64);
+
+#[derive(Resource, Default)]
+struct Generated {
+    biome: String,
+    boss: String,
+}
+
+/// Generates the world the way the game does, with unrelated random calls interleaved.
+fn generate_world(
+    seed: Res<MasterSeed>,
+    mut global: ResMut<GlobalRng>,
+    mut generated: ResMut<Generated>,
+) {
+    global.u64(..);
+
+    let mut weather_rng = WEATHER.rng_component(seed.0);
+    weather_rng.bool();
+
+    let mut boss_rng = FIRST_BOSS.rng_component(seed.0);
+    generated.boss = first_boss(boss_rng.get_mut());
+
+    global.f32();
+
+    let mut biome_rng = STARTING_BIOME.rng_component(seed.0);
+    generated.biome = starting_biome(biome_rng.get_mut());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn preview_matches_in_game_generation() {
+    let preview = menu_preview();
+
+    for seed in 0..50 {
+        let mut app = App::new();
+
+        app.add_plugins(RngPlugin::new().with_rng_seed(seed ^ 0xdead_beef))
+            .insert_resource(MasterSeed(seed))
+            .init_resource::<Generated>()
+            .add_systems(Startup, generate_world);
+
+        app.update();
+
+        let result = preview.preview(seed);
+        let generated = app.world().resource::<Generated>();
+
+        assert_eq!(result.get(STARTING_BIOME), Some(&generated.biome));
+        assert_eq!(result.get(FIRST_BOSS), Some(&generated.boss));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn preview_is_pinned() {
+    let result = menu_preview().preview(12345);
+
+    assert_eq!(result.seed, 12345);
+    assert_eq!(
+        result.items,
+        [
+            ("starting_biome".to_owned(), "desert".to_owned()),
+            ("first_boss".to_owned(), "lich".to_owned())
+        ]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn extractors_do_not_perturb_each_other() {
+    let extended = SeedPreview::new()
+        .with_extractor(WEATHER, weather)
+        .with_extractor(FIRST_BOSS, first_boss)
+        .with_extractor(STARTING_BIOME, starting_biome);
+
+    assert_eq!(
+        extended.keys().collect::<Vec<_>>(),
+        [WEATHER, FIRST_BOSS, STARTING_BIOME]
+    );
+
+    for seed in 0..50 {
+        let expected = menu_preview().preview(seed);
+        let result = extended.preview(seed);
+
+        assert_eq!(result.get(STARTING_BIOME), expected.get(STARTING_BIOME));
+        assert_eq!(result.get(FIRST_BOSS), expected.get(FIRST_BOSS));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn keys_derive_distinct_streams() {
+    assert_eq!(STARTING_BIOME.name(), "starting_biome");
+    assert_eq!(
+        STARTING_BIOME.seed_for(5),
+        SubsystemKey::new("starting_biome").seed_for(5)
+    );
+    assert_ne!(STARTING_BIOME.seed_for(5), FIRST_BOSS.seed_for(5));
+    assert_ne!(STARTING_BIOME.seed_for(5), STARTING_BIOME.seed_for(6));
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn preview_results_are_serializable() {
+    let result = menu_preview().preview(12345);
+
+    let serialized = ron::to_string(&result).unwrap();
+    let restored: SeedPreviewResult<String> = ron::from_str(&serialized).unwrap();
+
+    assert_eq!(restored, result);
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Component)]
+struct Enemy;
+
+#[derive(Component)]
+struct Armored;
+
+#[derive(Component)]
+struct Flying;
+
+#[derive(Resource, Default)]
+struct Picks {
+    one: Option<Entity>,
+    many: Vec<Entity>,
+    unstable: Option<Entity>,
+}
+
+fn pick(q_enemies: Query<Entity, With<Enemy>>, mut picks: ResMut<Picks>) {
+    let mut rng = RngComponent::with_seed(5);
+
+    picks.one = q_enemies.pick_random(&mut rng);
+    picks.many = q_enemies.pick_random_n(&mut rng, 4);
+    picks.unstable = q_enemies.pick_random_unstable(&mut rng);
+}
+
+/// Spawns the same eight enemies, then splits them into archetypes in an order depending on
+/// `reversed`, so that the archetypes and their tables are created and filled differently.
+fn app_with_enemies(reversed: bool) -> (App, Vec<Entity>) {
+    let mut app = App::new();
+
+    app.init_resource::<Picks>().add_systems(Update, pick);
+
+    let enemies: Vec<Entity> = (0..8).map(|_| app.world_mut().spawn(Enemy).id()).collect();
+
+    let mut order = enemies.clone();
+
+    if reversed {
+        order.reverse();
+    }
+
+    for (index, &entity) in order.iter().enumerate() {
+        let mut entity = app.world_mut().entity_mut(entity);
+
+        match (index % 3, reversed) {
+            (0, false) | (1, true) => entity.insert(Armored),
+            (1, false) | (0, true) => entity.insert(Flying),
+            _ => entity.insert((Flying, Armored)),
+        };
+    }
+
+    (app, enemies)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn picks_ignore_archetype_order() {
+    let (mut app, enemies) = app_with_enemies(false);
+    let (mut reversed_app, reversed_enemies) = app_with_enemies(true);
+
+    assert_eq!(enemies, reversed_enemies);
+
+    app.update();
+    reversed_app.update();
+
+    let picks = app.world().resource::<Picks>();
+    let reversed_picks = reversed_app.world().resource::<Picks>();
+
+    assert_eq!(picks.one, reversed_picks.one);
+    assert_eq!(picks.many, reversed_picks.many);
+    assert_eq!(picks.one, Some(enemies[2]));
+    assert_eq!(picks.many, [enemies[5], enemies[7], enemies[0], enemies[4]]);
+    assert!(enemies.contains(&picks.unstable.unwrap()));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn picks_many_distinct_entities() {
+    let mut world = World::new();
+
+    let enemies: Vec<Entity> = (0..5).map(|_| world.spawn(Enemy).id()).collect();
+
+    world
+        .run_system_once(move |q_enemies: Query<Entity, With<Enemy>>| {
+            let mut rng = RngComponent::with_seed(1);
+
+            let mut all = q_enemies.pick_random_n(&mut rng, 10);
+
+            assert_eq!(all.len(), 5);
+
+            all.sort();
+
+            assert_eq!(all, enemies);
+            assert_eq!(q_enemies.pick_random_n(&mut rng, 0), []);
+        })
+        .unwrap();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn empty_queries_pick_nothing() {
+    let mut world = World::new();
+
+    world.spawn(Armored);
+
+    world
+        .run_system_once(|q_enemies: Query<(Entity, &Armored), With<Enemy>>| {
+            let mut rng = RngComponent::with_seed(1);
+            let untouched = rng.clone();
+
+            assert_eq!(q_enemies.pick_random(&mut rng), None);
+            assert_eq!(q_enemies.pick_random_n(&mut rng, 3), []);
+            assert_eq!(q_enemies.pick_random_unstable(&mut rng), None);
+            assert_eq!(rng, untouched);
+        })
+        .unwrap();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn tuple_queries_pick_the_same_as_entity_queries() {
+    let mut world = World::new();
+
+    for index in 0..6 {
+        let mut entity = world.spawn(Enemy);
+
+        if index % 2 == 0 {
+            entity.insert(Armored);
+        }
+    }
+
+    world
+        .run_system_once(
+            |q_entities: Query<Entity, With<Enemy>>, q_tuples: Query<(Entity, &Enemy)>| {
+                let mut rng = RngComponent::with_seed(9);
+                let mut tuple_rng = rng.clone();
+
+                assert_eq!(
+                    q_entities.pick_random_n(&mut rng, 3),
+                    q_tuples.pick_random_n(&mut tuple_rng, 3)
+                );
+            },
+        )
+        .unwrap();
+}
@@ -0,0 +1,187 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn daily_quests() -> Vec<QuestEntry<&'static str>> {
+    vec![
+        QuestEntry::new("gather_wood", 2.0),
+        QuestEntry::new("build_hut", 1.0).with_requirement("gather_wood"),
+        QuestEntry::new("furnish_hut", 1.0).with_requirement("build_hut"),
+        QuestEntry::new("hunt", 1.0).with_exclusion("befriend_wolves"),
+        QuestEntry::new("befriend_wolves", 1.0),
+        QuestEntry::new("fish", 1.0),
+        QuestEntry::new("smoke_fish", 0.5)
+            .with_requirement("fish")
+            .with_requirement("gather_wood"),
+    ]
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rolls_are_deterministic() {
+    let roller = QuestRoller::new(daily_quests()).unwrap();
+    let mut rng = RngComponent::with_seed(5);
+
+    let first = roller.roll(4, &mut rng);
+    let second = roller.roll(4, &mut rng);
+
+    assert_eq!(
+        first.entries,
+        ["gather_wood", "build_hut", "fish", "smoke_fish"]
+    );
+    assert_eq!(second.entries, ["hunt", "gather_wood", "build_hut", "fish"]);
+    assert_eq!(first.shortfall, None);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rolls_respect_requirements_and_exclusions() {
+    let entries = daily_quests();
+    let roller = QuestRoller::new(entries.clone()).unwrap();
+    let mut rng = RngComponent::with_seed(7);
+
+    for count in 0..=entries.len() {
+        for _ in 0..200 {
+            let roll = roller.roll(count, &mut rng);
+
+            assert!(roll.entries.len() <= count);
+            assert_eq!(roll.shortfall.is_some(), roll.entries.len() < count);
+
+            for (position, id) in roll.entries.iter().enumerate() {
+                let entry = entries.iter().find(|entry| entry.id == *id).unwrap();
+
+                assert_eq!(
+                    roll.entries.iter().filter(|other| *other == id).count(),
+                    1,
+                    "{id} rolled twice"
+                );
+
+                for required in &entry.requires {
+                    assert!(
+                        roll.entries[..position].contains(required),
+                        "{id} rolled before its requirement {required}"
+                    );
+                }
+
+                for excluded in &entry.excludes {
+                    assert!(
+                        !roll.entries.contains(excluded),
+                        "{id} rolled alongside {excluded}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn impossible_rolls_report_a_shortfall() {
+    let mut rng = RngComponent::with_seed(5);
+
+    let exhausted = QuestRoller::new(vec![
+        QuestEntry::new("hunt", 1.0).with_exclusion("fish"),
+        QuestEntry::new("fish", 1.0),
+    ])
+    .unwrap()
+    .roll(2, &mut rng);
+
+    assert_eq!(exhausted.entries.len(), 1);
+    assert_eq!(exhausted.shortfall, Some(QuestShortfall::PoolExhausted));
+
+    let too_deep = QuestRoller::new(vec![
+        QuestEntry::new("gather_wood", 0.0),
+        QuestEntry::new("build_hut", 0.0).with_requirement("gather_wood"),
+        QuestEntry::new("furnish_hut", 1.0).with_requirement("build_hut"),
+    ])
+    .unwrap()
+    .roll(2, &mut rng);
+
+    assert!(too_deep.entries.is_empty());
+    assert_eq!(
+        too_deep.shortfall,
+        Some(QuestShortfall::RequirementsDontFit)
+    );
+
+    let conflicting = QuestRoller::new(vec![
+        QuestEntry::new("hunt", 0.0).with_exclusion("fish"),
+        QuestEntry::new("fish", 0.0),
+        QuestEntry::new("feast", 1.0)
+            .with_requirement("hunt")
+            .with_requirement("fish"),
+    ])
+    .unwrap()
+    .roll(3, &mut rng);
+
+    assert!(conflicting.entries.is_empty());
+    assert_eq!(
+        conflicting.shortfall,
+        Some(QuestShortfall::RequirementsDontFit)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn invalid_graphs_are_rejected() {
+    assert_eq!(
+        QuestRoller::new(vec![
+            QuestEntry::new("hunt", 1.0),
+            QuestEntry::new("hunt", 2.0)
+        ]),
+        Err(QuestGraphError::DuplicateId("hunt"))
+    );
+
+    assert_eq!(
+        QuestRoller::new(vec![QuestEntry::new("hunt", 1.0).with_exclusion("fish")]),
+        Err(QuestGraphError::DanglingReference {
+            entry: "hunt",
+            missing: "fish"
+        })
+    );
+
+    assert_eq!(
+        QuestRoller::new(vec![
+            QuestEntry::new("a", 1.0).with_requirement("b"),
+            QuestEntry::new("b", 1.0).with_requirement("c"),
+            QuestEntry::new("c", 1.0).with_requirement("a"),
+        ]),
+        Err(QuestGraphError::Cycle(vec!["a", "b", "c", "a"]))
+    );
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rollers_can_be_loaded_from_ron() {
+    let roller: QuestRoller<String> = ron::from_str(
+        r#"[
+            (id: "gather_wood", weight: 2.0),
+            (id: "build_hut", weight: 1.0, requires: ["gather_wood"]),
+            (id: "hunt", weight: 1.0, excludes: ["fish"]),
+            (id: "fish", weight: 1.0),
+        ]"#,
+    )
+    .unwrap();
+
+    assert_eq!(roller.entries().len(), 4);
+    assert_eq!(roller.entries()[1].requires, ["gather_wood"]);
+
+    let serialized = ron::to_string(&roller).unwrap();
+
+    assert_eq!(
+        ron::from_str::<QuestRoller<String>>(&serialized).unwrap(),
+        roller
+    );
+
+    let dangling = ron::from_str::<QuestRoller<String>>(
+        r#"[(id: "build_hut", weight: 1.0, requires: ["gather_wood"])]"#,
+    );
+
+    assert!(dangling.is_err());
+}
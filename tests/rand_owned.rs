@@ -0,0 +1,83 @@
+#![cfg(all(feature = "rand", feature = "wyrand"))]
+
+use bevy_turborand::prelude::*;
+use rand::{seq::SliceRandom, RngCore, SeedableRng};
+use rand_distr::{Distribution, Normal};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn fork_as_rand_is_deterministic() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut expected = RngComponent::with_seed(5);
+
+    let mut owned = rng.fork_as_rand();
+
+    assert_eq!(owned, RandOwned::from(expected.fork()));
+    assert_eq!(rng, expected, "only the fork advances the original");
+
+    let values: Vec<u64> = (0..3).map(|_| owned.next_u64()).collect();
+
+    assert_eq!(
+        values,
+        [
+            16609780926639888209,
+            17245170259017488294,
+            7969215459965656631
+        ]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn shuffles_with_slice_random() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut owned = rng.fork_as_rand();
+
+    let mut deck: Vec<u32> = (0..8).collect();
+
+    deck.shuffle(&mut owned);
+
+    assert_eq!(deck, [4, 6, 2, 1, 5, 7, 3, 0]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn samples_from_rand_distr() {
+    let mut rng = GlobalRng::with_seed(5);
+    let normal = Normal::new(10.0, 2.0).unwrap();
+
+    let samples: Vec<f64> = normal.sample_iter(rng.fork_as_rand()).take(3).collect();
+    let repeated: Vec<f64> = normal
+        .sample_iter(GlobalRng::with_seed(5).fork_as_rand())
+        .take(3)
+        .collect();
+
+    assert_eq!(samples, repeated);
+    assert_eq!(
+        samples,
+        [13.00822930813737, 12.22649351549255, 9.4251788541273]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seedable_rng_matches_with_seed() {
+    let mut from_seed = RandOwned::<Rng>::from_seed(5u64.to_le_bytes());
+    let mut from_u64 = RandOwned::<Rng>::seed_from_u64(5);
+    let rng = Rng::with_seed(5);
+
+    for _ in 0..10 {
+        let expected = rng.gen_u64();
+
+        assert_eq!(from_seed.next_u64(), expected);
+        assert_eq!(from_u64.next_u64(), expected);
+    }
+
+    assert_eq!(from_seed.into_inner(), rng);
+}
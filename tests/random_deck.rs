@@ -0,0 +1,110 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn every_item_is_drawn_once_per_cycle() {
+    let mut rng = RngComponent::with_seed(7);
+    let mut deck = RandomDeck::new((0..10).collect(), &mut rng);
+
+    assert_eq!(deck.remaining(), 10);
+
+    let mut drawn: Vec<u32> = std::iter::from_fn(|| deck.draw(&mut rng).copied()).collect();
+
+    drawn.sort_unstable();
+
+    assert_eq!(drawn, (0..10).collect::<Vec<_>>());
+    assert_eq!(deck.remaining(), 0);
+    assert_eq!(deck.draw(&mut rng), None);
+    assert_eq!(deck.peek(), None);
+
+    deck.reshuffle(&mut rng);
+
+    assert_eq!(deck.remaining(), 10);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn draw_order_is_deterministic_across_reshuffles() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut deck =
+        RandomDeck::new(vec!["A", "B", "C", "D", "E"], &mut rng).with_auto_reshuffle(true);
+
+    let drawn: Vec<&str> = (0..10).map(|_| *deck.draw(&mut rng).unwrap()).collect();
+
+    assert_eq!(drawn, ["B", "C", "D", "E", "A", "B", "C", "E", "D", "A"]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn peek_matches_the_next_draw() {
+    let mut rng = RngComponent::with_seed(3);
+    let mut deck = RandomDeck::new(vec![1, 2, 3, 4], &mut rng);
+
+    let upcoming: Vec<i32> = deck.remaining_items().copied().collect();
+
+    for expected in upcoming {
+        assert_eq!(deck.peek(), Some(&expected));
+        assert_eq!(deck.draw(&mut rng), Some(&expected));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn empty_decks_never_draw() {
+    let mut rng = RngComponent::with_seed(1);
+    let mut deck = RandomDeck::<u8>::new(Vec::new(), &mut rng).with_auto_reshuffle(true);
+
+    assert!(deck.is_empty());
+    assert_eq!(deck.draw(&mut rng), None);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn saved_decks_resume_mid_cycle() {
+    let mut rng = RngComponent::with_seed(9);
+    let mut deck = RandomDeck::new((0..8).collect::<Vec<u8>>(), &mut rng).with_auto_reshuffle(true);
+
+    deck.draw(&mut rng);
+    deck.draw(&mut rng);
+    deck.draw(&mut rng);
+
+    let saved = ron::to_string(&(&deck, &rng)).unwrap();
+
+    let (mut loaded, mut loaded_rng): (RandomDeck<u8>, RngComponent) =
+        ron::from_str(&saved).unwrap();
+
+    assert_eq!(loaded, deck);
+    assert_eq!(loaded.remaining(), 5);
+
+    let expected: Vec<u8> = (0..12).map(|_| *deck.draw(&mut rng).unwrap()).collect();
+    let resumed: Vec<u8> = (0..12)
+        .map(|_| *loaded.draw(&mut loaded_rng).unwrap())
+        .collect();
+
+    assert_eq!(resumed, expected);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn invalid_remaining_orders_are_rejected() {
+    let out_of_range = "(items:[1,2,3],remaining:[0,3],auto_reshuffle:false)";
+    let repeated = "(items:[1,2,3],remaining:[1,1],auto_reshuffle:false)";
+
+    assert!(ron::from_str::<RandomDeck<u8>>(out_of_range).is_err());
+    assert!(ron::from_str::<RandomDeck<u8>>(repeated).is_err());
+
+    let valid: RandomDeck<u8> =
+        ron::from_str("(items:[1,2,3],remaining:[0,2],auto_reshuffle:false)").unwrap();
+
+    assert_eq!(valid.remaining_items().copied().collect::<Vec<_>>(), [3, 1]);
+}
@@ -0,0 +1,143 @@
+#![cfg(all(feature = "wyrand", feature = "bevy_time"))]
+
+use bevy::{prelude::*, time::TimeUpdateStrategy, utils::Duration};
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+struct Spawns;
+
+#[derive(Resource, Default)]
+struct Fired(Vec<u32>);
+
+#[derive(Resource, Default)]
+struct Frame(u32);
+
+fn count_frame(mut frame: ResMut<Frame>) {
+    frame.0 += 1;
+}
+
+fn record_firing(frame: Res<Frame>, mut fired: ResMut<Fired>) {
+    fired.0.push(frame.0);
+}
+
+fn firing_frames(seed: u64, frames: u32) -> Vec<u32> {
+    firing_frames_with(RngPlugin::new().with_rng_seed(seed), frames)
+}
+
+fn firing_frames_with(plugin: RngPlugin, frames: u32) -> Vec<u32> {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        plugin.with_interval_resource::<Spawns>(
+            Duration::from_millis(500),
+            Duration::from_millis(200),
+        ),
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )))
+    .init_resource::<Fired>()
+    .init_resource::<Frame>()
+    .add_systems(
+        Update,
+        (
+            count_frame,
+            record_firing.run_if(interval_elapsed::<Spawns>),
+        )
+            .chain(),
+    );
+
+    for _ in 0..frames {
+        app.update();
+    }
+
+    app.world_mut().remove_resource::<Fired>().unwrap().0
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn firing_frames_are_deterministic() {
+    let fired = firing_frames(5, 30);
+
+    assert_eq!(fired, firing_frames(5, 30));
+    assert_ne!(fired, firing_frames(6, 30));
+    // The first frame has no delta, after which each of the 300-700ms intervals takes a
+    // whole number of 100ms frames.
+    assert_eq!(fired, [8, 15, 20, 24, 28]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn strict_mode_forks_from_the_strict_global() {
+    let strict = || RngPlugin::new().with_rng_seed(5).with_strict_global();
+
+    let fired = firing_frames_with(strict(), 30);
+
+    assert!(!fired.is_empty());
+    assert_eq!(fired, firing_frames_with(strict(), 30));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn intervals_stay_within_the_jitter() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut interval =
+        RandomInterval::<()>::new(Duration::from_secs(2), Duration::from_millis(500));
+
+    assert_eq!(interval.interval(), Duration::ZERO);
+
+    for _ in 0..1000 {
+        interval.tick(Duration::from_millis(100), &mut rng);
+
+        let current = interval.interval();
+
+        assert!(current >= Duration::from_millis(1500), "{current:?}");
+        assert!(current <= Duration::from_millis(2500), "{current:?}");
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn long_ticks_fire_once_and_drop_the_excess() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut interval = RandomInterval::<()>::new(Duration::from_secs(1), Duration::ZERO);
+
+    // Ten intervals' worth of time fires only once, with nothing carried over.
+    assert!(interval.tick(Duration::from_secs(10), &mut rng));
+    assert!(interval.just_elapsed());
+    assert_eq!(interval.elapsed(), Duration::ZERO);
+    assert_eq!(interval.remaining(), Duration::from_secs(1));
+
+    assert!(!interval.tick(Duration::from_millis(999), &mut rng));
+    assert!(!interval.just_elapsed());
+    assert!(interval.tick(Duration::from_millis(1), &mut rng));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn existing_interval_resources_are_kept() {
+    let mut app = App::new();
+
+    let existing = RandomInterval::<Spawns>::new(Duration::from_secs(9), Duration::ZERO);
+
+    app.insert_resource(existing.clone()).add_plugins(
+        RngPlugin::new()
+            .with_rng_seed(5)
+            .with_interval_resource::<Spawns>(Duration::from_secs(1), Duration::ZERO),
+    );
+
+    assert_eq!(*app.world().resource::<RandomInterval<Spawns>>(), existing);
+}
+
+#[test]
+#[should_panic(expected = "interval base must not be zero nor less than its jitter")]
+fn jitter_must_not_exceed_the_base() {
+    let _ = RngPlugin::new()
+        .with_interval_resource::<Spawns>(Duration::from_secs(1), Duration::from_secs(2));
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Component, PartialEq)]
+struct Strength(u32);
+
+fn nudge(strength: &mut Strength, rng: &mut Rng) {
+    strength.0 += rng.u32(1..=5);
+}
+
+fn nudge_seeded(mut q_strength: Query<(&mut Strength, &mut RngComponent)>) {
+    randomize_field(&mut q_strength, nudge);
+}
+
+fn nudge_keyed(
+    mut q_strength: Query<(Entity, &mut Strength), Without<RngComponent>>,
+    mut global: ResMut<GlobalRng>,
+) {
+    randomize_field_keyed(&mut q_strength, &mut global, nudge);
+}
+
+fn strengths(app: &mut App, entities: &[Entity]) -> Vec<u32> {
+    entities
+        .iter()
+        .map(|&entity| app.world().get::<Strength>(entity).unwrap().0)
+        .collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn randomize_field_with_components() {
+    let mut app = App::new();
+
+    let entities: Vec<Entity> = (0..3)
+        .map(|seed| {
+            app.world_mut()
+                .spawn((Strength(10), RngComponent::with_seed(seed)))
+                .id()
+        })
+        .collect();
+
+    app.add_systems(Update, nudge_seeded);
+
+    app.update();
+    app.update();
+
+    assert_eq!(strengths(&mut app, &entities), vec![16, 16, 18]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn randomize_field_keyed_is_order_independent() {
+    let mut forwards = App::new();
+    let mut backwards = App::new();
+
+    forwards.insert_resource(GlobalRng::with_seed(42));
+    backwards.insert_resource(GlobalRng::with_seed(42));
+
+    let entities: Vec<Entity> = (0..4)
+        .map(|_| forwards.world_mut().spawn(Strength(10)).id())
+        .collect();
+
+    // Reserve the same entity ids, but insert the components in the reverse order.
+    let reserved: Vec<Entity> = (0..4)
+        .map(|_| backwards.world_mut().spawn_empty().id())
+        .collect();
+    for &entity in reserved.iter().rev() {
+        backwards
+            .world_mut()
+            .entity_mut(entity)
+            .insert(Strength(10));
+    }
+
+    assert_eq!(entities, reserved);
+
+    forwards.add_systems(Update, nudge_keyed);
+    backwards.add_systems(Update, nudge_keyed);
+
+    forwards.update();
+    backwards.update();
+
+    let expected = vec![12, 11, 13, 15];
+
+    assert_eq!(strengths(&mut forwards, &entities), expected);
+    assert_eq!(strengths(&mut backwards, &entities), expected);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn randomize_field_command_covers_all_entities() {
+    let mut app = App::new();
+
+    app.insert_resource(GlobalRng::with_seed(7));
+
+    let seeded = app
+        .world_mut()
+        .spawn((Strength(10), RngComponent::with_seed(1)))
+        .id();
+    let unseeded = app.world_mut().spawn(Strength(10)).id();
+
+    app.add_systems(Update, |mut commands: Commands| {
+        commands.queue(RandomizeField::new(nudge));
+    });
+
+    app.update();
+
+    assert_eq!(strengths(&mut app, &[seeded, unseeded]), vec![11, 11]);
+
+    // The seeded entity draws from its own component, same as `randomize_field` does.
+    let mut reference = Strength(10);
+    nudge(&mut reference, RngComponent::with_seed(1).get_mut());
+
+    assert_eq!(app.world().get::<Strength>(seeded), Some(&reference));
+}
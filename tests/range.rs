@@ -0,0 +1,129 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+use std::{fmt::Debug, ops::RangeBounds};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// Samples `bounds` repeatedly, asserting that all values fall within it.
+fn sample_all<T, B>(bounds: B) -> Vec<T>
+where
+    T: SampleRange + PartialOrd + Debug,
+    B: RangeBounds<T> + Clone + Debug,
+{
+    let mut rng = RngComponent::with_seed(5);
+
+    let values: Vec<T> = (0..500).map(|_| rng.range(bounds.clone())).collect();
+
+    for value in &values {
+        assert!(bounds.contains(value), "{value:?} not in {bounds:?}");
+    }
+
+    values
+}
+
+macro_rules! test_integer_ranges {
+    ($($name:ident: $ty:ident),* $(,)?) => {
+        $(
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn $name() {
+                let inclusive = sample_all::<$ty, _>(3..=5);
+                let exclusive = sample_all::<$ty, _>(3..5);
+
+                assert!(inclusive.contains(&5));
+                assert!(!exclusive.contains(&5));
+                assert!(exclusive.contains(&4));
+
+                // Dispatches to the typed method, with the same output.
+                let mut rng = RngComponent::with_seed(5);
+                let mut expected = RngComponent::with_seed(5);
+
+                assert_eq!(rng.range::<$ty>(..), expected.$ty(..));
+                assert_eq!(rng.range::<$ty>(1..=9), expected.$ty(1..=9));
+            }
+        )*
+    };
+}
+
+test_integer_ranges!(
+    range_u8: u8,
+    range_u16: u16,
+    range_u32: u32,
+    range_u64: u64,
+    range_u128: u128,
+    range_usize: usize,
+    range_i8: i8,
+    range_i16: i16,
+    range_i32: i32,
+    range_i64: i64,
+    range_i128: i128,
+    range_isize: isize,
+);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn range_signed_integers() {
+    let values = sample_all::<i32, _>(-5..=-3);
+
+    assert!(values.contains(&-5));
+    assert!(values.contains(&-3));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn range_char() {
+    let inclusive = sample_all::<char, _>('a'..='c');
+    let exclusive = sample_all::<char, _>('a'..'c');
+
+    assert!(inclusive.contains(&'c'));
+    assert!(!exclusive.contains(&'c'));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn range_f32() {
+    sample_all::<f32, _>(-2.5..=2.5);
+    sample_all::<f32, _>(-2.5..2.5);
+
+    // A degenerate inclusive range always returns its bound.
+    assert_eq!(sample_all::<f32, _>(1.5..=1.5), vec![1.5; 500]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn range_f64() {
+    sample_all::<f64, _>(-2.5..=2.5);
+    sample_all::<f64, _>(1e-3..1e3);
+
+    assert_eq!(sample_all::<f64, _>(1.5..=1.5), vec![1.5; 500]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[cfg_attr(
+    debug_assertions,
+    should_panic(expected = "RngComponent::range called with empty range 1.0..1.0")
+)]
+#[cfg_attr(
+    not(debug_assertions),
+    should_panic(expected = "Range should not be zero sized or invalid")
+)]
+fn range_empty_float_panics() {
+    let mut rng = RngComponent::with_seed(5);
+
+    rng.range(1.0f32..1.0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "float ranges must have an upper bound")]
+fn range_unbounded_float_panics() {
+    let mut rng = RngComponent::with_seed(5);
+
+    rng.range(1.0f64..);
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "RngComponent::u32 called with empty range 5..5")]
+fn empty_integer_ranges_name_the_wrapper_and_method() {
+    let mut rng = RngComponent::with_seed(1);
+
+    rng.u32(5..5);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "GlobalRng::i8 called with empty range 10..=5")]
+#[allow(clippy::reversed_empty_ranges)]
+fn inverted_inclusive_ranges_name_the_wrapper_and_method() {
+    let mut rng = GlobalRng::with_seed(1);
+
+    rng.i8(10..=5);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "RngComponent::char called with empty range 'z'..'a'")]
+#[allow(clippy::reversed_empty_ranges)]
+fn empty_char_ranges_name_the_wrapper_and_method() {
+    let mut rng = RngComponent::with_seed(1);
+
+    rng.char('z'..'a');
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "RngComponent::index called with empty range 3..3")]
+fn empty_index_bounds_name_the_wrapper_and_method() {
+    let mut rng = RngComponent::with_seed(1);
+
+    rng.index(3..3);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "RngComponent::range called with empty range 2.5..=1.0")]
+#[allow(clippy::reversed_empty_ranges)]
+fn empty_float_ranges_name_the_wrapper_and_method() {
+    let mut rng = RngComponent::with_seed(1);
+
+    rng.range(2.5f32..=1.0);
+}
+
+#[cfg(all(debug_assertions, feature = "chacha"))]
+#[test]
+#[should_panic(expected = "GlobalChaChaRng::u64 called with empty range 7..3")]
+#[allow(clippy::reversed_empty_ranges)]
+fn chacha_wrappers_name_themselves() {
+    let mut rng = GlobalChaChaRng::with_seed([1; 40]);
+
+    rng.u64(7..3);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn valid_ranges_match_the_source() {
+    let mut rng = RngComponent::with_seed(5);
+    let source = Rng::with_seed(5);
+
+    assert_eq!(rng.u32(5..=5), source.u32(5..=5));
+    assert_eq!(rng.u32(..), source.u32(..));
+    assert_eq!(rng.i16(-3..4), source.i16(-3..4));
+    assert_eq!(rng.char('a'..='c'), source.char('a'..='c'));
+    assert_eq!(rng.index(..10), source.index(..10));
+}
+
+#[cfg(not(debug_assertions))]
+#[test]
+#[should_panic]
+fn release_builds_leave_the_check_to_the_source() {
+    let mut rng = RngComponent::with_seed(1);
+
+    rng.u32(5..5);
+}
@@ -0,0 +1,145 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn app() -> App {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5));
+
+    app
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn components_are_reseeded_through_the_registry() {
+    let mut app = app();
+
+    let entity = app.world_mut().spawn(RngComponent::new()).id();
+    let registry = app.world().resource::<AppTypeRegistry>().clone();
+
+    let mut entity_mut = app.world_mut().entity_mut(entity);
+
+    assert_eq!(
+        reseed_reflected(&mut entity_mut, &registry.read(), 42),
+        Ok(())
+    );
+    assert_eq!(
+        *entity_mut.get::<RngComponent>().unwrap(),
+        RngComponent::with_seed(42)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn resources_are_reseeded_through_type_data_alone() {
+    let mut app = app();
+
+    let registry = app.world().resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    // Find every reseedable resource by its type data, as an editor would.
+    let mut reseeded = 0;
+
+    for (registration, reseed) in registry.iter_with_data::<ReflectRngReseed>() {
+        let Some(resource) = registration.data::<ReflectResource>() else {
+            continue;
+        };
+
+        if let Some(mut value) = resource.reflect_mut(app.world_mut()) {
+            reseed.reseed(&mut *value, 42);
+            reseeded += 1;
+        }
+    }
+
+    assert_eq!(reseeded, 1);
+    assert_eq!(
+        *app.world().resource::<GlobalRng>(),
+        GlobalRng::with_seed(42)
+    );
+    assert_eq!(app.world().resource::<GlobalRng>().initial_seed(), Some(42));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reseeding_flags_the_component_as_changed() {
+    let mut app = app();
+
+    let entity = app.world_mut().spawn(RngComponent::with_seed(1)).id();
+
+    app.update();
+
+    let registry = app.world().resource::<AppTypeRegistry>().clone();
+
+    reseed_reflected(
+        &mut app.world_mut().entity_mut(entity),
+        &registry.read(),
+        42,
+    )
+    .unwrap();
+
+    let mut q_changed = app
+        .world_mut()
+        .query_filtered::<Entity, Changed<RngComponent>>();
+
+    assert_eq!(q_changed.iter(app.world()).collect::<Vec<_>>(), [entity]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn entities_without_rngs_are_reported() {
+    let mut app = app();
+
+    let entity = app.world_mut().spawn(Name::new("Rock")).id();
+    let registry = app.world().resource::<AppTypeRegistry>().clone();
+
+    assert_eq!(
+        reseed_reflected(
+            &mut app.world_mut().entity_mut(entity),
+            &registry.read(),
+            42
+        ),
+        Err(ReflectReseedError::NoRngComponent(entity))
+    );
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_components_are_reseeded_with_their_own_seeds() {
+    let mut app = app();
+
+    let entity = app
+        .world_mut()
+        .spawn((ChaChaRngComponent::new(), RngComponent::new()))
+        .id();
+    let registry = app.world().resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    let mut entity_mut = app.world_mut().entity_mut(entity);
+
+    reseed_reflected_chacha(&mut entity_mut, &registry, [7; 40]).unwrap();
+
+    assert_eq!(
+        *entity_mut.get::<ChaChaRngComponent>().unwrap(),
+        ChaChaRngComponent::with_seed([7; 40])
+    );
+    assert_ne!(
+        *entity_mut.get::<RngComponent>().unwrap(),
+        RngComponent::with_seed(42)
+    );
+
+    reseed_reflected(&mut entity_mut, &registry, 42).unwrap();
+
+    assert_eq!(
+        *entity_mut.get::<RngComponent>().unwrap(),
+        RngComponent::with_seed(42)
+    );
+}
@@ -0,0 +1,150 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+enum Temper {
+    Calm,
+    Wary,
+    Furious,
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect)]
+enum Loot {
+    Nothing,
+    Gold(u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect)]
+struct Stats {
+    health: u32,
+    speed: f32,
+    inner: Deeper,
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect)]
+struct Deeper(i32);
+
+#[derive(Debug, Clone, PartialEq, Reflect)]
+struct Enemy {
+    level: u8,
+    aggressive: bool,
+    temper: Temper,
+    loot: Loot,
+    stats: Stats,
+    #[reflect(@NoRandomize)]
+    id: u32,
+    name: String,
+}
+
+fn enemy() -> Enemy {
+    Enemy {
+        level: 10,
+        aggressive: false,
+        temper: Temper::Calm,
+        loot: Loot::Gold(5),
+        stats: Stats {
+            health: 200,
+            speed: 4.0,
+            inner: Deeper(-50),
+        },
+        id: 42,
+        name: "Goblin".into(),
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn randomizes_reflected_struct_deterministically() {
+    let mut value = enemy();
+    let mut rng = RngComponent::with_seed(7);
+
+    let summary = randomize_reflect(&mut value, &mut rng, &RandomizeOptions::default());
+
+    assert_eq!(
+        summary.randomized,
+        [
+            "level",
+            "aggressive",
+            "temper",
+            "stats.health",
+            "stats.speed",
+            "stats.inner.0"
+        ]
+    );
+    assert_eq!(summary.skipped, ["id"]);
+    assert_eq!(summary.unsupported, ["loot", "name"]);
+
+    assert_eq!(
+        value,
+        Enemy {
+            level: 9,
+            stats: Stats {
+                health: 181,
+                speed: 3.9594157,
+                inner: Deeper(-54),
+            },
+            ..enemy()
+        }
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn skips_listed_fields_and_nested_beyond_depth() {
+    let mut value = enemy();
+    let mut rng = RngComponent::with_seed(7);
+
+    let options = RandomizeOptions::default()
+        .with_skipped("stats.speed")
+        .with_skipped("temper")
+        .with_max_depth(1);
+
+    let summary = randomize_reflect(&mut value, &mut rng, &options);
+
+    assert_eq!(summary.randomized, ["level", "aggressive", "stats.health"]);
+    assert_eq!(
+        summary.skipped,
+        ["temper", "stats.speed", "stats.inner", "id"]
+    );
+    assert_eq!(value.temper, Temper::Calm);
+    assert_eq!(value.stats.speed, 4.0);
+    assert_eq!(value.stats.inner, Deeper(-50));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn jitter_stays_within_bounds() {
+    let mut rng = RngComponent::with_seed(3);
+    let options = RandomizeOptions::default()
+        .with_jitter(0.25)
+        .with_flip_chance(1.0);
+
+    let mut tempers = Vec::new();
+
+    for _ in 0..200 {
+        let mut value = enemy();
+
+        randomize_reflect(&mut value, &mut rng, &options);
+
+        assert!((7..=13).contains(&value.level));
+        assert!(value.aggressive);
+        assert!((150..=250).contains(&value.stats.health));
+        assert!((3.0..=5.0).contains(&value.stats.speed));
+        assert!((-63..=-37).contains(&value.stats.inner.0));
+        assert_eq!(value.id, 42);
+
+        if !tempers.contains(&value.temper) {
+            tempers.push(value.temper);
+        }
+    }
+
+    assert_eq!(tempers.len(), 3, "all variants should be rolled");
+}
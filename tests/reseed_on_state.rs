@@ -0,0 +1,166 @@
+#![cfg(feature = "bevy_state")]
+
+use bevy::{prelude::*, state::app::StatesPlugin};
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, States)]
+enum GameState {
+    #[default]
+    MainMenu,
+    InGame,
+}
+
+fn app(source: SeedSource) -> App {
+    let mut app = App::new();
+
+    app.add_plugins((
+        StatesPlugin,
+        RngPlugin::new()
+            .with_rng_seed(1)
+            .reseed_on_enter(GameState::InGame, source),
+    ))
+    .init_state::<GameState>();
+
+    app
+}
+
+fn enter(app: &mut App, state: GameState) {
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(state);
+    app.update();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn entering_the_state_reseeds_the_global() {
+    let mut app = app(SeedSource::Fixed(5));
+
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<GlobalRng>(),
+        &GlobalRng::with_seed(1)
+    );
+
+    enter(&mut app, GameState::InGame);
+
+    let mut global = app.world_mut().resource_mut::<GlobalRng>();
+
+    assert_eq!(global.u64(..), 5465023315925113795);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn every_entry_reseeds_again() {
+    let mut app = app(SeedSource::Fixed(5));
+
+    enter(&mut app, GameState::InGame);
+
+    let first = app.world_mut().resource_mut::<GlobalRng>().u64(..);
+
+    enter(&mut app, GameState::MainMenu);
+    enter(&mut app, GameState::InGame);
+
+    let second = app.world_mut().resource_mut::<GlobalRng>().u64(..);
+
+    assert_eq!(first, second);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn only_marked_components_are_reforked() {
+    let mut app = app(SeedSource::NextWorldSeed);
+
+    let gameplay = app
+        .world_mut()
+        .spawn((RngComponent::with_seed(2), ReseedOnState))
+        .id();
+    let cosmetic = app.world_mut().spawn(RngComponent::with_seed(3)).id();
+
+    app.insert_resource(NextWorldSeed(5));
+
+    enter(&mut app, GameState::InGame);
+
+    let mut expected_global = GlobalRng::with_seed(5);
+    let mut expected_gameplay = RngComponent::from(&mut expected_global);
+
+    let mut gameplay = app.world_mut().get_mut::<RngComponent>(gameplay).unwrap();
+
+    let value = gameplay.u64(..);
+
+    assert_eq!(value, expected_gameplay.u64(..));
+    assert_eq!(value, 5742123594450511079);
+
+    assert_eq!(
+        app.world().get::<RngComponent>(cosmetic),
+        Some(&RngComponent::with_seed(3))
+    );
+    assert_eq!(app.world().resource::<GlobalRng>(), &expected_global);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn missing_next_world_seed_leaves_global_untouched() {
+    let mut app = app(SeedSource::NextWorldSeed);
+
+    enter(&mut app, GameState::InGame);
+
+    assert_eq!(
+        app.world().resource::<GlobalRng>(),
+        &GlobalRng::with_seed(1)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn entropy_reseeds_unpredictably() {
+    let mut app = app(SeedSource::Entropy);
+
+    enter(&mut app, GameState::InGame);
+
+    assert_ne!(
+        app.world().resource::<GlobalRng>(),
+        &GlobalRng::with_seed(1)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn strict_mode_reseeds_the_strict_global() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        StatesPlugin,
+        RngPlugin::new()
+            .with_rng_seed(1)
+            .with_strict_global()
+            .reseed_on_enter(GameState::InGame, SeedSource::Fixed(5)),
+    ))
+    .init_state::<GameState>();
+
+    let gameplay = app
+        .world_mut()
+        .spawn((RngComponent::with_seed(2), ReseedOnState))
+        .id();
+
+    enter(&mut app, GameState::InGame);
+
+    let mut expected_global = StrictGlobalRng::with_seed(5);
+    let mut expected_gameplay = expected_global.seed_component();
+
+    assert_eq!(app.world().resource::<StrictGlobalRng>(), &expected_global);
+    assert_eq!(
+        app.world_mut()
+            .get_mut::<RngComponent>(gameplay)
+            .unwrap()
+            .u64(..),
+        expected_gameplay.u64(..)
+    );
+}
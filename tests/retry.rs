@@ -0,0 +1,76 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn retry_sample_succeeds_on_first_try() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut attempts = 0;
+
+    let value = rng.retry_sample(10, |rng| {
+        attempts += 1;
+        Some(rng.u32(..))
+    });
+
+    assert_eq!(value, Ok(RngComponent::with_seed(5).u32(..)));
+    assert_eq!(attempts, 1);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn retry_sample_succeeds_after_k_attempts() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut attempts = 0;
+
+    let value = rng.retry_sample(10, |rng| {
+        attempts += 1;
+        let value = rng.u8(..);
+        (attempts == 4).then_some(value)
+    });
+
+    let mut reference = RngComponent::with_seed(5);
+    let expected = (0..4).map(|_| reference.u8(..)).last();
+
+    assert_eq!(value.ok(), expected);
+    assert_eq!(attempts, 4);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn retry_sample_reports_exhaustion() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut attempts = 0;
+
+    let value: Result<u32, _> = rng.retry_sample(7, |rng| {
+        attempts += 1;
+        rng.u32(..);
+        None
+    });
+
+    assert_eq!(value, Err(RetryExhausted { attempts: 7 }));
+    assert_eq!(attempts, 7);
+    assert_eq!(
+        value.unwrap_err().to_string(),
+        "sampling failed after 7 attempts"
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn retry_sample_or_falls_back_to_default() {
+    let mut rng = RngComponent::with_seed(5);
+
+    assert_eq!(rng.retry_sample_or(3, 42, |_| None), 42);
+    assert_eq!(rng.retry_sample_or(0, 42, |rng| Some(rng.u32(..))), 42);
+    assert_ne!(
+        rng.retry_sample_or(3, u64::MAX, |rng| Some(rng.u64(..u64::MAX))),
+        u64::MAX
+    );
+}
@@ -0,0 +1,257 @@
+#![cfg(all(feature = "wyrand", feature = "rng-log"))]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Component)]
+struct Enemy;
+
+fn setup(mut commands: Commands, mut global: ResMut<GlobalRng>, log: Res<RngLog>) {
+    for _ in 0..3 {
+        commands.spawn((Enemy, log.wrap(RngComponent::from(&mut global))));
+    }
+
+    commands.insert_resource(log.wrap(global.clone()));
+}
+
+fn attack(
+    mut q_enemies: Query<(Entity, &mut LoggedRng<RngComponent>), With<Enemy>>,
+    mut global: ResMut<LoggedRng<GlobalRng>>,
+) {
+    let _weather = global.f32();
+
+    let mut enemies: Vec<_> = q_enemies.iter_mut().collect();
+
+    enemies.sort_unstable_by_key(|(entity, _)| *entity);
+
+    for (_, mut rng) in enemies {
+        if rng.chance(0.5) {
+            let _damage = rng.u32(1..=6);
+        }
+    }
+}
+
+/// Runs an app seeded with `seed` for `frames` frames, returning the log of its draws.
+fn run(seed: u64, frames: usize) -> RngLog {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(seed))
+        .add_systems(Startup, setup)
+        .add_systems(Update, attack);
+
+    for _ in 0..frames {
+        app.update();
+    }
+
+    app.world().resource::<RngLog>().clone()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn identically_seeded_apps_do_not_diverge() {
+    let recording = run(5, 4);
+    let replay = run(5, 4);
+
+    assert!(!recording.is_empty());
+    assert_eq!(recording.entries(), replay.entries());
+    assert_eq!(recording.diff(&replay), None);
+    assert_eq!(recording.frame(), 4);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn perturbed_seeds_diverge_at_the_first_draw() {
+    let recording = run(5, 4);
+    let replay = run(6, 4);
+
+    assert_eq!(recording.diff(&replay), Some(0));
+    assert_eq!(replay.diff(&recording), Some(0));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn entries_record_the_frame_type_and_method() {
+    let log = run(5, 3);
+    let entries = log.entries();
+
+    assert_eq!(entries.first().map(|entry| entry.frame), Some(0));
+    assert_eq!(entries.last().map(|entry| entry.frame), Some(2));
+    assert!(entries
+        .windows(2)
+        .all(|pair| pair[0].frame <= pair[1].frame));
+
+    let weather: Vec<_> = entries
+        .iter()
+        .filter(|entry| entry.type_name.ends_with("GlobalRng"))
+        .collect();
+
+    assert_eq!(weather.len(), 3);
+    assert!(weather.iter().all(|entry| entry.method == "f32"));
+    assert!(entries
+        .iter()
+        .filter(|entry| entry.type_name.ends_with("RngComponent"))
+        .all(|entry| entry.method == "chance" || entry.method == "u32"));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn logged_rngs_draw_the_same_values() {
+    let log = RngLog::default();
+    let mut logged = log.wrap(RngComponent::with_seed(5));
+    let mut plain = RngComponent::with_seed(5);
+
+    assert_eq!(logged.u64(..), plain.u64(..));
+
+    let value = plain.f64();
+
+    assert_eq!(logged.f64(), value);
+    assert_eq!(logged.bool(), plain.bool());
+    assert_eq!(logged.into_inner(), plain);
+
+    let entries = log.entries();
+
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].method, "u64");
+    assert_eq!(entries[1].value, value.to_bits());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn logs_keep_only_their_capacity() {
+    let log = RngLog::new(4);
+    let mut rng = log.wrap(RngComponent::with_seed(5));
+
+    for _ in 0..10 {
+        rng.u8(..);
+    }
+
+    assert_eq!(log.len(), 4);
+    assert_eq!(log.capacity(), 4);
+    assert_eq!(log.dropped(), 6);
+
+    log.clear();
+
+    assert!(log.is_empty());
+    assert_eq!(log.dropped(), 0);
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_log_capacity(16));
+
+    assert_eq!(app.world().resource::<RngLog>().capacity(), 16);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn diff_reports_where_a_shorter_log_ends() {
+    let recording = RngLog::default();
+    let replay = RngLog::default();
+
+    let mut recorded = recording.wrap(RngComponent::with_seed(5));
+    let mut replayed = replay.wrap(RngComponent::with_seed(5));
+
+    recorded.u32(..);
+    recorded.u32(..);
+    replayed.u32(..);
+
+    assert_eq!(recording.diff(&replay), Some(1));
+    assert_eq!(recording.diff(&recording.clone()), None);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn interleaved_streams_do_not_diverge() {
+    let recording = RngLog::default();
+    let replay = RngLog::default();
+
+    let (mut recorded_a, mut recorded_b) = (
+        recording.wrap(RngComponent::with_seed(5)),
+        recording.wrap(RngComponent::with_seed(6)),
+    );
+    let (mut replayed_a, mut replayed_b) = (
+        replay.wrap(RngComponent::with_seed(5)),
+        replay.wrap(RngComponent::with_seed(6)),
+    );
+
+    assert_eq!((recorded_a.stream(), recorded_b.stream()), (0, 1));
+
+    recorded_a.u32(..);
+    recorded_b.u32(..);
+    replayed_b.u32(..);
+    replayed_a.u32(..);
+
+    assert_eq!(recording.diff(&replay), None);
+
+    recorded_b.u32(..);
+    replayed_b.f32();
+
+    assert_eq!(recording.diff(&replay), Some(2));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sampling_shuffling_and_forking_are_recorded() {
+    let draw = |seed: u64| {
+        let log = RngLog::default();
+        let mut rng = log.wrap_with_stream(RngComponent::with_seed(seed), 42);
+        let mut list = [1, 2, 3, 4, 5];
+
+        let sampled = rng.sample(&list).copied();
+        let weighted = rng
+            .weighted_sample(&list, |(item, _)| f64::from(*item) / 5.0)
+            .copied();
+        rng.shuffle(&mut list);
+        let _fork = rng.fork();
+
+        (log, sampled, weighted, list)
+    };
+
+    let (log, sampled, weighted, list) = draw(5);
+
+    let mut plain = RngComponent::with_seed(5);
+    let mut expected = [1, 2, 3, 4, 5];
+
+    assert_eq!(plain.sample(&expected).copied(), sampled);
+    assert_eq!(
+        plain
+            .weighted_sample(&expected, |(item, _)| f64::from(*item) / 5.0)
+            .copied(),
+        weighted
+    );
+    plain.shuffle(&mut expected);
+    assert_eq!(expected, list);
+
+    let entries = log.entries();
+
+    assert_eq!(
+        entries
+            .iter()
+            .map(|entry| entry.method.as_ref())
+            .collect::<Vec<_>>(),
+        ["sample", "weighted_sample", "shuffle", "fork"]
+    );
+    assert!(entries.iter().all(|entry| entry.stream == 42));
+    assert_eq!(log.diff(&draw(5).0), None);
+    assert_eq!(log.diff(&draw(6).0), Some(0));
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn logs_can_be_exported_and_compared_later() {
+    let recording = run(5, 2);
+
+    let exported = ron::to_string(&recording).unwrap();
+    let imported: RngLog = ron::from_str(&exported).unwrap();
+
+    assert_eq!(imported.entries(), recording.entries());
+    assert_eq!(imported.frame(), recording.frame());
+    assert_eq!(imported.diff(&recording), None);
+    assert_eq!(imported.diff(&run(6, 2)), Some(0));
+}
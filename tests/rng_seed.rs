@@ -0,0 +1,155 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// Inserts a component from its reflected RON form onto a new entity, as loading a scene
+/// does.
+#[cfg(feature = "serialize")]
+fn spawn_from_ron(app: &mut App, ron: &str) -> Entity {
+    use bevy::reflect::serde::ReflectDeserializer;
+    use serde::de::DeserializeSeed;
+
+    let registry = app.world().resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    let mut deserializer = ron::de::Deserializer::from_str(ron).unwrap();
+    let reflected = ReflectDeserializer::new(&registry)
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+    let reflect_component = registry
+        .get_with_type_path(reflected.get_represented_type_info().unwrap().type_path())
+        .and_then(|registration| registration.data::<ReflectComponent>())
+        .unwrap();
+
+    let mut entity = app.world_mut().spawn_empty();
+
+    reflect_component.insert(&mut entity, reflected.as_partial_reflect(), &registry);
+
+    entity.id()
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn scene_seeds_resolve_into_rng_components() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5));
+
+    let entity = spawn_from_ron(
+        &mut app,
+        r#"{ "bevy_turborand::authoring::RngSeed": (12345) }"#,
+    );
+
+    app.update();
+
+    let world = app.world_mut();
+
+    assert!(world.get::<RngSeed>(entity).is_none());
+
+    let mut rng = world.get_mut::<RngComponent>(entity).unwrap();
+
+    assert_eq!(*rng, RngComponent::with_seed(12345));
+    assert_eq!(
+        [rng.u32(..), rng.u32(..), rng.u32(..)],
+        [1746607289, 2990652629, 3433698709]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seed_components_can_be_kept() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5).keep_seed_components());
+
+    let entity = app.world_mut().spawn(RngSeed(12345)).id();
+
+    app.update();
+    app.update();
+
+    let world = app.world();
+
+    assert_eq!(world.get::<RngSeed>(entity), Some(&RngSeed(12345)));
+    assert_eq!(
+        world.get::<RngComponent>(entity),
+        Some(&RngComponent::with_seed(12345))
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn existing_rng_components_are_left_as_is() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5));
+
+    let entity = app
+        .world_mut()
+        .spawn((RngSeed(12345), RngComponent::with_seed(7)))
+        .id();
+
+    app.update();
+
+    let world = app.world();
+
+    assert_eq!(world.get::<RngSeed>(entity), Some(&RngSeed(12345)));
+    assert_eq!(
+        world.get::<RngComponent>(entity),
+        Some(&RngComponent::with_seed(7))
+    );
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seeds_roundtrip_through_serde() {
+    let serialized = ron::to_string(&RngSeed(12345)).unwrap();
+
+    assert_eq!(serialized, "(12345)");
+    assert_eq!(
+        ron::from_str::<RngSeed>(&serialized).unwrap(),
+        RngSeed(12345)
+    );
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_seeds_resolve_into_chacha_components() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5));
+
+    let entity = app.world_mut().spawn(ChaChaSeed([7; 40])).id();
+
+    app.update();
+
+    let world = app.world();
+
+    assert!(world.get::<ChaChaSeed>(entity).is_none());
+    assert_eq!(
+        world.get::<ChaChaRngComponent>(entity),
+        Some(&ChaChaRngComponent::with_seed([7; 40]))
+    );
+}
+
+#[cfg(all(feature = "chacha", feature = "serialize"))]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_seeds_roundtrip_through_serde() {
+    let seed = ChaChaSeed(std::array::from_fn(|index| index as u8));
+
+    let serialized = ron::to_string(&seed).unwrap();
+
+    assert_eq!(ron::from_str::<ChaChaSeed>(&serialized).unwrap(), seed);
+    assert!(ron::from_str::<ChaChaSeed>("([1, 2, 3])").is_err());
+}
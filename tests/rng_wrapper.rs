@@ -0,0 +1,179 @@
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// A third-party SplitMix64 source, made [`Sync`] by keeping its state in an atomic.
+#[derive(Debug, Default)]
+struct SplitMix(AtomicU64);
+
+impl SplitMix {
+    fn next(&self) -> u64 {
+        let mut z = self
+            .0
+            .fetch_add(0x9e37_79b9_7f4a_7c15, Ordering::Relaxed)
+            .wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl Clone for SplitMix {
+    fn clone(&self) -> Self {
+        Self(AtomicU64::new(self.0.load(Ordering::Relaxed)))
+    }
+}
+
+impl PartialEq for SplitMix {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.load(Ordering::Relaxed) == other.0.load(Ordering::Relaxed)
+    }
+}
+
+impl TurboCore for SplitMix {
+    fn fill_bytes(&self, buffer: &mut [u8]) {
+        for chunk in buffer.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next().to_le_bytes()[..chunk.len()]);
+        }
+    }
+}
+
+impl GenCore for SplitMix {
+    const GEN_KIND: TurboKind = TurboKind::FAST;
+
+    fn gen<const SIZE: usize>(&self) -> [u8; SIZE] {
+        let mut bytes = [0; SIZE];
+        self.fill_bytes(&mut bytes);
+        bytes
+    }
+}
+
+impl SeededCore for SplitMix {
+    type Seed = u64;
+
+    fn with_seed(seed: Self::Seed) -> Self {
+        Self(AtomicU64::new(seed))
+    }
+
+    fn reseed(&self, seed: Self::Seed) {
+        self.0.store(seed, Ordering::Relaxed);
+    }
+}
+
+impl ForkableCore for SplitMix {
+    fn fork(&self) -> Self {
+        Self::with_seed(self.next())
+    }
+}
+
+#[derive(Debug, Component)]
+struct Enemy;
+
+#[derive(Debug, Default, Resource)]
+struct Rolls(Vec<u64>);
+
+fn spawn_enemies(mut commands: Commands, mut global: ResMut<GlobalRngWrapper<SplitMix>>) {
+    for _ in 0..3 {
+        commands.spawn((Enemy, RngComponentWrapper::from(&mut global)));
+    }
+}
+
+fn roll_enemies(
+    mut q_enemies: Query<(Entity, &mut RngComponentWrapper<SplitMix>), With<Enemy>>,
+    mut rolls: ResMut<Rolls>,
+) {
+    let mut enemies: Vec<_> = q_enemies.iter_mut().collect();
+
+    enemies.sort_unstable_by_key(|(entity, _)| *entity);
+
+    for (_, mut rng) in enemies {
+        rolls.0.push(rng.u64(..));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn third_party_sources_work_end_to_end() {
+    let mut app = App::new();
+
+    app.add_seeded_rng_source::<SplitMix>(5)
+        .init_resource::<Rolls>()
+        .add_systems(Startup, spawn_enemies)
+        .add_systems(Update, roll_enemies);
+
+    app.update();
+
+    let global = SplitMix::with_seed(5);
+    let expected: Vec<u64> = (0..3).map(|_| global.fork().gen_u64()).collect();
+
+    assert_eq!(app.world().resource::<Rolls>().0, expected);
+    assert_eq!(
+        app.world().resource::<GlobalRngWrapper<SplitMix>>(),
+        &GlobalRngWrapper::from_source(global)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn existing_sources_are_not_overwritten() {
+    let mut app = App::new();
+
+    app.insert_resource(GlobalRngWrapper::<SplitMix>::with_seed(7))
+        .add_seeded_rng_source::<SplitMix>(5)
+        .add_rng_source::<SplitMix>();
+
+    assert_eq!(
+        app.world().resource::<GlobalRngWrapper<SplitMix>>(),
+        &GlobalRngWrapper::with_seed(7)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn unseeded_sources_use_the_default_source() {
+    let mut app = App::new();
+
+    app.add_rng_source::<SplitMix>();
+
+    assert_eq!(
+        app.world().resource::<GlobalRngWrapper<SplitMix>>(),
+        &GlobalRngWrapper::from_source(SplitMix::default())
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn wrappers_delegate_to_their_source() {
+    let mut rng = RngComponentWrapper::<SplitMix>::with_seed(5);
+    let source = SplitMix::with_seed(5);
+
+    assert_eq!(rng.u32(..10), source.u32(..10));
+    assert_eq!(rng.f64(), source.f64());
+    assert_eq!(rng.chance(0.5), source.chance(0.5));
+    assert_eq!(rng.into_inner(), source);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn components_fork_from_other_components() {
+    let mut world = World::new();
+
+    let parent = world
+        .spawn(RngComponentWrapper::<SplitMix>::with_seed(5))
+        .id();
+
+    let mut source = world
+        .get_mut::<RngComponentWrapper<SplitMix>>(parent)
+        .unwrap();
+    let mut forked = RngComponentWrapper::from(&mut source);
+
+    let expected = SplitMix::with_seed(5).fork();
+
+    assert_eq!(forked.u64(..), expected.gen_u64());
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sample_array_is_deterministic() {
+    let list: Vec<u32> = (0..20).collect();
+
+    let sampled = RngComponent::with_seed(5)
+        .sample_array::<_, 4>(&list)
+        .unwrap();
+
+    assert_eq!(sampled, [&5, &14, &19, &13]);
+    assert_eq!(
+        GlobalRng::with_seed(5).sample_array::<_, 4>(&list),
+        Some(sampled)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sample_array_matches_partial_fisher_yates() {
+    let list: Vec<usize> = (0..12).collect();
+
+    for seed in 0..50 {
+        let mut reference = RngComponent::with_seed(seed);
+        let mut indices = list.clone();
+
+        for position in 0..5 {
+            let target = reference.index(position..indices.len());
+            indices.swap(position, target);
+        }
+
+        let sampled = RngComponent::with_seed(seed)
+            .sample_array::<_, 5>(&list)
+            .unwrap();
+
+        assert!(sampled.into_iter().eq(indices[..5].iter()));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sample_array_never_repeats_positions() {
+    let mut rng = RngComponent::with_seed(7);
+    let list: Vec<usize> = (0..10).collect();
+
+    for _ in 0..1000 {
+        let mut sampled = rng.sample_array::<_, 6>(&list).unwrap();
+        sampled.sort();
+
+        assert!(sampled.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
+    let mut whole = rng.sample_array::<_, 10>(&list).unwrap();
+    whole.sort();
+
+    assert!(whole.into_iter().eq(list.iter()));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sample_array_covers_every_item() {
+    let mut rng = RngComponent::with_seed(7);
+    let list: Vec<usize> = (0..8).collect();
+    let mut counts = [0u32; 8];
+
+    for _ in 0..8000 {
+        for &item in rng.sample_array::<_, 2>(&list).unwrap() {
+            counts[item] += 1;
+        }
+    }
+
+    // Each item is expected to be sampled 2000 times.
+    assert!(
+        counts.iter().all(|&count| (1800..2200).contains(&count)),
+        "{counts:?}"
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sample_array_returns_none_for_short_lists() {
+    let mut rng = RngComponent::with_seed(5);
+
+    assert_eq!(rng.sample_array::<_, 4>(&[1, 2, 3]), None);
+    assert_eq!(rng.sample_array::<u8, 0>(&[]), Some([]));
+    assert_eq!(rng, RngComponent::with_seed(5));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn shuffle_array_matches_shuffle() {
+    let mut array = [1, 2, 3, 4, 5, 6, 7, 8];
+    let mut slice = array;
+
+    RngComponent::with_seed(5).shuffle_array(&mut array);
+    RngComponent::with_seed(5).shuffle(&mut slice);
+
+    assert_eq!(array, slice);
+    assert_eq!(array, [7, 1, 8, 2, 4, 6, 5, 3]);
+}
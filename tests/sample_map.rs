@@ -0,0 +1,85 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn names() -> Vec<String> {
+    (0..64).map(|i| format!("item-{i}")).collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn separately_built_maps_sample_identically() {
+    let names = names();
+
+    // Each map is built with its own random hasher state, and in a different insertion order.
+    let map_a: HashMap<&str, usize> = names.iter().map(String::as_str).zip(0..).collect();
+    let map_b: HashMap<&str, usize> = names
+        .iter()
+        .rev()
+        .map(String::as_str)
+        .zip(0..)
+        .map(|(k, v)| (k, 63 - v))
+        .collect();
+
+    let mut rng_a = RngComponent::with_seed(11);
+    let mut rng_b = RngComponent::with_seed(11);
+
+    let picks_a: Vec<_> = (0..20).map(|_| rng_a.sample_map(&map_a).unwrap()).collect();
+    let picks_b: Vec<_> = (0..20).map(|_| rng_b.sample_map(&map_b).unwrap()).collect();
+
+    assert_eq!(picks_a, picks_b);
+    assert_eq!(picks_a[0], (&"item-11", &11));
+
+    let btree: BTreeMap<&str, usize> = map_a.clone().into_iter().collect();
+    let mut rng_c = RngComponent::with_seed(11);
+    let picks_c: Vec<_> = (0..20)
+        .map(|_| rng_c.sample_btree_map(&btree).unwrap())
+        .collect();
+
+    assert_eq!(picks_a, picks_c);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn separately_built_sets_sample_identically() {
+    let names = names();
+
+    let set_a: HashSet<&String> = names.iter().collect();
+    let set_b: HashSet<&String> = names.iter().rev().collect();
+
+    let mut rng_a = RngComponent::with_seed(11);
+    let mut rng_b = RngComponent::with_seed(11);
+
+    let picks_a: Vec<_> = (0..20).map(|_| rng_a.sample_set(&set_a).unwrap()).collect();
+    let picks_b: Vec<_> = (0..20).map(|_| rng_b.sample_set(&set_b).unwrap()).collect();
+
+    assert_eq!(picks_a, picks_b);
+
+    let btree: BTreeSet<&String> = names.iter().collect();
+    let mut rng_c = RngComponent::with_seed(11);
+    let picks_c: Vec<_> = (0..20)
+        .map(|_| rng_c.sample_btree_set(&btree).unwrap())
+        .collect();
+
+    assert_eq!(picks_a, picks_c);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn empty_collections_do_not_advance_the_rng() {
+    let mut rng = RngComponent::with_seed(11);
+
+    assert_eq!(rng.sample_map(&HashMap::<u32, u32>::new()), None);
+    assert_eq!(rng.sample_set(&HashSet::<u32>::new()), None);
+    assert_eq!(rng.sample_btree_map(&BTreeMap::<u32, u32>::new()), None);
+    assert_eq!(rng.sample_btree_set(&BTreeSet::<u32>::new()), None);
+
+    assert_eq!(rng, RngComponent::with_seed(11));
+}
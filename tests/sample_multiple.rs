@@ -0,0 +1,127 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn try_sample_multiple_errors_on_over_request() {
+    let mut rng = RngComponent::with_seed(5);
+    let deck = [1, 2, 3];
+
+    assert_eq!(
+        rng.try_sample_multiple(&deck, 4),
+        Err(SampleError::NotEnoughElements {
+            requested: 4,
+            available: 3
+        })
+    );
+
+    // Nothing was drawn from the RNG by the failed request.
+    assert_eq!(rng, RngComponent::with_seed(5));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn try_sample_multiple_returns_exact_length() {
+    let mut rng = RngComponent::with_seed(5);
+    let deck = [1, 2, 3, 4, 5, 6];
+
+    for amount in 0..=deck.len() {
+        assert_eq!(
+            rng.try_sample_multiple(&deck, amount).unwrap().len(),
+            amount
+        );
+    }
+
+    let mut global = GlobalRng::with_seed(5);
+
+    assert_eq!(global.try_sample_multiple(&deck, 6).unwrap().len(), 6);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn try_sample_multiple_matches_sample_multiple() {
+    let deck = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    let sampled = RngComponent::with_seed(5)
+        .try_sample_multiple(&deck, 3)
+        .unwrap();
+
+    assert_eq!(
+        sampled,
+        RngComponent::with_seed(5).sample_multiple(&deck, 3)
+    );
+    assert_eq!(sampled, [&1, &4, &3]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sample_multiple_distinct_never_repeats_values() {
+    let mut rng = RngComponent::with_seed(7);
+    let drops = [1, 1, 1, 2, 2, 3, 4, 4];
+
+    for _ in 0..100 {
+        let mut sampled = rng.sample_multiple_distinct(&drops, 4).unwrap();
+        sampled.sort();
+
+        assert_eq!(sampled, [&1, &2, &3, &4]);
+
+        let sampled = rng.sample_multiple_distinct(&drops, 2).unwrap();
+
+        assert_ne!(sampled[0], sampled[1]);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sample_multiple_distinct_errors_on_too_few_distinct_values() {
+    let mut rng = RngComponent::with_seed(5);
+    let drops = [1, 1, 2, 2];
+
+    assert_eq!(
+        rng.sample_multiple_distinct(&drops, 3),
+        Err(SampleError::NotEnoughElements {
+            requested: 3,
+            available: 2
+        })
+    );
+    assert_eq!(rng, RngComponent::with_seed(5));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sample_multiple_distinct_is_deterministic() {
+    let drops = ["gold", "gold", "sword", "shield", "potion", "potion", "bow"];
+
+    let sampled = RngComponent::with_seed(5)
+        .sample_multiple_distinct(&drops, 3)
+        .unwrap();
+
+    assert_eq!(
+        sampled,
+        GlobalRng::with_seed(5)
+            .sample_multiple_distinct(&drops, 3)
+            .unwrap()
+    );
+    assert_eq!(sampled, [&"gold", &"potion", &"shield"]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sample_error_display() {
+    let error = SampleError::NotEnoughElements {
+        requested: 5,
+        available: 2,
+    };
+
+    assert_eq!(
+        error.to_string(),
+        "requested 5 items to sample, but only 2 are available"
+    );
+}
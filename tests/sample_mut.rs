@@ -0,0 +1,41 @@
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// Mutates elements sampled through each of the `_mut` sampling methods, checking
+/// that the changes land in the original list.
+fn mutate_samples(rng: &mut impl DelegatedRng) {
+    let mut list = [0u32; 8];
+
+    *rng.sample_mut(&mut list).unwrap() += 1;
+
+    for value in rng.sample_multiple_mut(&mut list, 3) {
+        *value += 10;
+    }
+
+    *rng.weighted_sample_mut(&mut list, |(_, index)| if index == 7 { 1.0 } else { 0.0 })
+        .unwrap() += 100;
+
+    assert_eq!(list.iter().sum::<u32>(), 131);
+    assert!(list[7] >= 100);
+}
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mutate_samples_through_wyrand_wrappers() {
+    mutate_samples(&mut RngComponent::with_seed(1));
+    mutate_samples(&mut GlobalRng::with_seed(1));
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mutate_samples_through_chacha_wrappers() {
+    mutate_samples(&mut ChaChaRngComponent::with_seed([1; 40]));
+    mutate_samples(&mut GlobalChaChaRng::with_seed([1; 40]));
+}
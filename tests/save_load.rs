@@ -0,0 +1,328 @@
+#![cfg(all(feature = "wyrand", feature = "serialize"))]
+#![allow(clippy::type_complexity)]
+
+use bevy::prelude::*;
+use bevy::reflect::serde::{ReflectDeserializer, ReflectSerializer};
+use bevy::reflect::{ReflectRef, TypeRegistry};
+use bevy_turborand::prelude::*;
+use serde::de::DeserializeSeed;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+struct Combatant(u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+struct HitPoints {
+    total: u32,
+    max: u32,
+}
+
+#[derive(Debug, Component)]
+struct Attack {
+    min: u32,
+    max: u32,
+    hit: f64,
+}
+
+#[derive(Debug, Component)]
+struct Buff {
+    min: u32,
+    max: u32,
+    chance: f64,
+}
+
+#[derive(Debug, Component)]
+struct Player;
+
+#[derive(Debug, Component)]
+struct Enemy;
+
+/// The saved state of a single combatant, matched back up by its [`Combatant`] key.
+#[derive(Debug, Reflect)]
+struct SavedCombatant {
+    key: Combatant,
+    hit_points: HitPoints,
+    rng: RngComponent,
+}
+
+/// The save file, serialized via reflection.
+#[derive(Debug, Reflect)]
+struct SaveGame {
+    global: GlobalRng,
+    combatants: Vec<SavedCombatant>,
+}
+
+fn attack_player(
+    mut q_player: Query<&mut HitPoints, (With<Player>, Without<Enemy>)>,
+    mut q_enemies: Query<(&Attack, &mut RngComponent), (With<Enemy>, Without<Player>)>,
+) {
+    let mut player = q_player.single_mut();
+
+    for (attack, mut rng) in q_enemies.iter_mut() {
+        if rng.chance(attack.hit) {
+            player.total = player
+                .total
+                .saturating_sub(rng.u32(attack.min..=attack.max));
+        }
+    }
+}
+
+fn attack_random_enemy(
+    mut q_enemies: Query<&mut HitPoints, (With<Enemy>, Without<Player>)>,
+    mut q_player: Query<(&Attack, &mut RngComponent), (With<Player>, Without<Enemy>)>,
+) {
+    let (attack, mut rng) = q_player.single_mut();
+
+    for mut enemy in q_enemies.iter_mut() {
+        if rng.chance(attack.hit) {
+            enemy.total = enemy.total.saturating_sub(rng.u32(attack.min..=attack.max));
+            break;
+        }
+    }
+}
+
+fn buff_player(mut q_player: Query<(&mut HitPoints, &mut RngComponent, &Buff), With<Player>>) {
+    let (mut player, mut rng, buff) = q_player.single_mut();
+
+    if rng.chance(buff.chance) {
+        player.total = player
+            .total
+            .saturating_add(rng.u32(buff.min..=buff.max))
+            .clamp(0, player.max);
+    }
+}
+
+fn spawn_combatants(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+    commands.spawn((
+        Combatant(0),
+        Player,
+        HitPoints {
+            total: 300,
+            max: 300,
+        },
+        Attack {
+            min: 5,
+            max: 10,
+            hit: 0.6,
+        },
+        Buff {
+            min: 2,
+            max: 6,
+            chance: 0.1,
+        },
+        RngComponent::from(&mut global),
+    ));
+
+    for key in 1..=2 {
+        commands.spawn((
+            Combatant(key),
+            Enemy,
+            HitPoints {
+                total: 100,
+                max: 100,
+            },
+            Attack {
+                min: 3,
+                max: 6,
+                hit: 0.5,
+            },
+            RngComponent::from(&mut global),
+        ));
+    }
+}
+
+/// Builds the game. A loaded game spawns its combatants the same way as a new one, before
+/// their state is restored from the save.
+fn game(plugin: RngPlugin) -> App {
+    let mut app = App::new();
+
+    app.add_plugins(plugin)
+        .register_type::<Combatant>()
+        .register_type::<HitPoints>()
+        .register_type::<SavedCombatant>()
+        .register_type::<SaveGame>()
+        .add_systems(Startup, spawn_combatants)
+        .add_systems(
+            Update,
+            ((attack_random_enemy, buff_player).chain(), attack_player),
+        );
+
+    app
+}
+
+fn save(app: &mut App) -> String {
+    let world = app.world_mut();
+
+    let mut q_combatants = world.query::<(&Combatant, &HitPoints, &RngComponent)>();
+    let mut combatants: Vec<SavedCombatant> = q_combatants
+        .iter(world)
+        .map(|(key, hit_points, rng)| SavedCombatant {
+            key: *key,
+            hit_points: *hit_points,
+            rng: rng.clone(),
+        })
+        .collect();
+
+    combatants.sort_by_key(|combatant| combatant.key.0);
+
+    let save = SaveGame {
+        global: world.resource::<GlobalRng>().clone(),
+        combatants,
+    };
+
+    let registry = world.resource::<AppTypeRegistry>().read();
+
+    ron::ser::to_string_pretty(
+        &ReflectSerializer::new(&save, &registry),
+        ron::ser::PrettyConfig::default(),
+    )
+    .unwrap()
+}
+
+fn load(app: &mut App, save: &str) {
+    let registry = app.world().resource::<AppTypeRegistry>().clone();
+    let save = deserialize(&registry.read(), save);
+
+    let ReflectRef::Struct(save) = save.reflect_ref() else {
+        panic!("the save is not a struct");
+    };
+
+    let world = app.world_mut();
+
+    insert_reflected_resource(world, save.field("global").unwrap()).unwrap();
+
+    let ReflectRef::List(combatants) = save.field("combatants").unwrap().reflect_ref() else {
+        panic!("the combatants are not a list");
+    };
+
+    for combatant in combatants.iter() {
+        let ReflectRef::Struct(combatant) = combatant.reflect_ref() else {
+            panic!("a combatant is not a struct");
+        };
+
+        let key = Combatant::from_reflect(combatant.field("key").unwrap()).unwrap();
+        let entity = world
+            .query::<(Entity, &Combatant)>()
+            .iter(world)
+            .find_map(|(entity, other)| (*other == key).then_some(entity))
+            .unwrap();
+
+        for field in ["hit_points", "rng"] {
+            insert_reflected_component(world, entity, combatant.field(field).unwrap()).unwrap();
+        }
+    }
+}
+
+fn deserialize(registry: &TypeRegistry, save: &str) -> Box<dyn PartialReflect> {
+    let mut deserializer = ron::Deserializer::from_str(save).unwrap();
+
+    ReflectDeserializer::new(registry)
+        .deserialize(&mut deserializer)
+        .unwrap()
+}
+
+fn hit_points(app: &mut App) -> Vec<(u32, u32)> {
+    let world = app.world_mut();
+
+    let mut hit_points: Vec<(u32, u32)> = world
+        .query::<(&Combatant, &HitPoints)>()
+        .iter(world)
+        .map(|(key, hit_points)| (key.0, hit_points.total))
+        .collect();
+
+    hit_points.sort_unstable();
+    hit_points
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn loaded_games_play_out_the_same() {
+    let mut original = game(RngPlugin::new().with_rng_seed(12345));
+
+    for _ in 0..10 {
+        original.update();
+    }
+
+    let save = save(&mut original);
+
+    // The loaded game starts off with different RNG state, to be overwritten by the save.
+    let mut loaded = game(RngPlugin::new().with_rng_seed(54321));
+
+    loaded.update();
+    load(&mut loaded, &save);
+
+    assert_eq!(hit_points(&mut loaded), hit_points(&mut original));
+
+    for _ in 0..10 {
+        original.update();
+        loaded.update();
+    }
+
+    assert_eq!(hit_points(&mut loaded), hit_points(&mut original));
+    assert_eq!(hit_points(&mut original), [(0, 198), (1, 23), (2, 37)]);
+    assert_eq!(
+        loaded.world_mut().resource_mut::<GlobalRng>().u64(..),
+        original.world_mut().resource_mut::<GlobalRng>().u64(..)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn unregistered_types_are_reported() {
+    let mut world = World::new();
+
+    world.init_resource::<AppTypeRegistry>();
+
+    let entity = world.spawn_empty().id();
+
+    assert_eq!(
+        insert_reflected_component(&mut world, entity, &HitPoints { total: 1, max: 1 }),
+        Err(ReflectInsertError::Unregistered(
+            "save_load::HitPoints".to_string()
+        ))
+    );
+
+    world
+        .resource::<AppTypeRegistry>()
+        .write()
+        .register::<GlobalRng>();
+
+    assert_eq!(
+        insert_reflected_component(&mut world, entity, &GlobalRng::with_seed(5)),
+        Err(ReflectInsertError::MissingTypeData(
+            "bevy_turborand::global::rng::GlobalRng".to_string()
+        ))
+    );
+
+    world.despawn(entity);
+
+    assert_eq!(
+        insert_reflected_component(&mut world, entity, &GlobalRng::with_seed(5)),
+        Err(ReflectInsertError::MissingTypeData(
+            "bevy_turborand::global::rng::GlobalRng".to_string()
+        ))
+    );
+
+    world
+        .resource::<AppTypeRegistry>()
+        .write()
+        .register::<RngComponent>();
+
+    assert_eq!(
+        insert_reflected_component(&mut world, entity, &RngComponent::with_seed(5)),
+        Err(ReflectInsertError::MissingEntity(entity))
+    );
+
+    assert_eq!(
+        insert_reflected_resource(&mut world, &GlobalRng::with_seed(5)),
+        Ok(())
+    );
+    assert_eq!(*world.resource::<GlobalRng>(), GlobalRng::with_seed(5));
+}
@@ -0,0 +1,77 @@
+#![cfg(all(feature = "wyrand", feature = "bevy_math"))]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn quadrants() -> [Rect; 4] {
+    [
+        Rect::new(-100.0, -100.0, 0.0, 0.0),
+        Rect::new(0.0, -100.0, 100.0, 0.0),
+        Rect::new(-100.0, 0.0, 0.0, 100.0),
+        Rect::new(0.0, 0.0, 100.0, 100.0),
+    ]
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn scatter_balanced_counts_differ_by_at_most_one() {
+    let regions = quadrants();
+    let mut rng = RngComponent::with_seed(3);
+
+    for total in 0..50 {
+        let points = rng.scatter_balanced(&regions, total);
+
+        let mut counts = [0usize; 4];
+
+        for &(region, pos) in &points {
+            assert!(regions[region].contains(pos));
+            counts[region] += 1;
+        }
+
+        let min = counts.iter().min().unwrap();
+        let max = counts.iter().max().unwrap();
+
+        assert_eq!(points.len(), total);
+        assert!(max - min <= 1, "unbalanced counts {counts:?} for {total}");
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn scatter_balanced_is_deterministic() {
+    let regions = quadrants();
+
+    let mut rng1 = RngComponent::with_seed(7);
+    let mut rng2 = RngComponent::with_seed(7);
+
+    let points = rng1.scatter_balanced(&regions, 6);
+
+    assert_eq!(points, rng2.scatter_balanced(&regions, 6));
+
+    let regions_picked: Vec<usize> = points.iter().map(|&(region, _)| region).collect();
+
+    assert_eq!(regions_picked, [0, 1, 1, 2, 2, 3]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn scatter_balanced_zero_total_is_empty() {
+    let mut rng = RngComponent::with_seed(7);
+
+    assert!(rng.scatter_balanced(&quadrants(), 0).is_empty());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "at least one region is required")]
+fn scatter_balanced_without_regions_panics() {
+    let mut rng = RngComponent::with_seed(7);
+
+    rng.scatter_balanced(&[], 5);
+}
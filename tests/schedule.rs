@@ -0,0 +1,164 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Activity {
+    Wake,
+    Work,
+    Shop,
+    Tavern,
+    Nap,
+}
+
+const HOUR: u32 = 60;
+
+fn generator() -> ScheduleGenerator<Activity> {
+    ScheduleGenerator::new()
+        .with_activity(Activity::Wake, 6 * HOUR..8 * HOUR, 30..60)
+        .with_activity(Activity::Work, 8 * HOUR..10 * HOUR, 6 * HOUR..8 * HOUR)
+        .with_pool(
+            vec![(Activity::Shop, 3.0), (Activity::Tavern, 1.0)],
+            1..=3,
+            16 * HOUR..22 * HOUR,
+            30..90,
+        )
+}
+
+fn entry(activity: Activity, start: u32, end: u32) -> ScheduleEntry<Activity> {
+    ScheduleEntry {
+        activity,
+        start,
+        end,
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn schedule_is_pinned_for_key_day_and_seed() {
+    let schedule = generator().generate_with_seed(12345, "blacksmith", 3);
+
+    assert_eq!(
+        schedule.entries,
+        [
+            entry(Activity::Wake, 388, 431),
+            entry(Activity::Work, 589, 1044),
+            entry(Activity::Shop, 1127, 1172),
+            entry(Activity::Shop, 1245, 1284),
+        ]
+    );
+    assert!(schedule.dropped.is_empty());
+
+    for pair in schedule.entries.windows(2) {
+        assert!(pair[0].end <= pair[1].start);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn schedules_are_independent_of_call_order() {
+    let generator = generator();
+    let npcs = ["blacksmith", "baker", "guard", "bard"];
+
+    let forwards: Vec<_> = npcs
+        .iter()
+        .flat_map(|npc| (0..5).map(move |day| (npc, day)))
+        .map(|(npc, day)| generator.generate_with_seed(12345, npc, day))
+        .collect();
+
+    let mut backwards: Vec<_> = npcs
+        .iter()
+        .rev()
+        .flat_map(|npc| (0..5).rev().map(move |day| (npc, day)))
+        .map(|(npc, day)| generator.generate_with_seed(12345, npc, day))
+        .collect();
+    backwards.reverse();
+
+    assert_eq!(forwards, backwards);
+    assert_ne!(forwards[0], forwards[1], "days should differ");
+    assert_ne!(forwards[0], forwards[5], "npcs should differ");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn generate_does_not_advance_master() {
+    let master = GlobalRng::with_seed(7);
+    let generator = generator();
+
+    let first = generator.generate(42u32, 1, &master);
+    let second = generator.generate(42u32, 1, &master);
+
+    assert_eq!(first, second);
+    assert_eq!(master, GlobalRng::with_seed(7));
+
+    let seed = GlobalRng::with_seed(7).u64(..);
+
+    assert_eq!(first, generator.generate_with_seed(seed, 42u32, 1));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn overlapping_activities_are_pushed_back() {
+    // The nap always starts before work ends, so is pushed back to start when work ends.
+    let generator = ScheduleGenerator::new()
+        .with_activity(
+            Activity::Work,
+            8 * HOUR..8 * HOUR + 1,
+            4 * HOUR..4 * HOUR + 1,
+        )
+        .with_activity(Activity::Nap, 11 * HOUR..12 * HOUR + 1, 30..31);
+
+    let schedule = generator.generate_with_seed(1, "baker", 0);
+
+    assert_eq!(
+        schedule.entries,
+        [
+            entry(Activity::Work, 8 * HOUR, 12 * HOUR),
+            entry(Activity::Nap, 12 * HOUR, 12 * HOUR + 30),
+        ]
+    );
+    assert!(schedule.dropped.is_empty());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn activities_pushed_past_their_window_are_dropped() {
+    // Work runs until noon, so the nap can no longer start within its window.
+    let generator = ScheduleGenerator::new()
+        .with_activity(
+            Activity::Work,
+            8 * HOUR..8 * HOUR + 1,
+            4 * HOUR..4 * HOUR + 1,
+        )
+        .with_activity(Activity::Nap, 10 * HOUR..11 * HOUR, 30..31)
+        .with_activity(
+            Activity::Tavern,
+            23 * HOUR..23 * HOUR + 1,
+            2 * HOUR..2 * HOUR + 1,
+        );
+
+    let schedule = generator.generate_with_seed(1, "baker", 0);
+
+    assert_eq!(
+        schedule.entries,
+        [
+            entry(Activity::Work, 8 * HOUR, 12 * HOUR),
+            // Cut short at the end of the day.
+            entry(Activity::Tavern, 23 * HOUR, MINUTES_PER_DAY),
+        ]
+    );
+    assert_eq!(schedule.dropped, [Activity::Nap]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "schedule window must not be empty nor extend past the end of the day")]
+fn window_past_end_of_day_panics() {
+    let _ = ScheduleGenerator::new().with_activity(Activity::Nap, 23 * HOUR..25 * HOUR, 30..60);
+}
@@ -0,0 +1,103 @@
+use bevy_turborand::SeedMixer;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mixed_seeds_are_pinned() {
+    // These vectors must never change, or generated chunks would change between releases.
+    assert_eq!(SeedMixer::new(12345).finish(), 12345);
+    assert_eq!(SeedMixer::new(12345).mix(2).finish(), 3795883200167374461);
+    assert_eq!(
+        SeedMixer::new(12345).mix_str("SPICY-MANGO-42").finish(),
+        935380145936238633
+    );
+    assert_eq!(
+        SeedMixer::new(12345)
+            .mix_str("SPICY-MANGO-42")
+            .mix(2)
+            .mix(3)
+            .mix(-7i64 as u64)
+            .finish(),
+        15777921526933363351
+    );
+    assert_eq!(
+        SeedMixer::new(0).mix_bytes(&[]).finish(),
+        3806384473051541158
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mixed_chacha_seeds_are_pinned() {
+    assert_eq!(
+        SeedMixer::new(12345).mix(2).finish_chacha(),
+        [
+            82, 157, 222, 215, 133, 209, 243, 115, 146, 154, 223, 253, 255, 62, 134, 171, 224, 18,
+            179, 220, 127, 134, 82, 238, 215, 68, 58, 1, 164, 203, 38, 139, 95, 177, 182, 175, 9,
+            134, 68, 4
+        ]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mixing_order_matters() {
+    let base = SeedMixer::new(12345);
+
+    assert_ne!(base.mix(1).mix(2).finish(), base.mix(2).mix(1).finish());
+    assert_ne!(
+        base.mix_str("north").mix_str("east").finish(),
+        base.mix_str("east").mix_str("north").finish()
+    );
+    assert_ne!(
+        base.mix_str("chunk").mix(7).finish(),
+        base.mix(7).mix_str("chunk").finish()
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn strings_mix_the_same_as_their_bytes() {
+    let base = SeedMixer::new(12345);
+
+    for value in [
+        "",
+        "a",
+        "SPICY-MANGO-42",
+        "a string longer than a single word ✨",
+    ] {
+        assert_eq!(base.mix_str(value), base.mix_bytes(value.as_bytes()));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn strings_mix_their_length() {
+    let base = SeedMixer::new(12345);
+
+    assert_ne!(base.mix_str("a").finish(), base.mix_str("a\0").finish());
+    assert_ne!(base.mix_str("").finish(), base.finish());
+}
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rngs_with_mixed_seeds() {
+    use bevy_turborand::prelude::*;
+
+    let mixer = SeedMixer::new(12345).mix_str("SPICY-MANGO-42").mix(2);
+
+    assert_eq!(
+        RngComponent::with_mixed_seed(mixer),
+        RngComponent::with_seed(mixer.finish())
+    );
+    assert_eq!(
+        GlobalRng::with_mixed_seed(mixer),
+        GlobalRng::with_seed(mixer.finish())
+    );
+}
@@ -0,0 +1,133 @@
+#![cfg(feature = "wyrand")]
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+use common::with_warnings;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Enemy;
+
+fn spawn_player(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+    commands.spawn((Player, RngComponent::from(&mut global)));
+}
+
+fn spawn_enemies(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+    for _ in 0..2 {
+        commands.spawn((Enemy, RngComponent::from(&mut global)));
+    }
+}
+
+fn spawn_scenery(mut commands: Commands) {
+    commands.spawn(Name::new("Tree"));
+}
+
+fn player_roll(app: &mut App) -> u64 {
+    let mut q_player = app
+        .world_mut()
+        .query_filtered::<&mut RngComponent, With<Player>>();
+
+    q_player.single_mut(app.world_mut()).u64(..)
+}
+
+#[test]
+#[should_panic(expected = "are both in RngSystems::SeedSpawning without being ordered")]
+fn unordered_seeding_systems_panic_in_strict_mode() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5).strict_ordering(true))
+        .add_systems(
+            Startup,
+            (spawn_player, spawn_enemies).in_set(RngSystems::SeedSpawning),
+        );
+
+    app.update();
+}
+
+#[test]
+fn strict_mode_panics_before_anything_is_seeded() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5).strict_ordering(true))
+        .add_systems(
+            Startup,
+            (spawn_player, spawn_enemies).in_set(RngSystems::SeedSpawning),
+        );
+
+    let update = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| app.update()));
+
+    assert!(update.is_err());
+    assert_eq!(
+        app.world().resource::<GlobalRng>(),
+        &GlobalRng::with_seed(5)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn unordered_seeding_systems_only_warn_by_default() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5))
+        .add_systems(
+            Startup,
+            (spawn_player, spawn_enemies).in_set(RngSystems::SeedSpawning),
+        );
+
+    let ((), warnings) = with_warnings(|| {
+        app.update();
+        app.update();
+    });
+
+    assert_eq!(warnings.len(), 1, "{warnings:?}");
+    assert!(warnings[0].contains("spawn_player"), "{}", warnings[0]);
+    assert!(warnings[0].contains("spawn_enemies"), "{}", warnings[0]);
+    assert!(
+        warnings[0].contains("RngSystems::SeedSpawning"),
+        "{}",
+        warnings[0]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chained_seeding_systems_pass_in_strict_mode() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5).strict_ordering(true))
+        .add_systems(
+            Startup,
+            ((spawn_player, spawn_enemies).chain(), spawn_scenery).in_set(RngSystems::SeedSpawning),
+        );
+
+    app.update();
+
+    let mut global = GlobalRng::with_seed(5);
+
+    assert_eq!(
+        player_roll(&mut app),
+        RngComponent::from(&mut global).u64(..)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn systems_outside_the_set_are_not_checked() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5).strict_ordering(true))
+        .add_systems(Startup, spawn_player.in_set(RngSystems::SeedSpawning))
+        .add_systems(Startup, spawn_enemies);
+
+    app.update();
+}
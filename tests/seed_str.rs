@@ -0,0 +1,90 @@
+use bevy_turborand::prelude::*;
+use bevy_turborand::{chacha_seed_from_str, seed_from_str, EMPTY_SEED};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seed_strings_are_pinned() {
+    // These vectors must never change, or shared seeds would stop meaning the same thing.
+    assert_eq!(seed_from_str("SPICY-MANGO-42"), 1704106425841164652);
+    assert_eq!(seed_from_str("spicy-mango-42"), 6460384662730849297);
+    assert_eq!(seed_from_str("a"), 18132234018526297402);
+    assert_eq!(
+        seed_from_str("a longer seed string spanning several words"),
+        5744197355113002678
+    );
+    assert_eq!(seed_from_str(""), EMPTY_SEED);
+    assert_eq!(EMPTY_SEED, 0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn hex_seed_strings_map_to_their_value() {
+    assert_eq!(seed_from_str("0x2a"), 42);
+    assert_eq!(seed_from_str("0xFFFFFFFFFFFFFFFF"), u64::MAX);
+
+    let seed = seed_from_str("SPICY-MANGO-42");
+
+    assert_eq!(seed_from_str(&format!("{seed:#x}")), seed);
+
+    // Not valid hex seeds, so they are hashed instead.
+    assert_ne!(seed_from_str("0x"), 0);
+    assert_ne!(seed_from_str("0x+2a"), 42);
+    assert_ne!(seed_from_str("0x10000000000000000"), 0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_seed_strings_are_pinned() {
+    assert_eq!(
+        chacha_seed_from_str("SPICY-MANGO-42"),
+        [
+            247, 78, 228, 252, 58, 12, 155, 124, 218, 78, 57, 31, 59, 53, 48, 228, 48, 143, 164,
+            196, 80, 204, 60, 29, 222, 205, 114, 98, 62, 110, 48, 38, 128, 72, 106, 250, 248, 82,
+            200, 67
+        ]
+    );
+}
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn global_rng_from_seed_str() {
+    use bevy::prelude::*;
+
+    let mut expected = GlobalRng::with_seed(seed_from_str("SPICY-MANGO-42"));
+
+    assert_eq!(GlobalRng::from_seed_str("SPICY-MANGO-42"), expected);
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed_str("SPICY-MANGO-42"));
+
+    let mut global = app.world_mut().resource_mut::<GlobalRng>();
+
+    assert_eq!(global.u64(..), expected.u64(..));
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn global_chacha_rng_from_seed_str() {
+    use bevy::prelude::*;
+
+    let mut expected = GlobalChaChaRng::with_seed(chacha_seed_from_str("SPICY-MANGO-42"));
+
+    assert_eq!(GlobalChaChaRng::from_seed_str("SPICY-MANGO-42"), expected);
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_chacha_seed_str("SPICY-MANGO-42"));
+
+    let mut global = app.world_mut().resource_mut::<GlobalChaChaRng>();
+
+    assert_eq!(global.u64(..), expected.u64(..));
+}
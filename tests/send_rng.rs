@@ -0,0 +1,104 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy::tasks::{block_on, poll_once, AsyncComputeTaskPool, Task, TaskPool};
+use bevy_turborand::prelude::*;
+use std::collections::BTreeMap;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Component)]
+struct ChunkTask(u32, Task<Vec<u32>>);
+
+#[derive(Resource, Default)]
+struct Chunks(BTreeMap<u32, Vec<u32>>);
+
+/// Generates the heights of a chunk, the same way inside and outside of tasks.
+fn chunk_heights(rng: &impl TurboRand) -> Vec<u32> {
+    (0..16).map(|_| rng.u32(0..100)).collect()
+}
+
+fn spawn_chunk_tasks(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+    let pool = AsyncComputeTaskPool::get();
+
+    for chunk in 0..4 {
+        let rng = global.fork_send();
+        let task = pool.spawn(async move { chunk_heights(&rng) });
+
+        commands.spawn(ChunkTask(chunk, task));
+    }
+}
+
+fn poll_chunk_tasks(
+    mut commands: Commands,
+    mut q_tasks: Query<(Entity, &mut ChunkTask)>,
+    mut chunks: ResMut<Chunks>,
+) {
+    for (entity, mut task) in q_tasks.iter_mut() {
+        if let Some(heights) = block_on(poll_once(&mut task.1)) {
+            chunks.0.insert(task.0, heights);
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chunk_tasks_match_a_single_threaded_reference() {
+    AsyncComputeTaskPool::get_or_init(TaskPool::default);
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5))
+        .init_resource::<Chunks>()
+        .add_systems(Startup, spawn_chunk_tasks)
+        .add_systems(Update, poll_chunk_tasks);
+
+    for _ in 0..10 {
+        app.update();
+
+        if app.world().resource::<Chunks>().0.len() == 4 {
+            break;
+        }
+    }
+
+    let mut reference = GlobalRng::with_seed(5);
+    let expected: BTreeMap<u32, Vec<u32>> = (0..4)
+        .map(|chunk| (chunk, chunk_heights(&reference.fork())))
+        .collect();
+
+    assert_eq!(app.world().resource::<Chunks>().0, expected);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn send_rngs_are_the_forked_sources() {
+    let mut global = GlobalRng::with_seed(5);
+    let mut expected = GlobalRng::with_seed(5);
+
+    let rng = global.fork_send();
+
+    assert_eq!(rng.clone().into_inner(), expected.fork());
+    assert_eq!(global, expected);
+    assert_eq!(rng, SendRng::new(GlobalRng::with_seed(5).fork()));
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_sources_are_sendable_too() {
+    let mut global = GlobalChaChaRng::with_seed([3; 40]);
+    let mut expected = GlobalChaChaRng::with_seed([3; 40]);
+
+    let rng = global.fork_send();
+
+    let rolls = std::thread::spawn(move || chunk_heights(&rng))
+        .join()
+        .unwrap();
+
+    assert_eq!(rolls, chunk_heights(&expected.fork()));
+}
@@ -0,0 +1,116 @@
+#![cfg(all(feature = "wyrand", feature = "bevy_math"))]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// Chi-squared statistic of `points` in the unit square, bucketed into a 16 by 16 grid.
+fn grid_chi_squared(points: &[Vec2]) -> f32 {
+    let mut counts = [0u32; 256];
+
+    for point in points {
+        let x = (point.x * 16.0) as usize;
+        let y = (point.y * 16.0) as usize;
+
+        counts[y.min(15) * 16 + x.min(15)] += 1;
+    }
+
+    let expected = points.len() as f32 / 256.0;
+
+    counts
+        .iter()
+        .map(|&count| (count as f32 - expected).powi(2) / expected)
+        .sum()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn unscrambled_points_follow_the_halton_sequence() {
+    let mut sequence = HaltonSequence::new(1);
+
+    let points: Vec<Vec3> = (0..4)
+        .map(|_| sequence.next_vec3(0.0..1.0, 0.0..1.0, 0.0..1.0))
+        .collect();
+
+    assert_eq!(
+        points,
+        [
+            Vec3::new(0.5, 1.0 / 3.0, 0.2),
+            Vec3::new(0.25, 2.0 / 3.0, 0.4),
+            Vec3::new(0.75, 1.0 / 9.0, 0.6),
+            Vec3::new(0.125, 4.0 / 9.0, 0.8),
+        ]
+    );
+    assert_eq!(sequence.index(), 5);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seeded_sequences_are_deterministic() {
+    let points = |seed| {
+        let mut rng = RngComponent::with_seed(seed);
+        let mut sequence = HaltonSequence::from_rng(&mut rng).scrambled(&mut rng);
+
+        (0..3)
+            .map(|_| sequence.next_vec2(0.0..1.0, 0.0..1.0))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(points(5), points(5));
+    assert_ne!(points(5), points(6));
+    assert_eq!(
+        points(5),
+        [
+            Vec2::new(0.8427543, 0.15002312),
+            Vec2::new(0.21775432, 0.48335645),
+            Vec2::new(0.7177543, 0.7055787),
+        ]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn points_are_mapped_into_their_ranges() {
+    let mut rng = RngComponent::with_seed(2);
+
+    for mut sequence in [
+        HaltonSequence::from_rng(&mut rng),
+        HaltonSequence::from_rng(&mut rng).scrambled(&mut rng),
+    ] {
+        for _ in 0..1000 {
+            let point = sequence.next_vec3(-10.0..10.0, 5.0..6.0, -3.0..-1.0);
+
+            assert!((-10.0..=10.0).contains(&point.x), "{point}");
+            assert!((5.0..=6.0).contains(&point.y), "{point}");
+            assert!((-3.0..=-1.0).contains(&point.z), "{point}");
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn points_are_better_stratified_than_uniform_ones() {
+    let mut rng = RngComponent::with_seed(9);
+
+    let uniform: Vec<Vec2> = (0..1024).map(|_| Vec2::new(rng.f32(), rng.f32())).collect();
+
+    let mut plain = HaltonSequence::from_rng(&mut rng);
+    let mut scrambled = HaltonSequence::from_rng(&mut rng).scrambled(&mut rng);
+
+    let plain: Vec<Vec2> = (0..1024)
+        .map(|_| plain.next_vec2(0.0..1.0, 0.0..1.0))
+        .collect();
+    let scrambled: Vec<Vec2> = (0..1024)
+        .map(|_| scrambled.next_vec2(0.0..1.0, 0.0..1.0))
+        .collect();
+
+    let uniform = grid_chi_squared(&uniform);
+
+    assert!(grid_chi_squared(&plain) < uniform / 2.0);
+    assert!(grid_chi_squared(&scrambled) < uniform / 2.0);
+}
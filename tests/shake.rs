@@ -0,0 +1,118 @@
+#![cfg(all(feature = "wyrand", feature = "bevy_math"))]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+#[cfg(feature = "testing")]
+use bevy_turborand::stats::chi_squared_uniform;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn sampler(seed: u64) -> ShakeSampler {
+    ShakeSampler::from_rng(&mut RngComponent::with_seed(seed))
+        .with_amplitude(2.0)
+        .with_frequency(10.0)
+        .with_decay(1.5)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn same_seed_same_curve() {
+    let a = sampler(12345);
+    let b = sampler(12345);
+
+    for step in 0..200 {
+        let t = step as f32 * 0.0173;
+
+        assert_eq!(a.offset(t), b.offset(t));
+        assert_eq!(a.offset_3d(t), b.offset_3d(t));
+        assert_eq!(a.rotation(t), b.rotation(t));
+    }
+
+    assert_ne!(sampler(12345).offset(0.35), sampler(54321).offset(0.35));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pinned_curve() {
+    let shake = sampler(12345);
+
+    assert_eq!(shake.offset(0.0), Vec2::new(-1.0396607, -0.33434105));
+    assert_eq!(shake.offset(0.25), Vec2::new(-0.7835454, -0.05970823));
+    assert_eq!(shake.rotation(0.25), 0.007395608);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn frame_rate_independent() {
+    let shake = sampler(777);
+
+    // Sample the same second of shake with a 64 fps, a 32 fps and an irregular frame pacing.
+    let fast: Vec<(f32, Vec2)> = (0..=64)
+        .map(|frame| frame as f32 / 64.0)
+        .map(|t| (t, shake.offset(t)))
+        .collect();
+    let slow: Vec<(f32, Vec2)> = (0..=32)
+        .map(|frame| frame as f32 / 32.0)
+        .map(|t| (t, shake.offset(t)))
+        .collect();
+
+    for (t, offset) in slow.iter() {
+        let (_, matching) = fast.iter().find(|(fast_t, _)| fast_t == t).unwrap();
+
+        assert_eq!(offset, matching);
+    }
+
+    let mut elapsed = 0.0;
+    for delta in [0.015625, 0.03125, 0.046875, 0.0625, 0.015625]
+        .iter()
+        .cycle()
+        .take(20)
+    {
+        elapsed += delta;
+
+        let (_, matching) = fast.iter().find(|(fast_t, _)| *fast_t == elapsed).unwrap();
+
+        assert_eq!(&shake.offset(elapsed), matching);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn curve_is_continuous_and_decays() {
+    let shake = sampler(99);
+
+    let mut previous = shake.offset(0.0);
+
+    for step in 1..1000 {
+        let t = step as f32 * 0.001;
+        let offset = shake.offset(t);
+
+        assert!(offset.abs().max_element() <= 2.0);
+        assert!((offset - previous).length() < 0.1, "jump at {t}");
+
+        previous = offset;
+    }
+
+    assert!(shake.offset(10.0).length() < 2.0 * shake.envelope(10.0) * 2.0f32.sqrt());
+    assert!(shake.envelope(10.0) < 1e-6);
+}
+
+#[cfg(feature = "testing")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn lattice_values_are_uniform() {
+    // With a frequency of 1.0, whole seconds land exactly on the lattice points,
+    // which are uniformly spread over the `-1.0..1.0` range.
+    let shake = ShakeSampler::from_rng(&mut RngComponent::with_seed(5))
+        .with_frequency(1.0)
+        .with_max_rotation(1.0);
+
+    chi_squared_uniform(
+        (0..10_000).map(|second| ((shake.rotation(second as f32) + 1.0) * 4.0) as usize),
+        8,
+    );
+}
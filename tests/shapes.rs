@@ -0,0 +1,203 @@
+#![cfg(all(feature = "shapes", feature = "wyrand"))]
+
+use bevy::math::{
+    primitives::{Annulus, Circle, Cuboid, Rectangle, Sphere, Triangle2d},
+    Vec2, Vec3,
+};
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+const SAMPLES: usize = 1000;
+const EPSILON: f32 = 1e-4;
+
+fn interior<S: SampleShape>(shape: &S, seed: u64) -> Vec<S::Output> {
+    let mut rng = RngComponent::with_seed(seed);
+
+    (0..SAMPLES)
+        .map(|_| shape.sample_interior(&mut rng))
+        .collect()
+}
+
+fn boundary<S: SampleShape>(shape: &S, seed: u64) -> Vec<S::Output> {
+    let mut rng = RngComponent::with_seed(seed);
+
+    (0..SAMPLES)
+        .map(|_| shape.sample_boundary(&mut rng))
+        .collect()
+}
+
+/// Returns the barycentric coordinates of `point` relative to the triangle.
+fn barycentric(triangle: &Triangle2d, point: Vec2) -> Vec3 {
+    let [a, b, c] = triangle.vertices;
+
+    let area = (b - a).perp_dot(c - a);
+    let u = (c - b).perp_dot(point - b) / area;
+    let v = (a - c).perp_dot(point - c) / area;
+
+    Vec3::new(u, v, 1.0 - u - v)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn circle_samples() {
+    let circle = Circle::new(3.0);
+
+    assert!(interior(&circle, 1)
+        .iter()
+        .all(|point| point.length() <= 3.0 + EPSILON));
+    assert!(boundary(&circle, 1)
+        .iter()
+        .all(|point| (point.length() - 3.0).abs() < EPSILON));
+
+    // Uniform over the area, so a quarter of the points lie within half the radius.
+    let inner = interior(&circle, 2)
+        .iter()
+        .filter(|point| point.length() < 1.5)
+        .count();
+
+    assert!((200..300).contains(&inner), "{inner}");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn annulus_samples() {
+    let annulus = Annulus::new(1.0, 3.0);
+
+    assert!(interior(&annulus, 1)
+        .iter()
+        .all(|point| (1.0 - EPSILON..=3.0 + EPSILON).contains(&point.length())));
+
+    let edges = boundary(&annulus, 1);
+
+    assert!(edges.iter().all(|point| {
+        (point.length() - 1.0).abs() < EPSILON || (point.length() - 3.0).abs() < EPSILON
+    }));
+
+    // The outer circle is three times as long as the inner one.
+    let outer = edges.iter().filter(|point| point.length() > 2.0).count();
+
+    assert!((700..800).contains(&outer), "{outer}");
+
+    // Weighted by area, so only 5 / 8 of the points lie past a radius of 2.
+    let outer = interior(&annulus, 2)
+        .iter()
+        .filter(|point| point.length() > 2.0)
+        .count();
+
+    assert!((575..675).contains(&outer), "{outer}");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rectangle_samples() {
+    let rectangle = Rectangle::new(4.0, 2.0);
+
+    assert!(interior(&rectangle, 1)
+        .iter()
+        .all(|point| point.abs().cmple(Vec2::new(2.0, 1.0)).all()));
+
+    let edges = boundary(&rectangle, 1);
+
+    assert!(edges.iter().all(|point| {
+        point.abs().cmple(Vec2::new(2.0, 1.0)).all()
+            && ((point.x.abs() - 2.0).abs() < EPSILON || (point.y.abs() - 1.0).abs() < EPSILON)
+    }));
+
+    // The horizontal sides make up two thirds of the perimeter.
+    let horizontal = edges
+        .iter()
+        .filter(|point| (point.y.abs() - 1.0).abs() < EPSILON)
+        .count();
+
+    assert!((620..720).contains(&horizontal), "{horizontal}");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn triangle_samples() {
+    let triangle = Triangle2d::new(
+        Vec2::new(-1.0, 0.0),
+        Vec2::new(4.0, 0.5),
+        Vec2::new(0.0, 3.0),
+    );
+
+    assert!(interior(&triangle, 1)
+        .iter()
+        .all(|&point| barycentric(&triangle, point)
+            .cmpge(Vec3::splat(-EPSILON))
+            .all()));
+    assert!(boundary(&triangle, 1).iter().all(|&point| {
+        let coords = barycentric(&triangle, point);
+
+        coords.cmpge(Vec3::splat(-EPSILON)).all() && coords.min_element() < EPSILON
+    }));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sphere_samples() {
+    let sphere = Sphere::new(2.0);
+
+    assert!(interior(&sphere, 1)
+        .iter()
+        .all(|point| point.length() <= 2.0 + EPSILON));
+    assert!(boundary(&sphere, 1)
+        .iter()
+        .all(|point| (point.length() - 2.0).abs() < EPSILON));
+
+    // Uniform over the volume, so an eighth of the points lie within half the radius.
+    let inner = interior(&sphere, 2)
+        .iter()
+        .filter(|point| point.length() < 1.0)
+        .count();
+
+    assert!((90..160).contains(&inner), "{inner}");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cuboid_samples() {
+    let cuboid = Cuboid::new(2.0, 4.0, 8.0);
+    let half = Vec3::new(1.0, 2.0, 4.0);
+
+    assert!(interior(&cuboid, 1)
+        .iter()
+        .all(|point| point.abs().cmple(half).all()));
+
+    let faces = boundary(&cuboid, 1);
+
+    assert!(faces.iter().all(|point| {
+        point.abs().cmple(half).all() && (point.abs() - half).abs().min_element() < EPSILON
+    }));
+
+    // The faces facing along x make up 32 of every 56 units of surface area.
+    let along_x = faces
+        .iter()
+        .filter(|point| (point.x.abs() - 1.0).abs() < EPSILON)
+        .count();
+
+    assert!((520..630).contains(&along_x), "{along_x}");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seeded_samples_are_deterministic() {
+    let circle = Circle::new(1.0);
+    let cuboid = Cuboid::new(1.0, 2.0, 3.0);
+
+    assert_eq!(interior(&circle, 7), interior(&circle, 7));
+    assert_eq!(boundary(&cuboid, 7), boundary(&cuboid, 7));
+    assert_ne!(interior(&circle, 7), interior(&circle, 8));
+
+    let mut rng = RngComponent::with_seed(7);
+
+    assert_eq!(
+        circle.sample_interior(&mut rng),
+        Vec2::new(0.7014158, -0.52242106)
+    );
+}
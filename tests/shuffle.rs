@@ -0,0 +1,111 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+// The outputs below are pinned, and the same on every platform, including wasm32.
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn shuffles_are_pinned() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut values: Vec<u32> = (0..10).collect();
+
+    rng.shuffle(&mut values);
+
+    assert_eq!(values, [0, 8, 5, 1, 3, 9, 4, 7, 6, 2]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn prefix_shuffles_are_pinned() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut values: Vec<u32> = (0..10).collect();
+
+    let (shuffled, rest) = rng.shuffle_prefix(&mut values, 4);
+
+    assert_eq!(shuffled, [2, 7, 9, 1]);
+    assert_eq!(rest.len(), 6);
+    assert_eq!(values, [2, 7, 9, 1, 4, 5, 6, 3, 8, 0]);
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_shuffles_are_pinned() {
+    let mut rng = ChaChaRngComponent::with_seed([3; 40]);
+    let mut values: Vec<u32> = (0..10).collect();
+
+    rng.shuffle(&mut values);
+
+    assert_eq!(values, [7, 3, 5, 0, 1, 4, 9, 2, 8, 6]);
+
+    let mut values: Vec<u32> = (0..10).collect();
+
+    rng.shuffle_prefix(&mut values, 3);
+
+    assert_eq!(values, [2, 4, 5, 3, 1, 0, 6, 7, 8, 9]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn prefix_shuffles_draw_once_per_position() {
+    let mut rng = RngComponent::with_seed(7);
+    let mut manual = rng.clone();
+    let mut values: Vec<u32> = (0..100).collect();
+
+    rng.shuffle_prefix(&mut values, 5);
+
+    for index in 0..5 {
+        manual.index(index..100);
+    }
+
+    assert_eq!(rng, manual);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn prefix_shuffles_keep_every_element() {
+    let mut rng = RngComponent::with_seed(11);
+
+    for amount in [0, 1, 5, 10, 20] {
+        let mut values: Vec<u32> = (0..10).collect();
+
+        let (shuffled, rest) = rng.shuffle_prefix(&mut values, amount);
+
+        assert_eq!(shuffled.len(), amount.min(10));
+        assert_eq!(rest.len(), 10 - amount.min(10));
+
+        values.sort_unstable();
+
+        assert_eq!(values, (0..10).collect::<Vec<_>>());
+    }
+
+    let mut empty: [u32; 0] = [];
+    let mut single = [1];
+
+    assert_eq!(rng.shuffle_prefix(&mut empty, 3).0, []);
+    assert_eq!(rng.shuffle_prefix(&mut single, 3).0, [1]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn partial_shuffles_keep_delegating() {
+    let mut rng = RngComponent::with_seed(5);
+    let source = rng.clone().into_inner();
+    let mut values: Vec<u32> = (0..10).collect();
+    let mut expected = values.clone();
+
+    let (shuffled, rest) = rng.partial_shuffle(&mut values, 4);
+    let (expected_shuffled, expected_rest) = source.partial_shuffle(&mut expected, 4);
+
+    assert_eq!(shuffled, expected_shuffled);
+    assert_eq!(rest, expected_rest);
+    assert_eq!(shuffled.len(), 4);
+    assert_eq!(rng.into_inner(), source);
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::{prelude::*, utils::Duration};
+use bevy_turborand::prelude::*;
+use std::sync::{Arc, Mutex};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+const CELLS: usize = 2048;
+
+#[derive(Debug, Resource, Default)]
+struct Completed(Vec<Entity>);
+
+fn record_completion(
+    mut events: EventReader<'_, '_, GenerationComplete>,
+    mut completed: ResMut<'_, Completed>,
+) {
+    completed.0.extend(events.read().map(|event| event.entity));
+}
+
+/// A generator filling in cells one at a time, drawing a variable amount per cell.
+fn fill_cells(
+    cells: Arc<Mutex<Vec<u32>>>,
+) -> impl FnMut(&mut Rng, Budget) -> GenProgress + Send + Sync + 'static {
+    move |rng, _budget| {
+        let mut cells = cells.lock().unwrap();
+
+        let rolls = rng.usize(1..=3);
+        let cell = (0..rolls).map(|_| rng.u32(..16)).sum();
+        cells.push(cell);
+
+        if cells.len() < CELLS {
+            GenProgress::Pending
+        } else {
+            GenProgress::Complete
+        }
+    }
+}
+
+fn generate(budget: Duration) -> (Vec<u32>, usize) {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(7))
+        .init_resource::<Completed>()
+        .add_systems(
+            Update,
+            record_completion.after(RngSystems::SlicedGeneration),
+        );
+
+    let cells = Arc::new(Mutex::new(Vec::new()));
+
+    let generator = {
+        let mut global = app.world_mut().resource_mut::<GlobalRng>();
+        SlicedGenerator::new(&mut *global, fill_cells(cells.clone())).with_budget(budget)
+    };
+
+    let entity = app.world_mut().spawn(generator).id();
+
+    let mut frames = 0;
+
+    while !app
+        .world()
+        .get::<SlicedGenerator>(entity)
+        .unwrap()
+        .is_finished()
+    {
+        // Other systems drawing from the global RNG in between frames.
+        app.world_mut().resource_mut::<GlobalRng>().u64(..);
+        app.update();
+        frames += 1;
+    }
+
+    assert_eq!(app.world().resource::<Completed>().0, vec![entity]);
+
+    app.update();
+
+    // Completion is only reported once.
+    assert_eq!(app.world().resource::<Completed>().0, vec![entity]);
+
+    let cells = cells.lock().unwrap().clone();
+
+    (cells, frames)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn output_is_independent_of_budget() {
+    let (sliced, sliced_frames) = generate(Duration::ZERO);
+    let (whole, whole_frames) = generate(Duration::from_secs(60));
+
+    assert_eq!(sliced_frames, CELLS);
+    assert_eq!(whole_frames, 1);
+    assert_eq!(sliced.len(), CELLS);
+    assert_eq!(sliced, whole);
+    assert_eq!(&sliced[..4], &[7, 23, 18, 27]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn finished_generators_are_not_resumed() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(7));
+
+    let calls = Arc::new(Mutex::new(0));
+
+    let generator = SlicedGenerator::with_seed(42, {
+        let calls = calls.clone();
+        move |_, _| {
+            *calls.lock().unwrap() += 1;
+            GenProgress::Complete
+        }
+    });
+
+    let entity = app.world_mut().spawn(generator).id();
+
+    app.update();
+    app.update();
+
+    assert!(app
+        .world()
+        .get::<SlicedGenerator>(entity)
+        .unwrap()
+        .is_finished());
+    assert_eq!(*calls.lock().unwrap(), 1);
+}
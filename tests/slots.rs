@@ -0,0 +1,151 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn character() -> SlotRoller<&'static str> {
+    SlotRoller::new()
+        .with_item("left_shoulder", "pauldron", 2.0, Some("armor"))
+        .with_item("left_shoulder", "spikes", 1.0, Some("armor"))
+        .with_item("right_shoulder", "pauldron", 2.0, Some("armor"))
+        .with_item("right_shoulder", "spikes", 1.0, Some("armor"))
+        .with_item("brow", "scar", 1.0, Some("face"))
+        .with_item("brow", "piercing", 1.0, Some("face"))
+        .with_item("cheek", "scar", 1.0, Some("face"))
+        .with_item("cheek", "tattoo", 1.0, Some("face"))
+        .with_item("chin", "beard", 1.0, Some("face"))
+        .with_item("chin", "scar", 1.0, Some("face"))
+        .with_item("back", "cape", 1.0, None)
+        .with_exclusion("left_shoulder", "spikes", "right_shoulder", "pauldron")
+        .with_exclusion("left_shoulder", "pauldron", "right_shoulder", "spikes")
+        .with_exclusion("brow", "scar", "cheek", "scar")
+        .with_cap("face", 2)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seeded_assignment_is_pinned() {
+    let mut rng = RngComponent::with_seed(5);
+
+    let assignment = character().roll(&mut rng);
+
+    let assigned: Vec<(&str, &str)> = assignment
+        .assigned
+        .iter()
+        .map(|(slot, item)| (slot.as_str(), *item))
+        .collect();
+
+    assert_eq!(
+        assigned,
+        [
+            ("left_shoulder", "pauldron"),
+            ("right_shoulder", "pauldron"),
+            ("brow", "piercing"),
+            ("cheek", "tattoo"),
+            ("back", "cape"),
+        ]
+    );
+    assert_eq!(assignment.empty, ["chin"]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn constraints_are_never_violated() {
+    let roller = character();
+
+    for seed in 0..1000 {
+        let mut rng = RngComponent::with_seed(seed);
+
+        let assignment = roller.roll(&mut rng);
+
+        // Every slot is either assigned or reported empty, in resolution order.
+        let resolved = assignment.assigned.len() + assignment.empty.len();
+
+        assert_eq!(resolved, roller.slots().len());
+
+        for rule in roller.exclusions() {
+            let both = assignment.get(&rule.first.slot) == Some(&rule.first.item)
+                && assignment.get(&rule.second.slot) == Some(&rule.second.item);
+
+            assert!(!both, "seed {seed} violated {rule:?}");
+        }
+
+        let faces = assignment
+            .assigned
+            .iter()
+            .filter(|(slot, _)| ["brow", "cheek", "chin"].contains(&slot.as_str()))
+            .count();
+
+        assert!(faces <= 2, "seed {seed} rolled {faces} face items");
+
+        // Only the face cap can leave slots empty in this roller.
+        assert_eq!(assignment.empty.len(), 3 - faces);
+
+        // The shoulders always match, as the exclusions rule out mismatched armor.
+        assert_eq!(
+            assignment.get("left_shoulder"),
+            assignment.get("right_shoulder")
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn exclusions_renormalise_remaining_items() {
+    let roller = SlotRoller::new()
+        .with_item("left", "a", 1.0, None)
+        .with_item("right", "a", 1000.0, None)
+        .with_item("right", "b", 1.0, None)
+        .with_exclusion("left", "a", "right", "a");
+
+    let mut rng = RngComponent::with_seed(5);
+
+    for _ in 0..20 {
+        let assignment = roller.roll(&mut rng);
+
+        assert_eq!(assignment.get("right"), Some(&"b"));
+    }
+
+    let blocked = SlotRoller::new()
+        .with_item("left", "a", 1.0, None)
+        .with_item("right", "a", 1.0, None)
+        .with_exclusion("right", "a", "left", "a");
+
+    let assignment = blocked.roll(&mut rng);
+
+    assert_eq!(assignment.assigned, vec![("left".to_owned(), "a")]);
+    assert_eq!(assignment.empty, vec!["right".to_owned()]);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rollers_can_be_authored_in_ron() {
+    let authored: SlotRoller<String> = ron::from_str(
+        r#"(
+            slots: [
+                (name: "left", items: [(id: "a", weight: 1.0, category: Some("armor"))]),
+                (name: "right", items: [(id: "a", weight: 1.0), (id: "b", weight: 2.0)]),
+            ],
+            exclusions: [
+                (first: (slot: "left", item: "a"), second: (slot: "right", item: "a")),
+            ],
+            caps: {"armor": 1},
+        )"#,
+    )
+    .unwrap();
+
+    let expected = SlotRoller::new()
+        .with_item("left", "a".to_owned(), 1.0, Some("armor"))
+        .with_item("right", "a".to_owned(), 1.0, None)
+        .with_item("right", "b".to_owned(), 2.0, None)
+        .with_exclusion("left", "a".to_owned(), "right", "a".to_owned())
+        .with_cap("armor", 1);
+
+    assert_eq!(authored, expected);
+}
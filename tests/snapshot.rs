@@ -0,0 +1,155 @@
+#![cfg(all(feature = "wyrand", feature = "serialize"))]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, Copy, PartialEq, Component, serde::Serialize, serde::Deserialize)]
+struct NpcId(u32);
+
+fn roll(mut global: ResMut<GlobalRng>, mut q_rngs: Query<&mut RngComponent>) {
+    global.u64(..);
+
+    for mut rng in q_rngs.iter_mut() {
+        rng.u64(..);
+    }
+}
+
+fn setup() -> (App, Vec<Entity>) {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5))
+        .add_systems(Update, roll);
+
+    let npcs = (0..3)
+        .map(|id| {
+            let rng = RngComponent::from(&mut *app.world_mut().resource_mut::<GlobalRng>());
+
+            app.world_mut().spawn((NpcId(id), rng)).id()
+        })
+        .collect();
+
+    (app, npcs)
+}
+
+fn outputs(world: &mut World, npcs: &[Entity]) -> Vec<u64> {
+    let mut outputs = vec![world.resource_mut::<GlobalRng>().u64(..)];
+
+    for &npc in npcs {
+        outputs.push(world.get_mut::<RngComponent>(npc).unwrap().u64(..));
+    }
+
+    outputs
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn restoring_a_snapshot_replays_the_same_timeline() {
+    let (mut app, npcs) = setup();
+
+    app.update();
+    app.update();
+
+    let snapshot = RngWorldSnapshot::capture(app.world_mut());
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let original = outputs(app.world_mut(), &npcs);
+
+    snapshot.apply(app.world_mut()).unwrap();
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert_eq!(outputs(app.world_mut(), &npcs), original);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn snapshots_capture_every_rng() {
+    let (mut app, npcs) = setup();
+
+    let unrelated = app.world_mut().spawn(NpcId(10)).id();
+
+    let snapshot = RngWorldSnapshot::capture(app.world_mut());
+
+    assert_eq!(snapshot.keys().count(), npcs.len());
+    assert!(snapshot.entity(&unrelated).is_none());
+    assert_eq!(
+        snapshot.entity(&npcs[0]).unwrap().rng.as_ref(),
+        app.world().get::<RngComponent>(npcs[0])
+    );
+    assert_eq!(snapshot.global(), Some(app.world().resource::<GlobalRng>()));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn keyed_snapshots_restore_onto_another_world() {
+    let (mut app, npcs) = setup();
+
+    app.update();
+
+    let snapshot = RngWorldSnapshot::<NpcId>::capture_keyed(app.world_mut());
+    let serialized = ron::to_string(&snapshot).unwrap();
+
+    app.update();
+
+    let original = outputs(app.world_mut(), &npcs);
+
+    // A freshly loaded world, with the entities spawned in a different order.
+    let mut loaded = App::new();
+
+    loaded
+        .add_plugins(RngPlugin::new().with_rng_seed(99))
+        .add_systems(Update, roll);
+
+    let mut loaded_npcs: Vec<Entity> = (0..3)
+        .rev()
+        .map(|id| loaded.world_mut().spawn(NpcId(id)).id())
+        .collect();
+    loaded_npcs.reverse();
+
+    let snapshot: RngWorldSnapshot<NpcId> = ron::from_str(&serialized).unwrap();
+
+    snapshot.apply_keyed(loaded.world_mut()).unwrap();
+
+    loaded.update();
+
+    assert_eq!(outputs(loaded.world_mut(), &loaded_npcs), original);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn missing_entities_are_reported_without_restoring() {
+    let (mut app, npcs) = setup();
+
+    let snapshot = RngWorldSnapshot::capture(app.world_mut());
+
+    app.world_mut().despawn(npcs[1]);
+    app.update();
+
+    let advanced = app.world().resource::<GlobalRng>().clone();
+
+    assert_eq!(
+        snapshot.apply(app.world_mut()),
+        Err(SnapshotError::MissingEntities(vec![npcs[1]]))
+    );
+    assert_eq!(app.world().resource::<GlobalRng>(), &advanced);
+
+    let keyed = RngWorldSnapshot::<NpcId>::capture_keyed(app.world_mut());
+
+    app.world_mut().despawn(npcs[2]);
+
+    assert_eq!(
+        keyed.apply_keyed(app.world_mut()),
+        Err(SnapshotError::MissingEntities(vec![NpcId(2)]))
+    );
+}
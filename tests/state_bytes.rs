@@ -0,0 +1,110 @@
+#![cfg(feature = "state-bytes")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rng_state_round_trip() {
+    let mut original = RngComponent::with_seed(12345);
+
+    original.u64(..);
+
+    let bytes = original.state_bytes();
+
+    assert_eq!(bytes.len(), 8);
+
+    let mut restored = RngComponent::new();
+    restored.restore_from_bytes(&bytes).unwrap();
+
+    assert_eq!(original, restored);
+
+    let original_outputs: Vec<u64> = (0..10).map(|_| original.u64(..)).collect();
+    let restored_outputs: Vec<u64> = (0..10).map(|_| restored.u64(..)).collect();
+
+    assert_eq!(original_outputs, restored_outputs);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn global_rng_state_round_trip() {
+    let mut original = GlobalRng::with_seed(54321);
+    let bytes = original.state_bytes();
+
+    assert_eq!(bytes, vec![99, 168, 1, 0, 0, 0, 0, 0]);
+
+    let mut restored = GlobalRng::new();
+    restored.restore_from_bytes(&bytes).unwrap();
+
+    assert_eq!(original.u32(..), restored.u32(..));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rng_state_wrong_length() {
+    let mut rng = RngComponent::with_seed(1);
+    let reference = rng.clone();
+
+    assert_eq!(
+        rng.restore_from_bytes(&[0; 7]),
+        Err(RngStateError::InvalidLength {
+            expected: 8,
+            found: 7
+        })
+    );
+    assert_eq!(rng, reference);
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_state_round_trip() {
+    let mut original = ChaChaRngComponent::with_seed([7; 40]);
+
+    // Partially consume the entropy buffer so the cursor is mid-way.
+    original.u32(..);
+
+    let bytes = original.state_bytes();
+
+    assert_eq!(bytes.len(), 136);
+
+    let mut restored = ChaChaRngComponent::new();
+    restored.restore_from_bytes(&bytes).unwrap();
+
+    assert_eq!(original, restored);
+
+    let original_outputs: Vec<u64> = (0..40).map(|_| original.u64(..)).collect();
+    let restored_outputs: Vec<u64> = (0..40).map(|_| restored.u64(..)).collect();
+
+    assert_eq!(original_outputs, restored_outputs);
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_state_errors() {
+    let mut rng = ChaChaRngComponent::with_seed([1; 40]);
+    let reference = rng.clone();
+
+    assert_eq!(
+        rng.restore_from_bytes(&[0; 40]),
+        Err(RngStateError::InvalidLength {
+            expected: 136,
+            found: 40
+        })
+    );
+
+    let mut bytes = rng.state_bytes();
+    bytes[128] = 65;
+
+    assert_eq!(
+        rng.restore_from_bytes(&bytes),
+        Err(RngStateError::InvalidState)
+    );
+    assert_eq!(rng, reference);
+}
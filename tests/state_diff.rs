@@ -0,0 +1,169 @@
+#![cfg(feature = "state-bytes")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rng_diff_same_stream_advancement() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut replica = rng.clone();
+
+    let baseline = rng.clone();
+
+    for _ in 0..10 {
+        rng.u64(..);
+    }
+
+    let diff = rng.diff(&baseline);
+
+    assert_eq!(diff.change, RngStateChange::Advanced { draws: 10 });
+
+    replica.apply_diff(&diff).unwrap();
+
+    assert_eq!(replica, rng);
+    assert_eq!(replica.u64(..), rng.u64(..));
+}
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rng_diff_reseeded_divergence() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut replica = rng.clone();
+
+    let baseline = rng.clone();
+
+    rng.reseed(12345);
+    rng.u64(..);
+
+    let diff = rng.diff(&baseline);
+
+    assert!(matches!(diff.change, RngStateChange::Full(_)));
+
+    replica.apply_diff(&diff).unwrap();
+
+    assert_eq!(replica, rng);
+}
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rng_diff_unchanged() {
+    let mut rng = GlobalRng::with_seed(5);
+    let baseline = rng.clone();
+
+    let diff = rng.diff(&baseline);
+
+    assert_eq!(diff.change, RngStateChange::Advanced { draws: 0 });
+
+    let mut replica = baseline.clone();
+
+    replica.apply_diff(&diff).unwrap();
+
+    assert_eq!(replica, baseline);
+}
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rng_diff_rejects_mismatched_baseline() {
+    let mut rng = RngComponent::with_seed(5);
+    let baseline = rng.clone();
+
+    rng.u64(..);
+
+    let diff = rng.diff(&baseline);
+
+    let mut stale = baseline.clone();
+    stale.u64(..);
+    stale.u64(..);
+
+    let untouched = stale.clone();
+
+    assert!(matches!(
+        stale.apply_diff(&diff),
+        Err(RngDiffError::BaselineMismatch { .. })
+    ));
+    assert_eq!(stale, untouched);
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_rng_diff_same_stream_advancement() {
+    let mut rng = ChaChaRngComponent::with_seed([3; 40]);
+    let mut replica = rng.clone();
+
+    let mut baseline = rng.clone();
+
+    // Cover advancing within a block, to its exact end, and across many blocks.
+    for draws in [1, 7, 64, 8, 1000, 3] {
+        for _ in 0..draws {
+            rng.u8(..);
+        }
+
+        let diff = rng.diff(&baseline);
+
+        assert_eq!(diff.change, RngStateChange::Advanced { draws });
+
+        replica.apply_diff(&diff).unwrap();
+
+        assert_eq!(replica, rng);
+
+        baseline = rng.clone();
+    }
+
+    assert_eq!(replica.u64(..), rng.u64(..));
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_rng_diff_reseeded_divergence() {
+    let mut rng = GlobalChaChaRng::with_seed([3; 40]);
+    let mut replica = rng.clone();
+
+    let baseline = rng.clone();
+
+    rng.reseed([4; 40]);
+    rng.u64(..);
+
+    let diff = rng.diff(&baseline);
+
+    assert!(matches!(diff.change, RngStateChange::Full(_)));
+
+    replica.apply_diff(&diff).unwrap();
+
+    assert_eq!(replica, rng);
+
+    // Going backwards along the stream is not an advancement either.
+    let diff = baseline.clone().diff(&rng);
+
+    assert!(matches!(diff.change, RngStateChange::Full(_)));
+}
+
+#[cfg(all(feature = "chacha", feature = "serialize"))]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_rng_diff_serializes_compactly() {
+    let mut rng = ChaChaRngComponent::with_seed([3; 40]);
+    let baseline = rng.clone();
+
+    rng.u64(..);
+
+    let diff = rng.diff(&baseline);
+    let serialized = ron::to_string(&diff).unwrap();
+
+    assert!(serialized.len() < 64);
+
+    let deserialized: RngStateDiff = ron::from_str(&serialized).unwrap();
+
+    assert_eq!(deserialized, diff);
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// Draws a few values, enough to tell two streams apart.
+fn stream(rng: &mut impl DelegatedRng) -> Vec<u64> {
+    (0..8).map(|_| rng.u64(..)).collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn promoted_components_continue_as_the_global() {
+    let mut player = RngComponent::with_seed(5);
+
+    player.u32(..);
+
+    let mut global = GlobalRng::from_component(&player);
+
+    assert_eq!(global.initial_seed(), None);
+    assert_eq!(stream(&mut global), stream(&mut player));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn copying_the_global_state_does_not_advance_it() {
+    let mut global = GlobalRng::with_seed(5);
+    let untouched = global.clone();
+
+    let mut copied = RngComponent::from_global_state(&global);
+
+    assert_eq!(global, untouched);
+    assert_eq!(stream(&mut copied), stream(&mut global));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn forking_differs_from_copying() {
+    let mut global = GlobalRng::with_seed(5);
+    let mut copied = RngComponent::from_global_state(&global);
+    let mut forked = RngComponent::from(&mut global);
+
+    assert_ne!(stream(&mut forked), stream(&mut copied));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn swapping_is_symmetric() {
+    let mut player = RngComponent::with_seed(5);
+    let mut global = GlobalRng::with_seed(6);
+
+    player.swap_state(&mut global);
+
+    assert_eq!(player, RngComponent::with_seed(6));
+    assert_eq!(global, GlobalRng::with_seed(5));
+    assert_eq!(global.initial_seed(), Some(6));
+
+    global.swap_state(&mut player);
+
+    assert_eq!(player, RngComponent::with_seed(5));
+    assert_eq!(global, GlobalRng::with_seed(6));
+
+    let mut other = RngComponent::with_seed(7);
+
+    player.swap_state(&mut other);
+
+    assert_eq!(stream(&mut player), stream(&mut RngComponent::with_seed(7)));
+    assert_eq!(stream(&mut other), stream(&mut RngComponent::with_seed(5)));
+}
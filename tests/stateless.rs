@@ -0,0 +1,93 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn hashes_are_pinned() {
+    let stateless = StatelessRng::new(5);
+
+    assert_eq!(
+        [0, 1, 2].map(|key| stateless.hash_u64(key)),
+        [5315567842575892308, 4363103846858642610, 678889507036613377]
+    );
+    assert_eq!(
+        [0, 1, 2].map(|key| stateless.hash_f32(key)),
+        [0.28815746, 0.23652428, 0.03680265]
+    );
+    assert_eq!(
+        stateless.hash_entity(Entity::from_raw(3), 7),
+        5857750235491968527
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn hashes_are_pure_functions_of_seed_and_key() {
+    let stateless = StatelessRng::new(5);
+
+    assert_eq!(stateless.hash_u64(9), stateless.hash_u64(9));
+    assert_eq!(stateless.hash_u64(9), StatelessRng::new(5).hash_u64(9));
+    assert_ne!(stateless.hash_u64(9), StatelessRng::new(6).hash_u64(9));
+    assert_ne!(stateless.hash_u64(9), stateless.hash_u64(10));
+    assert!((0..10_000).all(|key| (0.0..1.0).contains(&stateless.hash_f32(key))));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn respawned_entities_hash_differently() {
+    let mut world = World::new();
+    let stateless = StatelessRng::new(5);
+
+    let entity = world.spawn_empty().id();
+    let before = stateless.hash_entity(entity, 1);
+
+    assert_eq!(stateless.hash_entity(entity, 1), before);
+    assert_ne!(stateless.hash_entity(entity, 2), before);
+    assert_eq!(
+        stateless.hash_entity(Entity::from_bits(entity.to_bits()), 1),
+        before
+    );
+
+    world.despawn(entity);
+
+    let respawned = world.spawn_empty().id();
+
+    // The index is reused, but with a new generation, so the values differ.
+    assert_eq!(respawned.index(), entity.index());
+    assert_ne!(respawned.generation(), entity.generation());
+    assert_ne!(stateless.hash_entity(respawned, 1), before);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn plugin_inserts_a_stateless_rng_from_the_global_seed() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(12345));
+
+    assert_eq!(
+        *app.world().resource::<StatelessRng>(),
+        StatelessRng::new(12345)
+    );
+
+    let mut app = App::new();
+
+    app.insert_resource(GlobalRng::with_seed(5))
+        .add_plugins(RngPlugin::new().with_rng_seed(12345));
+
+    assert_eq!(app.world().resource::<StatelessRng>().seed(), 5);
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(12345).without_resources());
+
+    assert!(!app.world().contains_resource::<StatelessRng>());
+}
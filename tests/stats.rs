@@ -0,0 +1,118 @@
+#![cfg(feature = "testing")]
+
+use bevy_turborand::{
+    prelude::*,
+    stats::{chi_squared_uniform, expect_frequency, expect_frequency_with},
+};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chance_matches_its_rate() {
+    expect_frequency(
+        |rng| rng.chance(0.3),
+        50_000,
+        &[(true, 0.3), (false, 0.7)],
+        0.01,
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ratio_matches_its_odds() {
+    expect_frequency(
+        |rng| rng.ratio(3, 8),
+        50_000,
+        &[(true, 0.375), (false, 0.625)],
+        0.01,
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_sample_matches_its_weights() {
+    let loot = [("common", 0.78), ("rare", 0.2), ("epic", 0.02)];
+
+    expect_frequency(
+        |rng| {
+            rng.weighted_sample(&loot, |((_, weight), _)| *weight)
+                .unwrap()
+                .0
+        },
+        100_000,
+        &loot,
+        0.005,
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ranges_are_uniform() {
+    let mut rng = RngComponent::with_seed(5);
+
+    chi_squared_uniform((0..60_000).map(|_| rng.index(..12)), 12);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seeded_trials_can_use_other_sources() {
+    expect_frequency_with(
+        &mut GlobalRng::with_seed(1),
+        |rng| rng.index(..4),
+        40_000,
+        &[(0, 0.25), (1, 0.25), (2, 0.25), (3, 0.25)],
+        0.01,
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "outcome \"epic\" occurred at a frequency of")]
+fn expect_frequency_catches_biased_samplers() {
+    // Epic drops were meant to be 2%, but are off by one in the roll.
+    expect_frequency(
+        |rng| {
+            if rng.u32(..100) <= 2 {
+                "epic"
+            } else {
+                "common"
+            }
+        },
+        100_000,
+        &[("epic", 0.02), ("common", 0.98)],
+        0.005,
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "outcome \"legendary\" is not one of the expected outcomes")]
+fn expect_frequency_catches_unexpected_outcomes() {
+    expect_frequency(
+        |rng| {
+            if rng.chance(0.5) {
+                "common"
+            } else {
+                "legendary"
+            }
+        },
+        1_000,
+        &[("common", 1.0)],
+        0.005,
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "bucket 0 has")]
+fn chi_squared_catches_biased_samplers() {
+    let mut rng = RngComponent::with_seed(5);
+
+    // Modulo bias from folding 0..10 into 8 buckets favours buckets 0 and 1.
+    chi_squared_uniform((0..60_000).map(|_| rng.index(..10) % 8), 8);
+}
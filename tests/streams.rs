@@ -0,0 +1,135 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn streams_are_deterministic_from_parent_seed() {
+    let mut streams = RngStreams::from_rng(&mut GlobalRng::with_seed(5), ["movement", "loot"]);
+    let mut replayed = RngStreams::from_rng(&mut GlobalRng::with_seed(5), ["movement", "loot"]);
+
+    assert_eq!(streams, replayed);
+    assert_eq!(streams.labels().collect::<Vec<_>>(), ["movement", "loot"]);
+
+    let movement: Vec<u32> = (0..3).map(|_| streams.stream("movement").u32(..)).collect();
+    let loot: Vec<u32> = (0..3).map(|_| streams.stream("loot").u32(..)).collect();
+
+    assert_eq!(movement, [1872536807, 1140316643, 403641247]);
+    assert_eq!(loot, [3081343458, 1733008857, 2110834183]);
+
+    assert_ne!(streams, replayed);
+
+    for _ in 0..3 {
+        replayed.stream("movement").u32(..);
+        replayed.stream("loot").u32(..);
+    }
+
+    assert_eq!(streams, replayed);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn drawing_from_one_stream_leaves_others_untouched() {
+    let mut streams = RngStreams::from_rng(&mut GlobalRng::with_seed(5), ["movement", "loot"]);
+    let mut expected = streams.clone();
+
+    for _ in 0..100 {
+        streams.stream("movement").f32();
+    }
+
+    assert_eq!(
+        streams.stream("loot").u64(..),
+        expected.stream("loot").u64(..)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn adding_a_label_keeps_existing_streams() {
+    let mut two = RngStreams::from_rng(&mut GlobalRng::with_seed(5), ["movement", "loot"]);
+    let mut three = RngStreams::from_rng(
+        &mut GlobalRng::with_seed(5),
+        ["movement", "loot", "dialogue"],
+    );
+
+    assert_eq!(three.len(), 3);
+
+    for label in ["movement", "loot"] {
+        assert_eq!(two.stream(label), three.stream(label));
+    }
+
+    let mut source = GlobalRng::with_seed(5);
+    let mut extended = RngStreams::from_rng(&mut source, ["movement", "loot"]);
+
+    extended.add_stream(&mut source, "dialogue");
+
+    assert_eq!(extended, three);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn repeated_labels_are_forked_once() {
+    let mut source = GlobalRng::with_seed(5);
+    let mut streams = RngStreams::from_rng(&mut source, ["loot", "loot"]);
+
+    assert_eq!(streams.len(), 1);
+
+    let before = streams.clone();
+
+    streams.add_stream(&mut source, "loot");
+
+    assert_eq!(streams, before);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(
+    expected = "no RNG stream labelled `combat`, expected one of [\"movement\", \"loot\"]"
+)]
+fn unknown_labels_panic() {
+    let mut streams = RngStreams::from_rng(&mut GlobalRng::with_seed(5), ["movement", "loot"]);
+
+    assert!(streams.try_stream("combat").is_none());
+
+    streams.stream("combat");
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn streams_roundtrip_through_serde_and_reflection() {
+    use bevy::reflect::{
+        serde::{ReflectDeserializer, ReflectSerializer},
+        FromReflect, TypeRegistry,
+    };
+    use serde::de::DeserializeSeed;
+
+    let mut streams = RngStreams::from_rng(&mut GlobalRng::with_seed(5), ["movement", "loot"]);
+
+    let serialized = ron::to_string(&streams).unwrap();
+    let mut restored: RngStreams = ron::from_str(&serialized).unwrap();
+
+    assert_eq!(
+        restored.stream("loot").u64(..),
+        streams.stream("loot").u64(..)
+    );
+
+    let mut registry = TypeRegistry::default();
+    registry.register::<RngStreams>();
+
+    let ser = ReflectSerializer::new(&streams, &registry);
+    let serialized = ron::to_string(&ser).unwrap();
+
+    let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+    let value = ReflectDeserializer::new(&registry)
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+    assert_eq!(RngStreams::take_from_reflect(value).unwrap(), streams);
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn strict_global_seeds_like_global_rng() {
+    let mut strict = StrictGlobalRng::with_seed(12345);
+    let mut global = GlobalRng::with_seed(12345);
+
+    assert_eq!(strict.seed_component(), RngComponent::from(&mut global));
+    assert_eq!(strict.fork().gen_u64(), global.fork().gen_u64());
+
+    let forks = strict.fork_many(3);
+    let expected = global.fork_many(3);
+
+    assert_eq!(
+        forks.iter().map(Rng::gen_u64).collect::<Vec<_>>(),
+        expected.iter().map(Rng::gen_u64).collect::<Vec<_>>()
+    );
+
+    assert_eq!(StrictGlobalRng::from(global), strict);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn plugin_inserts_strict_resources() {
+    #[derive(Component)]
+    struct Player;
+
+    fn setup_player(mut commands: Commands<'_, '_>, mut global: ResMut<'_, StrictGlobalRng>) {
+        commands.spawn((Player, global.seed_component()));
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(12345).with_strict_global())
+        .add_systems(Startup, setup_player);
+
+    app.update();
+
+    assert!(!app.world().contains_resource::<GlobalRng>());
+    assert!(app.world().contains_resource::<GlobalEntropy>());
+
+    let mut expected = StrictGlobalRng::with_seed(12345);
+    let entropy = expected.seed_entropy();
+    let player = expected.seed_component();
+
+    assert_eq!(app.world().resource::<GlobalEntropy>(), &entropy);
+
+    let mut query = app
+        .world_mut()
+        .query_filtered::<&RngComponent, With<Player>>();
+
+    assert_eq!(query.single(app.world()), &player);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn strict_mode_forks_on_spawn() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5).with_strict_global());
+
+    let entity = app
+        .world_mut()
+        .spawn((RngComponent::with_seed(1), ForkOnSpawn))
+        .id();
+
+    app.update();
+
+    let mut expected = StrictGlobalRng::with_seed(5);
+    let _ = expected.seed_entropy();
+
+    assert_eq!(
+        app.world().get::<RngComponent>(entity),
+        Some(&expected.seed_component())
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn global_entropy_draws_values() {
+    let mut entropy = GlobalEntropy::with_seed(5);
+    let mut rng = RngComponent::with_seed(5);
+
+    assert_eq!(entropy.u32(..), rng.u32(..));
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn wrappers_are_send_and_sync() {
+    assert_send_sync::<GlobalRng>();
+    assert_send_sync::<RngComponent>();
+    assert_send_sync::<RngStreams>();
+    assert_send_sync::<StrictGlobalRng>();
+    assert_send_sync::<GlobalEntropy>();
+
+    #[cfg(feature = "chacha")]
+    {
+        assert_send_sync::<GlobalChaChaRng>();
+        assert_send_sync::<ChaChaRngComponent>();
+    }
+
+    #[cfg(feature = "compat")]
+    assert_send_sync::<LegacyRngComponent>();
+}
+
+#[test]
+fn shared_references_only_read_the_state() {
+    let rng = RngComponent::with_seed(5);
+    let shared = &rng;
+
+    let clones: Vec<RngComponent> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| scope.spawn(move || shared.clone()))
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    for mut clone in clones {
+        assert_eq!(clone, rng);
+        assert_eq!(clone.u64(..), RngComponent::with_seed(5).u64(..));
+    }
+
+    assert_eq!(rng, RngComponent::with_seed(5));
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn payloads_are_unchanged_by_the_wrapper() {
+    let rng = Rng::with_seed(5);
+    let state = ron::to_string(&rng).unwrap();
+
+    assert_eq!(
+        ron::to_string(&StrictGlobalRng::with_seed(5)).unwrap(),
+        format!("({state})")
+    );
+    assert_eq!(
+        ron::to_string(&GlobalRng::with_seed(5)).unwrap(),
+        format!("({state},Some(5))")
+    );
+    assert_eq!(
+        ron::to_string(&RngComponent::with_seed(5)).unwrap(),
+        format!("({state})")
+    );
+
+    let mut restored: GlobalEntropy =
+        ron::from_str(&ron::to_string(&GlobalEntropy::with_seed(5)).unwrap()).unwrap();
+
+    assert_eq!(restored.u64(..), rng.u64(..));
+}
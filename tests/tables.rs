@@ -0,0 +1,148 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn wave() -> Cdf<&'static str> {
+    Cdf::new([("goblin", 2.0), ("orc", 1.0), ("troll", 1.0)]).unwrap()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn bins_include_their_lower_edge() {
+    let wave = wave();
+
+    assert_eq!(wave.locate(0.0), &"goblin");
+    assert_eq!(wave.locate(0.49999), &"goblin");
+    assert_eq!(wave.locate(0.5), &"orc");
+    assert_eq!(wave.locate(0.74999), &"orc");
+    assert_eq!(wave.locate(0.75), &"troll");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rolls_approaching_one_pick_the_last_item() {
+    let wave = wave();
+
+    assert_eq!(wave.locate(0.999_999), &"troll");
+    assert_eq!(wave.locate(1.0 - f64::EPSILON), &"troll");
+    assert_eq!(wave.locate(1.0), &"troll");
+    assert_eq!(wave.locate(7.0), &"troll");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn out_of_range_rolls_are_clamped() {
+    let wave = wave();
+
+    assert_eq!(wave.locate(-1.0), &"goblin");
+    assert_eq!(wave.locate(f64::NAN), &"goblin");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn zero_weight_items_are_never_picked() {
+    let cdf = Cdf::new([
+        ("never", 0.0),
+        ("a", 1.0),
+        ("also_never", 0.0),
+        ("b", 1.0),
+        ("nope", 0.0),
+    ])
+    .unwrap();
+
+    assert_eq!(cdf.locate(0.0), &"a");
+    assert_eq!(cdf.locate(0.5), &"b");
+    assert_eq!(cdf.locate(1.0 - f64::EPSILON), &"b");
+    assert_eq!(cdf.locate(1.0), &"b");
+    assert_eq!(cdf.probability(0), Some(0.0));
+    assert_eq!(cdf.probability(2), Some(0.0));
+    assert_eq!(cdf.probability(4), Some(0.0));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weights_are_normalized() {
+    let wave = wave();
+
+    assert_eq!(wave.len(), 3);
+    assert_eq!(wave.probability(0), Some(0.5));
+    assert_eq!(wave.probability(1), Some(0.25));
+    assert_eq!(wave.probability(2), Some(0.25));
+    assert_eq!(wave.probability(3), None);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn invalid_weights_are_rejected() {
+    assert_eq!(Cdf::<u8>::new([]), Err(CdfError::Empty));
+    assert_eq!(
+        Cdf::new([(1, 1.0), (2, -1.0)]),
+        Err(CdfError::InvalidWeight {
+            index: 1,
+            weight: -1.0
+        })
+    );
+    assert!(matches!(
+        Cdf::new([(1, f64::NAN)]),
+        Err(CdfError::InvalidWeight { index: 0, .. })
+    ));
+    assert_eq!(
+        Cdf::new([(1, f64::INFINITY)]),
+        Err(CdfError::InvalidWeight {
+            index: 0,
+            weight: f64::INFINITY
+        })
+    );
+    assert_eq!(Cdf::new([(1, 0.0), (2, -0.0)]), Err(CdfError::InvalidTotal));
+    assert_eq!(
+        Cdf::new([(1, f64::MAX), (2, f64::MAX)]),
+        Err(CdfError::InvalidTotal)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sample_locates_an_f64_roll() {
+    let wave = wave();
+
+    let mut rng = RngComponent::with_seed(5);
+    let mut rolls = RngComponent::with_seed(5);
+
+    let spawns: Vec<_> = (0..8).map(|_| *wave.sample(&mut rng)).collect();
+
+    for spawn in &spawns {
+        assert_eq!(wave.locate(rolls.f64()), spawn);
+    }
+
+    assert_eq!(
+        spawns,
+        ["goblin", "orc", "troll", "orc", "goblin", "orc", "goblin", "troll"]
+    );
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cdfs_roundtrip_through_serde() {
+    let wave = Cdf::new([
+        ("goblin".to_owned(), 6.0),
+        ("orc".to_owned(), 3.0),
+        ("troll".to_owned(), 1.0),
+    ])
+    .unwrap();
+
+    let serialized = ron::to_string(&wave).unwrap();
+    let restored: Cdf<String> = ron::from_str(&serialized).unwrap();
+
+    for roll in [0.0, 0.3, 0.6, 0.89, 0.9, 0.99] {
+        assert_eq!(restored.locate(roll), wave.locate(roll));
+    }
+
+    assert!(ron::from_str::<Cdf<String>>("[(\"goblin\", -1.0)]").is_err());
+}
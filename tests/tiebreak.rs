@@ -0,0 +1,92 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+use std::cmp::Reverse;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// Scores with tie groups of 3 (score 5), 1 (score 4), 4 (score 2) and 2 (score 1).
+const SCORES: [(char, u32); 10] = [
+    ('a', 2),
+    ('b', 5),
+    ('c', 1),
+    ('d', 5),
+    ('e', 2),
+    ('f', 4),
+    ('g', 2),
+    ('h', 5),
+    ('i', 1),
+    ('j', 2),
+];
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn tiebreak_sort_is_pinned() {
+    let mut rng = RngComponent::with_seed(7);
+    let mut scores = SCORES;
+
+    rng.tiebreak_sort_by_key(&mut scores, |&(_, score)| Reverse(score));
+
+    let names: String = scores.iter().map(|&(name, _)| name).collect();
+
+    assert_eq!(names, "dhbfjeagic");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn tiebreak_sort_keeps_untied_order() {
+    let mut rng = RngComponent::with_seed(7);
+
+    for _ in 0..50 {
+        let mut scores = SCORES;
+
+        rng.tiebreak_sort_by_key(&mut scores, |&(_, score)| Reverse(score));
+
+        let sorted: Vec<u32> = scores.iter().map(|&(_, score)| score).collect();
+
+        assert_eq!(sorted, [5, 5, 5, 4, 2, 2, 2, 2, 1, 1]);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn tiebreak_draws_only_for_tied_groups() {
+    let mut rng = RngComponent::with_seed(7);
+    let mut reference = RngComponent::with_seed(7);
+
+    let mut untied = [3, 1, 2, 5, 4];
+    rng.tiebreak_sort_by_key(&mut untied, |&n| n);
+
+    assert_eq!(untied, [1, 2, 3, 4, 5]);
+    assert_eq!(rng, reference);
+
+    let mut scores = SCORES;
+    rng.tiebreak_sort_by_key(&mut scores, |&(_, score)| score);
+
+    // Groups of 3, 4 and 2 items make 2, 3 and 1 draws respectively.
+    for _ in 0..6 {
+        reference.usize(..);
+    }
+
+    assert_eq!(rng.u64(..), reference.u64(..));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn tiebreak_order_matches_sort() {
+    let mut rng = RngComponent::with_seed(11);
+    let mut reference = RngComponent::with_seed(11);
+
+    let order = rng.tiebreak_order_by_key(&SCORES, |&(_, score)| Reverse(score));
+
+    let mut scores = SCORES;
+    reference.tiebreak_sort_by_key(&mut scores, |&(_, score)| Reverse(score));
+
+    let ordered: Vec<_> = order.iter().map(|&index| SCORES[index]).collect();
+
+    assert_eq!(ordered, scores);
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+enum Attack {
+    Miss,
+    Hit,
+    Crit,
+    Supercrit,
+}
+
+fn attack() -> TieredOutcome<Attack> {
+    TieredOutcome::new([
+        (Attack::Miss, 0.0),
+        (Attack::Hit, 0.5),
+        (Attack::Crit, 0.125),
+        (Attack::Supercrit, 0.125),
+    ])
+    .unwrap()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn thresholds_include_their_lower_edge() {
+    let attack = attack();
+
+    // The first tier gets the remaining 0.25.
+    assert_eq!(attack.roll_with(0.0), &Attack::Miss);
+    assert_eq!(attack.roll_with(0.249_999), &Attack::Miss);
+    assert_eq!(attack.roll_with(0.25), &Attack::Hit);
+    assert_eq!(attack.roll_with(0.75), &Attack::Crit);
+    assert_eq!(attack.roll_with(0.875), &Attack::Supercrit);
+    assert_eq!(attack.roll_with(1.0 - f64::EPSILON), &Attack::Supercrit);
+    assert_eq!(attack.roll_with(1.0), &Attack::Supercrit);
+    assert_eq!(attack.roll_with(-1.0), &Attack::Miss);
+    assert_eq!(attack.probability(0), Some(0.25));
+    assert_eq!(attack.probability(4), None);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn zero_probability_tiers_are_never_rolled() {
+    let attack = TieredOutcome::new([
+        (Attack::Hit, 1.0),
+        (Attack::Crit, 0.0),
+        (Attack::Supercrit, 0.0),
+    ])
+    .unwrap();
+
+    for roll in [0.0, 0.5, 1.0 - f64::EPSILON, 1.0] {
+        assert_eq!(attack.roll_with(roll), &Attack::Hit);
+    }
+
+    let always_first = TieredOutcome::new([(Attack::Miss, 0.0), (Attack::Hit, 0.0)]).unwrap();
+
+    assert_eq!(always_first.roll_with(0.99), &Attack::Miss);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn invalid_tables_are_rejected() {
+    assert_eq!(TieredOutcome::<Attack>::new([]), Err(CdfError::Empty));
+    assert_eq!(
+        TieredOutcome::new([(Attack::Hit, 0.5), (Attack::Crit, -0.1)]),
+        Err(CdfError::InvalidWeight {
+            index: 1,
+            weight: -0.1
+        })
+    );
+    assert_eq!(
+        TieredOutcome::new([(Attack::Hit, 1.5)]),
+        Err(CdfError::InvalidWeight {
+            index: 0,
+            weight: 1.5
+        })
+    );
+    assert!(matches!(
+        TieredOutcome::new([(Attack::Hit, f64::NAN)]),
+        Err(CdfError::InvalidWeight { index: 0, .. })
+    ));
+    assert_eq!(
+        TieredOutcome::new([(Attack::Hit, 0.6), (Attack::Crit, 0.5)]),
+        Err(CdfError::InvalidTotal)
+    );
+
+    // Rounding in authored probabilities is tolerated.
+    assert!(TieredOutcome::new([
+        (Attack::Hit, 0.7),
+        (Attack::Crit, 0.2),
+        (Attack::Supercrit, 0.1)
+    ])
+    .is_ok());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rolls_are_deterministic() {
+    let attack = attack();
+
+    let mut rng = RngComponent::with_seed(5);
+    let mut replay = rng.clone();
+
+    let rolls: Vec<Attack> = (0..8).map(|_| *attack.roll(&mut rng)).collect();
+    let replayed: Vec<Attack> = (0..8).map(|_| *attack.roll_with(replay.f64())).collect();
+
+    assert_eq!(rolls, replayed);
+    assert_eq!(
+        rolls,
+        [
+            Attack::Hit,
+            Attack::Hit,
+            Attack::Supercrit,
+            Attack::Hit,
+            Attack::Hit,
+            Attack::Hit,
+            Attack::Hit,
+            Attack::Supercrit
+        ]
+    );
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn tables_are_authored_with_serde() {
+    let attack: TieredOutcome<Attack> =
+        ron::from_str("[(Miss, 0.0), (Hit, 0.5), (Crit, 0.125), (Supercrit, 0.125)]").unwrap();
+
+    assert_eq!(attack, self::attack());
+    assert_eq!(
+        ron::to_string(&attack).unwrap(),
+        "[(Miss,0.25),(Hit,0.5),(Crit,0.125),(Supercrit,0.125)]"
+    );
+    assert!(ron::from_str::<TieredOutcome<Attack>>("[(Hit, 0.6), (Crit, 0.5)]").is_err());
+}
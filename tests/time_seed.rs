@@ -0,0 +1,95 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::{prelude::*, utils::SystemTime};
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn time_seed_seeds_global_with_startup_seed() {
+    let before = now();
+
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_time_seed());
+
+    let after = now();
+    let seed = app.world().resource::<RngStartupSeed>().get();
+
+    assert!((before..=after).contains(&seed));
+    assert_eq!(
+        app.world().resource::<GlobalRng>(),
+        &GlobalRng::with_seed(seed)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn time_seed_takes_precedence_over_rng_seed() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5).with_time_seed());
+
+    let seed = app.world().resource::<RngStartupSeed>().get();
+
+    assert_ne!(seed, 5);
+    assert_eq!(
+        app.world().resource::<GlobalRng>(),
+        &GlobalRng::with_seed(seed)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn time_seed_seeds_strict_global() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_time_seed().with_strict_global());
+
+    let seed = app.world().resource::<RngStartupSeed>().get();
+    let mut expected = StrictGlobalRng::with_seed(seed);
+    let entropy = expected.seed_entropy();
+
+    assert_eq!(app.world().resource::<GlobalEntropy>(), &entropy);
+
+    assert_eq!(app.world().resource::<StrictGlobalRng>(), &expected);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn startup_seed_is_only_inserted_for_known_seeds() {
+    let mut seeded = App::new();
+
+    seeded.add_plugins(RngPlugin::new().with_rng_seed(5));
+
+    assert_eq!(
+        seeded.world().resource::<RngStartupSeed>(),
+        &RngStartupSeed(5)
+    );
+
+    let mut randomised = App::new();
+
+    randomised.add_plugins(RngPlugin::new());
+
+    assert!(!randomised.world().contains_resource::<RngStartupSeed>());
+
+    let mut manual = App::new();
+
+    manual
+        .insert_resource(GlobalRng::with_seed(7))
+        .add_plugins(RngPlugin::new().with_time_seed());
+
+    assert!(!manual.world().contains_resource::<RngStartupSeed>());
+}
@@ -0,0 +1,135 @@
+#![cfg(all(feature = "wyrand", feature = "bevy_time"))]
+
+use bevy::{prelude::*, utils::Duration};
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn repeating_durations_are_deterministic() {
+    let mut rng = RngComponent::with_seed(42);
+    let mut timer = JitteredTimer::new(1.0..2.0, TimerMode::Repeating);
+
+    let mut durations = Vec::new();
+    let mut finishes = 0;
+
+    // Ten seconds at 60 frames per second.
+    for _ in 0..600 {
+        let duration = timer.duration();
+
+        if timer
+            .tick(Duration::from_secs_f32(1.0 / 60.0), &mut rng)
+            .just_finished()
+        {
+            durations.push(duration.as_millis());
+            finishes += timer.times_finished_this_tick();
+        }
+
+        assert!(timer.elapsed() < timer.duration());
+    }
+
+    assert_eq!(finishes as usize, durations.len());
+    assert_eq!(durations, vec![1017, 1554, 1777, 1359, 1067, 1178, 1587]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn repeating_timers_carry_over_excess_time() {
+    let mut rng = RngComponent::with_seed(42);
+    let mut timer = JitteredTimer::new(1.0..2.0, TimerMode::Repeating);
+
+    timer.tick(Duration::ZERO, &mut rng);
+
+    let first = timer.duration();
+
+    // A long frame spanning several cycles.
+    timer.tick(Duration::from_secs(5), &mut rng);
+
+    assert!(timer.just_finished());
+    assert!(timer.times_finished_this_tick() >= 3);
+    assert!(timer.duration() >= Duration::from_secs(1));
+    assert_ne!(timer.duration(), first);
+    assert!(timer.remaining() <= timer.duration());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn once_timers_finish_once() {
+    let mut rng = RngComponent::with_seed(42);
+    let mut timer = JitteredTimer::new(0.5..0.75, TimerMode::Once);
+
+    assert!(!timer.finished());
+
+    timer.tick(Duration::from_millis(100), &mut rng);
+
+    assert!(!timer.finished());
+    assert!(timer.remaining() > Duration::ZERO);
+
+    timer.tick(Duration::from_secs(1), &mut rng);
+
+    assert!(timer.finished());
+    assert!(timer.just_finished());
+    assert_eq!(timer.remaining(), Duration::ZERO);
+
+    let duration = timer.duration();
+
+    timer.tick(Duration::from_secs(1), &mut rng);
+
+    assert!(timer.finished());
+    assert!(!timer.just_finished());
+    assert_eq!(timer.duration(), duration);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn timers_roundtrip_through_serde() {
+    let mut rng = RngComponent::with_seed(42);
+    let mut timer = JitteredTimer::new(1.0..2.0, TimerMode::Repeating);
+
+    timer.tick(Duration::from_millis(300), &mut rng);
+
+    let serialized = ron::to_string(&timer).unwrap();
+    let deserialized: JitteredTimer = ron::from_str(&serialized).unwrap();
+
+    assert_eq!(timer, deserialized);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "timer range must not be empty nor negative")]
+fn timer_ranges_must_not_be_empty() {
+    let _ = JitteredTimer::new(2.0..2.0, TimerMode::Repeating);
+}
+
+#[test]
+#[should_panic(expected = "timer range must be finite and fit in a Duration")]
+fn timer_ranges_must_be_finite() {
+    let _ = JitteredTimer::new(0.0..f32::INFINITY, TimerMode::Repeating);
+}
+
+#[test]
+#[should_panic(expected = "timer range must be finite and fit in a Duration")]
+fn timer_ranges_must_fit_in_a_duration() {
+    let _ = JitteredTimer::new(0.0..f32::MAX, TimerMode::Once);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn tiny_durations_finish_a_bounded_amount_of_times_per_tick() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut timer = JitteredTimer::new(0.0..1e-9, TimerMode::Repeating);
+
+    timer.tick(Duration::from_secs(1), &mut rng);
+
+    assert_eq!(
+        timer.times_finished_this_tick(),
+        JitteredTimer::MAX_CYCLES_PER_TICK
+    );
+    assert_eq!(timer.elapsed(), Duration::ZERO);
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "chacha")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+const BASE64URL: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Decodes unpadded base64url one bit at a time, as a reference for the encoder.
+fn decode_base64url(encoded: &str) -> Vec<u8> {
+    let bits: Vec<bool> = encoded
+        .chars()
+        .flat_map(|character| {
+            let sextet = BASE64URL.find(character).unwrap();
+
+            (0..6).rev().map(move |bit| (sextet >> bit) & 1 == 1)
+        })
+        .collect();
+
+    bits.chunks_exact(8)
+        .map(|byte| byte.iter().fold(0, |acc, &bit| (acc << 1) | u8::from(bit)))
+        .collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn tokens_have_the_requested_length() {
+    let mut rng = ChaChaRngComponent::new();
+
+    for len in 0..40 {
+        assert_eq!(rng.token_hex(len).len(), len * 2);
+        assert_eq!(rng.token_base64url(len).len(), (len * 4).div_ceil(3));
+    }
+
+    let token: [u8; 24] = rng.token_bytes();
+
+    assert_eq!(token.len(), 24);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn tokens_only_use_their_alphabet() {
+    let mut rng = GlobalChaChaRng::with_seed([9; 40]);
+
+    for _ in 0..100 {
+        assert!(rng
+            .token_hex(16)
+            .chars()
+            .all(|character| matches!(character, '0'..='9' | 'a'..='f')));
+        assert!(rng
+            .token_base64url(16)
+            .chars()
+            .all(|character| BASE64URL.contains(character)));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn tokens_encode_the_same_bytes() {
+    for len in [1, 2, 3, 4, 5, 16, 32] {
+        let mut bytes = ChaChaRngComponent::with_seed([4; 40]);
+        let mut hex = bytes.clone();
+        let mut base64url = bytes.clone();
+
+        let mut expected = vec![0; len];
+        bytes.get_mut().fill_bytes(&mut expected);
+
+        let hex = hex.token_hex(len);
+        let decoded_hex: Vec<u8> = (0..len)
+            .map(|index| u8::from_str_radix(&hex[index * 2..index * 2 + 2], 16).unwrap())
+            .collect();
+
+        assert_eq!(decoded_hex, expected);
+        assert_eq!(decode_base64url(&base64url.token_base64url(len)), expected);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seeded_tokens_are_deterministic() {
+    let mut rng = ChaChaRngComponent::with_seed([1; 40]);
+
+    assert_eq!(rng.token_hex(8), "13d27d3d0e28201c");
+    assert_eq!(rng.token_base64url(8), "cBRltpAQHTQ");
+    assert_eq!(rng.token_bytes::<4>(), [233, 29, 188, 164]);
+}
@@ -0,0 +1,143 @@
+#![cfg(all(feature = "wyrand", any(debug_assertions, feature = "audit")))]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn audited_app() -> App {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(12345))
+        .insert_resource(RngAuditConfig {
+            panic_on_unseeded: true,
+        });
+
+    app
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "RngComponents seeded from entropy were added to entities")]
+fn default_components_are_reported() {
+    let mut app = audited_app();
+
+    app.world_mut().spawn(RngComponent::with_seed(5));
+    app.world_mut().spawn(RngComponent::default());
+
+    app.update();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "RngComponents seeded from entropy were added to entities")]
+fn components_added_later_are_reported() {
+    let mut app = audited_app();
+
+    let entity = app.world_mut().spawn_empty().id();
+
+    app.update();
+
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(RngComponent::new());
+
+    app.update();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seeded_components_are_not_reported() {
+    let mut app = audited_app();
+
+    let mut global = GlobalRng::with_seed(7);
+    let mut source = RngComponent::with_seed(9);
+
+    app.world_mut().spawn(RngComponent::with_seed(5));
+    app.world_mut().spawn(RngComponent::from(&mut global));
+    app.world_mut().spawn(RngComponent::from(&mut source));
+    app.world_mut().spawn(RngComponent::from(Rng::with_seed(3)));
+
+    app.update();
+    app.update();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn components_forked_on_spawn_are_not_reported() {
+    let mut app = audited_app();
+
+    app.world_mut().spawn((RngComponent::new(), ForkOnSpawn));
+
+    app.update();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn unseeded_components_are_ignored_without_the_config() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(12345));
+
+    app.world_mut().spawn(RngComponent::new());
+
+    app.update();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn unseeded_components_are_only_warned_about_by_default() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(12345))
+        .init_resource::<RngAuditConfig>();
+
+    app.world_mut().spawn(RngComponent::new());
+
+    app.update();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn provenance_is_ignored_when_comparing() {
+    let mut seeded = RngComponent::with_seed(1);
+    let mut unseeded = RngComponent::new();
+
+    // `Rng::with_seed` adjusts the seed while `reseed` doesn't, so reseed both the same way.
+    seeded.reseed(5);
+    unseeded.reseed(5);
+
+    assert_eq!(seeded, unseeded);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deserialized_components_are_not_reported() {
+    let mut app = audited_app();
+
+    let serialized = ron::to_string(&RngComponent::new()).unwrap();
+    let restored: RngComponent = ron::from_str(&serialized).unwrap();
+
+    app.world_mut().spawn(restored);
+
+    app.update();
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic(expected = "ChaChaRngComponents seeded from entropy were added to entities")]
+fn default_chacha_components_are_reported() {
+    let mut app = audited_app();
+
+    app.world_mut()
+        .spawn(ChaChaRngComponent::with_seed([5; 40]));
+    app.world_mut().spawn(ChaChaRngComponent::default());
+
+    app.update();
+}
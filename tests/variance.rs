@@ -0,0 +1,171 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn joint_profile() -> VarianceProfile {
+    VarianceProfile::new()
+        .with_parameter(
+            "break_force",
+            VarianceDistribution::Normal {
+                mean: 500.0,
+                std_dev: 25.0,
+                clamp: None,
+            },
+        )
+        .with_parameter(
+            "friction",
+            VarianceDistribution::Uniform { min: 0.4, max: 0.6 },
+        )
+        .with_parameter("restitution", VarianceDistribution::Constant(0.1))
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn resolved_values_are_pinned() {
+    let mut rng = RngComponent::with_seed(5);
+
+    let resolved = joint_profile().apply(&mut rng);
+    let values: Vec<(&str, f32)> = resolved.iter().collect();
+
+    assert_eq!(
+        values,
+        [
+            ("break_force", 501.70584),
+            ("friction", 0.45863938),
+            ("restitution", 0.1)
+        ]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn parameters_are_independent_of_each_other() {
+    let profile = joint_profile();
+
+    let reordered = VarianceProfile::new()
+        .with_parameter("restitution", VarianceDistribution::Constant(0.1))
+        .with_parameter(
+            "friction",
+            VarianceDistribution::Uniform { min: 0.4, max: 0.6 },
+        )
+        .with_parameter(
+            "break_force",
+            VarianceDistribution::Normal {
+                mean: 500.0,
+                std_dev: 25.0,
+                clamp: None,
+            },
+        );
+
+    let extended = joint_profile().with_parameter(
+        "angular_damping",
+        VarianceDistribution::Uniform { min: 0.0, max: 1.0 },
+    );
+
+    let mut reduced = joint_profile();
+    reduced.remove("break_force");
+
+    for seed in 0..100 {
+        let expected = profile.resolve(seed);
+
+        assert_eq!(reordered.resolve(seed), expected);
+
+        let extended = extended.resolve(seed);
+        let reduced = reduced.resolve(seed);
+
+        for (name, value) in expected.iter() {
+            assert_eq!(extended.get(name), Some(value));
+        }
+
+        assert_eq!(reduced.get("friction"), expected.get("friction"));
+        assert_eq!(reduced.get("break_force"), None);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn apply_draws_once_per_instance() {
+    let profile = joint_profile();
+    let mut rng = RngComponent::with_seed(5);
+    let mut expected = RngComponent::with_seed(5);
+
+    let mut stamped = Vec::new();
+
+    profile.stamp(&mut rng, ["a", "b", "c"], |target, variance| {
+        stamped.push((target, variance.clone()));
+    });
+
+    for (target, variance) in stamped {
+        assert_eq!(variance, profile.resolve(expected.u64(..)), "{target}");
+    }
+
+    assert_eq!(rng, expected);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn distributions_stay_in_range() {
+    let profile = VarianceProfile::new()
+        .with_parameter(
+            "clamped",
+            VarianceDistribution::Normal {
+                mean: 1.0,
+                std_dev: 10.0,
+                clamp: Some((0.5, 1.5)),
+            },
+        )
+        .with_parameter(
+            "uniform",
+            VarianceDistribution::Uniform {
+                min: -1.0,
+                max: 1.0,
+            },
+        )
+        .with_parameter(
+            "normal",
+            VarianceDistribution::Normal {
+                mean: 0.0,
+                std_dev: 1.0,
+                clamp: None,
+            },
+        );
+
+    let resolved: Vec<ResolvedVariance> = (0..2000).map(|seed| profile.resolve(seed)).collect();
+
+    assert!(resolved
+        .iter()
+        .all(|values| (0.5..=1.5).contains(&values.get_or("clamped", 0.0))));
+    assert!(resolved
+        .iter()
+        .all(|values| (-1.0..1.0).contains(&values.get_or("uniform", 2.0))));
+
+    // About 68% of a normal distribution lies within one standard deviation of the mean.
+    let within = resolved
+        .iter()
+        .filter(|values| values.get_or("normal", 2.0).abs() < 1.0)
+        .count();
+
+    assert!((1260..1460).contains(&within), "{within}");
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn profiles_can_be_authored_in_ron() {
+    let authored: VarianceProfile = ron::from_str(
+        r#"{
+            "break_force": Normal(mean: 500.0, std_dev: 25.0),
+            "friction": Uniform(min: 0.4, max: 0.6),
+            "restitution": Constant(0.1),
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(authored, joint_profile());
+}
@@ -0,0 +1,144 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+#[cfg(feature = "testing")]
+use bevy_turborand::stats::expect_frequency;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Weather {
+    Clear,
+    Rain,
+    Storm,
+}
+
+const ALL: [Weather; 3] = [Weather::Clear, Weather::Rain, Weather::Storm];
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn empty_options_are_rejected() {
+    let mut rng = RngComponent::with_seed(5);
+    let untouched = rng.clone();
+
+    assert_eq!(rng.pick::<Weather>(&[]), None);
+    assert_eq!(rng, untouched);
+    assert_eq!(
+        VariantSampler::<Weather>::uniform(&[]),
+        Err(CdfError::Empty)
+    );
+    assert_eq!(
+        VariantSampler::<Weather>::weighted(&[]),
+        Err(CdfError::Empty)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn invalid_weights_are_rejected() {
+    assert_eq!(
+        VariantSampler::weighted(&[(Weather::Clear, 1.0), (Weather::Rain, -1.0)]),
+        Err(CdfError::InvalidWeight {
+            index: 1,
+            weight: -1.0
+        })
+    );
+    assert_eq!(
+        VariantSampler::weighted(&[(Weather::Clear, 0.0), (Weather::Rain, 0.0)]),
+        Err(CdfError::InvalidTotal)
+    );
+    assert!(matches!(
+        VariantSampler::weighted(&[(Weather::Clear, f64::NAN)]),
+        Err(CdfError::InvalidWeight { index: 0, .. })
+    ));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn single_options_are_always_picked() {
+    let mut rng = RngComponent::with_seed(5);
+
+    let uniform = VariantSampler::uniform(&[Weather::Rain]).unwrap();
+    let weighted = VariantSampler::weighted(&[(Weather::Storm, 0.1)]).unwrap();
+
+    for _ in 0..20 {
+        assert_eq!(rng.pick(&[Weather::Clear]), Some(Weather::Clear));
+        assert_eq!(uniform.sample(&mut rng), Weather::Rain);
+        assert_eq!(weighted.sample(&mut rng), Weather::Storm);
+    }
+}
+
+#[cfg(feature = "testing")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weights_shape_the_frequencies() {
+    let sampler = VariantSampler::weighted(&[
+        (Weather::Clear, 7.0),
+        (Weather::Rain, 3.0),
+        (Weather::Storm, 0.0),
+    ])
+    .unwrap();
+
+    // Storm is never sampled, as it would be an unexpected outcome.
+    expect_frequency(
+        |rng| sampler.sample(rng),
+        100_000,
+        &[(Weather::Clear, 0.7), (Weather::Rain, 0.3)],
+        0.01,
+    );
+    assert_eq!(sampler.options(), ALL);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn uniform_samples_are_deterministic() {
+    let sampler = VariantSampler::uniform(&ALL).unwrap();
+
+    let mut rng = RngComponent::with_seed(5);
+    let mut pick_rng = rng.clone();
+    let mut sample_rng = rng.clone();
+
+    let sampled: Vec<_> = (0..8).map(|_| sampler.sample(&mut rng)).collect();
+    let picked: Vec<_> = (0..8).map(|_| pick_rng.pick(&ALL).unwrap()).collect();
+    let referenced: Vec<_> = (0..8).map(|_| *sample_rng.sample(&ALL).unwrap()).collect();
+
+    assert_eq!(sampled, picked);
+    assert_eq!(sampled, referenced);
+    assert_eq!(
+        sampled,
+        [
+            Weather::Clear,
+            Weather::Storm,
+            Weather::Storm,
+            Weather::Rain,
+            Weather::Rain,
+            Weather::Storm,
+            Weather::Rain,
+            Weather::Storm
+        ]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_samples_match_the_cdf() {
+    let weights = [
+        (Weather::Clear, 8.0),
+        (Weather::Rain, 2.0),
+        (Weather::Storm, 0.5),
+    ];
+
+    let sampler = VariantSampler::weighted(&weights).unwrap();
+    let cdf = Cdf::new(weights).unwrap();
+
+    let mut rng = RngComponent::with_seed(5);
+    let mut cdf_rng = rng.clone();
+
+    for _ in 0..100 {
+        assert_eq!(sampler.sample(&mut rng), *cdf.sample(&mut cdf_rng));
+    }
+}
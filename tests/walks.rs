@@ -0,0 +1,134 @@
+#![cfg(all(feature = "wyrand", feature = "bevy_math"))]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn walk_trajectory_is_pinned() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut walk = RandomWalk::new(0.5).with_value(1.0);
+
+    let trajectory: Vec<f32> = (0..5).map(|_| walk.step(&mut rng)).collect();
+
+    assert_eq!(
+        trajectory,
+        [1.2302492, 1.1939926, 0.82456124, 0.802849, 1.097736]
+    );
+    assert_eq!(walk.value(), trajectory[4]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn vector_walks_are_pinned() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut walk = RandomWalk::new(0.5);
+
+    assert_eq!(walk.step_vec2(&mut rng), Vec2::new(0.2302491, -0.03625655));
+    assert_eq!(
+        walk.step_vec3(&mut rng),
+        Vec3::new(-0.13918227, -0.057968795, 0.294887)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn steps_only_advance_the_axes_used() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut walk = RandomWalk::new(0.5).with_value_vec3(Vec3::new(1.0, 2.0, 3.0));
+
+    walk.step(&mut rng);
+
+    assert_eq!(walk.value_vec3().yz(), Vec2::new(2.0, 3.0));
+
+    walk.step_vec2(&mut rng);
+
+    assert_eq!(walk.value_vec3().z, 3.0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn walks_stay_within_bounds() {
+    let mut rng = RngComponent::with_seed(7);
+    let mut walk = RandomWalk::new(2.0).with_bounds(1.0, -1.0).with_value(5.0);
+
+    for _ in 0..1000 {
+        let value = walk.step_vec3(&mut rng);
+
+        assert!(value.cmpge(Vec3::NEG_ONE).all() && value.cmple(Vec3::ONE).all());
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn walks_move_by_at_most_max_step() {
+    let mut rng = RngComponent::with_seed(7);
+    let mut walk = RandomWalk::new(0.25);
+
+    let mut previous = walk.value();
+
+    for _ in 0..1000 {
+        let value = walk.step(&mut rng);
+
+        assert!((value - previous).abs() <= 0.25);
+
+        previous = value;
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mean_reversion_keeps_walks_near_the_mean() {
+    let mut rng = RngComponent::with_seed(7);
+
+    let mut free = RandomWalk::new(1.0);
+    let mut reverting = RandomWalk::new(1.0).with_mean_reversion(10.0, 0.2);
+
+    assert_eq!(
+        reverting.mode(),
+        WalkMode::MeanReverting {
+            mean: 10.0,
+            pull: 0.2
+        }
+    );
+
+    let mut free_spread = 0.0f32;
+    let mut reverting_spread = 0.0f32;
+
+    for step in 0..10_000 {
+        let free_value = free.step(&mut rng);
+        let reverting_value = reverting.step(&mut rng);
+
+        // Give the reverting walk time to reach the mean first.
+        if step >= 100 {
+            free_spread = free_spread.max(free_value.abs());
+            reverting_spread = reverting_spread.max((reverting_value - 10.0).abs());
+        }
+    }
+
+    // The stationary spread of the reverting walk is a small multiple of its step size.
+    assert!(reverting_spread < 5.0, "{reverting_spread}");
+    assert!(free_spread > reverting_spread, "{free_spread}");
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn walks_roundtrip_through_serde() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut walk = RandomWalk::new(0.5)
+        .with_bounds(-1.0, 1.0)
+        .with_mean_reversion(0.0, 0.1);
+
+    walk.step_vec2(&mut rng);
+
+    let serialized = ron::to_string(&walk).unwrap();
+
+    assert_eq!(ron::from_str::<RandomWalk>(&serialized).unwrap(), walk);
+}
@@ -0,0 +1,87 @@
+#![cfg(all(target_arch = "wasm32", feature = "web-persist"))]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn build_app(plugin: RngPlugin) -> App {
+    let mut app = App::new();
+
+    app.add_plugins(plugin);
+
+    app
+}
+
+#[wasm_bindgen_test]
+fn generates_and_persists_a_seed() {
+    let key = "bevy_turborand_test_generate";
+
+    clear_persisted_seed(key);
+
+    assert_eq!(persisted_seed(key), None);
+
+    let app = build_app(RngPlugin::new().with_persisted_seed(key));
+
+    let seed = persisted_seed(key).expect("seed should be persisted");
+
+    assert_eq!(
+        app.world().resource::<GlobalRng>(),
+        &GlobalRng::with_seed(seed)
+    );
+
+    clear_persisted_seed(key);
+}
+
+#[wasm_bindgen_test]
+fn resumes_from_a_persisted_seed() {
+    let key = "bevy_turborand_test_resume";
+
+    clear_persisted_seed(key);
+
+    let first = build_app(RngPlugin::new().with_persisted_seed(key));
+    let second = build_app(RngPlugin::new().with_rng_seed(5).with_persisted_seed(key));
+
+    assert_eq!(
+        first.world().resource::<GlobalRng>(),
+        second.world().resource::<GlobalRng>()
+    );
+
+    clear_persisted_seed(key);
+}
+
+#[wasm_bindgen_test]
+fn keys_are_independent() {
+    let first_key = "bevy_turborand_test_first";
+    let second_key = "bevy_turborand_test_second";
+
+    clear_persisted_seed(first_key);
+    clear_persisted_seed(second_key);
+
+    let _ = build_app(RngPlugin::new().with_persisted_seed(first_key));
+
+    assert!(persisted_seed(first_key).is_some());
+    assert_eq!(persisted_seed(second_key), None);
+
+    clear_persisted_seed(first_key);
+}
+
+#[wasm_bindgen_test]
+fn clearing_starts_a_new_seed() {
+    let key = "bevy_turborand_test_clear";
+
+    clear_persisted_seed(key);
+
+    let _ = build_app(RngPlugin::new().with_persisted_seed(key));
+    let first = persisted_seed(key);
+
+    assert!(clear_persisted_seed(key));
+    assert_eq!(persisted_seed(key), None);
+
+    let _ = build_app(RngPlugin::new().with_persisted_seed(key));
+
+    assert_ne!(persisted_seed(key), first);
+
+    clear_persisted_seed(key);
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "wyrand")]
+
+use bevy_turborand::prelude::*;
+#[cfg(feature = "testing")]
+use bevy_turborand::stats::expect_frequency;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[cfg(feature = "testing")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn heavy_items_tend_to_come_first() {
+    // Each item comes first with a probability of its weight out of 10.
+    expect_frequency(
+        |rng| {
+            let mut items = [(0, 1.0), (1, 1.0), (2, 1.0), (3, 7.0)];
+
+            rng.weighted_shuffle(&mut items, |&(_, weight)| weight);
+
+            items[0].0
+        },
+        10_000,
+        &[(3, 0.7), (0, 0.1), (1, 0.1), (2, 0.1)],
+        0.02,
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn shuffles_are_deterministic() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut inherent = GlobalRng::with_seed(5);
+
+    let mut items: Vec<u32> = (1..=8).collect();
+    let mut inherent_items = items.clone();
+
+    rng.weighted_shuffle(&mut items, |&item| f64::from(item));
+    inherent.weighted_shuffle(&mut inherent_items, |&item| f64::from(item));
+
+    assert_eq!(items, inherent_items);
+    assert_eq!(items, [7, 5, 6, 4, 8, 1, 2, 3]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn unweighted_items_go_last_in_order() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut expected = rng.clone();
+
+    let mut items = [
+        ("a", 0.0),
+        ("b", 2.0),
+        ("c", f64::NAN),
+        ("d", -1.0),
+        ("e", 1.0),
+        ("f", 0.0),
+    ];
+
+    for _ in 0..100 {
+        rng.weighted_shuffle(&mut items, |&(_, weight)| weight);
+
+        let names: Vec<_> = items.iter().map(|(name, _)| *name).collect();
+
+        assert_eq!(names[2..], ["a", "c", "d", "f"]);
+        assert!(names[..2] == ["b", "e"] || names[..2] == ["e", "b"]);
+
+        items.sort_by_key(|(name, _)| *name);
+    }
+
+    // Unweighted items still draw, so the amount of draws only depends on the length.
+    for _ in 0..600 {
+        expected.f64();
+    }
+
+    assert_eq!(rng, expected);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn all_unweighted_items_are_left_in_place() {
+    let mut rng = RngComponent::with_seed(5);
+
+    let mut items = [3, 1, 2];
+
+    rng.weighted_shuffle(&mut items, |_| 0.0);
+
+    assert_eq!(items, [3, 1, 2]);
+
+    let mut empty: [u8; 0] = [];
+
+    rng.weighted_shuffle(&mut empty, |_| 1.0);
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn source_draws_match_delegated_draws() {
+    let mut rng = RngComponent::with_seed(5);
+    let mut delegated = rng.clone();
+
+    let draws: Vec<u32> = rng.with_source(|source| (0..100).map(|_| source.u32(..)).collect());
+    let expected: Vec<u32> = (0..100).map(|_| delegated.u32(..)).collect();
+
+    assert_eq!(draws, expected);
+    assert_eq!(rng, delegated);
+
+    let mut global = GlobalRng::with_seed(5);
+    let mut delegated = global.clone();
+
+    let roll = global.with_source(|source| source.f64());
+
+    assert_eq!(roll, delegated.f64());
+    assert_eq!(global, delegated);
+}
+
+#[derive(Resource, Default)]
+struct GlobalChanged(bool);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn source_is_reachable_through_change_detection() {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(5))
+        .init_resource::<GlobalChanged>()
+        .add_systems(
+            Update,
+            (
+                |mut global: ResMut<GlobalRng>| {
+                    global.with_source(|source| source.u64(..));
+                },
+                |global: Res<GlobalRng>, mut changed: ResMut<GlobalChanged>| {
+                    changed.0 = global.is_changed();
+                },
+            )
+                .chain(),
+        );
+
+    app.update();
+
+    let mut expected = GlobalRng::with_seed(5);
+    expected.u64(..);
+
+    assert_eq!(app.world().resource::<GlobalRng>(), &expected);
+    assert!(app.world().resource::<GlobalChanged>().0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn bulk_fills_match_with_source() {
+    let mut rng = RngComponent::with_seed(9);
+    let mut manual = rng.clone();
+
+    let mut filled = [0.0; 64];
+    rng.fill_f32_range(&mut filled, -2.0..2.0);
+
+    let expected: Vec<f32> =
+        manual.with_source(|source| (0..64).map(|_| -2.0 + 4.0 * source.f32()).collect());
+
+    assert_eq!(filled.as_slice(), expected.as_slice());
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_source_draws_match_delegated_draws() {
+    let mut rng = ChaChaRngComponent::with_seed([3; 40]);
+    let mut delegated = rng.clone();
+
+    let draws: Vec<u64> = rng.with_source(|source| (0..16).map(|_| source.u64(..)).collect());
+    let expected: Vec<u64> = (0..16).map(|_| delegated.u64(..)).collect();
+
+    assert_eq!(draws, expected);
+}
@@ -0,0 +1,133 @@
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Default, Resource)]
+struct Rolls(Vec<u32>);
+
+fn rolls_after<M>(system: impl IntoSystemConfigs<M>) -> Vec<u32> {
+    let mut app = App::new();
+
+    app.add_plugins(RngPlugin::new().with_rng_seed(12345))
+        .init_resource::<Rolls>()
+        .add_systems(Update, system);
+
+    app.update();
+
+    let mut q_rngs = app.world_mut().query::<&mut RngComponent>();
+    let mut rolls: Vec<u32> = q_rngs
+        .iter_mut(app.world_mut())
+        .map(|mut rng| rng.u32(..))
+        .collect();
+
+    rolls.append(&mut app.world_mut().resource_mut::<Rolls>().0);
+    rolls.sort_unstable();
+
+    rolls
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn exclusive_forks_match_resource_forks() {
+    let exclusive = rolls_after(|world: &mut World| {
+        let forked = world.fork_rng().unwrap();
+        let from_global = RngComponent::from(&mut world.rng());
+        let roll = world.rng().u32(..);
+
+        world.spawn(RngComponent::from(forked));
+        world.spawn(from_global);
+        world.resource_mut::<Rolls>().0.push(roll);
+    });
+
+    let regular = rolls_after(
+        |mut commands: Commands, mut global: ResMut<GlobalRng>, mut rolls: ResMut<Rolls>| {
+            commands.spawn(RngComponent::from(global.fork()));
+            commands.spawn(RngComponent::from(&mut global));
+            rolls.0.push(global.u32(..));
+        },
+    );
+
+    assert_eq!(exclusive, regular);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn entity_commands_can_reach_rng_components() {
+    let from_command = rolls_after(|mut commands: Commands| {
+        commands
+            .spawn_empty()
+            .queue(|entity: Entity, world: &mut World| {
+                let rng = world.fork_rng().unwrap();
+
+                world.entity_mut(entity).insert(RngComponent::from(rng));
+
+                let roll = world.rng_component_for(entity).unwrap().u32(..);
+
+                world.resource_mut::<Rolls>().0.push(roll);
+            });
+    });
+
+    let regular = rolls_after(
+        |mut commands: Commands, mut global: ResMut<GlobalRng>, mut rolls: ResMut<Rolls>| {
+            let mut rng = RngComponent::from(global.fork());
+
+            rolls.0.push(rng.u32(..));
+            commands.spawn(rng);
+        },
+    );
+
+    assert_eq!(from_command, regular);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn missing_components_are_none() {
+    let mut world = World::new();
+    let entity = world.spawn_empty().id();
+
+    assert!(world.rng_component_for(entity).is_none());
+
+    world.despawn(entity);
+
+    assert!(world.rng_component_for(entity).is_none());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn forking_without_the_plugin_is_an_error() {
+    let mut world = World::new();
+
+    assert_eq!(
+        world.fork_rng(),
+        Err(MissingGlobalRng {
+            resource: "GlobalRng"
+        })
+    );
+
+    world.insert_resource(GlobalRng::with_seed(5));
+
+    assert_eq!(world.fork_rng(), Ok(GlobalRng::with_seed(5).fork()));
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_forks_match_resource_forks() {
+    let mut world = World::new();
+
+    assert!(world.fork_chacha().is_err());
+
+    world.insert_resource(GlobalChaChaRng::with_seed([3; 40]));
+
+    assert_eq!(
+        world.fork_chacha(),
+        Ok(GlobalChaChaRng::with_seed([3; 40]).fork())
+    );
+}